@@ -0,0 +1,42 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+from json import loads as json_loads
+
+import pytest
+
+from orjson import OPT_ASCII
+from orjson import dumps as orjson_dumps
+
+from .data import libraries
+
+# objects with many short ASCII keys, as are most dict keys and all dataclass
+# field names, and values that are cheap to serialize so that keys dominate
+KEYS = [f"field_{idx}" for idx in range(24)]
+
+DICTS = [dict.fromkeys(KEYS, 1) for _ in range(1000)]
+
+Record = dataclasses.make_dataclass("Record", KEYS)
+
+DATACLASSES = [Record(*([1] * len(KEYS))) for _ in range(1000)]
+
+
+@pytest.mark.parametrize("library", libraries)
+def test_dumps_short_keys(benchmark, library):
+    dumper, loader = libraries[library]
+    benchmark.group = "short keys serialization"
+    benchmark.extra_info["lib"] = library
+    benchmark.extra_info["correct"] = json_loads(dumper(DICTS)) == DICTS
+    benchmark(dumper, DICTS)
+
+
+def test_dumps_short_keys_ascii(benchmark):
+    benchmark.group = "short keys serialization"
+    benchmark.extra_info["lib"] = "orjson OPT_ASCII"
+    benchmark(orjson_dumps, DICTS, option=OPT_ASCII)
+
+
+def test_dumps_short_keys_dataclass(benchmark):
+    benchmark.group = "short keys serialization"
+    benchmark.extra_info["lib"] = "orjson dataclass"
+    benchmark(orjson_dumps, DATACLASSES)
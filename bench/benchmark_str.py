@@ -0,0 +1,27 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+from json import loads as json_loads
+
+import pytest
+
+from .data import libraries
+
+# str without escapes, by UTF-8 width, and of a length at which copying rather
+# than inspecting each byte dominates
+STRS = {
+    "ascii": "orjson serializes str without escapes quickly " * 1024,
+    "latin1": "üýþÿ" * 8192,
+    "bmp": "東京好〜" * 8192,
+    "astral": "\U0001f408\U0001f600" * 8192,
+}
+
+
+@pytest.mark.parametrize("library", libraries)
+@pytest.mark.parametrize("kind", STRS)
+def test_dumps_str(benchmark, kind, library):
+    dumper, loader = libraries[library]
+    benchmark.group = f"{kind} str serialization"
+    benchmark.extra_info["lib"] = library
+    data = [STRS[kind]] * 16
+    benchmark.extra_info["correct"] = json_loads(dumper(data)) == data
+    benchmark(dumper, data)
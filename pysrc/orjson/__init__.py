@@ -6,22 +6,82 @@ from .orjson import __version__
 __all__ = (
     "__version__",
     "dumps",
+    "dumps_into",
+    "dumps_lines",
+    "dumps_str",
+    "dumps_with_stats",
+    "Encoder",
     "Fragment",
     "JSONDecodeError",
     "JSONEncodeError",
+    "load",
     "loads",
+    "loads_ndarray",
+    "loads_typed",
+    "OPT_ALLOW_COMMENTS",
+    "OPT_ALLOW_NONFINITE",
+    "OPT_ALLOW_TRAILING_COMMA",
     "OPT_APPEND_NEWLINE",
+    "OPT_ASCII",
+    "OPT_CAMELCASE",
+    "OPT_CANONICAL",
+    "OPT_CAPITALIZE_BOOL",
+    "OPT_DATACLASS_FIELD_RENAME",
+    "OPT_DATACLASS_SKIP_MISSING",
+    "OPT_DECIMAL_AS_STRING",
+    "OPT_DICT_AS_PAIRS",
+    "OPT_ENUM_BY_NAME",
+    "OPT_ESCAPE_FORWARD_SLASH",
+    "OPT_FLOAT_FIXED",
+    "OPT_INCLUDE_PRIVATE_FIELDS",
     "OPT_INDENT_2",
+    "OPT_INDENT_4",
+    "OPT_INDENT_TAB",
+    "OPT_INTERN_KEYS",
+    "OPT_KEYS_AS_BYTES",
     "OPT_NAIVE_UTC",
+    "OPT_NANOSECOND",
     "OPT_NON_STR_KEYS",
+    "OPT_NULL_AS_NIL",
+    "OPT_NUMPY_NONFINITE_AS_NULL",
     "OPT_OMIT_MICROSECONDS",
+    "OPT_PARSE_DECIMAL",
+    "OPT_PARSE_UUID",
     "OPT_PASSTHROUGH_DATACLASS",
     "OPT_PASSTHROUGH_DATETIME",
+    "OPT_PASSTHROUGH_ENUM",
     "OPT_PASSTHROUGH_SUBCLASS",
+    "OPT_PRESERVE_ORDERED_DICT",
+    "OPT_REJECT_DUPLICATE_KEYS",
+    "OPT_REQUIRE_AWARE_DATETIME",
+    "OPT_SERIALIZE_ARRAY",
+    "OPT_SERIALIZE_COMPLEX",
     "OPT_SERIALIZE_DATACLASS",
+    "OPT_SERIALIZE_DECIMAL",
+    "OPT_SERIALIZE_EXCEPTION",
+    "OPT_SERIALIZE_FRACTION",
+    "OPT_SERIALIZE_IPADDRESS",
+    "OPT_SERIALIZE_ITERATORS",
+    "OPT_SERIALIZE_JSON_PROTOCOL",
+    "OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT",
     "OPT_SERIALIZE_NUMPY",
+    "OPT_SERIALIZE_PANDAS",
+    "OPT_SERIALIZE_PATH",
+    "OPT_SERIALIZE_PROXY",
+    "OPT_SERIALIZE_RANGE",
+    "OPT_SERIALIZE_SET",
     "OPT_SERIALIZE_UUID",
+    "OPT_SKIP_NONE_VALUES",
     "OPT_SORT_KEYS",
+    "OPT_SORT_KEYS_CASE_INSENSITIVE",
+    "OPT_SPACE_AFTER_COLON",
+    "OPT_SPACE_AFTER_COMMA",
     "OPT_STRICT_INTEGER",
+    "OPT_TIMEDELTA_ISO8601",
+    "OPT_TIMEDELTA_SECONDS",
     "OPT_UTC_Z",
+    "OPT_UTF8_BOM",
+    "OPT_VALIDATE_FRAGMENT",
+    "OPT_WHOLE_FLOAT_AS_INT",
+    "Serializer",
 )
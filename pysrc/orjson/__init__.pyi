@@ -1,5 +1,18 @@
 import json
-from typing import Any, Callable, Optional, Union
+from typing import (
+    AbstractSet,
+    Any,
+    Callable,
+    Iterable,
+    Literal,
+    Optional,
+    Type,
+    TypedDict,
+    TypeVar,
+    Union,
+)
+
+_T = TypeVar("_T")
 
 __version__: str
 
@@ -7,26 +20,236 @@ def dumps(
     __obj: Any,
     default: Optional[Callable[[Any], Any]] = ...,
     option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    include: Optional[AbstractSet[str]] = ...,
+    float_precision: Optional[int] = ...,
+    bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+    complex_format: Optional[Literal["object"]] = ...,
+    date_format: Optional[Literal["epoch_day"]] = ...,
+    datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+    exception_format: Optional[Literal["traceback"]] = ...,
+    fraction_format: Optional[Literal["float", "string"]] = ...,
+    range_format: Optional[Literal["array"]] = ...,
+    time_format: Optional[Literal["millis"]] = ...,
+    uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
 ) -> bytes: ...
-def loads(__obj: Union[bytes, bytearray, memoryview, str]) -> Any: ...
+def dumps_str(
+    __obj: Any,
+    default: Optional[Callable[[Any], Any]] = ...,
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    include: Optional[AbstractSet[str]] = ...,
+    float_precision: Optional[int] = ...,
+    bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+    complex_format: Optional[Literal["object"]] = ...,
+    date_format: Optional[Literal["epoch_day"]] = ...,
+    datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+    exception_format: Optional[Literal["traceback"]] = ...,
+    fraction_format: Optional[Literal["float", "string"]] = ...,
+    range_format: Optional[Literal["array"]] = ...,
+    time_format: Optional[Literal["millis"]] = ...,
+    uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+) -> str: ...
+class _DumpsStats(TypedDict):
+    fast_str_writes: int
+    slow_str_writes: int
+    max_depth: int
+    default_calls: int
+
+def dumps_with_stats(
+    __obj: Any,
+    default: Optional[Callable[[Any], Any]] = ...,
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    include: Optional[AbstractSet[str]] = ...,
+    float_precision: Optional[int] = ...,
+    bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+    complex_format: Optional[Literal["object"]] = ...,
+    date_format: Optional[Literal["epoch_day"]] = ...,
+    datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+    exception_format: Optional[Literal["traceback"]] = ...,
+    fraction_format: Optional[Literal["float", "string"]] = ...,
+    range_format: Optional[Literal["array"]] = ...,
+    time_format: Optional[Literal["millis"]] = ...,
+    uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+) -> tuple[bytes, _DumpsStats]: ...
+def dumps_into(
+    __obj: Any,
+    __buffer: bytearray,
+    default: Optional[Callable[[Any], Any]] = ...,
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    include: Optional[AbstractSet[str]] = ...,
+    float_precision: Optional[int] = ...,
+    bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+    complex_format: Optional[Literal["object"]] = ...,
+    date_format: Optional[Literal["epoch_day"]] = ...,
+    datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+    exception_format: Optional[Literal["traceback"]] = ...,
+    fraction_format: Optional[Literal["float", "string"]] = ...,
+    range_format: Optional[Literal["array"]] = ...,
+    time_format: Optional[Literal["millis"]] = ...,
+    uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+) -> int: ...
+def dumps_lines(
+    __obj: Iterable[Any],
+    default: Optional[Callable[[Any], Any]] = ...,
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    include: Optional[AbstractSet[str]] = ...,
+    float_precision: Optional[int] = ...,
+    bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+    complex_format: Optional[Literal["object"]] = ...,
+    date_format: Optional[Literal["epoch_day"]] = ...,
+    datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+    exception_format: Optional[Literal["traceback"]] = ...,
+    fraction_format: Optional[Literal["float", "string"]] = ...,
+    range_format: Optional[Literal["array"]] = ...,
+    time_format: Optional[Literal["millis"]] = ...,
+    uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+) -> bytes: ...
+def load(
+    __fp: Any,
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    object_hook: Optional[Callable[[dict[str, Any]], Any]] = ...,
+    array_hook: Optional[Callable[[list[Any]], Any]] = ...,
+) -> Any: ...
+def loads(
+    __obj: Union[bytes, bytearray, memoryview, str],
+    option: Optional[int] = ...,
+    *,
+    max_depth: Optional[int] = ...,
+    object_hook: Optional[Callable[[dict[str, Any]], Any]] = ...,
+    array_hook: Optional[Callable[[list[Any]], Any]] = ...,
+) -> Any: ...
+def loads_ndarray(
+    __obj: Union[bytes, bytearray, memoryview, str],
+    dtype: Any = ...,
+) -> Any: ...
+def loads_typed(
+    __obj: Union[bytes, bytearray, memoryview, str],
+    __cls: Type[_T],
+    *,
+    strict: bool = ...,
+) -> _T: ...
+
+class JSONDecodeError(json.JSONDecodeError):
+    snippet: Optional[str]
 
-class JSONDecodeError(json.JSONDecodeError): ...
 class JSONEncodeError(TypeError): ...
 
+class Encoder:
+    def __init__(
+        self,
+        default: Optional[Callable[[Any], Any]] = ...,
+        option: Optional[int] = ...,
+        *,
+        bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+        complex_format: Optional[Literal["object"]] = ...,
+        date_format: Optional[Literal["epoch_day"]] = ...,
+        datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+        exception_format: Optional[Literal["traceback"]] = ...,
+        fraction_format: Optional[Literal["float", "string"]] = ...,
+        range_format: Optional[Literal["array"]] = ...,
+        time_format: Optional[Literal["millis"]] = ...,
+        uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+    ) -> None: ...
+    def write_chunk(self, __obj: Any) -> bytes: ...
+    def close(self) -> bytes: ...
+
 class Fragment(tuple):
     contents: Union[bytes, str]
 
+class Serializer:
+    def __init__(
+        self,
+        default: Optional[Callable[[Any], Any]] = ...,
+        option: Optional[int] = ...,
+        *,
+        max_depth: Optional[int] = ...,
+        include: Optional[AbstractSet[str]] = ...,
+        float_precision: Optional[int] = ...,
+        bytes_format: Optional[Literal["base64", "base64url"]] = ...,
+        complex_format: Optional[Literal["object"]] = ...,
+        date_format: Optional[Literal["epoch_day"]] = ...,
+        datetime_format: Optional[Literal["rfc2822", "tz_name"]] = ...,
+        exception_format: Optional[Literal["traceback"]] = ...,
+        fraction_format: Optional[Literal["float", "string"]] = ...,
+        range_format: Optional[Literal["array"]] = ...,
+        time_format: Optional[Literal["millis"]] = ...,
+        uuid_format: Optional[Literal["int", "base64", "base64url"]] = ...,
+    ) -> None: ...
+    def dumps(self, __obj: Any) -> bytes: ...
+
+OPT_ALLOW_COMMENTS: int
+OPT_ALLOW_NONFINITE: int
+OPT_ALLOW_TRAILING_COMMA: int
 OPT_APPEND_NEWLINE: int
+OPT_ASCII: int
+OPT_CAMELCASE: int
+OPT_CANONICAL: int
+OPT_CAPITALIZE_BOOL: int
+OPT_DATACLASS_FIELD_RENAME: int
+OPT_DATACLASS_SKIP_MISSING: int
+OPT_DECIMAL_AS_STRING: int
+OPT_DICT_AS_PAIRS: int
+OPT_ENUM_BY_NAME: int
+OPT_ESCAPE_FORWARD_SLASH: int
+OPT_FLOAT_FIXED: int
+OPT_INCLUDE_PRIVATE_FIELDS: int
 OPT_INDENT_2: int
+OPT_INDENT_4: int
+OPT_INDENT_TAB: int
+OPT_INTERN_KEYS: int
+OPT_KEYS_AS_BYTES: int
 OPT_NAIVE_UTC: int
+OPT_NANOSECOND: int
 OPT_NON_STR_KEYS: int
+OPT_NULL_AS_NIL: int
+OPT_NUMPY_NONFINITE_AS_NULL: int
 OPT_OMIT_MICROSECONDS: int
+OPT_PARSE_DECIMAL: int
+OPT_PARSE_UUID: int
 OPT_PASSTHROUGH_DATACLASS: int
 OPT_PASSTHROUGH_DATETIME: int
+OPT_PASSTHROUGH_ENUM: int
 OPT_PASSTHROUGH_SUBCLASS: int
+OPT_PRESERVE_ORDERED_DICT: int
+OPT_REJECT_DUPLICATE_KEYS: int
+OPT_REQUIRE_AWARE_DATETIME: int
+OPT_SERIALIZE_ARRAY: int
+OPT_SERIALIZE_COMPLEX: int
 OPT_SERIALIZE_DATACLASS: int
+OPT_SERIALIZE_DECIMAL: int
+OPT_SERIALIZE_EXCEPTION: int
+OPT_SERIALIZE_FRACTION: int
+OPT_SERIALIZE_IPADDRESS: int
+OPT_SERIALIZE_ITERATORS: int
+OPT_SERIALIZE_JSON_PROTOCOL: int
+OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT: int
 OPT_SERIALIZE_NUMPY: int
+OPT_SERIALIZE_PANDAS: int
+OPT_SERIALIZE_PATH: int
+OPT_SERIALIZE_PROXY: int
+OPT_SERIALIZE_RANGE: int
+OPT_SERIALIZE_SET: int
 OPT_SERIALIZE_UUID: int
+OPT_SKIP_NONE_VALUES: int
 OPT_SORT_KEYS: int
+OPT_SORT_KEYS_CASE_INSENSITIVE: int
+OPT_SPACE_AFTER_COLON: int
+OPT_SPACE_AFTER_COMMA: int
 OPT_STRICT_INTEGER: int
+OPT_TIMEDELTA_ISO8601: int
+OPT_TIMEDELTA_SECONDS: int
 OPT_UTC_Z: int
+OPT_UTF8_BOM: int
+OPT_VALIDATE_FRAGMENT: int
+OPT_WHOLE_FLOAT_AS_INT: int
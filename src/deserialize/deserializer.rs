@@ -1,17 +1,39 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
-use crate::deserialize::utf8::read_input_to_buf;
+use crate::deserialize::pyobject::take_maybe_big_int;
+use crate::deserialize::utf8::{read_input_to_buf, skip_bom};
 use crate::deserialize::DeserializeError;
+use crate::opt::Opt;
 use crate::typeref::EMPTY_UNICODE;
 use core::ptr::NonNull;
 
 pub fn deserialize(
     ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+    max_depth: Option<usize>,
+    object_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+    array_hook: Option<NonNull<pyo3_ffi::PyObject>>,
 ) -> Result<NonNull<pyo3_ffi::PyObject>, DeserializeError<'static>> {
     debug_assert!(ffi!(Py_REFCNT(ptr)) >= 1);
-    let buffer = read_input_to_buf(ptr)?;
+    let input = read_input_to_buf(ptr)?;
+    let (skipped, buffer) = skip_bom(input)?;
+    if unlikely!(skipped != 0) {
+        let input_str = unsafe { std::str::from_utf8_unchecked(input) };
+        return deserialize_buffer(buffer, opts, max_depth, object_hook, array_hook)
+            .map_err(|err| err.in_input(input_str, skipped));
+    }
+    deserialize_buffer(buffer, opts, max_depth, object_hook, array_hook)
+}
 
-    if unlikely!(buffer.len() == 2) {
+fn deserialize_buffer(
+    buffer: &'static [u8],
+    opts: Opt,
+    max_depth: Option<usize>,
+    object_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+    array_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+) -> Result<NonNull<pyo3_ffi::PyObject>, DeserializeError<'static>> {
+    let hooked = object_hook.is_some() || array_hook.is_some();
+    if unlikely!(buffer.len() == 2 && max_depth != Some(0) && !hooked) {
         if buffer == b"[]" {
             return Ok(nonnull!(ffi!(PyList_New(0))));
         } else if buffer == b"{}" {
@@ -23,13 +45,43 @@ pub fn deserialize(
 
     let buffer_str = unsafe { std::str::from_utf8_unchecked(buffer) };
 
-    #[cfg(feature = "yyjson")]
-    {
-        crate::deserialize::yyjson::deserialize_yyjson(buffer_str)
+    if unlikely!(opts != 0 || max_depth.is_some() || hooked) {
+        return crate::deserialize::parser::deserialize_parser(
+            buffer_str,
+            opts,
+            max_depth,
+            object_hook,
+            array_hook,
+        );
     }
 
+    #[cfg(feature = "yyjson")]
+    let res = crate::deserialize::yyjson::deserialize_yyjson(buffer_str);
+
+    // serde_json differs from yyjson in its error messages and positions and
+    // in its recursion limit of 128, so a document it rejects is parsed again
+    // by the parser, whose errors match those of yyjson.
+    #[cfg(not(feature = "yyjson"))]
+    let res = crate::deserialize::json::deserialize_json(buffer_str);
     #[cfg(not(feature = "yyjson"))]
-    {
-        crate::deserialize::json::deserialize_json(buffer_str)
+    if unlikely!(res.is_err()) {
+        take_maybe_big_int();
+        return crate::deserialize::parser::deserialize_parser(
+            buffer_str, opts, max_depth, None, None,
+        );
+    }
+
+    // yyjson and serde_json read an integer that overflows 64 bits as a
+    // float, or reject it if it exceeds the range of a float, so a document
+    // that may have one is parsed again by the parser, which reads it as an
+    // exact int.
+    if unlikely!(take_maybe_big_int()) {
+        if let Ok(val) = res {
+            ffi!(Py_DECREF(val.as_ptr()));
+        }
+        return crate::deserialize::parser::deserialize_parser(
+            buffer_str, opts, max_depth, None, None,
+        );
     }
+    res
 }
@@ -2,15 +2,17 @@
 
 use std::borrow::Cow;
 
+/// The number of characters before and after an error to include in its
+/// snippet.
+const SNIPPET_CHARS: usize = 40;
+
 pub struct DeserializeError<'a> {
     pub message: Cow<'a, str>,
-    #[cfg(not(feature = "yyjson"))]
-    pub line: usize, // start at 1
-    #[cfg(not(feature = "yyjson"))]
-    pub column: usize, // start at 1
     pub data: Option<&'a str>,
-    #[cfg(feature = "yyjson")]
     pub pos: i64,
+    /// The Python exception, e.g., raised by `object_hook`, is already set
+    /// and is to be propagated rather than replaced by `JSONDecodeError`.
+    pub raised: bool,
 }
 
 impl<'a> DeserializeError<'a> {
@@ -18,24 +20,18 @@ impl<'a> DeserializeError<'a> {
     pub fn invalid(message: Cow<'a, str>) -> Self {
         DeserializeError {
             message: message,
-            #[cfg(not(feature = "yyjson"))]
-            line: 0,
-            #[cfg(not(feature = "yyjson"))]
-            column: 0,
             data: None,
-            #[cfg(feature = "yyjson")]
             pos: 0,
+            raised: false,
         }
     }
 
+    /// An error for the Python exception that is already set.
     #[cold]
-    #[cfg(not(feature = "yyjson"))]
-    pub fn from_json(message: Cow<'a, str>, line: usize, column: usize, data: &'a str) -> Self {
+    pub fn raised() -> Self {
         DeserializeError {
-            message,
-            line,
-            column,
-            data: Some(data),
+            raised: true,
+            ..DeserializeError::invalid(Cow::Borrowed(""))
         }
     }
 
@@ -46,12 +42,51 @@ impl<'a> DeserializeError<'a> {
             message: message,
             data: Some(data),
             pos: pos,
+            raised: false,
+        }
+    }
+
+    /// An error at the 1-based `line` and byte `column` in `data`, from
+    /// serde_json.
+    #[cold]
+    #[cfg(not(feature = "yyjson"))]
+    pub fn from_json(message: Cow<'a, str>, line: usize, column: usize, data: &'a str) -> Self {
+        let line_start = data
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>();
+        let mut pos = (line_start + column.saturating_sub(1)).min(data.len());
+        while !data.is_char_boundary(pos) {
+            pos -= 1;
         }
+        DeserializeError::from_parser(message, pos, data)
+    }
+
+    /// An error at the byte offset `pos` in `data`, from `parser`.
+    #[cold]
+    pub fn from_parser(message: Cow<'a, str>, pos: usize, data: &'a str) -> Self {
+        DeserializeError {
+            message: message,
+            data: Some(data),
+            pos: pos as i64,
+            raised: false,
+        }
+    }
+
+    /// Locate the error in `data`, of which the `skipped` bytes before the
+    /// part that was parsed, e.g., a byte order mark, were not.
+    #[cold]
+    pub fn in_input(mut self, data: &'a str, skipped: usize) -> Self {
+        if self.data.is_some() {
+            self.data = Some(data);
+            self.pos += skipped as i64;
+        }
+        self
     }
 
     /// Return position of the error in the deserialized data
     #[cold]
-    #[cfg(feature = "yyjson")]
     #[cfg_attr(feature = "optimize", optimize(size))]
     pub fn pos(&self) -> i64 {
         match self.data {
@@ -60,47 +95,37 @@ impl<'a> DeserializeError<'a> {
         }
     }
 
-    /// Return position of the error in the deserialized data
+    /// Return the part of the line containing the error within
+    /// `SNIPPET_CHARS` characters of it, and the position of the error in
+    /// it, both in characters.
     #[cold]
-    #[cfg(not(feature = "yyjson"))]
     #[cfg_attr(feature = "optimize", optimize(size))]
-    pub fn pos(&self) -> i64 {
-        if self.line == 0 || self.data.is_none() {
-            return 1;
+    pub fn snippet(&self) -> Option<(&'a str, usize)> {
+        let data = self.data?;
+        let pos = self.pos() as usize;
+        let offset = data
+            .char_indices()
+            .nth(pos)
+            .map_or(data.len(), |(idx, _)| idx);
+        let line_start = data[..offset].rfind(['\n', '\r']).map_or(0, |idx| idx + 1);
+        let line_end = data[offset..]
+            .find(['\n', '\r'])
+            .map_or(data.len(), |idx| offset + idx);
+        let before = &data[line_start..offset];
+        let skip = before.chars().count().saturating_sub(SNIPPET_CHARS);
+        let start = before
+            .char_indices()
+            .nth(skip)
+            .map_or(offset, |(idx, _)| line_start + idx);
+        let after = &data[offset..line_end];
+        let end = after
+            .char_indices()
+            .nth(SNIPPET_CHARS)
+            .map_or(line_end, |(idx, _)| offset + idx);
+        let snippet = &data[start..end];
+        if snippet.trim().is_empty() {
+            return None;
         }
-
-        let val = self.data.unwrap()
-            .split('\n')
-            // take only the relevant lines
-            .take(self.line)
-            .enumerate()
-            .map(|(idx, s)| {
-                if idx == self.line - 1 {
-                    // Last line: only characters until the column of the error are relevant.
-                    // Note: Rust uses bytes whereas Python uses chars: we hence cannot
-                    //       directly use the `column` field
-                    if self.column == 0 { return 0; }
-
-                    // Find a column we can safely slice on
-                    let mut column = self.column - 1;
-                    while column > 0 && !s.is_char_boundary(column) {
-                        column -= 1;
-                    }
-
-                    let chars_count = s[..column].chars().count();
-                    if chars_count == s.chars().count() - 1 {
-                        chars_count + 1
-                    } else {
-                        chars_count
-                    }
-                } else {
-                    // Other lines
-                    s.chars().count()
-                }
-            })
-            .sum::<usize>()
-            // add missed '\n' characters
-            + (self.line - 1);
-        val as i64
+        Some((snippet, data[start..offset].chars().count()))
     }
 }
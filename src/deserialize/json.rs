@@ -80,7 +80,7 @@ impl<'de> Visitor<'de> for JsonValue {
     where
         E: de::Error,
     {
-        Ok(parse_f64(value))
+        Ok(parse_backend_f64(value))
     }
 
     fn visit_borrowed_str<E>(self, value: &str) -> Result<Self::Value, E>
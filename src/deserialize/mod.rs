@@ -3,7 +3,10 @@
 mod cache;
 mod deserializer;
 mod error;
+mod ndarray;
+mod parser;
 mod pyobject;
+mod typed;
 mod utf8;
 
 #[cfg(not(feature = "yyjson"))]
@@ -15,3 +18,6 @@ mod yyjson;
 pub use cache::{KeyMap, KEY_MAP};
 pub use deserializer::deserialize;
 pub use error::DeserializeError;
+pub use ndarray::deserialize_ndarray;
+pub use typed::deserialize_typed;
+pub use utf8::{buffer_protocol_view, is_valid_utf8};
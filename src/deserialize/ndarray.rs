@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::deserialize::parser::read_number_array;
+use crate::deserialize::utf8::{read_input_to_buf, skip_bom};
+use crate::deserialize::DeserializeError;
+use crate::serialize::{PyArrayInterface, PyCapsule, NPY_ARRAY_NOTSWAPPED};
+use crate::typeref::{load_numpy_types, NumpyTypes, ARRAY_STRUCT_STR, NUMPY_TYPES};
+use core::ffi::c_char;
+use core::ptr::{null_mut, NonNull};
+use std::borrow::Cow;
+
+/// The datatype of the elements of the array.
+#[derive(Clone, Copy)]
+enum Element {
+    F64,
+    F32,
+    I64,
+    I32,
+    I16,
+    I8,
+    U64,
+    U32,
+    U16,
+    U8,
+}
+
+impl Element {
+    /// Map `dtype.kind` and `dtype.itemsize` to an `Element`.
+    fn from_kind(typekind: c_char, itemsize: i32) -> Option<Element> {
+        match (typekind as u8, itemsize) {
+            (b'f', 8) => Some(Element::F64),
+            (b'f', 4) => Some(Element::F32),
+            (b'i', 8) => Some(Element::I64),
+            (b'i', 4) => Some(Element::I32),
+            (b'i', 2) => Some(Element::I16),
+            (b'i', 1) => Some(Element::I8),
+            (b'u', 8) => Some(Element::U64),
+            (b'u', 4) => Some(Element::U32),
+            (b'u', 2) => Some(Element::U16),
+            (b'u', 1) => Some(Element::U8),
+            _ => None,
+        }
+    }
+
+    fn itemsize(self) -> usize {
+        match self {
+            Element::F64 | Element::I64 | Element::U64 => 8,
+            Element::F32 | Element::I32 | Element::U32 => 4,
+            Element::I16 | Element::U16 => 2,
+            Element::I8 | Element::U8 => 1,
+        }
+    }
+
+    /// Append the number `token` converted to this datatype to `buf`.
+    fn push(self, buf: &mut Vec<u8>, token: &str, is_float: bool) -> Result<(), &'static str> {
+        macro_rules! push_int {
+            ($ty:ty) => {{
+                if is_float {
+                    return Err("number is not an integer");
+                }
+                let val = if token.as_bytes()[0] == b'-' {
+                    token
+                        .parse::<i64>()
+                        .ok()
+                        .and_then(|val| <$ty>::try_from(val).ok())
+                } else {
+                    token
+                        .parse::<u64>()
+                        .ok()
+                        .and_then(|val| <$ty>::try_from(val).ok())
+                };
+                match val {
+                    Some(val) => buf.extend_from_slice(&val.to_ne_bytes()),
+                    None => return Err("number is out of range for dtype"),
+                }
+            }};
+        }
+
+        match self {
+            Element::F64 | Element::F32 => {
+                let val = match token.parse::<f64>() {
+                    Ok(val) if val.is_finite() => val,
+                    _ => return Err("number is infinity when parsed as double"),
+                };
+                if let Element::F32 = self {
+                    let val = val as f32;
+                    if unlikely!(val.is_infinite()) {
+                        return Err("number is out of range for dtype");
+                    }
+                    buf.extend_from_slice(&val.to_ne_bytes());
+                } else {
+                    buf.extend_from_slice(&val.to_ne_bytes());
+                }
+            }
+            Element::I64 => push_int!(i64),
+            Element::I32 => push_int!(i32),
+            Element::I16 => push_int!(i16),
+            Element::I8 => push_int!(i8),
+            Element::U64 => push_int!(u64),
+            Element::U32 => push_int!(u32),
+            Element::U16 => push_int!(u16),
+            Element::U8 => push_int!(u8),
+        }
+        Ok(())
+    }
+}
+
+/// A new one-dimensional `numpy.ndarray` and its `__array_struct__`.
+struct NewArray {
+    array: *mut pyo3_ffi::PyObject,
+    capsule: *mut pyo3_ffi::PyObject,
+}
+
+impl NewArray {
+    /// Create an uninitialized array of `len` elements of `dtype`.
+    fn new(
+        numpy: &NumpyTypes,
+        len: usize,
+        dtype: *mut pyo3_ffi::PyObject,
+    ) -> Result<Self, DeserializeError<'static>> {
+        let shape = ffi!(PyTuple_New(1));
+        ffi!(PyTuple_SET_ITEM(shape, 0, ffi!(PyLong_FromSize_t(len))));
+        let array = unsafe {
+            pyo3_ffi::PyObject_CallFunctionObjArgs(
+                numpy.array as *mut pyo3_ffi::PyObject,
+                shape,
+                dtype,
+                null_mut::<pyo3_ffi::PyObject>(),
+            )
+        };
+        ffi!(Py_DECREF(shape));
+        if unlikely!(array.is_null()) {
+            return Err(DeserializeError::raised());
+        }
+        let capsule = ffi!(PyObject_GetAttr(array, ARRAY_STRUCT_STR));
+        if unlikely!(capsule.is_null()) {
+            ffi!(Py_DECREF(array));
+            return Err(DeserializeError::raised());
+        }
+        Ok(NewArray {
+            array: array,
+            capsule: capsule,
+        })
+    }
+
+    fn interface(&self) -> *mut PyArrayInterface {
+        unsafe { (*(self.capsule as *mut PyCapsule)).pointer as *mut PyArrayInterface }
+    }
+
+    /// The datatype of the array, if it is supported.
+    fn element(&self) -> Option<Element> {
+        let interface = self.interface();
+        unsafe {
+            if (*interface).two != 2 || (*interface).flags & NPY_ARRAY_NOTSWAPPED == 0 {
+                return None;
+            }
+            Element::from_kind((*interface).typekind, (*interface).itemsize)
+        }
+    }
+
+    /// Copy `buf` to the data of the array and return the array.
+    fn finish(self, buf: &[u8]) -> NonNull<pyo3_ffi::PyObject> {
+        if !buf.is_empty() {
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    buf.as_ptr(),
+                    (*self.interface()).data as *mut u8,
+                    buf.len(),
+                );
+            }
+        }
+        ffi!(Py_DECREF(self.capsule));
+        nonnull!(self.array)
+    }
+
+    fn release(self) {
+        ffi!(Py_DECREF(self.capsule));
+        ffi!(Py_DECREF(self.array));
+    }
+}
+
+#[cold]
+fn dtype_error() -> DeserializeError<'static> {
+    unsafe {
+        pyo3_ffi::PyErr_SetString(
+            pyo3_ffi::PyExc_TypeError,
+            "loads_ndarray() dtype must be a native numpy integer or floating type\0".as_ptr()
+                as *const c_char,
+        )
+    };
+    DeserializeError::raised()
+}
+
+/// Deserialize a flat array of numbers to a one-dimensional `numpy.ndarray`
+/// of `dtype`, e.g., `numpy.float32`. Numbers are converted as they are read,
+/// without creating a Python object for each. An element that is not a
+/// number, a number with a fraction or exponent for an integer `dtype`, or a
+/// number out of the range of `dtype` is an error.
+pub fn deserialize_ndarray(
+    ptr: *mut pyo3_ffi::PyObject,
+    dtype: *mut pyo3_ffi::PyObject,
+) -> Result<NonNull<pyo3_ffi::PyObject>, DeserializeError<'static>> {
+    let numpy = match unsafe { (*core::ptr::addr_of!(NUMPY_TYPES)).get_or_init(load_numpy_types) } {
+        Some(numpy) => unsafe { numpy.as_ref() },
+        None => {
+            return Err(DeserializeError::invalid(Cow::Borrowed(
+                "loads_ndarray() could not import numpy",
+            )))
+        }
+    };
+    let dtype = if dtype.is_null() {
+        numpy.float64 as *mut pyo3_ffi::PyObject
+    } else {
+        dtype
+    };
+    let probe = NewArray::new(numpy, 0, dtype)?;
+    let element = probe.element();
+    probe.release();
+    let element = element.ok_or_else(dtype_error)?;
+
+    let input = read_input_to_buf(ptr)?;
+    let (skipped, buffer) = skip_bom(input)?;
+    let data = unsafe { std::str::from_utf8_unchecked(buffer) };
+    let mut buf: Vec<u8> = Vec::new();
+    read_number_array(data, |token, is_float| {
+        element.push(&mut buf, token, is_float)
+    })
+    .map_err(|err| err.in_input(unsafe { std::str::from_utf8_unchecked(input) }, skipped))?;
+
+    let array = NewArray::new(numpy, buf.len() / element.itemsize(), dtype)?;
+    Ok(array.finish(&buf))
+}
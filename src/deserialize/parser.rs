@@ -0,0 +1,985 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::deserialize::pyobject::*;
+use crate::deserialize::DeserializeError;
+use crate::opt::{
+    Opt, ALLOW_COMMENTS, ALLOW_NONFINITE, ALLOW_TRAILING_COMMA, DICT_AS_PAIRS, INTERN_KEYS,
+    KEYS_AS_BYTES, PARSE_DECIMAL, PARSE_UUID, REJECT_DUPLICATE_KEYS, WHOLE_FLOAT_AS_INT,
+};
+use crate::str::unicode_from_str;
+use crate::typeref::{DECIMAL_TYPE, INT_ATTR_STR, LIST_TYPE, UUID_TYPE};
+use core::ptr::{null_mut, NonNull};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+const RECURSION_LIMIT: usize = 1024;
+
+/// The number of distinct keys shared by `OPT_INTERN_KEYS` in one call. Keys
+/// after this are created as without the option.
+const INTERN_KEYS_LIMIT: usize = 4096;
+
+/// Deserialize with options that the yyjson and serde_json backends do not
+/// support. This is used only if such an option is specified, or to parse
+/// again a document that a backend may have read an integer beyond 64 bits
+/// of as a float, or that serde_json rejected. Containers are parsed using a
+/// stack of frames rather than recursion, and error messages match those of
+/// yyjson. If `max_depth` is given, it replaces the recursion limit of 1024
+/// and the error reports the depth and byte offset. If `object_hook` is
+/// given, each object is replaced by the result of calling it with the `dict`
+/// once the object is complete, and likewise each array if `array_hook` is
+/// given.
+///
+/// This is not done by walking the yyjson document because the values in it
+/// do not record their position in the input, which the errors for
+/// `max_depth` and `OPT_REJECT_DUPLICATE_KEYS` report, and because the same
+/// options must be supported if built without yyjson, which serde_json does
+/// not. `test_parsing.py` checks that it accepts and rejects every document
+/// of JSONTestSuite as yyjson does, with the same errors.
+pub fn deserialize_parser(
+    data: &'static str,
+    opts: Opt,
+    max_depth: Option<usize>,
+    object_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+    array_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+) -> Result<NonNull<pyo3_ffi::PyObject>, DeserializeError<'static>> {
+    let mut parser = Parser {
+        data: data,
+        pos: 0,
+        opts: opts,
+        max_depth: max_depth,
+        object_hook: object_hook,
+        array_hook: array_hook,
+        scratch: String::new(),
+        keys: if opts & INTERN_KEYS != 0 {
+            Some(KeyTable::default())
+        } else {
+            None
+        },
+    };
+    if unlikely!(data.trim_start_matches([' ', '\t', '\n', '\r']).is_empty()) {
+        return Err(parser.error("input data is empty", 0));
+    }
+    let mut stack: Vec<Frame> = Vec::new();
+    let res = parser.parse(&mut stack);
+    if unlikely!(res.is_err()) {
+        for frame in stack.drain(..) {
+            frame.release();
+        }
+    }
+    res
+}
+
+/// Read a flat array of numbers, as for `loads_ndarray()`, calling `push` with
+/// the text of each number and whether it has a fraction or exponent. If
+/// `push` returns a message, e.g., because the number is out of range, it is
+/// the error at the number. Any other element is an error.
+pub fn read_number_array<F>(data: &'static str, mut push: F) -> ParseResult<()>
+where
+    F: FnMut(&str, bool) -> Result<(), &'static str>,
+{
+    let mut parser = Parser {
+        data: data,
+        pos: 0,
+        opts: 0,
+        max_depth: None,
+        object_hook: None,
+        array_hook: None,
+        scratch: String::new(),
+        keys: None,
+    };
+    parser.skip_whitespace()?;
+    match parser.peek() {
+        Some(b'[') => parser.pos += 1,
+        None => return Err(parser.error("input data is empty", 0)),
+        Some(_) => return Err(parser.error("expected array of numbers", parser.pos)),
+    }
+    parser.skip_whitespace()?;
+    if parser.peek() == Some(b']') {
+        parser.pos += 1;
+    } else {
+        loop {
+            match parser.peek() {
+                Some(b'-' | b'0'..=b'9') => (),
+                None => return Err(parser.unexpected()),
+                Some(_) => return Err(parser.error("array element is not a number", parser.pos)),
+            }
+            let start = parser.pos;
+            let is_float = parser.read_number()?;
+            if let Err(message) = push(&data[start..parser.pos], is_float) {
+                return Err(parser.error(message, start));
+            }
+            parser.skip_whitespace()?;
+            match parser.peek() {
+                Some(b',') => {
+                    parser.pos += 1;
+                    parser.skip_whitespace()?;
+                }
+                Some(b']') => {
+                    parser.pos += 1;
+                    break;
+                }
+                _ => return Err(parser.unexpected()),
+            }
+        }
+    }
+    parser.skip_whitespace()?;
+    if unlikely!(parser.pos < data.len()) {
+        return Err(parser.error("unexpected content after document", parser.pos));
+    }
+    Ok(())
+}
+
+type ParseResult<T> = Result<T, DeserializeError<'static>>;
+
+/// An array or object whose closing bracket has not yet been read. An array
+/// has the byte offset of its opening bracket and, with `OPT_DICT_AS_PAIRS`,
+/// the spans of its items.
+enum Frame {
+    Array(Vec<*mut pyo3_ffi::PyObject>, usize, Vec<ItemSpan>),
+    Object {
+        dict: *mut pyo3_ffi::PyObject,
+        key: *mut pyo3_ffi::PyObject,
+        key_span: (usize, usize),
+    },
+}
+
+/// The bytes of a value in the document and, if it is an array, of its first
+/// item, which is the key if the array is a pair of `OPT_DICT_AS_PAIRS`.
+#[derive(Clone, Copy)]
+struct ItemSpan {
+    item: (usize, usize),
+    key: (usize, usize),
+}
+
+impl Frame {
+    #[cold]
+    fn release(self) {
+        match self {
+            Frame::Array(items, _, _) => {
+                for item in items {
+                    ffi!(Py_DECREF(item));
+                }
+            }
+            Frame::Object { dict, key, .. } => {
+                ffi!(Py_DECREF(dict));
+                if !key.is_null() {
+                    ffi!(Py_DECREF(key));
+                }
+            }
+        }
+    }
+}
+
+struct Parser {
+    data: &'static str,
+    pos: usize,
+    opts: Opt,
+    max_depth: Option<usize>,
+    object_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+    array_hook: Option<NonNull<pyo3_ffi::PyObject>>,
+    scratch: String,
+    keys: Option<KeyTable>,
+}
+
+/// Object keys already read in this call, for `OPT_INTERN_KEYS`, so that
+/// equal keys anywhere in the document are the same `str`. The table holds a
+/// reference to each key until the call returns.
+#[derive(Default)]
+struct KeyTable {
+    map: HashMap<String, *mut pyo3_ffi::PyObject, ahash::RandomState>,
+}
+
+impl KeyTable {
+    fn get(&mut self, key: &str, opts: Opt) -> *mut pyo3_ffi::PyObject {
+        if let Some(&pykey) = self.map.get(key) {
+            ffi!(Py_INCREF(pykey));
+            return pykey;
+        }
+        let pykey = create_key(key, opts);
+        if self.map.len() < INTERN_KEYS_LIMIT {
+            ffi!(Py_INCREF(pykey));
+            self.map.insert(String::from(key), pykey);
+        }
+        pykey
+    }
+}
+
+impl Drop for KeyTable {
+    fn drop(&mut self) {
+        for (_, pykey) in self.map.drain() {
+            ffi!(Py_DECREF(pykey));
+        }
+    }
+}
+
+impl Parser {
+    #[inline(always)]
+    fn peek(&self) -> Option<u8> {
+        self.data.as_bytes().get(self.pos).copied()
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn error(&self, message: &'static str, pos: usize) -> DeserializeError<'static> {
+        DeserializeError::from_parser(Cow::Borrowed(message), pos, self.data)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn unexpected(&self) -> DeserializeError<'static> {
+        if self.pos >= self.data.len() {
+            self.error("unexpected end of data", self.data.len())
+        } else {
+            self.error("unexpected character", self.pos)
+        }
+    }
+
+    /// An error for a key, as written in the document, that is a duplicate of
+    /// one earlier in the same object.
+    #[cold]
+    #[inline(never)]
+    fn duplicate_key(&self, key_span: (usize, usize)) -> DeserializeError<'static> {
+        let (start, end) = key_span;
+        let message = format!(
+            "duplicate key {} in object at byte {}",
+            &self.data[start..end],
+            start
+        );
+        DeserializeError::from_parser(Cow::Owned(message), start, self.data)
+    }
+
+    /// The error for a container at `depth` opened beyond `max_depth`.
+    #[cold]
+    #[inline(never)]
+    fn max_depth_exceeded(&self, depth: usize, max_depth: usize) -> DeserializeError<'static> {
+        let message = format!(
+            "array and object nesting depth {} exceeds max_depth of {} at byte {}",
+            depth, max_depth, self.pos
+        );
+        DeserializeError::from_parser(Cow::Owned(message), self.pos, self.data)
+    }
+
+    /// Skip whitespace and, if `OPT_ALLOW_COMMENTS`, comments.
+    #[inline(always)]
+    fn skip_whitespace(&mut self) -> ParseResult<()> {
+        loop {
+            match self.peek() {
+                Some(b' ' | b'\t' | b'\n' | b'\r') => self.pos += 1,
+                Some(b'/') if opt_enabled!(self.opts, ALLOW_COMMENTS) => self.skip_comment()?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Skip a `//` comment through the end of the line or a `/* */` comment.
+    #[cold]
+    #[inline(never)]
+    fn skip_comment(&mut self) -> ParseResult<()> {
+        let bytes = self.data.as_bytes();
+        let start = self.pos;
+        match bytes.get(start + 1) {
+            Some(b'/') => {
+                self.pos = match bytes[start + 2..].iter().position(|&ch| ch == b'\n') {
+                    Some(idx) => start + 2 + idx + 1,
+                    None => bytes.len(),
+                };
+                Ok(())
+            }
+            Some(b'*') => match self.data[start + 2..].find("*/") {
+                Some(idx) => {
+                    self.pos = start + 2 + idx + 2;
+                    Ok(())
+                }
+                None => Err(self.error("unclosed multiline comment", start)),
+            },
+            _ => Err(self.error("unexpected character", start)),
+        }
+    }
+
+    fn parse(&mut self, stack: &mut Vec<Frame>) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        'value: loop {
+            self.skip_whitespace()?;
+            let value_start = self.pos;
+            let mut value = match self.peek() {
+                Some(b'[') => {
+                    let start = self.pos;
+                    self.enter_container(stack.len())?;
+                    self.skip_whitespace()?;
+                    if self.peek() == Some(b']') {
+                        self.pos += 1;
+                        self.end_array(ffi!(PyList_New(0)))?
+                    } else {
+                        stack.push(Frame::Array(Vec::new(), start, Vec::new()));
+                        continue 'value;
+                    }
+                }
+                Some(b'{') => {
+                    self.enter_container(stack.len())?;
+                    self.skip_whitespace()?;
+                    if self.peek() == Some(b'}') {
+                        self.pos += 1;
+                        self.end_object(ffi!(PyDict_New()))?
+                    } else {
+                        stack.push(Frame::Object {
+                            dict: ffi!(PyDict_New()),
+                            key: null_mut(),
+                            key_span: (0, 0),
+                        });
+                        self.parse_key(stack)?;
+                        continue 'value;
+                    }
+                }
+                _ => self.parse_scalar()?.as_ptr(),
+            };
+            let mut span = ItemSpan {
+                item: (value_start, self.pos),
+                key: (value_start, self.pos),
+            };
+            loop {
+                match stack.last_mut() {
+                    None => {
+                        let res = self.skip_whitespace();
+                        if unlikely!(res.is_err() || self.pos < self.data.len()) {
+                            ffi!(Py_DECREF(value));
+                            res?;
+                            return Err(self.error("unexpected content after document", self.pos));
+                        }
+                        return Ok(nonnull!(value));
+                    }
+                    Some(Frame::Array(items, _, spans)) => {
+                        items.push(value);
+                        if unlikely!(opt_enabled!(self.opts, DICT_AS_PAIRS)) {
+                            spans.push(span);
+                        }
+                        self.skip_whitespace()?;
+                        match self.peek() {
+                            Some(b',') => {
+                                self.pos += 1;
+                                if !self.check_trailing_comma(b']')? {
+                                    continue 'value;
+                                }
+                            }
+                            Some(b']') => (),
+                            _ => return Err(self.unexpected()),
+                        }
+                        self.pos += 1;
+                        match stack.pop() {
+                            Some(Frame::Array(items, start, spans)) => {
+                                span = ItemSpan {
+                                    item: (start, self.pos),
+                                    key: spans.first().map_or((start, self.pos), |span| span.item),
+                                };
+                                value = if unlikely!(opt_enabled!(self.opts, DICT_AS_PAIRS)) {
+                                    let val = self.end_array_of_pairs(items, &spans)?;
+                                    if is_type!(ob_type!(val), LIST_TYPE) {
+                                        self.end_array(val)?
+                                    } else {
+                                        val
+                                    }
+                                } else {
+                                    self.end_array(list_from_items(items))?
+                                };
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    Some(Frame::Object {
+                        dict,
+                        key,
+                        key_span,
+                    }) => {
+                        let pykey = core::mem::replace(key, null_mut());
+                        let inserted = add_to_dict(*dict, pykey, value, key_hash(pykey, self.opts));
+                        if unlikely!(!inserted && opt_enabled!(self.opts, REJECT_DUPLICATE_KEYS)) {
+                            return Err(self.duplicate_key(*key_span));
+                        }
+                        self.skip_whitespace()?;
+                        match self.peek() {
+                            Some(b',') => {
+                                self.pos += 1;
+                                if !self.check_trailing_comma(b'}')? {
+                                    self.parse_key(stack)?;
+                                    continue 'value;
+                                }
+                            }
+                            Some(b'}') => (),
+                            _ => return Err(self.unexpected()),
+                        }
+                        self.pos += 1;
+                        match stack.pop() {
+                            Some(Frame::Object { dict, .. }) => {
+                                span = ItemSpan {
+                                    item: (self.pos, self.pos),
+                                    key: (self.pos, self.pos),
+                                };
+                                value = self.end_object(dict)?;
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the value of a complete object, which is `dict` or, if there is
+    /// an `object_hook`, the result of calling it with `dict`.
+    #[inline(always)]
+    fn end_object(&self, dict: *mut pyo3_ffi::PyObject) -> ParseResult<*mut pyo3_ffi::PyObject> {
+        match self.object_hook {
+            None => Ok(dict),
+            Some(hook) => {
+                let val = call_function!(hook.as_ptr(), dict);
+                ffi!(Py_DECREF(dict));
+                if unlikely!(val.is_null()) {
+                    return Err(DeserializeError::raised());
+                }
+                Ok(val)
+            }
+        }
+    }
+
+    /// Return the value of a complete array, which is `list` or, if there is
+    /// an `array_hook`, the result of calling it with `list`.
+    #[inline(always)]
+    fn end_array(&self, list: *mut pyo3_ffi::PyObject) -> ParseResult<*mut pyo3_ffi::PyObject> {
+        match self.array_hook {
+            None => Ok(list),
+            Some(hook) => {
+                let val = call_function!(hook.as_ptr(), list);
+                ffi!(Py_DECREF(list));
+                if unlikely!(val.is_null()) {
+                    return Err(DeserializeError::raised());
+                }
+                Ok(val)
+            }
+        }
+    }
+
+    /// With `OPT_DICT_AS_PAIRS`, return the value of a complete array, which is
+    /// a `dict` if each of its `items`, at `spans`, is an array of a key and a
+    /// value and otherwise a `list`. Arrays in a key are converted
+    /// to `tuple` so that it is hashable. If a key is still not hashable, e.g.,
+    /// it is or contains an object, the array is a `list`.
+    #[cold]
+    #[inline(never)]
+    fn end_array_of_pairs(
+        &self,
+        items: Vec<*mut pyo3_ffi::PyObject>,
+        spans: &[ItemSpan],
+    ) -> ParseResult<*mut pyo3_ffi::PyObject> {
+        if !items
+            .iter()
+            .all(|&item| is_type!(ob_type!(item), LIST_TYPE) && ffi!(Py_SIZE(item)) == 2)
+        {
+            return Ok(list_from_items(items));
+        }
+        let mut keys: Vec<(*mut pyo3_ffi::PyObject, pyo3_ffi::Py_hash_t)> =
+            Vec::with_capacity(items.len());
+        for &item in items.iter() {
+            let key = hashable_key(ffi!(PyList_GET_ITEM(item, 0)));
+            let hash = ffi!(PyObject_Hash(key));
+            if unlikely!(hash == -1) {
+                ffi!(PyErr_Clear());
+                ffi!(Py_DECREF(key));
+                for (key, _) in keys {
+                    ffi!(Py_DECREF(key));
+                }
+                return Ok(list_from_items(items));
+            }
+            keys.push((key, hash));
+        }
+        let dict = ffi!(PyDict_New());
+        let mut duplicate = None;
+        for (idx, (&item, &(key, hash))) in items.iter().zip(keys.iter()).enumerate() {
+            if duplicate.is_some() {
+                ffi!(Py_DECREF(key));
+                continue;
+            }
+            let value = ffi!(PyList_GET_ITEM(item, 1));
+            ffi!(Py_INCREF(value));
+            let inserted = add_to_dict(dict, key, value, hash);
+            if unlikely!(!inserted && opt_enabled!(self.opts, REJECT_DUPLICATE_KEYS)) {
+                duplicate = Some(idx);
+            }
+        }
+        for item in items {
+            ffi!(Py_DECREF(item));
+        }
+        if let Some(idx) = duplicate {
+            ffi!(Py_DECREF(dict));
+            let (start, end) = spans[idx].key;
+            let message = format!(
+                "duplicate key {} in array of pairs at byte {}",
+                &self.data[start..end],
+                start
+            );
+            return Err(DeserializeError::from_parser(
+                Cow::Owned(message),
+                start,
+                self.data,
+            ));
+        }
+        Ok(dict)
+    }
+
+    /// Read the opening bracket of a container at `depth`.
+    #[inline(always)]
+    fn enter_container(&mut self, depth: usize) -> ParseResult<()> {
+        match self.max_depth {
+            None => {
+                if unlikely!(depth >= RECURSION_LIMIT) {
+                    return Err(
+                        self.error("array and object recursion depth exceeded", self.pos + 1)
+                    );
+                }
+            }
+            Some(max_depth) => {
+                if unlikely!(depth >= max_depth) {
+                    return Err(self.max_depth_exceeded(depth + 1, max_depth));
+                }
+            }
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Check for a closing bracket after the comma just read, returning
+    /// whether there is one. It is an error unless `OPT_ALLOW_TRAILING_COMMA`.
+    #[inline(always)]
+    fn check_trailing_comma(&mut self, close: u8) -> ParseResult<bool> {
+        let comma = self.pos - 1;
+        self.skip_whitespace()?;
+        if unlikely!(self.peek() == Some(close)) {
+            if opt_disabled!(self.opts, ALLOW_TRAILING_COMMA) {
+                return Err(self.error("trailing comma is not allowed", comma));
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Read an object key and the following colon, storing the key in the
+    /// object on top of `stack`.
+    fn parse_key(&mut self, stack: &mut [Frame]) -> ParseResult<()> {
+        self.skip_whitespace()?;
+        if unlikely!(self.peek() != Some(b'"')) {
+            return Err(self.unexpected());
+        }
+        let start = self.pos;
+        let opts = self.opts;
+        let pykey = if let Some(mut keys) = self.keys.take() {
+            let res = self.read_str().map(|val| keys.get(val, opts));
+            self.keys = Some(keys);
+            res?
+        } else {
+            create_key(self.read_str()?, opts)
+        };
+        match stack.last_mut() {
+            Some(Frame::Object { key, key_span, .. }) => {
+                *key = pykey;
+                *key_span = (start, self.pos);
+            }
+            _ => unreachable!(),
+        }
+        self.skip_whitespace()?;
+        if unlikely!(self.peek() != Some(b':')) {
+            return Err(self.unexpected());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn parse_scalar(&mut self) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        match self.peek() {
+            Some(b'"') if unlikely!(opt_enabled!(self.opts, PARSE_UUID)) => {
+                let start = self.pos;
+                let value = self.read_str()?;
+                match parse_uuid_hex(value.as_bytes()) {
+                    Some(val) => self.parse_uuid(val, start),
+                    None => Ok(nonnull!(unicode_from_str(value))),
+                }
+            }
+            Some(b'"') => Ok(nonnull!(unicode_from_str(self.read_str()?))),
+            Some(b't') => self.parse_literal(b"true", parse_true),
+            Some(b'f') => self.parse_literal(b"false", parse_false),
+            Some(b'n') => self.parse_literal(b"null", parse_none),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b'N' | b'I') if opt_enabled!(self.opts, ALLOW_NONFINITE) => self.parse_nonfinite(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// `NaN`, `Infinity`, or `-Infinity`, the same as the standard library,
+    /// if `OPT_ALLOW_NONFINITE`. These are not valid JSON.
+    #[cold]
+    #[inline(never)]
+    fn parse_nonfinite(&mut self) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        match self.peek() {
+            Some(b'N') => self.parse_literal(b"NaN", parse_nan),
+            Some(b'I') => self.parse_literal(b"Infinity", parse_infinity),
+            _ => self.parse_literal(b"-Infinity", parse_negative_infinity),
+        }
+    }
+
+    fn parse_literal(
+        &mut self,
+        literal: &'static [u8],
+        value: fn() -> NonNull<pyo3_ffi::PyObject>,
+    ) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        let rest = &self.data.as_bytes()[self.pos..];
+        if rest.starts_with(literal) {
+            self.pos += literal.len();
+            Ok(value())
+        } else if literal.starts_with(rest) {
+            Err(self.error("unexpected end of data", self.data.len()))
+        } else {
+            Err(self.error("invalid literal", self.pos))
+        }
+    }
+
+    /// Read the digits of a number, returning whether it has a fraction or
+    /// exponent.
+    fn read_number(&mut self) -> ParseResult<bool> {
+        let bytes = self.data.as_bytes();
+        if bytes[self.pos] == b'-' {
+            self.pos += 1;
+        }
+        match bytes.get(self.pos) {
+            Some(b'0') => {
+                if unlikely!(matches!(bytes.get(self.pos + 1), Some(b'0'..=b'9'))) {
+                    return Err(self.error("number with leading zero is not allowed", self.pos));
+                }
+                self.pos += 1;
+            }
+            Some(b'1'..=b'9') => self.skip_digits(),
+            Some(_) => return Err(self.error("no digit after minus sign", self.pos)),
+            None => return Err(self.unexpected()),
+        }
+        let mut is_float = false;
+        if self.peek() == Some(b'.') {
+            is_float = true;
+            self.pos += 1;
+            match self.peek() {
+                Some(b'0'..=b'9') => self.skip_digits(),
+                Some(_) => return Err(self.error("no digit after decimal point", self.pos)),
+                None => return Err(self.unexpected()),
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            is_float = true;
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            match self.peek() {
+                Some(b'0'..=b'9') => self.skip_digits(),
+                Some(_) => return Err(self.error("no digit after exponent sign", self.pos)),
+                None => return Err(self.unexpected()),
+            }
+        }
+        Ok(is_float)
+    }
+
+    #[inline(always)]
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_number(&mut self) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        if unlikely!(opt_enabled!(self.opts, ALLOW_NONFINITE))
+            && self.data.as_bytes()[self.pos..].starts_with(b"-I")
+        {
+            return self.parse_nonfinite();
+        }
+        let start = self.pos;
+        let is_float = self.read_number()?;
+        let token = &self.data[start..self.pos];
+        if !is_float {
+            let parsed = if token.as_bytes()[0] == b'-' {
+                token.parse::<i64>().ok().map(parse_i64)
+            } else {
+                token.parse::<u64>().ok().map(parse_u64)
+            };
+            if let Some(val) = parsed {
+                return Ok(val);
+            }
+            return parse_big_int(token.as_bytes())
+                .ok_or_else(|| self.error("number is too large to convert to int", start));
+        } else if opt_enabled!(self.opts, PARSE_DECIMAL) {
+            return self.parse_decimal(token, start);
+        }
+        match token.parse::<f64>() {
+            Ok(val) if val.is_finite() => {
+                if unlikely!(opt_enabled!(self.opts, WHOLE_FLOAT_AS_INT)) && is_whole_i64(val) {
+                    return Ok(parse_i64(val as i64));
+                }
+                Ok(parse_f64(val))
+            }
+            _ => Err(self.error("number is infinity when parsed as double", start)),
+        }
+    }
+
+    /// Create a `decimal.Decimal` from the exact text of a number.
+    fn parse_decimal(&self, token: &str, start: usize) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        let arg = unicode_from_str(token);
+        let val = call_function!(unsafe { DECIMAL_TYPE as *mut pyo3_ffi::PyObject }, arg);
+        ffi!(Py_DECREF(arg));
+        if unlikely!(val.is_null()) {
+            ffi!(PyErr_Clear());
+            return Err(self.error("failed to create decimal.Decimal", start));
+        }
+        Ok(nonnull!(val))
+    }
+
+    /// Create a `uuid.UUID` from the value of a string in the canonical form.
+    fn parse_uuid(&self, val: u128, start: usize) -> ParseResult<NonNull<pyo3_ffi::PyObject>> {
+        let bytes = val.to_le_bytes();
+        let int = unsafe { pyo3_ffi::_PyLong_FromByteArray(bytes.as_ptr(), 16, 1, 0) };
+        let args = ffi!(PyTuple_New(0));
+        let kwargs = ffi!(PyDict_New());
+        ffi!(PyDict_SetItem(kwargs, INT_ATTR_STR, int));
+        let uuid = ffi!(PyObject_Call(
+            UUID_TYPE as *mut pyo3_ffi::PyObject,
+            args,
+            kwargs
+        ));
+        ffi!(Py_DECREF(int));
+        ffi!(Py_DECREF(args));
+        ffi!(Py_DECREF(kwargs));
+        if unlikely!(uuid.is_null()) {
+            ffi!(PyErr_Clear());
+            return Err(self.error("failed to create uuid.UUID", start));
+        }
+        Ok(nonnull!(uuid))
+    }
+
+    /// Read a string at `self.pos`, which is a quote, returning it without
+    /// escapes. This borrows the input unless the string contains escapes.
+    fn read_str(&mut self) -> ParseResult<&str> {
+        let bytes = self.data.as_bytes();
+        let start = self.pos + 1;
+        let mut idx = start;
+        loop {
+            match bytes.get(idx) {
+                Some(b'"') => {
+                    self.pos = idx + 1;
+                    return Ok(&self.data[start..idx]);
+                }
+                Some(b'\\') => break,
+                Some(&ch) if ch < 0x20 => {
+                    return Err(self.error("unexpected control character in string", idx))
+                }
+                Some(_) => idx += 1,
+                None => return Err(self.error("unexpected end of data", bytes.len())),
+            }
+        }
+        self.scratch.clear();
+        self.scratch.push_str(&self.data[start..idx]);
+        loop {
+            match bytes.get(idx) {
+                Some(b'"') => {
+                    self.pos = idx + 1;
+                    return Ok(&self.scratch);
+                }
+                Some(b'\\') => {
+                    let ch = match bytes.get(idx + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\x08',
+                        Some(b'f') => '\x0c',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let (ch, len) = self.read_unicode_escape(idx)?;
+                            self.scratch.push(ch);
+                            idx += len;
+                            continue;
+                        }
+                        Some(_) => {
+                            return Err(self.error("invalid escaped character in string", idx + 1))
+                        }
+                        None => return Err(self.error("unexpected end of data", bytes.len())),
+                    };
+                    self.scratch.push(ch);
+                    idx += 2;
+                }
+                Some(&ch) if ch < 0x20 => {
+                    return Err(self.error("unexpected control character in string", idx))
+                }
+                Some(_) => {
+                    let run = idx;
+                    while let Some(&ch) = bytes.get(idx) {
+                        if ch == b'"' || ch == b'\\' || ch < 0x20 {
+                            break;
+                        }
+                        idx += 1;
+                    }
+                    self.scratch.push_str(&self.data[run..idx]);
+                }
+                None => return Err(self.error("unexpected end of data", bytes.len())),
+            }
+        }
+    }
+
+    /// Read `\uXXXX` at `idx`, or a surrogate pair of them, returning the
+    /// character and the length of the escape.
+    fn read_unicode_escape(&self, idx: usize) -> ParseResult<(char, usize)> {
+        let bytes = self.data.as_bytes();
+        let high = self.read_hex4(idx)?;
+        match high {
+            0xd800..=0xdbff => {
+                if bytes.get(idx + 6) != Some(&b'\\') || bytes.get(idx + 7) != Some(&b'u') {
+                    return Err(self.error("no low surrogate in string", idx + 6));
+                }
+                let low = self.read_hex4(idx + 6)?;
+                if !(0xdc00..=0xdfff).contains(&low) {
+                    return Err(self.error("invalid low surrogate in string", idx + 6));
+                }
+                let cp = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                Ok((char::from_u32(cp).unwrap_or_else(|| unreachable!()), 12))
+            }
+            0xdc00..=0xdfff => Err(self.error("invalid high surrogate in string", idx)),
+            _ => Ok((char::from_u32(high).unwrap_or_else(|| unreachable!()), 6)),
+        }
+    }
+
+    /// Read the four hex digits of `\uXXXX` at `idx`.
+    fn read_hex4(&self, idx: usize) -> ParseResult<u32> {
+        let bytes = self.data.as_bytes();
+        if unlikely!(idx + 6 > bytes.len()) {
+            return Err(self.error("invalid escaped sequence in string", idx));
+        }
+        let mut val: u32 = 0;
+        for &ch in &bytes[idx + 2..idx + 6] {
+            let digit = match ch {
+                b'0'..=b'9' => ch - b'0',
+                b'a'..=b'f' => ch - b'a' + 10,
+                b'A'..=b'F' => ch - b'A' + 10,
+                _ => return Err(self.error("invalid escaped sequence in string", idx)),
+            };
+            val = (val << 4) | digit as u32;
+        }
+        Ok(val)
+    }
+}
+
+fn parse_nan() -> NonNull<pyo3_ffi::PyObject> {
+    parse_f64(f64::NAN)
+}
+
+fn parse_infinity() -> NonNull<pyo3_ffi::PyObject> {
+    parse_f64(f64::INFINITY)
+}
+
+fn parse_negative_infinity() -> NonNull<pyo3_ffi::PyObject> {
+    parse_f64(f64::NEG_INFINITY)
+}
+
+fn list_from_items(items: Vec<*mut pyo3_ffi::PyObject>) -> *mut pyo3_ffi::PyObject {
+    let ptr = ffi!(PyList_New(items.len() as isize));
+    for (i, &obj) in items.iter().enumerate() {
+        ffi!(PyList_SET_ITEM(ptr, i as isize, obj));
+    }
+    ptr
+}
+
+/// A new reference to `obj` or, if it is a `list`, a `tuple` of its items,
+/// converted in the same way, for a key of `OPT_DICT_AS_PAIRS`.
+fn hashable_key(obj: *mut pyo3_ffi::PyObject) -> *mut pyo3_ffi::PyObject {
+    if !is_type!(ob_type!(obj), LIST_TYPE) {
+        ffi!(Py_INCREF(obj));
+        return obj;
+    }
+    let len = ffi!(Py_SIZE(obj));
+    let tuple = ffi!(PyTuple_New(len));
+    for idx in 0..len {
+        ffi!(PyTuple_SET_ITEM(
+            tuple,
+            idx,
+            hashable_key(ffi!(PyList_GET_ITEM(obj, idx)))
+        ));
+    }
+    tuple
+}
+
+/// Whether `val` has no fraction and is in the range of `i64`, for
+/// `OPT_WHOLE_FLOAT_AS_INT`.
+#[inline(always)]
+fn is_whole_i64(val: f64) -> bool {
+    // -2**63 is exact as a double and `i64::MAX` is not
+    val.trunc() == val && (i64::MIN as f64..-(i64::MIN as f64)).contains(&val)
+}
+
+/// The value of a UUID in the canonical form of 32 hexadecimal digits, of
+/// either case, in groups of 8, 4, 4, 4, and 12 separated by hyphens, e.g.,
+/// `7202d115-7ff3-4c81-a7c1-2a1f067b1ece`, or `None` if `value` is not.
+fn parse_uuid_hex(value: &[u8]) -> Option<u128> {
+    if value.len() != 36 {
+        return None;
+    }
+    let mut val: u128 = 0;
+    for (idx, &ch) in value.iter().enumerate() {
+        if matches!(idx, 8 | 13 | 18 | 23) {
+            if ch != b'-' {
+                return None;
+            }
+            continue;
+        }
+        let digit = match ch {
+            b'0'..=b'9' => ch - b'0',
+            b'a'..=b'f' => ch - b'a' + 10,
+            b'A'..=b'F' => ch - b'A' + 10,
+            _ => return None,
+        };
+        val = (val << 4) | u128::from(digit);
+    }
+    Some(val)
+}
+
+/// Create an object key, which is `bytes` if `OPT_KEYS_AS_BYTES` and
+/// otherwise `str`.
+#[inline(always)]
+fn create_key(key: &str, opts: Opt) -> *mut pyo3_ffi::PyObject {
+    if unlikely!(opt_enabled!(opts, KEYS_AS_BYTES)) {
+        get_bytes_key(key)
+    } else {
+        get_unicode_key(key)
+    }
+}
+
+/// The hash of a key from `create_key()`. A `str` key is already hashed.
+#[inline(always)]
+fn key_hash(key: *mut pyo3_ffi::PyObject, opts: Opt) -> pyo3_ffi::Py_hash_t {
+    if unlikely!(opt_enabled!(opts, KEYS_AS_BYTES)) {
+        ffi!(PyObject_Hash(key))
+    } else {
+        str_hash!(key)
+    }
+}
+
+/// Set `key` with `hash` to `value`, taking ownership of both, and return
+/// whether `key` was not already in `dict`.
+#[inline(always)]
+fn add_to_dict(
+    dict: *mut pyo3_ffi::PyObject,
+    key: *mut pyo3_ffi::PyObject,
+    value: *mut pyo3_ffi::PyObject,
+    hash: pyo3_ffi::Py_hash_t,
+) -> bool {
+    let len = ffi!(Py_SIZE(dict));
+    let _ = unsafe { pyo3_ffi::_PyDict_SetItem_KnownHash(dict, key, value, hash) };
+    reverse_pydict_incref!(value);
+    if likely!(ffi!(Py_SIZE(dict)) > len) {
+        reverse_pydict_incref!(key);
+        true
+    } else {
+        // the dict keeps its existing key object
+        ffi!(Py_DECREF(key));
+        false
+    }
+}
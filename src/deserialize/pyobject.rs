@@ -3,7 +3,9 @@
 use crate::deserialize::cache::*;
 use crate::str::{hash_str, unicode_from_str};
 use crate::typeref::{FALSE, NONE, TRUE};
-use core::ptr::NonNull;
+use core::ffi::c_char;
+use core::ptr::{null_mut, NonNull};
+use smallvec::SmallVec;
 
 #[inline(always)]
 pub fn get_unicode_key(key_str: &str) -> *mut pyo3_ffi::PyObject {
@@ -31,6 +33,17 @@ pub fn get_unicode_key(key_str: &str) -> *mut pyo3_ffi::PyObject {
     }
 }
 
+/// Create an object key as `bytes` for `OPT_KEYS_AS_BYTES`. These are not
+/// cached.
+#[cold]
+#[inline(never)]
+pub fn get_bytes_key(key_str: &str) -> *mut pyo3_ffi::PyObject {
+    ffi!(PyBytes_FromStringAndSize(
+        key_str.as_ptr() as *const c_char,
+        key_str.len() as pyo3_ffi::Py_ssize_t
+    ))
+}
+
 #[allow(dead_code)]
 #[inline(always)]
 pub fn parse_bool(val: bool) -> NonNull<pyo3_ffi::PyObject> {
@@ -60,11 +73,69 @@ pub fn parse_u64(val: u64) -> NonNull<pyo3_ffi::PyObject> {
     nonnull!(ffi!(PyLong_FromUnsignedLongLong(val)))
 }
 
+/// Create an `int` from the digits of an integer that overflows 64 bits, or
+/// `None` if Python cannot convert it, e.g., because it has more digits than
+/// `sys.get_int_max_str_digits()`.
+#[cold]
+#[inline(never)]
+pub fn parse_big_int(token: &[u8]) -> Option<NonNull<pyo3_ffi::PyObject>> {
+    let mut buf: SmallVec<[u8; 64]> = SmallVec::with_capacity(token.len() + 1);
+    buf.extend_from_slice(token);
+    buf.push(0);
+    let val = ffi!(PyLong_FromString(
+        buf.as_ptr() as *const c_char,
+        null_mut(),
+        10
+    ));
+    if unlikely!(val.is_null()) {
+        ffi!(PyErr_Clear());
+        return None;
+    }
+    Some(nonnull!(val))
+}
+
 #[inline(always)]
 pub fn parse_f64(val: f64) -> NonNull<pyo3_ffi::PyObject> {
     nonnull!(ffi!(PyFloat_FromDouble(val)))
 }
 
+/// The magnitude, 2**63, from which a float read by the yyjson or serde_json
+/// backend may be an integer that overflowed 64 bits. Both read such an
+/// integer as a float.
+const MAYBE_BIG_INT: f64 = 9223372036854775808.0;
+
+std::thread_local! {
+    /// Whether the document being read by the yyjson or serde_json backend
+    /// has a float of at least `MAYBE_BIG_INT` in magnitude.
+    static HAS_MAYBE_BIG_INT: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Create a `float` read by the yyjson or serde_json backend, recording if it
+/// may be an integer that overflowed 64 bits so that the document is parsed
+/// again to deserialize it as an exact `int`.
+#[inline(always)]
+pub fn parse_backend_f64(val: f64) -> NonNull<pyo3_ffi::PyObject> {
+    if unlikely!(val.abs() >= MAYBE_BIG_INT) {
+        set_maybe_big_int();
+    }
+    parse_f64(val)
+}
+
+/// Record that the document being read by the yyjson or serde_json backend
+/// may have an integer that overflowed 64 bits, including one that they
+/// reject for exceeding the range of a float.
+#[cold]
+pub fn set_maybe_big_int() {
+    HAS_MAYBE_BIG_INT.with(|flag| flag.set(true));
+}
+
+/// Whether the document just read by the yyjson or serde_json backend may
+/// have an integer that overflowed 64 bits, resetting it.
+#[inline(always)]
+pub fn take_maybe_big_int() -> bool {
+    HAS_MAYBE_BIG_INT.with(|flag| flag.replace(false))
+}
+
 #[inline(always)]
 pub fn parse_none() -> NonNull<pyo3_ffi::PyObject> {
     nonnull!(use_immortal!(NONE))
@@ -0,0 +1,497 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::deserialize::DeserializeError;
+use crate::str::unicode_to_str;
+use crate::typeref::{
+    load_typing_types, TypingTypes, BOOL_TYPE, DATACLASS_FIELDS_STR, DEFAULT, DEFAULT_FACTORY_STR,
+    DICT_TYPE, FIELD_TYPE, FIELD_TYPE_STR, FLOAT_TYPE, INIT_STR, INT_TYPE, LIST_TYPE, NONE,
+    NONE_TYPE, STR_TYPE, TRUE, TYPING_TYPES,
+};
+use core::ffi::CStr;
+use core::ptr::NonNull;
+use std::borrow::Cow;
+
+/// Deserialize to an instance of the dataclass `cls`. Each object is converted
+/// according to the annotation of the field it is the value of, recursing into
+/// dataclasses, `list`, `dict`, and unions of them, e.g., `Optional`. Scalars
+/// must be of the annotated type if it is `bool`, `int`, `float`, `str`, or
+/// `None`, and `int` is converted to `float` for `float`. Values annotated
+/// otherwise are as `loads()` deserializes them. If `strict`, a key that is
+/// not an `init` field of the dataclass is an error; otherwise, it is ignored.
+pub fn deserialize_typed(
+    ptr: *mut pyo3_ffi::PyObject,
+    cls: *mut pyo3_ffi::PyObject,
+    strict: bool,
+) -> Result<NonNull<pyo3_ffi::PyObject>, DeserializeError<'static>> {
+    let typing =
+        match unsafe { (*core::ptr::addr_of!(TYPING_TYPES)).get_or_init(load_typing_types) } {
+            Some(typing) => unsafe { typing.as_ref() },
+            None => {
+                return Err(DeserializeError::invalid(Cow::Borrowed(
+                    "loads_typed() could not import typing",
+                )))
+            }
+        };
+    let mut typed = Typed {
+        typing: typing,
+        strict: strict,
+        schemas: Vec::new(),
+        empty_tuple: ffi!(PyTuple_New(0)),
+    };
+    let hint = typed.hint(cls)?;
+    let val = crate::deserialize::deserialize(ptr, 0, None, None, None)?;
+    let mut path: Vec<PathSegment> = Vec::new();
+    typed
+        .convert(val.as_ptr(), &hint, &mut path)
+        .map(|val| nonnull!(val))
+}
+
+/// The type an annotation requires a value to be converted to.
+enum Hint {
+    Any,
+    Bool,
+    Int,
+    Float,
+    Str,
+    None,
+    Dataclass(usize),
+    List(Box<Hint>),
+    Dict(Box<Hint>),
+    Union(Vec<Hint>),
+}
+
+/// An `init` field of a dataclass.
+struct Field {
+    name: *mut pyo3_ffi::PyObject,
+    hint: Hint,
+    required: bool,
+}
+
+struct Schema {
+    cls: *mut pyo3_ffi::PyObject,
+    fields: Vec<Field>,
+}
+
+/// A key or index from the document root to a value, for errors.
+#[derive(Copy, Clone)]
+enum PathSegment {
+    Key(*mut pyo3_ffi::PyObject),
+    Index(usize),
+}
+
+struct Typed {
+    typing: &'static TypingTypes,
+    strict: bool,
+    schemas: Vec<Schema>,
+    empty_tuple: *mut pyo3_ffi::PyObject,
+}
+
+impl Drop for Typed {
+    fn drop(&mut self) {
+        for schema in self.schemas.iter() {
+            for field in schema.fields.iter() {
+                ffi!(Py_DECREF(field.name));
+            }
+            ffi!(Py_DECREF(schema.cls));
+        }
+        ffi!(Py_DECREF(self.empty_tuple));
+    }
+}
+
+impl Typed {
+    /// Read the annotation `ptr`, reading the fields of any dataclass in it.
+    fn hint(&mut self, ptr: *mut pyo3_ffi::PyObject) -> Result<Hint, DeserializeError<'static>> {
+        unsafe {
+            if ptr == NONE || ptr == NONE_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::None);
+            } else if ptr == BOOL_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::Bool);
+            } else if ptr == INT_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::Int);
+            } else if ptr == FLOAT_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::Float);
+            } else if ptr == STR_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::Str);
+            } else if ptr == LIST_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::List(Box::new(Hint::Any)));
+            } else if ptr == DICT_TYPE as *mut pyo3_ffi::PyObject {
+                return Ok(Hint::Dict(Box::new(Hint::Any)));
+            }
+        }
+        if ffi!(PyType_Check(ptr)) != 0 {
+            if ffi!(PyObject_HasAttr(ptr, DATACLASS_FIELDS_STR)) == 1 {
+                return Ok(Hint::Dataclass(self.schema(ptr)?));
+            }
+            return Ok(Hint::Any);
+        }
+        let origin = call_function!(self.typing.get_origin, ptr);
+        if unlikely!(origin.is_null()) {
+            return Err(DeserializeError::raised());
+        }
+        ffi!(Py_DECREF(origin));
+        let is_union = origin == self.typing.union || origin == self.typing.union_type;
+        let is_list = unsafe { origin == LIST_TYPE as *mut pyo3_ffi::PyObject };
+        let is_dict = unsafe { origin == DICT_TYPE as *mut pyo3_ffi::PyObject };
+        if !(is_union || is_list || is_dict) {
+            return Ok(Hint::Any);
+        }
+        let args = call_function!(self.typing.get_args, ptr);
+        if unlikely!(args.is_null()) {
+            return Err(DeserializeError::raised());
+        }
+        let res = self.hint_args(args, is_union, is_list);
+        ffi!(Py_DECREF(args));
+        res
+    }
+
+    fn hint_args(
+        &mut self,
+        args: *mut pyo3_ffi::PyObject,
+        is_union: bool,
+        is_list: bool,
+    ) -> Result<Hint, DeserializeError<'static>> {
+        let len = ffi!(Py_SIZE(args));
+        if is_union {
+            let mut members: Vec<Hint> = Vec::with_capacity(len as usize);
+            for i in 0..len {
+                members.push(self.hint(ffi!(PyTuple_GET_ITEM(args, i)))?);
+            }
+            Ok(Hint::Union(members))
+        } else if is_list {
+            let item = match len {
+                0 => Hint::Any,
+                _ => self.hint(ffi!(PyTuple_GET_ITEM(args, 0)))?,
+            };
+            Ok(Hint::List(Box::new(item)))
+        } else {
+            let item = match len {
+                2 => self.hint(ffi!(PyTuple_GET_ITEM(args, 1)))?,
+                _ => Hint::Any,
+            };
+            Ok(Hint::Dict(Box::new(item)))
+        }
+    }
+
+    /// Return the index of the schema of the dataclass `cls`, reading it if
+    /// this is the first time it is used. A schema is added before its fields
+    /// are read so that a dataclass may refer to itself.
+    fn schema(&mut self, cls: *mut pyo3_ffi::PyObject) -> Result<usize, DeserializeError<'static>> {
+        if let Some(idx) = self.schemas.iter().position(|schema| schema.cls == cls) {
+            return Ok(idx);
+        }
+        let idx = self.schemas.len();
+        ffi!(Py_INCREF(cls));
+        self.schemas.push(Schema {
+            cls: cls,
+            fields: Vec::new(),
+        });
+        let hints = call_function!(self.typing.get_type_hints, cls);
+        if unlikely!(hints.is_null()) {
+            return Err(DeserializeError::raised());
+        }
+        let fields = ffi!(PyObject_GetAttr(cls, DATACLASS_FIELDS_STR));
+        let res = self.fields(idx, fields, hints);
+        ffi!(Py_DECREF(fields));
+        ffi!(Py_DECREF(hints));
+        res.map(|_| idx)
+    }
+
+    /// Add the `init` fields of `fields`, annotated as in `hints`, to the
+    /// schema at `idx`.
+    fn fields(
+        &mut self,
+        idx: usize,
+        fields: *mut pyo3_ffi::PyObject,
+        hints: *mut pyo3_ffi::PyObject,
+    ) -> Result<(), DeserializeError<'static>> {
+        let mut pos = 0;
+        let mut name: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        let mut field: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        while pydict_next!(fields, &mut pos, &mut name, &mut field) == 1 {
+            let field_type = ffi!(PyObject_GetAttr(field, FIELD_TYPE_STR));
+            ffi!(Py_DECREF(field_type));
+            if unsafe { field_type as *mut pyo3_ffi::PyTypeObject != FIELD_TYPE } {
+                continue;
+            }
+            let init = ffi!(PyObject_GetAttr(field, INIT_STR));
+            ffi!(Py_DECREF(init));
+            if unsafe { init != TRUE } {
+                continue;
+            }
+            let default = ffi!(PyObject_GetAttr(field, DEFAULT));
+            ffi!(Py_DECREF(default));
+            let default_factory = ffi!(PyObject_GetAttr(field, DEFAULT_FACTORY_STR));
+            ffi!(Py_DECREF(default_factory));
+            let required = default == self.typing.missing && default_factory == self.typing.missing;
+            let annotation = ffi!(PyDict_GetItem(hints, name));
+            let hint = if annotation.is_null() {
+                Hint::Any
+            } else {
+                self.hint(annotation)?
+            };
+            ffi!(Py_INCREF(name));
+            self.schemas[idx].fields.push(Field {
+                name: name,
+                hint: hint,
+                required: required,
+            });
+        }
+        Ok(())
+    }
+
+    /// Convert `val`, taking ownership of it, to `hint`.
+    fn convert(
+        &self,
+        val: *mut pyo3_ffi::PyObject,
+        hint: &Hint,
+        path: &mut Vec<PathSegment>,
+    ) -> Result<*mut pyo3_ffi::PyObject, DeserializeError<'static>> {
+        match hint {
+            Hint::Any => Ok(val),
+            Hint::Dataclass(idx) if is_type!(ob_type!(val), DICT_TYPE) => {
+                self.instantiate(val, &self.schemas[*idx], path)
+            }
+            Hint::List(item) if is_type!(ob_type!(val), LIST_TYPE) => {
+                if matches!(**item, Hint::Any) {
+                    return Ok(val);
+                }
+                for i in 0..ffi!(Py_SIZE(val)) {
+                    let each = ffi!(PyList_GET_ITEM(val, i));
+                    ffi!(Py_INCREF(each));
+                    path.push(PathSegment::Index(i as usize));
+                    let res = self.convert(each, item, path);
+                    path.pop();
+                    match res {
+                        Ok(each) => {
+                            ffi!(PyList_SetItem(val, i, each));
+                        }
+                        Err(err) => {
+                            ffi!(Py_DECREF(val));
+                            return Err(err);
+                        }
+                    }
+                }
+                Ok(val)
+            }
+            Hint::Dict(item) if is_type!(ob_type!(val), DICT_TYPE) => {
+                if matches!(**item, Hint::Any) {
+                    return Ok(val);
+                }
+                let mut pos = 0;
+                let mut key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+                let mut each: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+                // Replacing the value of an existing key does not change the
+                // iteration.
+                while pydict_next!(val, &mut pos, &mut key, &mut each) == 1 {
+                    ffi!(Py_INCREF(each));
+                    path.push(PathSegment::Key(key));
+                    let res = self.convert(each, item, path);
+                    path.pop();
+                    match res {
+                        Ok(each) => {
+                            ffi!(PyDict_SetItem(val, key, each));
+                            ffi!(Py_DECREF(each));
+                        }
+                        Err(err) => {
+                            ffi!(Py_DECREF(val));
+                            return Err(err);
+                        }
+                    }
+                }
+                Ok(val)
+            }
+            Hint::Union(members) => {
+                let member = members
+                    .iter()
+                    .find(|member| self.accepts(member, val, true))
+                    .or_else(|| {
+                        members
+                            .iter()
+                            .find(|member| self.accepts(member, val, false))
+                    });
+                match member {
+                    Some(member) => self.convert(val, member, path),
+                    None => Err(self.mismatch(val, hint, path)),
+                }
+            }
+            Hint::Float if is_type!(ob_type!(val), INT_TYPE) => {
+                let res = ffi!(PyNumber_Float(val));
+                ffi!(Py_DECREF(val));
+                if unlikely!(res.is_null()) {
+                    return Err(DeserializeError::raised());
+                }
+                Ok(res)
+            }
+            _ if self.accepts(hint, val, true) => Ok(val),
+            _ => Err(self.mismatch(val, hint, path)),
+        }
+    }
+
+    /// Create an instance of the dataclass of `schema` from the object `dict`,
+    /// taking ownership of it.
+    fn instantiate(
+        &self,
+        dict: *mut pyo3_ffi::PyObject,
+        schema: &Schema,
+        path: &mut Vec<PathSegment>,
+    ) -> Result<*mut pyo3_ffi::PyObject, DeserializeError<'static>> {
+        let kwargs = ffi!(PyDict_New());
+        let res = self.kwargs(dict, kwargs, schema, path);
+        ffi!(Py_DECREF(dict));
+        if let Err(err) = res {
+            ffi!(Py_DECREF(kwargs));
+            return Err(err);
+        }
+        let obj = ffi!(PyObject_Call(schema.cls, self.empty_tuple, kwargs));
+        ffi!(Py_DECREF(kwargs));
+        if unlikely!(obj.is_null()) {
+            return Err(DeserializeError::raised());
+        }
+        Ok(obj)
+    }
+
+    fn kwargs(
+        &self,
+        dict: *mut pyo3_ffi::PyObject,
+        kwargs: *mut pyo3_ffi::PyObject,
+        schema: &Schema,
+        path: &mut Vec<PathSegment>,
+    ) -> Result<(), DeserializeError<'static>> {
+        for field in schema.fields.iter() {
+            let item = ffi!(PyDict_GetItem(dict, field.name));
+            if item.is_null() {
+                if unlikely!(field.required) {
+                    let message = format!(
+                        "missing required field '{}' of {} at {}",
+                        str_of(field.name),
+                        type_name(schema.cls),
+                        path_to_string(path)
+                    );
+                    return Err(DeserializeError::invalid(Cow::Owned(message)));
+                }
+                continue;
+            }
+            ffi!(Py_INCREF(item));
+            path.push(PathSegment::Key(field.name));
+            let res = self.convert(item, &field.hint, path);
+            path.pop();
+            let item = res?;
+            ffi!(PyDict_SetItem(kwargs, field.name, item));
+            ffi!(Py_DECREF(item));
+        }
+        if self.strict && ffi!(Py_SIZE(kwargs)) < ffi!(Py_SIZE(dict)) {
+            let mut pos = 0;
+            let mut key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+            let mut value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+            while pydict_next!(dict, &mut pos, &mut key, &mut value) == 1 {
+                if ffi!(PyDict_Contains(kwargs, key)) == 0 {
+                    let message = format!(
+                        "unknown field '{}' of {} at {}",
+                        str_of(key),
+                        type_name(schema.cls),
+                        path_to_string(path)
+                    );
+                    return Err(DeserializeError::invalid(Cow::Owned(message)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `val` is of the type of `hint`, not considering its contents.
+    /// If not `exact`, an `int` is accepted for `float`.
+    fn accepts(&self, hint: &Hint, val: *mut pyo3_ffi::PyObject, exact: bool) -> bool {
+        let ob_type = ob_type!(val);
+        match hint {
+            Hint::Any => true,
+            Hint::Bool => is_type!(ob_type, BOOL_TYPE),
+            Hint::Int => is_type!(ob_type, INT_TYPE),
+            Hint::Float => is_type!(ob_type, FLOAT_TYPE) || (!exact && is_type!(ob_type, INT_TYPE)),
+            Hint::Str => is_type!(ob_type, STR_TYPE),
+            Hint::None => unsafe { val == NONE },
+            Hint::Dataclass(_) | Hint::Dict(_) => is_type!(ob_type, DICT_TYPE),
+            Hint::List(_) => is_type!(ob_type, LIST_TYPE),
+            Hint::Union(members) => members
+                .iter()
+                .any(|member| self.accepts(member, val, exact)),
+        }
+    }
+
+    /// The error for `val`, which is released, not being of the type of
+    /// `hint`.
+    #[cold]
+    #[inline(never)]
+    fn mismatch(
+        &self,
+        val: *mut pyo3_ffi::PyObject,
+        hint: &Hint,
+        path: &[PathSegment],
+    ) -> DeserializeError<'static> {
+        let message = format!(
+            "expected {} at {}, got {}",
+            self.describe(hint),
+            path_to_string(path),
+            type_name(ob_type!(val) as *mut pyo3_ffi::PyObject)
+        );
+        ffi!(Py_DECREF(val));
+        DeserializeError::invalid(Cow::Owned(message))
+    }
+
+    #[cold]
+    fn describe(&self, hint: &Hint) -> String {
+        match hint {
+            Hint::Any => String::from("object"),
+            Hint::Bool => String::from("bool"),
+            Hint::Int => String::from("int"),
+            Hint::Float => String::from("float"),
+            Hint::Str => String::from("str"),
+            Hint::None => String::from("None"),
+            Hint::Dataclass(idx) => type_name(self.schemas[*idx].cls),
+            Hint::List(item) => match **item {
+                Hint::Any => String::from("list"),
+                _ => format!("list[{}]", self.describe(item)),
+            },
+            Hint::Dict(item) => match **item {
+                Hint::Any => String::from("dict"),
+                _ => format!("dict[str, {}]", self.describe(item)),
+            },
+            Hint::Union(members) => members
+                .iter()
+                .map(|member| self.describe(member))
+                .collect::<Vec<String>>()
+                .join(" | "),
+        }
+    }
+}
+
+#[cold]
+fn str_of(ptr: *mut pyo3_ffi::PyObject) -> &'static str {
+    unicode_to_str(ptr).unwrap_or("")
+}
+
+#[cold]
+fn type_name(ptr: *mut pyo3_ffi::PyObject) -> String {
+    unsafe {
+        CStr::from_ptr((*(ptr as *mut pyo3_ffi::PyTypeObject)).tp_name)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// The path to a value, e.g., `$.items[42].name`.
+#[cold]
+fn path_to_string(path: &[PathSegment]) -> String {
+    let mut buf = String::from("$");
+    for segment in path.iter() {
+        match segment {
+            PathSegment::Key(key) => {
+                buf.push('.');
+                buf.push_str(str_of(*key));
+            }
+            PathSegment::Index(idx) => {
+                buf.push_str(&format!("[{}]", idx));
+            }
+        }
+    }
+    buf
+}
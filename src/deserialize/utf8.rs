@@ -6,10 +6,11 @@ use crate::str::unicode_to_str;
 use crate::typeref::{BYTEARRAY_TYPE, BYTES_TYPE, MEMORYVIEW_TYPE, STR_TYPE};
 use crate::util::INVALID_STR;
 use core::ffi::c_char;
+use core::ptr::NonNull;
 use std::borrow::Cow;
 
 #[cfg(all(target_arch = "x86_64", not(target_feature = "sse4.2")))]
-fn is_valid_utf8(buf: &[u8]) -> bool {
+pub fn is_valid_utf8(buf: &[u8]) -> bool {
     if std::is_x86_feature_detected!("sse4.2") {
         simdutf8::basic::from_utf8(buf).is_ok()
     } else {
@@ -18,20 +19,50 @@ fn is_valid_utf8(buf: &[u8]) -> bool {
 }
 
 #[cfg(all(target_arch = "x86_64", target_feature = "sse4.2"))]
-fn is_valid_utf8(buf: &[u8]) -> bool {
+pub fn is_valid_utf8(buf: &[u8]) -> bool {
     simdutf8::basic::from_utf8(buf).is_ok()
 }
 
 #[cfg(target_arch = "aarch64")]
-fn is_valid_utf8(buf: &[u8]) -> bool {
+pub fn is_valid_utf8(buf: &[u8]) -> bool {
     simdutf8::basic::from_utf8(buf).is_ok()
 }
 
 #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
-fn is_valid_utf8(buf: &[u8]) -> bool {
+pub fn is_valid_utf8(buf: &[u8]) -> bool {
     std::str::from_utf8(buf).is_ok()
 }
 
+/// A `memoryview` of `ptr` if it is not `bytes`, `bytearray`, `memoryview`, or
+/// `str` but supports the buffer protocol, e.g., `mmap.mmap` or
+/// `array.array`, so that it can be read in place. The caller releases it.
+pub fn buffer_protocol_view(
+    ptr: *mut pyo3_ffi::PyObject,
+) -> Result<Option<NonNull<pyo3_ffi::PyObject>>, DeserializeError<'static>> {
+    let obj_type_ptr = ob_type!(ptr);
+    if likely!(
+        is_type!(obj_type_ptr, BYTES_TYPE)
+            || is_type!(obj_type_ptr, STR_TYPE)
+            || is_type!(obj_type_ptr, MEMORYVIEW_TYPE)
+            || is_type!(obj_type_ptr, BYTEARRAY_TYPE)
+    ) || ffi!(PyObject_CheckBuffer(ptr)) == 0
+    {
+        return Ok(None);
+    }
+    let view = ffi!(PyMemoryView_FromObject(ptr));
+    if unlikely!(view.is_null()) {
+        return Err(DeserializeError::raised());
+    }
+    let membuf = unsafe { PyMemoryView_GET_BUFFER(view) };
+    if unsafe { pyo3_ffi::PyBuffer_IsContiguous(membuf, b'C' as c_char) == 0 } {
+        ffi!(Py_DECREF(view));
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            "Input buffer must be C contiguous",
+        )));
+    }
+    Ok(Some(nonnull!(view)))
+}
+
 pub fn read_input_to_buf(
     ptr: *mut pyo3_ffi::PyObject,
 ) -> Result<&'static [u8], DeserializeError<'static>> {
@@ -79,7 +110,7 @@ pub fn read_input_to_buf(
         }
     } else {
         return Err(DeserializeError::invalid(Cow::Borrowed(
-            "Input must be bytes, bytearray, memoryview, or str",
+            "Input must be bytes, bytearray, memoryview, str, or support the buffer protocol",
         )));
     }
     if unlikely!(buffer.is_empty()) {
@@ -90,3 +121,20 @@ pub fn read_input_to_buf(
         Ok(buffer)
     }
 }
+
+/// The UTF-8 byte order mark, which is skipped at the beginning of input.
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// The length of the byte order mark at the beginning of `buffer`, if any,
+/// and the input after it, which must not be empty.
+pub fn skip_bom(
+    buffer: &'static [u8],
+) -> Result<(usize, &'static [u8]), DeserializeError<'static>> {
+    match buffer.strip_prefix(UTF8_BOM) {
+        Some([]) => Err(DeserializeError::invalid(Cow::Borrowed(
+            "Input is a zero-length, empty document",
+        ))),
+        Some(rest) => Ok((UTF8_BOM.len(), rest)),
+        None => Ok((0, buffer)),
+    }
+}
@@ -72,6 +72,9 @@ pub fn deserialize_yyjson(
     };
     if unlikely!(doc.is_null()) {
         let msg: Cow<str> = unsafe { core::ffi::CStr::from_ptr(err.msg).to_string_lossy() };
+        if msg == "number is infinity when parsed as double" {
+            set_maybe_big_int();
+        }
         Err(DeserializeError::from_yyjson(msg, err.pos as i64, data))
     } else {
         let val = yyjson_doc_get_root(doc);
@@ -174,7 +177,7 @@ fn parse_yy_i64(elem: *mut yyjson_val) -> NonNull<pyo3_ffi::PyObject> {
 
 #[inline(always)]
 fn parse_yy_f64(elem: *mut yyjson_val) -> NonNull<pyo3_ffi::PyObject> {
-    parse_f64(unsafe { (*elem).uni.f64_ })
+    parse_backend_f64(unsafe { (*elem).uni.f64_ })
 }
 
 macro_rules! append_to_list {
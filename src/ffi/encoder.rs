@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{Format, Opt};
+use crate::serialize::DataclassLayouts;
+use core::ffi::{c_char, c_ulong};
+use core::ptr::{null_mut, NonNull};
+use pyo3_ffi::*;
+
+// https://docs.python.org/3/c-api/typeobj.html#typedef-examples
+
+#[repr(C)]
+pub struct Encoder {
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    pub default: *mut pyo3_ffi::PyObject,
+    pub opts: Opt,
+    pub formats: Format,
+    pub count: usize,
+    pub closed: bool,
+    pub layouts: *mut DataclassLayouts,
+}
+
+/// The arguments to the constructor of `orjson.Encoder` or
+/// `orjson.Serializer`. `default` is null if not given or `None`, and is
+/// otherwise a borrowed reference, as is `include`.
+pub struct ConstructorArgs {
+    pub default: *mut PyObject,
+    pub opts: Opt,
+    pub formats: Format,
+    pub max_depth: u16,
+    pub include: *mut PyObject,
+    pub float_precision: u8,
+}
+
+/// Read the arguments to the constructor of `orjson.Encoder` or
+/// `orjson.Serializer`, named `name` in error messages, returning `None` if
+/// an exception has been raised. These are `default`, `option`, and the
+/// `*_format` arguments, and also `max_depth`, `include`, and
+/// `float_precision` if `dumps_keywords`.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe fn constructor_args(
+    name: &str,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+    dumps_keywords: bool,
+) -> Option<ConstructorArgs> {
+    let num_args = Py_SIZE(args);
+    if num_args > 2 {
+        crate::raise_dumps_exception_fixed(&format!(
+            "{}() takes at most 2 positional arguments",
+            name
+        ));
+        return None;
+    }
+    let mut default: *mut PyObject = null_mut();
+    let mut optsptr: *mut PyObject = null_mut();
+    let mut depthptr: *mut PyObject = null_mut();
+    let mut includeptr: *mut PyObject = null_mut();
+    let mut precisionptr: *mut PyObject = null_mut();
+    if num_args >= 1 {
+        default = PyTuple_GET_ITEM(args, 0);
+    }
+    if num_args == 2 {
+        optsptr = PyTuple_GET_ITEM(args, 1);
+    }
+    let mut formats: Format = 0;
+    if !kwds.is_null() {
+        let mut pos: Py_ssize_t = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while PyDict_Next(kwds, &mut pos, &mut key, &mut value) != 0 {
+            if PyObject_RichCompareBool(key, crate::typeref::DEFAULT, Py_EQ) == 1 {
+                if num_args >= 1 {
+                    crate::raise_dumps_exception_fixed(&format!(
+                        "{}() got multiple values for argument: 'default'",
+                        name
+                    ));
+                    return None;
+                }
+                default = value;
+            } else if PyObject_RichCompareBool(key, crate::typeref::OPTION, Py_EQ) == 1 {
+                if num_args == 2 {
+                    crate::raise_dumps_exception_fixed(&format!(
+                        "{}() got multiple values for argument: 'option'",
+                        name
+                    ));
+                    return None;
+                }
+                optsptr = value;
+            } else if dumps_keywords
+                && PyObject_RichCompareBool(key, crate::typeref::MAX_DEPTH, Py_EQ) == 1
+            {
+                depthptr = value;
+            } else if dumps_keywords
+                && PyObject_RichCompareBool(key, crate::typeref::INCLUDE, Py_EQ) == 1
+            {
+                includeptr = value;
+            } else if dumps_keywords
+                && PyObject_RichCompareBool(key, crate::typeref::FLOAT_PRECISION, Py_EQ) == 1
+            {
+                precisionptr = value;
+            } else if let Err(msg) = crate::dumps_format(name, key, value, &mut formats) {
+                crate::raise_dumps_exception_fixed(&msg);
+                return None;
+            }
+        }
+    }
+    // OPT_FLOAT_FIXED requires float_precision, which orjson.Encoder does
+    // not take.
+    let opts = match crate::dumps_opts(NonNull::new(optsptr)) {
+        Some(opts) if dumps_keywords || opts & crate::opt::FLOAT_FIXED == 0 => opts,
+        _ => {
+            crate::raise_dumps_exception_fixed("Invalid opts");
+            return None;
+        }
+    };
+    let max_depth = match crate::dumps_max_depth(NonNull::new(depthptr)) {
+        Some(max_depth) => max_depth,
+        None => {
+            crate::raise_dumps_exception_fixed("Invalid max_depth");
+            return None;
+        }
+    };
+    let include = match crate::dumps_include(NonNull::new(includeptr)) {
+        Some(include) => include,
+        None => {
+            crate::raise_dumps_exception_fixed("Invalid include");
+            return None;
+        }
+    };
+    let float_precision = match crate::dumps_float_precision(NonNull::new(precisionptr), opts) {
+        Some(float_precision) => float_precision,
+        None => {
+            crate::raise_dumps_exception_fixed("Invalid float_precision");
+            return None;
+        }
+    };
+    if let Err(msg) = crate::dumps_formats_valid(opts, formats) {
+        crate::raise_dumps_exception_fixed(msg);
+        return None;
+    }
+    if default == crate::typeref::NONE {
+        default = null_mut();
+    }
+    Some(ConstructorArgs {
+        default: default,
+        opts: opts,
+        formats: formats,
+        max_depth: max_depth,
+        include: include,
+        float_precision: float_precision,
+    })
+}
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_encoder_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match constructor_args("orjson.Encoder", args, kwds, false) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    if !parsed.default.is_null() {
+        Py_INCREF(parsed.default);
+    }
+    let obj = Box::new(Encoder {
+        ob_refcnt: 1,
+        ob_type: crate::typeref::ENCODER_TYPE,
+        default: parsed.default,
+        opts: parsed.opts,
+        formats: parsed.formats,
+        count: 0,
+        closed: false,
+        layouts: Box::into_raw(Box::<DataclassLayouts>::default()),
+    });
+    Box::into_raw(obj) as *mut PyObject
+}
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_encoder_dealloc(object: *mut PyObject) {
+    let default = (*(object as *mut Encoder)).default;
+    if !default.is_null() {
+        Py_DECREF(default);
+    }
+    drop(Box::from_raw((*(object as *mut Encoder)).layouts));
+    std::alloc::dealloc(object as *mut u8, std::alloc::Layout::new::<Encoder>());
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn orjson_encoder_write_chunk(
+    object: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    let encoder = object as *mut Encoder;
+    if unlikely!((*encoder).closed) {
+        return crate::raise_dumps_exception_fixed("orjson.Encoder is closed");
+    }
+    let res = crate::serialize::with_dataclass_layouts((*encoder).layouts, || {
+        crate::serialize::with_formats((*encoder).formats, || {
+            crate::serialize::serialize_array_element(
+                obj,
+                NonNull::new((*encoder).default),
+                (*encoder).opts,
+                (*encoder).count == 0,
+            )
+        })
+    });
+    match res {
+        Ok(val) => {
+            (*encoder).count += 1;
+            val.as_ptr()
+        }
+        Err(err) => crate::raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_encoder_close(
+    object: *mut PyObject,
+    _args: *mut PyObject,
+) -> *mut PyObject {
+    let encoder = object as *mut Encoder;
+    if unlikely!((*encoder).closed) {
+        return crate::raise_dumps_exception_fixed("orjson.Encoder is closed");
+    }
+    (*encoder).closed = true;
+    crate::serialize::serialize_array_end((*encoder).opts, (*encoder).count == 0).as_ptr()
+}
+
+#[cfg(Py_3_10)]
+const ENCODER_TP_FLAGS: c_ulong = Py_TPFLAGS_DEFAULT | Py_TPFLAGS_IMMUTABLETYPE;
+
+#[cfg(not(Py_3_10))]
+const ENCODER_TP_FLAGS: c_ulong = Py_TPFLAGS_DEFAULT;
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_encodertype_new() -> *mut PyTypeObject {
+    let methods = Box::new([
+        PyMethodDef {
+            ml_name: "write_chunk\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                PyCFunction: orjson_encoder_write_chunk,
+            },
+            ml_flags: METH_O,
+            ml_doc: "write_chunk($self, obj, /)\n--\n\nSerialize obj as the next element of the array.\0"
+                .as_ptr() as *const c_char,
+        },
+        PyMethodDef {
+            ml_name: "close\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                PyCFunction: orjson_encoder_close,
+            },
+            ml_flags: METH_NOARGS,
+            ml_doc: "close($self, /)\n--\n\nEnd the array.\0".as_ptr() as *const c_char,
+        },
+        PyMethodDef::zeroed(),
+    ]);
+    let ob = Box::new(PyTypeObject {
+        ob_base: PyVarObject {
+            ob_base: PyObject {
+                #[cfg(Py_3_12)]
+                ob_refcnt: pyo3_ffi::PyObjectObRefcnt { ob_refcnt: 0 },
+                #[cfg(not(Py_3_12))]
+                ob_refcnt: 0,
+                ob_type: core::ptr::addr_of_mut!(PyType_Type),
+            },
+            ob_size: 0,
+        },
+        tp_name: "orjson.Encoder\0".as_ptr() as *const c_char,
+        tp_basicsize: core::mem::size_of::<Encoder>() as isize,
+        tp_itemsize: 0,
+        tp_dealloc: Some(orjson_encoder_dealloc),
+        tp_init: None,
+        tp_new: Some(orjson_encoder_tp_new),
+        tp_flags: ENCODER_TP_FLAGS,
+        // ...
+        tp_bases: null_mut(),
+        tp_cache: null_mut(),
+        tp_del: None,
+        tp_finalize: None,
+        tp_free: None,
+        tp_is_gc: None,
+        tp_mro: null_mut(),
+        tp_subclasses: null_mut(),
+        tp_vectorcall: None,
+        tp_version_tag: 0,
+        tp_weaklist: null_mut(),
+        #[cfg(not(Py_3_9))]
+        tp_print: None,
+        tp_vectorcall_offset: 0,
+        tp_getattr: None,
+        tp_setattr: None,
+        tp_as_async: null_mut(),
+        tp_repr: None,
+        tp_as_number: null_mut(),
+        tp_as_sequence: null_mut(),
+        tp_as_mapping: null_mut(),
+        tp_hash: None,
+        tp_call: None,
+        tp_str: None,
+        tp_getattro: None,
+        tp_setattro: None,
+        tp_as_buffer: null_mut(),
+        tp_doc: "Encoder(default=None, option=None, *, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize a JSON array one element at a time.\0"
+            .as_ptr() as *const c_char,
+        tp_traverse: None,
+        tp_clear: None,
+        tp_richcompare: None,
+        tp_weaklistoffset: 0,
+        tp_iter: None,
+        tp_iternext: None,
+        tp_methods: Box::into_raw(methods) as *mut PyMethodDef,
+        tp_members: null_mut(),
+        tp_getset: null_mut(),
+        tp_base: null_mut(),
+        tp_dict: null_mut(),
+        tp_descr_get: None,
+        tp_descr_set: None,
+        tp_dictoffset: 0,
+        tp_alloc: None,
+        #[cfg(Py_3_12)]
+        tp_watched: 0,
+    });
+    let ob_ptr = Box::into_raw(ob);
+    PyType_Ready(ob_ptr);
+    ob_ptr
+}
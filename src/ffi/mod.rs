@@ -2,12 +2,16 @@
 
 mod buffer;
 mod bytes;
+mod encoder;
 mod fragment;
 mod long;
+mod serializer;
 #[cfg(feature = "yyjson")]
 pub mod yyjson;
 
 pub use buffer::*;
 pub use bytes::*;
+pub use encoder::orjson_encodertype_new;
 pub use fragment::{orjson_fragmenttype_new, Fragment};
 pub use long::{pylong_is_unsigned, pylong_is_zero, pylong_value_signed, pylong_value_unsigned};
+pub use serializer::orjson_serializertype_new;
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::ffi::encoder::constructor_args;
+use crate::opt::{Format, Opt};
+use core::ffi::{c_char, c_ulong};
+use core::ptr::{null_mut, NonNull};
+use pyo3_ffi::*;
+
+// https://docs.python.org/3/c-api/typeobj.html#typedef-examples
+
+/// `orjson.Serializer`, which validates the arguments of `dumps()` other than
+/// `obj` once and reuses them for each call to `dumps()`. It is not modified
+/// after it is created, so it may be shared between threads. `include` is
+/// copied to a `frozenset` so that it is not either.
+#[repr(C)]
+pub struct Serializer {
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    pub default: *mut pyo3_ffi::PyObject,
+    pub include: *mut pyo3_ffi::PyObject,
+    pub opts: Opt,
+    pub formats: Format,
+    pub max_depth: u16,
+    pub float_precision: u8,
+}
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_serializer_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match constructor_args("orjson.Serializer", args, kwds, true) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    if !parsed.default.is_null() && PyCallable_Check(parsed.default) == 0 {
+        return crate::raise_dumps_exception_fixed("Invalid default");
+    }
+    let mut include = parsed.include;
+    if !include.is_null() {
+        include = PyFrozenSet_New(include);
+        if include.is_null() {
+            return null_mut();
+        }
+    }
+    if !parsed.default.is_null() {
+        Py_INCREF(parsed.default);
+    }
+    let obj = Box::new(Serializer {
+        ob_refcnt: 1,
+        ob_type: crate::typeref::SERIALIZER_TYPE,
+        default: parsed.default,
+        include: include,
+        opts: parsed.opts,
+        formats: parsed.formats,
+        max_depth: parsed.max_depth,
+        float_precision: parsed.float_precision,
+    });
+    Box::into_raw(obj) as *mut PyObject
+}
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_serializer_dealloc(object: *mut PyObject) {
+    let default = (*(object as *mut Serializer)).default;
+    if !default.is_null() {
+        Py_DECREF(default);
+    }
+    let include = (*(object as *mut Serializer)).include;
+    if !include.is_null() {
+        Py_DECREF(include);
+    }
+    std::alloc::dealloc(object as *mut u8, std::alloc::Layout::new::<Serializer>());
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn orjson_serializer_dumps(
+    object: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    let serializer = &*(object as *mut Serializer);
+    let res = crate::serialize::with_float_precision(serializer.float_precision, || {
+        crate::serialize::with_dataclass_include(serializer.include, || {
+            crate::serialize::with_formats(serializer.formats, || {
+                crate::serialize::serialize(
+                    obj,
+                    NonNull::new(serializer.default),
+                    serializer.opts,
+                    serializer.max_depth,
+                )
+            })
+        })
+    });
+    match res {
+        Ok(val) => val.as_ptr(),
+        Err(err) => crate::raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+#[cfg(Py_3_10)]
+const SERIALIZER_TP_FLAGS: c_ulong = Py_TPFLAGS_DEFAULT | Py_TPFLAGS_IMMUTABLETYPE;
+
+#[cfg(not(Py_3_10))]
+const SERIALIZER_TP_FLAGS: c_ulong = Py_TPFLAGS_DEFAULT;
+
+#[no_mangle]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub unsafe extern "C" fn orjson_serializertype_new() -> *mut PyTypeObject {
+    let methods = Box::new([
+        PyMethodDef {
+            ml_name: "dumps\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                PyCFunction: orjson_serializer_dumps,
+            },
+            ml_flags: METH_O,
+            ml_doc: "dumps($self, obj, /)\n--\n\nSerialize obj to JSON with the arguments of the serializer.\0"
+                .as_ptr() as *const c_char,
+        },
+        PyMethodDef::zeroed(),
+    ]);
+    let ob = Box::new(PyTypeObject {
+        ob_base: PyVarObject {
+            ob_base: PyObject {
+                #[cfg(Py_3_12)]
+                ob_refcnt: pyo3_ffi::PyObjectObRefcnt { ob_refcnt: 0 },
+                #[cfg(not(Py_3_12))]
+                ob_refcnt: 0,
+                ob_type: core::ptr::addr_of_mut!(PyType_Type),
+            },
+            ob_size: 0,
+        },
+        tp_name: "orjson.Serializer\0".as_ptr() as *const c_char,
+        tp_basicsize: core::mem::size_of::<Serializer>() as isize,
+        tp_itemsize: 0,
+        tp_dealloc: Some(orjson_serializer_dealloc),
+        tp_init: None,
+        tp_new: Some(orjson_serializer_tp_new),
+        tp_flags: SERIALIZER_TP_FLAGS,
+        // ...
+        tp_bases: null_mut(),
+        tp_cache: null_mut(),
+        tp_del: None,
+        tp_finalize: None,
+        tp_free: None,
+        tp_is_gc: None,
+        tp_mro: null_mut(),
+        tp_subclasses: null_mut(),
+        tp_vectorcall: None,
+        tp_version_tag: 0,
+        tp_weaklist: null_mut(),
+        #[cfg(not(Py_3_9))]
+        tp_print: None,
+        tp_vectorcall_offset: 0,
+        tp_getattr: None,
+        tp_setattr: None,
+        tp_as_async: null_mut(),
+        tp_repr: None,
+        tp_as_number: null_mut(),
+        tp_as_sequence: null_mut(),
+        tp_as_mapping: null_mut(),
+        tp_hash: None,
+        tp_call: None,
+        tp_str: None,
+        tp_getattro: None,
+        tp_setattro: None,
+        tp_as_buffer: null_mut(),
+        tp_doc: "Serializer(default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize Python objects to JSON with arguments validated once.\0"
+            .as_ptr() as *const c_char,
+        tp_traverse: None,
+        tp_clear: None,
+        tp_richcompare: None,
+        tp_weaklistoffset: 0,
+        tp_iter: None,
+        tp_iternext: None,
+        tp_methods: Box::into_raw(methods) as *mut PyMethodDef,
+        tp_members: null_mut(),
+        tp_getset: null_mut(),
+        tp_base: null_mut(),
+        tp_dict: null_mut(),
+        tp_descr_get: None,
+        tp_descr_set: None,
+        tp_dictoffset: 0,
+        tp_alloc: None,
+        #[cfg(Py_3_12)]
+        tp_watched: 0,
+    });
+    let ob_ptr = Box::into_raw(ob);
+    PyType_Ready(ob_ptr);
+    ob_ptr
+}
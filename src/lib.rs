@@ -38,6 +38,7 @@ mod typeref;
 
 use core::ffi::{c_char, c_int, c_void};
 use pyo3_ffi::*;
+use std::borrow::Cow;
 
 #[allow(unused_imports)]
 use core::ptr::{null, null_mut, NonNull};
@@ -56,14 +57,11 @@ macro_rules! add {
     };
 }
 
+// Options are added as `int` objects because PyModule_AddIntConstant() takes a
+// C long, which is 32 bits on Windows, and is signed.
 macro_rules! opt {
     ($mptr:expr, $name:expr, $opt:expr) => {
-        #[cfg(all(not(target_os = "windows"), target_pointer_width = "64"))]
-        PyModule_AddIntConstant($mptr, $name.as_ptr() as *const c_char, $opt as i64);
-        #[cfg(all(not(target_os = "windows"), target_pointer_width = "32"))]
-        PyModule_AddIntConstant($mptr, $name.as_ptr() as *const c_char, $opt as i32);
-        #[cfg(target_os = "windows")]
-        PyModule_AddIntConstant($mptr, $name.as_ptr() as *const c_char, $opt as i32);
+        add!($mptr, $name, PyLong_FromUnsignedLongLong($opt));
     };
 }
 
@@ -81,7 +79,7 @@ pub unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
     }
     {
         let dumps_doc =
-            "dumps(obj, /, default=None, option=None)\n--\n\nSerialize Python objects to JSON.\0";
+            "dumps(obj, /, default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize Python objects to JSON.\0";
 
         let wrapped_dumps = PyMethodDef {
             ml_name: "dumps\0".as_ptr() as *const c_char,
@@ -101,12 +99,97 @@ pub unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
     }
 
     {
-        let loads_doc = "loads(obj, /)\n--\n\nDeserialize JSON to Python objects.\0";
+        let dumps_str_doc =
+            "dumps_str(obj, /, default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize Python objects to JSON as a str.\0";
+
+        let wrapped_dumps_str = PyMethodDef {
+            ml_name: "dumps_str\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: dumps_str,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: dumps_str_doc.as_ptr() as *const c_char,
+        };
+
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_dumps_str)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "dumps_str\0", func);
+    }
+
+    {
+        let dumps_with_stats_doc =
+            "dumps_with_stats(obj, /, default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize Python objects to JSON, returning the bytes and counts of what serializing did.\0";
+
+        let wrapped_dumps_with_stats = PyMethodDef {
+            ml_name: "dumps_with_stats\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: dumps_with_stats,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: dumps_with_stats_doc.as_ptr() as *const c_char,
+        };
+
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_dumps_with_stats)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "dumps_with_stats\0", func);
+    }
+
+    {
+        let dumps_into_doc = "dumps_into(obj, buffer, /, default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize Python objects to JSON by appending to a bytearray.\0";
+
+        let wrapped_dumps_into = PyMethodDef {
+            ml_name: "dumps_into\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: dumps_into,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: dumps_into_doc.as_ptr() as *const c_char,
+        };
+
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_dumps_into)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "dumps_into\0", func);
+    }
+
+    {
+        let dumps_lines_doc =
+            "dumps_lines(obj, /, default=None, option=None, *, max_depth=None, include=None, float_precision=None, bytes_format=None, complex_format=None, date_format=None, datetime_format=None, exception_format=None, fraction_format=None, range_format=None, time_format=None, uuid_format=None)\n--\n\nSerialize each element of an iterable to JSON on its own line.\0";
+
+        let wrapped_dumps_lines = PyMethodDef {
+            ml_name: "dumps_lines\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: dumps_lines,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: dumps_lines_doc.as_ptr() as *const c_char,
+        };
+
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_dumps_lines)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "dumps_lines\0", func);
+    }
+
+    {
+        let loads_doc = "loads(obj, /, option=None, *, max_depth=None, object_hook=None, array_hook=None)\n--\n\nDeserialize JSON to Python objects.\0";
 
         let wrapped_loads = PyMethodDef {
             ml_name: "loads\0".as_ptr() as *const c_char,
-            ml_meth: PyMethodDefPointer { PyCFunction: loads },
-            ml_flags: METH_O,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: loads,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
             ml_doc: loads_doc.as_ptr() as *const c_char,
         };
         let func = PyCFunction_NewEx(
@@ -117,13 +200,124 @@ pub unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         add!(mptr, "loads\0", func);
     }
 
+    {
+        let load_doc = "load(fp, /, option=None, *, max_depth=None, object_hook=None, array_hook=None)\n--\n\nDeserialize JSON read from a file object or buffer to Python objects.\0";
+
+        let wrapped_load = PyMethodDef {
+            ml_name: "load\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: load,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: load_doc.as_ptr() as *const c_char,
+        };
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_load)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "load\0", func);
+    }
+
+    {
+        let loads_typed_doc = "loads_typed(obj, cls, /, *, strict=True)\n--\n\nDeserialize JSON to an instance of a dataclass.\0";
+
+        let wrapped_loads_typed = PyMethodDef {
+            ml_name: "loads_typed\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: loads_typed,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: loads_typed_doc.as_ptr() as *const c_char,
+        };
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_loads_typed)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "loads_typed\0", func);
+    }
+
+    {
+        let loads_ndarray_doc = "loads_ndarray(obj, /, dtype=None)\n--\n\nDeserialize a JSON array of numbers to a numpy.ndarray.\0";
+
+        let wrapped_loads_ndarray = PyMethodDef {
+            ml_name: "loads_ndarray\0".as_ptr() as *const c_char,
+            ml_meth: PyMethodDefPointer {
+                _PyCFunctionFastWithKeywords: loads_ndarray,
+            },
+            ml_flags: pyo3_ffi::METH_FASTCALL | METH_KEYWORDS,
+            ml_doc: loads_ndarray_doc.as_ptr() as *const c_char,
+        };
+        let func = PyCFunction_NewEx(
+            Box::into_raw(Box::new(wrapped_loads_ndarray)),
+            null_mut(),
+            PyUnicode_InternFromString("orjson\0".as_ptr() as *const c_char),
+        );
+        add!(mptr, "loads_ndarray\0", func);
+    }
+
+    add!(mptr, "Encoder\0", typeref::ENCODER_TYPE as *mut PyObject);
     add!(mptr, "Fragment\0", typeref::FRAGMENT_TYPE as *mut PyObject);
+    add!(
+        mptr,
+        "Serializer\0",
+        typeref::SERIALIZER_TYPE as *mut PyObject
+    );
 
+    opt!(mptr, "OPT_ALLOW_COMMENTS\0", opt::ALLOW_COMMENTS);
+    opt!(mptr, "OPT_ALLOW_NONFINITE\0", opt::ALLOW_NONFINITE);
+    opt!(
+        mptr,
+        "OPT_ALLOW_TRAILING_COMMA\0",
+        opt::ALLOW_TRAILING_COMMA
+    );
     opt!(mptr, "OPT_APPEND_NEWLINE\0", opt::APPEND_NEWLINE);
+    opt!(mptr, "OPT_ASCII\0", opt::ASCII);
+    opt!(mptr, "OPT_CAMELCASE\0", opt::CAMELCASE);
+    opt!(mptr, "OPT_CANONICAL\0", opt::CANONICAL);
+    opt!(mptr, "OPT_CAPITALIZE_BOOL\0", opt::CAPITALIZE_BOOL);
+    opt!(
+        mptr,
+        "OPT_DATACLASS_FIELD_RENAME\0",
+        opt::DATACLASS_FIELD_RENAME
+    );
+    opt!(
+        mptr,
+        "OPT_DATACLASS_SKIP_MISSING\0",
+        opt::DATACLASS_SKIP_MISSING
+    );
+    opt!(mptr, "OPT_DECIMAL_AS_STRING\0", opt::DECIMAL_AS_STRING);
+    opt!(mptr, "OPT_DICT_AS_PAIRS\0", opt::DICT_AS_PAIRS);
+    opt!(mptr, "OPT_ENUM_BY_NAME\0", opt::ENUM_BY_NAME);
+    opt!(
+        mptr,
+        "OPT_ESCAPE_FORWARD_SLASH\0",
+        opt::ESCAPE_FORWARD_SLASH
+    );
+    opt!(mptr, "OPT_FLOAT_FIXED\0", opt::FLOAT_FIXED);
+    opt!(
+        mptr,
+        "OPT_INCLUDE_PRIVATE_FIELDS\0",
+        opt::INCLUDE_PRIVATE_FIELDS
+    );
     opt!(mptr, "OPT_INDENT_2\0", opt::INDENT_2);
+    opt!(mptr, "OPT_INDENT_4\0", opt::INDENT_4);
+    opt!(mptr, "OPT_INDENT_TAB\0", opt::INDENT_TAB);
+    opt!(mptr, "OPT_INTERN_KEYS\0", opt::INTERN_KEYS);
+    opt!(mptr, "OPT_KEYS_AS_BYTES\0", opt::KEYS_AS_BYTES);
     opt!(mptr, "OPT_NAIVE_UTC\0", opt::NAIVE_UTC);
+    opt!(mptr, "OPT_NANOSECOND\0", opt::NANOSECOND);
     opt!(mptr, "OPT_NON_STR_KEYS\0", opt::NON_STR_KEYS);
+    opt!(mptr, "OPT_NULL_AS_NIL\0", opt::NULL_AS_NIL);
+    opt!(
+        mptr,
+        "OPT_NUMPY_NONFINITE_AS_NULL\0",
+        opt::NUMPY_NONFINITE_AS_NULL
+    );
     opt!(mptr, "OPT_OMIT_MICROSECONDS\0", opt::OMIT_MICROSECONDS);
+    opt!(mptr, "OPT_PARSE_DECIMAL\0", opt::PARSE_DECIMAL);
+    opt!(mptr, "OPT_PARSE_UUID\0", opt::PARSE_UUID);
     opt!(
         mptr,
         "OPT_PASSTHROUGH_DATACLASS\0",
@@ -134,17 +328,68 @@ pub unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         "OPT_PASSTHROUGH_DATETIME\0",
         opt::PASSTHROUGH_DATETIME
     );
+    opt!(mptr, "OPT_PASSTHROUGH_ENUM\0", opt::PASSTHROUGH_ENUM);
     opt!(
         mptr,
         "OPT_PASSTHROUGH_SUBCLASS\0",
         opt::PASSTHROUGH_SUBCLASS
     );
+    opt!(
+        mptr,
+        "OPT_PRESERVE_ORDERED_DICT\0",
+        opt::PRESERVE_ORDERED_DICT
+    );
+    opt!(
+        mptr,
+        "OPT_REJECT_DUPLICATE_KEYS\0",
+        opt::REJECT_DUPLICATE_KEYS
+    );
+    opt!(
+        mptr,
+        "OPT_REQUIRE_AWARE_DATETIME\0",
+        opt::REQUIRE_AWARE_DATETIME
+    );
+    opt!(mptr, "OPT_SERIALIZE_ARRAY\0", opt::SERIALIZE_ARRAY);
+    opt!(mptr, "OPT_SERIALIZE_COMPLEX\0", opt::SERIALIZE_COMPLEX);
     opt!(mptr, "OPT_SERIALIZE_DATACLASS\0", opt::SERIALIZE_DATACLASS);
+    opt!(mptr, "OPT_SERIALIZE_DECIMAL\0", opt::SERIALIZE_DECIMAL);
+    opt!(mptr, "OPT_SERIALIZE_EXCEPTION\0", opt::SERIALIZE_EXCEPTION);
+    opt!(mptr, "OPT_SERIALIZE_FRACTION\0", opt::SERIALIZE_FRACTION);
+    opt!(mptr, "OPT_SERIALIZE_IPADDRESS\0", opt::SERIALIZE_IPADDRESS);
+    opt!(mptr, "OPT_SERIALIZE_ITERATORS\0", opt::SERIALIZE_ITERATORS);
+    opt!(
+        mptr,
+        "OPT_SERIALIZE_JSON_PROTOCOL\0",
+        opt::SERIALIZE_JSON_PROTOCOL
+    );
+    opt!(
+        mptr,
+        "OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT\0",
+        opt::SERIALIZE_NAMEDTUPLE_AS_OBJECT
+    );
     opt!(mptr, "OPT_SERIALIZE_NUMPY\0", opt::SERIALIZE_NUMPY);
+    opt!(mptr, "OPT_SERIALIZE_PANDAS\0", opt::SERIALIZE_PANDAS);
+    opt!(mptr, "OPT_SERIALIZE_PATH\0", opt::SERIALIZE_PATH);
+    opt!(mptr, "OPT_SERIALIZE_PROXY\0", opt::SERIALIZE_PROXY);
+    opt!(mptr, "OPT_SERIALIZE_RANGE\0", opt::SERIALIZE_RANGE);
+    opt!(mptr, "OPT_SERIALIZE_SET\0", opt::SERIALIZE_SET);
     opt!(mptr, "OPT_SERIALIZE_UUID\0", opt::SERIALIZE_UUID);
+    opt!(mptr, "OPT_SKIP_NONE_VALUES\0", opt::SKIP_NONE_VALUES);
     opt!(mptr, "OPT_SORT_KEYS\0", opt::SORT_KEYS);
+    opt!(
+        mptr,
+        "OPT_SORT_KEYS_CASE_INSENSITIVE\0",
+        opt::SORT_KEYS_CASE_INSENSITIVE
+    );
+    opt!(mptr, "OPT_SPACE_AFTER_COLON\0", opt::SPACE_AFTER_COLON);
+    opt!(mptr, "OPT_SPACE_AFTER_COMMA\0", opt::SPACE_AFTER_COMMA);
     opt!(mptr, "OPT_STRICT_INTEGER\0", opt::STRICT_INTEGER);
+    opt!(mptr, "OPT_TIMEDELTA_ISO8601\0", opt::TIMEDELTA_ISO8601);
+    opt!(mptr, "OPT_TIMEDELTA_SECONDS\0", opt::TIMEDELTA_SECONDS);
     opt!(mptr, "OPT_UTC_Z\0", opt::UTC_Z);
+    opt!(mptr, "OPT_UTF8_BOM\0", opt::UTF8_BOM);
+    opt!(mptr, "OPT_VALIDATE_FRAGMENT\0", opt::VALIDATE_FRAGMENT);
+    opt!(mptr, "OPT_WHOLE_FLOAT_AS_INT\0", opt::WHOLE_FLOAT_AS_INT);
 
     add!(mptr, "JSONDecodeError\0", typeref::JsonDecodeError);
     add!(mptr, "JSONEncodeError\0", typeref::JsonEncodeError);
@@ -215,7 +460,11 @@ pub unsafe extern "C" fn PyInit_orjson() -> *mut PyModuleDef {
 #[inline(never)]
 #[cfg_attr(feature = "optimize", optimize(size))]
 fn raise_loads_exception(err: deserialize::DeserializeError) -> *mut PyObject {
+    if err.raised {
+        return null_mut();
+    }
     let pos = err.pos();
+    let snippet = err.snippet();
     let msg = err.message;
     let doc = match err.data {
         Some(as_str) => unsafe {
@@ -233,13 +482,60 @@ fn raise_loads_exception(err: deserialize::DeserializeError) -> *mut PyObject {
         PyTuple_SET_ITEM(args, 0, err_msg);
         PyTuple_SET_ITEM(args, 1, doc);
         PyTuple_SET_ITEM(args, 2, pos);
-        PyErr_SetObject(typeref::JsonDecodeError, args);
+        // Discard any error from reading the input, as PyErr_SetObject() would.
+        PyErr_Clear();
+        let exc = PyObject_Call(typeref::JsonDecodeError, args, null_mut());
         debug_assert!(ffi!(Py_REFCNT(args)) <= 2);
         Py_DECREF(args);
+        if unlikely!(exc.is_null()) {
+            return null_mut();
+        }
+        set_loads_exception_snippet(exc, snippet);
+        PyErr_SetObject(typeref::JsonDecodeError, exc);
+        Py_DECREF(exc);
     };
     null_mut()
 }
 
+/// Set `snippet` on a `JSONDecodeError` and append it to the message with a
+/// caret under the position of the error.
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn set_loads_exception_snippet(exc: *mut PyObject, snippet: Option<(&str, usize)>) {
+    match snippet {
+        Some((snippet, offset)) => {
+            let val = PyUnicode_FromStringAndSize(
+                snippet.as_ptr() as *const c_char,
+                snippet.len() as isize,
+            );
+            PyObject_SetAttrString(exc, "snippet\0".as_ptr() as *const c_char, val);
+            Py_DECREF(val);
+
+            let msg = PyObject_Str(exc);
+            let indent: String = snippet
+                .chars()
+                .take(offset)
+                .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+                .collect();
+            let suffix = format!("\n{}\n{}^", snippet, indent);
+            let suffix = PyUnicode_FromStringAndSize(
+                suffix.as_ptr() as *const c_char,
+                suffix.len() as isize,
+            );
+            let args = PyTuple_New(1);
+            PyTuple_SET_ITEM(args, 0, PyUnicode_Concat(msg, suffix));
+            PyObject_SetAttrString(exc, "args\0".as_ptr() as *const c_char, args);
+            Py_DECREF(args);
+            Py_DECREF(suffix);
+            Py_DECREF(msg);
+        }
+        None => {
+            PyObject_SetAttrString(exc, "snippet\0".as_ptr() as *const c_char, typeref::NONE);
+        }
+    }
+}
+
 #[cold]
 #[inline(never)]
 #[cfg_attr(feature = "optimize", optimize(size))]
@@ -259,6 +555,9 @@ fn raise_dumps_exception_fixed(msg: &str) -> *mut PyObject {
 #[cfg_attr(feature = "optimize", optimize(size))]
 #[cfg(Py_3_12)]
 fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
+    if serialize::take_exception_raised() {
+        return null_mut();
+    }
     unsafe {
         let cause_exc: *mut PyObject = PyErr_GetRaisedException();
 
@@ -282,6 +581,9 @@ fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
 #[cfg_attr(feature = "optimize", optimize(size))]
 #[cfg(not(Py_3_12))]
 fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
+    if serialize::take_exception_raised() {
+        return null_mut();
+    }
     unsafe {
         let mut cause_tp: *mut PyObject = null_mut();
         let mut cause_val: *mut PyObject = null_mut();
@@ -313,29 +615,540 @@ fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
     null_mut()
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn loads(_self: *mut PyObject, obj: *mut PyObject) -> *mut PyObject {
-    match crate::deserialize::deserialize(obj) {
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+fn raise_loads_exception_fixed(msg: &'static str) -> *mut PyObject {
+    raise_loads_exception(deserialize::DeserializeError::invalid(Cow::Borrowed(msg)))
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+fn raise_loads_argument_error(msg: &str) -> *mut PyObject {
+    unsafe {
+        let err_msg =
+            PyUnicode_FromStringAndSize(msg.as_ptr() as *const c_char, msg.len() as isize);
+        PyErr_SetObject(PyExc_TypeError, err_msg);
+        Py_DECREF(err_msg);
+    };
+    null_mut()
+}
+
+/// The arguments to `loads()` and `load()` other than the input.
+struct LoadsArgs {
+    opts: opt::Opt,
+    max_depth: Option<usize>,
+    object_hook: Option<NonNull<PyObject>>,
+    array_hook: Option<NonNull<PyObject>>,
+}
+
+/// Read the arguments to `loads()` or `load()`, named `name` in error
+/// messages, returning `None` if an exception has been raised.
+#[inline(always)]
+unsafe fn loads_args(
+    name: &str,
+    arg_name: &str,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> Option<LoadsArgs> {
+    let mut optsptr: Option<NonNull<PyObject>> = None;
+    let mut max_depth_ptr: Option<NonNull<PyObject>> = None;
+    let mut object_hook: Option<NonNull<PyObject>> = None;
+    let mut array_hook: Option<NonNull<PyObject>> = None;
+
+    let num_args = PyVectorcall_NARGS(nargs as usize);
+    if unlikely!(num_args == 0) {
+        raise_loads_argument_error(&format!(
+            "{}() missing 1 required positional argument: '{}'",
+            name, arg_name
+        ));
+        return None;
+    }
+    if unlikely!(num_args > 2) {
+        raise_loads_argument_error(&format!("{}() takes at most 2 positional arguments", name));
+        return None;
+    }
+    if num_args == 2 {
+        optsptr = Some(NonNull::new_unchecked(*args.offset(1)));
+    }
+    if unlikely!(!kwnames.is_null()) {
+        for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+            let arg = PyTuple_GET_ITEM(kwnames, i as Py_ssize_t);
+            if arg == typeref::OPTION {
+                if unlikely!(num_args == 2) {
+                    raise_loads_argument_error(&format!(
+                        "{}() got multiple values for argument: 'option'",
+                        name
+                    ));
+                    return None;
+                }
+                optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::MAX_DEPTH {
+                max_depth_ptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::OBJECT_HOOK {
+                let hook = *args.offset(num_args + i);
+                if hook != typeref::NONE {
+                    object_hook = Some(NonNull::new_unchecked(hook));
+                }
+            } else if arg == typeref::ARRAY_HOOK {
+                let hook = *args.offset(num_args + i);
+                if hook != typeref::NONE {
+                    array_hook = Some(NonNull::new_unchecked(hook));
+                }
+            } else {
+                raise_loads_argument_error(&format!(
+                    "{}() got an unexpected keyword argument",
+                    name
+                ));
+                return None;
+            }
+        }
+    }
+
+    let mut optsbits: opt::Opt = 0;
+    if let Some(opts) = optsptr {
+        if (*opts.as_ptr()).ob_type == typeref::INT_TYPE {
+            optsbits = PyLong_AsUnsignedLongLong(opts.as_ptr());
+            if unlikely!(optsbits & !opt::MAX_LOADS_OPT != 0) {
+                PyErr_Clear();
+                raise_loads_exception_fixed("Invalid opts");
+                return None;
+            }
+        } else if unlikely!(opts.as_ptr() != typeref::NONE) {
+            raise_loads_exception_fixed("Invalid opts");
+            return None;
+        }
+    }
+
+    let mut max_depth: Option<usize> = None;
+    if let Some(depth) = max_depth_ptr {
+        if (*depth.as_ptr()).ob_type == typeref::INT_TYPE {
+            let val = PyLong_AsLongLong(depth.as_ptr());
+            if unlikely!(val < 0) {
+                PyErr_Clear();
+                raise_loads_exception_fixed("Invalid max_depth");
+                return None;
+            }
+            max_depth = Some(val as usize);
+        } else if unlikely!(depth.as_ptr() != typeref::NONE) {
+            raise_loads_exception_fixed("Invalid max_depth");
+            return None;
+        }
+    }
+
+    Some(LoadsArgs {
+        opts: optsbits,
+        max_depth: max_depth,
+        object_hook: object_hook,
+        array_hook: array_hook,
+    })
+}
+
+/// Call `f` with `obj` or, if it is another object supporting the buffer
+/// protocol, e.g., `mmap.mmap`, with a `memoryview` of it so that it is read
+/// in place. The view is released only after any error has been raised
+/// because the error refers to the buffer.
+#[inline(always)]
+unsafe fn loads_input<F>(obj: *mut PyObject, f: F) -> *mut PyObject
+where
+    F: FnOnce(*mut PyObject) -> Result<NonNull<PyObject>, deserialize::DeserializeError<'static>>,
+{
+    let view = match deserialize::buffer_protocol_view(obj) {
+        Ok(view) => view,
+        Err(err) => return raise_loads_exception(err),
+    };
+    let ret = match f(view.map_or(obj, |view| view.as_ptr())) {
         Ok(val) => val.as_ptr(),
         Err(err) => raise_loads_exception(err),
+    };
+    if let Some(view) = view {
+        Py_DECREF(view.as_ptr());
     }
+    ret
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn dumps(
+pub unsafe extern "C" fn loads(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match loads_args("loads", "obj", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    loads_input(*args, |obj| {
+        crate::deserialize::deserialize(
+            obj,
+            parsed.opts,
+            parsed.max_depth,
+            parsed.object_hook,
+            parsed.array_hook,
+        )
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn load(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match loads_args("load", "fp", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    let fp = *args;
+    if PyObject_CheckBuffer(fp) == 1 {
+        return loads_input(fp, |obj| {
+            crate::deserialize::deserialize(
+                obj,
+                parsed.opts,
+                parsed.max_depth,
+                parsed.object_hook,
+                parsed.array_hook,
+            )
+        });
+    }
+    let read = PyObject_GetAttr(fp, typeref::READ_STR);
+    if unlikely!(read.is_null()) {
+        PyErr_Clear();
+        return raise_loads_argument_error(
+            "load() fp must have a read() method or support the buffer protocol",
+        );
+    }
+    let contents = PyObject_CallFunctionObjArgs(read, null_mut::<PyObject>());
+    Py_DECREF(read);
+    if unlikely!(contents.is_null()) {
+        return null_mut();
+    }
+    let ret = loads_input(contents, |obj| {
+        crate::deserialize::deserialize(
+            obj,
+            parsed.opts,
+            parsed.max_depth,
+            parsed.object_hook,
+            parsed.array_hook,
+        )
+    });
+    Py_DECREF(contents);
+    ret
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn loads_typed(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let mut strict = true;
+
+    let num_args = PyVectorcall_NARGS(nargs as usize);
+    if unlikely!(num_args != 2) {
+        return raise_loads_argument_error(
+            "loads_typed() takes exactly 2 positional arguments: 'obj' and 'cls'",
+        );
+    }
+    if unlikely!(!kwnames.is_null()) {
+        for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+            let arg = PyTuple_GET_ITEM(kwnames, i as Py_ssize_t);
+            if arg == typeref::STRICT {
+                let val = *args.offset(num_args + i);
+                if val == typeref::FALSE {
+                    strict = false;
+                } else if unlikely!(val != typeref::TRUE) {
+                    return raise_loads_exception_fixed("Invalid strict");
+                }
+            } else {
+                return raise_loads_argument_error(
+                    "loads_typed() got an unexpected keyword argument",
+                );
+            }
+        }
+    }
+
+    let cls = *args.offset(1);
+    if unlikely!(
+        PyType_Check(cls) == 0 || PyObject_HasAttr(cls, typeref::DATACLASS_FIELDS_STR) == 0
+    ) {
+        return raise_loads_argument_error("loads_typed() cls must be a dataclass");
+    }
+
+    loads_input(*args, |obj| {
+        crate::deserialize::deserialize_typed(obj, cls, strict)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn loads_ndarray(
     _self: *mut PyObject,
     args: *const *mut PyObject,
     nargs: Py_ssize_t,
     kwnames: *mut PyObject,
 ) -> *mut PyObject {
+    let mut dtype: *mut PyObject = null_mut();
+
+    let num_args = PyVectorcall_NARGS(nargs as usize);
+    if unlikely!(num_args == 0) {
+        return raise_loads_argument_error(
+            "loads_ndarray() missing 1 required positional argument: 'obj'",
+        );
+    }
+    if unlikely!(num_args > 2) {
+        return raise_loads_argument_error("loads_ndarray() takes at most 2 positional arguments");
+    }
+    if num_args == 2 {
+        dtype = *args.offset(1);
+    }
+    if unlikely!(!kwnames.is_null()) {
+        for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+            let arg = PyTuple_GET_ITEM(kwnames, i as Py_ssize_t);
+            if arg == typeref::DTYPE_STR {
+                if unlikely!(num_args == 2) {
+                    return raise_loads_argument_error(
+                        "loads_ndarray() got multiple values for argument: 'dtype'",
+                    );
+                }
+                dtype = *args.offset(num_args + i);
+            } else {
+                return raise_loads_argument_error(
+                    "loads_ndarray() got an unexpected keyword argument",
+                );
+            }
+        }
+    }
+    if dtype == typeref::NONE {
+        dtype = null_mut();
+    }
+
+    loads_input(*args, |obj| {
+        crate::deserialize::deserialize_ndarray(obj, dtype)
+    })
+}
+
+/// Read the `option` argument to `dumps()`, returning `None` if it is invalid.
+#[inline(always)]
+unsafe fn dumps_opts(optsptr: Option<NonNull<PyObject>>) -> Option<opt::Opt> {
+    let mut optsbits: opt::Opt = 0;
+    if let Some(opts) = optsptr {
+        if (*opts.as_ptr()).ob_type == typeref::INT_TYPE {
+            optsbits = PyLong_AsUnsignedLongLong(opts.as_ptr());
+            if unlikely!(optsbits & !opt::MAX_OPT != 0) {
+                PyErr_Clear();
+                return None;
+            }
+            if unlikely!((optsbits & opt::INDENT_ANY).count_ones() > 1) {
+                return None;
+            }
+            if unlikely!((optsbits & opt::TIMEDELTA_ANY).count_ones() > 1) {
+                return None;
+            }
+            if unlikely!((optsbits & opt::DECIMAL_ANY).count_ones() > 1) {
+                return None;
+            }
+            if unlikely!(
+                optsbits & opt::REQUIRE_AWARE_DATETIME != 0 && optsbits & opt::NAIVE_UTC != 0
+            ) {
+                return None;
+            }
+            if unlikely!(optsbits & opt::SORT_KEYS_CASE_INSENSITIVE != 0) {
+                optsbits |= opt::SORT_KEYS;
+            }
+            if unlikely!(optsbits & opt::CANONICAL != 0) {
+                if optsbits & opt::NOT_CANONICAL != 0 {
+                    return None;
+                }
+                optsbits |= opt::SORT_KEYS | opt::STRICT_INTEGER;
+            }
+        } else if unlikely!(opts.as_ptr() != typeref::NONE) {
+            return None;
+        }
+    }
+    Some(optsbits)
+}
+
+/// Read the `max_depth` argument to `dumps()`, returning `None` if it is
+/// invalid.
+#[inline(always)]
+unsafe fn dumps_max_depth(depthptr: Option<NonNull<PyObject>>) -> Option<u16> {
+    if let Some(depth) = depthptr {
+        if (*depth.as_ptr()).ob_type == typeref::INT_TYPE {
+            let val = PyLong_AsLongLong(depth.as_ptr());
+            if unlikely!(val < 1 || val > serialize::MAX_RECURSION_LIMIT as i64) {
+                PyErr_Clear();
+                return None;
+            }
+            return Some(val as u16);
+        } else if unlikely!(depth.as_ptr() != typeref::NONE) {
+            return None;
+        }
+    }
+    Some(serialize::RECURSION_LIMIT)
+}
+
+/// Read the `include` argument to `dumps()`, returning `None` if it is
+/// invalid and a null pointer if it is not given.
+#[inline(always)]
+unsafe fn dumps_include(includeptr: Option<NonNull<PyObject>>) -> Option<*mut PyObject> {
+    match includeptr {
+        Some(include) if include.as_ptr() == typeref::NONE => Some(null_mut()),
+        Some(include) if PyAnySet_Check(include.as_ptr()) == 0 => None,
+        Some(include) => Some(include.as_ptr()),
+        None => Some(null_mut()),
+    }
+}
+
+/// Read the `float_precision` argument to `dumps()`, returning `None` if it
+/// is invalid. It is required with `OPT_FLOAT_FIXED` and otherwise invalid.
+#[inline(always)]
+unsafe fn dumps_float_precision(
+    precisionptr: Option<NonNull<PyObject>>,
+    opts: opt::Opt,
+) -> Option<u8> {
+    let precision = precisionptr.filter(|precision| precision.as_ptr() != typeref::NONE);
+    match precision {
+        None if opts & opt::FLOAT_FIXED == 0 => Some(0),
+        Some(precision)
+            if opts & opt::FLOAT_FIXED != 0
+                && (*precision.as_ptr()).ob_type == typeref::INT_TYPE =>
+        {
+            let val = PyLong_AsLongLong(precision.as_ptr());
+            if unlikely!(val < 0 || val > serialize::MAX_FLOAT_PRECISION as i64) {
+                PyErr_Clear();
+                return None;
+            }
+            Some(val as u8)
+        }
+        _ => None,
+    }
+}
+
+/// The `*_format` keyword arguments of `dumps()` and the `opt::Format` of
+/// each of their values.
+const FORMAT_ARGS: [(&str, &[(&str, opt::Format)]); 9] = [
+    (
+        "bytes_format",
+        &[
+            ("base64", opt::BYTES_BASE64),
+            ("base64url", opt::BYTES_BASE64 | opt::BYTES_BASE64_URLSAFE),
+        ],
+    ),
+    ("complex_format", &[("object", opt::COMPLEX_AS_OBJECT)]),
+    ("date_format", &[("epoch_day", opt::DATE_AS_EPOCH_DAY)]),
+    (
+        "datetime_format",
+        &[
+            ("rfc2822", opt::RFC2822_DATETIME),
+            ("tz_name", opt::DATETIME_TZ_NAME),
+        ],
+    ),
+    (
+        "exception_format",
+        &[("traceback", opt::EXCEPTION_TRACEBACK)],
+    ),
+    (
+        "fraction_format",
+        &[
+            ("float", opt::FRACTION_AS_FLOAT),
+            ("string", opt::FRACTION_AS_STRING),
+        ],
+    ),
+    ("range_format", &[("array", opt::RANGE_AS_ARRAY)]),
+    ("time_format", &[("millis", opt::TIME_MILLIS)]),
+    (
+        "uuid_format",
+        &[
+            ("int", opt::UUID_AS_INT),
+            ("base64", opt::UUID_AS_BASE64),
+            ("base64url", opt::UUID_AS_BASE64 | opt::UUID_BASE64_URLSAFE),
+        ],
+    ),
+];
+
+/// Read a keyword argument to `dumps()` named `kwname` that is not one of
+/// its other arguments as a `*_format` argument, adding its value to
+/// `formats`. The error is the message of the exception to raise.
+#[cold]
+#[inline(never)]
+unsafe fn dumps_format(
+    name: &str,
+    kwname: *mut PyObject,
+    value: *mut PyObject,
+    formats: &mut opt::Format,
+) -> Result<(), String> {
+    let kwname = crate::str::unicode_to_str(kwname).unwrap_or("");
+    let values = match FORMAT_ARGS.iter().find(|(arg, _)| *arg == kwname) {
+        Some((_, values)) => values,
+        None => return Err(format!("{}() got an unexpected keyword argument", name)),
+    };
+    if value == typeref::NONE {
+        return Ok(());
+    }
+    let value = if (*value).ob_type == typeref::STR_TYPE {
+        crate::str::unicode_to_str(value)
+    } else {
+        None
+    };
+    match values.iter().find(|(val, _)| Some(*val) == value) {
+        Some((_, format)) => {
+            *formats |= format;
+            Ok(())
+        }
+        None => Err(format!("Invalid {}", kwname)),
+    }
+}
+
+/// Check the `*_format` arguments to `dumps()` against its options, returning
+/// the message of the exception to raise if they conflict.
+#[inline(always)]
+fn dumps_formats_valid(opts: opt::Opt, formats: opt::Format) -> Result<(), &'static str> {
+    if unlikely!(opts & opt::CANONICAL != 0 && formats & opt::UUID_AS_INT != 0) {
+        // a UUID as an int exceeds the range of integers in RFC 8785
+        return Err("Invalid uuid_format");
+    }
+    Ok(())
+}
+
+/// The arguments to `dumps()`, `dumps_str()`, `dumps_with_stats()`, and
+/// `dumps_lines()` other than `obj`.
+struct DumpsArgs {
+    default: Option<NonNull<PyObject>>,
+    opts: opt::Opt,
+    max_depth: u16,
+    include: *mut PyObject,
+    float_precision: u8,
+    formats: opt::Format,
+}
+
+/// Read the arguments to `dumps()`, `dumps_str()`, `dumps_with_stats()`, or
+/// `dumps_lines()`, named `name` in error messages, returning `None` if an
+/// exception has been raised.
+#[inline(always)]
+unsafe fn dumps_args(
+    name: &str,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> Option<DumpsArgs> {
     let mut default: Option<NonNull<PyObject>> = None;
     let mut optsptr: Option<NonNull<PyObject>> = None;
+    let mut depthptr: Option<NonNull<PyObject>> = None;
+    let mut includeptr: Option<NonNull<PyObject>> = None;
+    let mut precisionptr: Option<NonNull<PyObject>> = None;
+    let mut formats: opt::Format = 0;
 
     let num_args = PyVectorcall_NARGS(nargs as usize);
     if unlikely!(num_args == 0) {
-        return raise_dumps_exception_fixed(
-            "dumps() missing 1 required positional argument: 'obj'",
-        );
+        raise_dumps_exception_fixed(&format!(
+            "{}() missing 1 required positional argument: 'obj'",
+            name
+        ));
+        return None;
     }
     if num_args & 2 == 2 {
         default = Some(NonNull::new_unchecked(*args.offset(1)));
@@ -348,39 +1161,311 @@ pub unsafe extern "C" fn dumps(
             let arg = PyTuple_GET_ITEM(kwnames, i as Py_ssize_t);
             if arg == typeref::DEFAULT {
                 if unlikely!(num_args & 2 == 2) {
+                    raise_dumps_exception_fixed(&format!(
+                        "{}() got multiple values for argument: 'default'",
+                        name
+                    ));
+                    return None;
+                }
+                default = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::OPTION {
+                if unlikely!(num_args & 3 == 3) {
+                    raise_dumps_exception_fixed(&format!(
+                        "{}() got multiple values for argument: 'option'",
+                        name
+                    ));
+                    return None;
+                }
+                optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::MAX_DEPTH {
+                depthptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::INCLUDE {
+                includeptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::FLOAT_PRECISION {
+                precisionptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if let Err(msg) =
+                dumps_format(name, arg, *args.offset(num_args + i), &mut formats)
+            {
+                raise_dumps_exception_fixed(&msg);
+                return None;
+            }
+        }
+    }
+
+    let opts = match dumps_opts(optsptr) {
+        Some(opts) => opts,
+        None => {
+            raise_dumps_exception_fixed("Invalid opts");
+            return None;
+        }
+    };
+    let max_depth = match dumps_max_depth(depthptr) {
+        Some(max_depth) => max_depth,
+        None => {
+            raise_dumps_exception_fixed("Invalid max_depth");
+            return None;
+        }
+    };
+    let include = match dumps_include(includeptr) {
+        Some(include) => include,
+        None => {
+            raise_dumps_exception_fixed("Invalid include");
+            return None;
+        }
+    };
+    let float_precision = match dumps_float_precision(precisionptr, opts) {
+        Some(float_precision) => float_precision,
+        None => {
+            raise_dumps_exception_fixed("Invalid float_precision");
+            return None;
+        }
+    };
+    if let Err(msg) = dumps_formats_valid(opts, formats) {
+        raise_dumps_exception_fixed(msg);
+        return None;
+    }
+    Some(DumpsArgs {
+        default: default,
+        opts: opts,
+        max_depth: max_depth,
+        include: include,
+        float_precision: float_precision,
+        formats: formats,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dumps(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match dumps_args("dumps", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    let res = crate::serialize::with_float_precision(parsed.float_precision, || {
+        crate::serialize::with_dataclass_include(parsed.include, || {
+            crate::serialize::with_formats(parsed.formats, || {
+                crate::serialize::serialize(*args, parsed.default, parsed.opts, parsed.max_depth)
+            })
+        })
+    });
+    match res {
+        Ok(val) => val.as_ptr(),
+        Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dumps_str(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match dumps_args("dumps_str", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    let res = crate::serialize::with_float_precision(parsed.float_precision, || {
+        crate::serialize::with_dataclass_include(parsed.include, || {
+            crate::serialize::with_formats(parsed.formats, || {
+                crate::serialize::serialize_str(
+                    *args,
+                    parsed.default,
+                    parsed.opts,
+                    parsed.max_depth,
+                )
+            })
+        })
+    });
+    match res {
+        Ok(val) => val.as_ptr(),
+        Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dumps_with_stats(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match dumps_args("dumps_with_stats", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    let (res, stats) = crate::serialize::with_stats(parsed.max_depth, || {
+        crate::serialize::with_float_precision(parsed.float_precision, || {
+            crate::serialize::with_dataclass_include(parsed.include, || {
+                crate::serialize::with_formats(parsed.formats, || {
+                    crate::serialize::serialize(
+                        *args,
+                        parsed.default,
+                        parsed.opts | opt::STATS,
+                        parsed.max_depth,
+                    )
+                })
+            })
+        })
+    });
+    match res {
+        Ok(val) => {
+            let ret = PyTuple_New(2);
+            PyTuple_SET_ITEM(ret, 0, val.as_ptr());
+            PyTuple_SET_ITEM(ret, 1, stats_dict(&stats));
+            ret
+        }
+        Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+/// The `dict` returned by `dumps_with_stats()` for `stats`.
+unsafe fn stats_dict(stats: &crate::serialize::Stats) -> *mut PyObject {
+    let dict = PyDict_New();
+    for (name, val) in [
+        ("fast_str_writes\0", stats.fast_str_writes),
+        ("slow_str_writes\0", stats.slow_str_writes),
+        ("max_depth\0", stats.max_depth as usize),
+        ("default_calls\0", stats.default_calls),
+    ] {
+        let pyval = PyLong_FromSize_t(val);
+        PyDict_SetItemString(dict, name.as_ptr() as *const c_char, pyval);
+        Py_DECREF(pyval);
+    }
+    dict
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dumps_lines(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let parsed = match dumps_args("dumps_lines", args, nargs, kwnames) {
+        Some(parsed) => parsed,
+        None => return null_mut(),
+    };
+    // each document must be on one line
+    if unlikely!(parsed.opts & opt::INDENT_ANY != 0) {
+        return raise_dumps_exception_fixed("Invalid opts");
+    }
+    let res = crate::serialize::with_float_precision(parsed.float_precision, || {
+        crate::serialize::with_dataclass_include(parsed.include, || {
+            crate::serialize::with_formats(parsed.formats, || {
+                crate::serialize::serialize_lines(
+                    *args,
+                    parsed.default,
+                    parsed.opts,
+                    parsed.max_depth,
+                )
+            })
+        })
+    });
+    match res {
+        Ok(val) => val.as_ptr(),
+        Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dumps_into(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    let mut default: Option<NonNull<PyObject>> = None;
+    let mut optsptr: Option<NonNull<PyObject>> = None;
+    let mut depthptr: Option<NonNull<PyObject>> = None;
+    let mut includeptr: Option<NonNull<PyObject>> = None;
+    let mut precisionptr: Option<NonNull<PyObject>> = None;
+    let mut formats: opt::Format = 0;
+
+    let num_args = PyVectorcall_NARGS(nargs as usize);
+    if unlikely!(num_args < 2) {
+        return raise_dumps_exception_fixed(
+            "dumps_into() missing required positional argument: 'obj' or 'buffer'",
+        );
+    }
+    if unlikely!(num_args > 4) {
+        return raise_dumps_exception_fixed("dumps_into() takes at most 4 positional arguments");
+    }
+    if num_args >= 3 {
+        default = Some(NonNull::new_unchecked(*args.offset(2)));
+    }
+    if num_args == 4 {
+        optsptr = Some(NonNull::new_unchecked(*args.offset(3)));
+    }
+    if unlikely!(!kwnames.is_null()) {
+        for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+            let arg = PyTuple_GET_ITEM(kwnames, i as Py_ssize_t);
+            if arg == typeref::DEFAULT {
+                if unlikely!(num_args >= 3) {
                     return raise_dumps_exception_fixed(
-                        "dumps() got multiple values for argument: 'default'",
+                        "dumps_into() got multiple values for argument: 'default'",
                     );
                 }
                 default = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
             } else if arg == typeref::OPTION {
-                if unlikely!(num_args & 3 == 3) {
+                if unlikely!(num_args == 4) {
                     return raise_dumps_exception_fixed(
-                        "dumps() got multiple values for argument: 'option'",
+                        "dumps_into() got multiple values for argument: 'option'",
                     );
                 }
                 optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
-            } else {
-                return raise_dumps_exception_fixed("dumps() got an unexpected keyword argument");
+            } else if arg == typeref::MAX_DEPTH {
+                depthptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::INCLUDE {
+                includeptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if arg == typeref::FLOAT_PRECISION {
+                precisionptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+            } else if let Err(msg) =
+                dumps_format("dumps_into", arg, *args.offset(num_args + i), &mut formats)
+            {
+                return raise_dumps_exception_fixed(&msg);
             }
         }
     }
 
-    let mut optsbits: i32 = 0;
-    if unlikely!(optsptr.is_some()) {
-        let opts = optsptr.unwrap();
-        if (*opts.as_ptr()).ob_type == typeref::INT_TYPE {
-            optsbits = PyLong_AsLong(optsptr.unwrap().as_ptr()) as i32;
-            if unlikely!(!(0..=opt::MAX_OPT).contains(&optsbits)) {
-                return raise_dumps_exception_fixed("Invalid opts");
-            }
-        } else if unlikely!(opts.as_ptr() != typeref::NONE) {
-            return raise_dumps_exception_fixed("Invalid opts");
-        }
+    let buffer = *args.offset(1);
+    if unlikely!(PyByteArray_Check(buffer) == 0) {
+        return raise_dumps_exception_fixed("dumps_into() buffer must be a bytearray");
     }
 
-    match crate::serialize::serialize(*args, default, optsbits as opt::Opt) {
-        Ok(val) => val.as_ptr(),
+    let optsbits = match dumps_opts(optsptr) {
+        Some(opts) => opts,
+        None => return raise_dumps_exception_fixed("Invalid opts"),
+    };
+    let max_depth = match dumps_max_depth(depthptr) {
+        Some(max_depth) => max_depth,
+        None => return raise_dumps_exception_fixed("Invalid max_depth"),
+    };
+    let include = match dumps_include(includeptr) {
+        Some(include) => include,
+        None => return raise_dumps_exception_fixed("Invalid include"),
+    };
+    let float_precision = match dumps_float_precision(precisionptr, optsbits) {
+        Some(float_precision) => float_precision,
+        None => return raise_dumps_exception_fixed("Invalid float_precision"),
+    };
+    if let Err(msg) = dumps_formats_valid(optsbits, formats) {
+        return raise_dumps_exception_fixed(msg);
+    }
+
+    let res = crate::serialize::with_float_precision(float_precision, || {
+        crate::serialize::with_dataclass_include(include, || {
+            crate::serialize::with_formats(formats, || {
+                crate::serialize::serialize_into(*args, buffer, default, optsbits, max_depth)
+            })
+        })
+    });
+    match res {
+        Ok(len) => PyLong_FromSize_t(len),
         Err(err) => raise_dumps_exception_dynamic(err.as_str()),
     }
 }
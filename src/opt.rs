@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
-pub type Opt = u32;
+pub type Opt = u64;
 
 pub const INDENT_2: Opt = 1;
 pub const NAIVE_UTC: Opt = 1 << 1;
@@ -14,6 +14,96 @@ pub const PASSTHROUGH_SUBCLASS: Opt = 1 << 8;
 pub const PASSTHROUGH_DATETIME: Opt = 1 << 9;
 pub const APPEND_NEWLINE: Opt = 1 << 10;
 pub const PASSTHROUGH_DATACLASS: Opt = 1 << 11;
+pub const INCLUDE_PRIVATE_FIELDS: Opt = 1 << 12;
+pub const DATACLASS_FIELD_RENAME: Opt = 1 << 13;
+pub const SKIP_NONE_VALUES: Opt = 1 << 14;
+pub const SERIALIZE_DECIMAL: Opt = 1 << 15;
+pub const ENUM_BY_NAME: Opt = 1 << 16;
+pub const SERIALIZE_SET: Opt = 1 << 17;
+pub const ASCII: Opt = 1 << 18;
+pub const ESCAPE_FORWARD_SLASH: Opt = 1 << 19;
+pub const INDENT_4: Opt = 1 << 20;
+pub const INDENT_TAB: Opt = 1 << 21;
+pub const SERIALIZE_PATH: Opt = 1 << 24;
+pub const SERIALIZE_IPADDRESS: Opt = 1 << 25;
+pub const TIMEDELTA_SECONDS: Opt = 1 << 26;
+pub const TIMEDELTA_ISO8601: Opt = 1 << 27;
+pub const SERIALIZE_ARRAY: Opt = 1 << 29;
+pub const VALIDATE_FRAGMENT: Opt = 1 << 30;
+pub const SORT_KEYS_CASE_INSENSITIVE: Opt = 1 << 33;
+pub const SERIALIZE_COMPLEX: Opt = 1 << 34;
+pub const NUMPY_NONFINITE_AS_NULL: Opt = 1 << 35;
+pub const PRESERVE_ORDERED_DICT: Opt = 1 << 36;
+pub const CAMELCASE: Opt = 1 << 37;
+pub const DATACLASS_SKIP_MISSING: Opt = 1 << 38;
+pub const SERIALIZE_PANDAS: Opt = 1 << 39;
+pub const NANOSECOND: Opt = 1 << 41;
+pub const DECIMAL_AS_STRING: Opt = 1 << 44;
+pub const SPACE_AFTER_COLON: Opt = 1 << 45;
+pub const SPACE_AFTER_COMMA: Opt = 1 << 46;
+pub const SERIALIZE_ITERATORS: Opt = 1 << 47;
+pub const SERIALIZE_NAMEDTUPLE_AS_OBJECT: Opt = 1 << 48;
+pub const SERIALIZE_RANGE: Opt = 1 << 49;
+pub const SERIALIZE_FRACTION: Opt = 1 << 50;
+pub const SERIALIZE_JSON_PROTOCOL: Opt = 1 << 51;
+pub const CANONICAL: Opt = 1 << 52;
+pub const FLOAT_FIXED: Opt = 1 << 53;
+pub const SERIALIZE_PROXY: Opt = 1 << 54;
+pub const UTF8_BOM: Opt = 1 << 55;
+pub const PASSTHROUGH_ENUM: Opt = 1 << 57;
+pub const REQUIRE_AWARE_DATETIME: Opt = 1 << 60;
+pub const CAPITALIZE_BOOL: Opt = 1 << 61;
+pub const NULL_AS_NIL: Opt = 1 << 62;
+pub const SERIALIZE_EXCEPTION: Opt = 1 << 63;
+
+// loads
+pub const PARSE_DECIMAL: Opt = 1 << 22;
+pub const ALLOW_COMMENTS: Opt = 1 << 31;
+pub const ALLOW_TRAILING_COMMA: Opt = 1 << 32;
+pub const INTERN_KEYS: Opt = 1 << 42;
+pub const PARSE_UUID: Opt = 1 << 43;
+pub const KEYS_AS_BYTES: Opt = 1 << 58;
+pub const WHOLE_FLOAT_AS_INT: Opt = 1 << 59;
+
+// dumps and loads
+pub const ALLOW_NONFINITE: Opt = 1 << 28;
+pub const DICT_AS_PAIRS: Opt = 1 << 40;
+// dumps raises on keys that are not unique once serialized as str, e.g., by
+// OPT_NON_STR_KEYS or OPT_CAMELCASE; loads raises on keys repeated in input
+pub const REJECT_DUPLICATE_KEYS: Opt = 1 << 23;
+
+// internal, set only by dumps_with_stats()
+pub const STATS: Opt = 1 << 56;
+
+// The second word of options, set by the `*_format` keyword arguments of
+// dumps(). These change only how a type that is already serialized is
+// written, so rather than being carried in `SerializerState`, they are read
+// by the serializers of those types from `crate::serialize::formats()`.
+pub type Format = u16;
+
+// bytes_format
+pub const BYTES_BASE64: Format = 1;
+pub const BYTES_BASE64_URLSAFE: Format = 1 << 1;
+// complex_format
+pub const COMPLEX_AS_OBJECT: Format = 1 << 2;
+// date_format
+pub const DATE_AS_EPOCH_DAY: Format = 1 << 3;
+// datetime_format
+pub const RFC2822_DATETIME: Format = 1 << 4;
+pub const DATETIME_TZ_NAME: Format = 1 << 5;
+// exception_format
+pub const EXCEPTION_TRACEBACK: Format = 1 << 6;
+// fraction_format
+pub const FRACTION_AS_FLOAT: Format = 1 << 7;
+pub const FRACTION_AS_STRING: Format = 1 << 8;
+// range_format
+pub const RANGE_AS_ARRAY: Format = 1 << 9;
+// time_format
+pub const TIME_MILLIS: Format = 1 << 10;
+// uuid_format
+pub const UUID_AS_INT: Format = 1 << 11;
+pub const UUID_AS_BASE64: Format = 1 << 12;
+pub const UUID_BASE64_URLSAFE: Format = 1 << 13;
 
 // deprecated
 pub const SERIALIZE_DATACLASS: Opt = 0;
@@ -21,20 +111,97 @@ pub const SERIALIZE_UUID: Opt = 0;
 
 pub const SORT_OR_NON_STR_KEYS: Opt = SORT_KEYS | NON_STR_KEYS;
 
+pub const INDENT_ANY: Opt = INDENT_2 | INDENT_4 | INDENT_TAB;
+
+pub const TIMEDELTA_ANY: Opt = TIMEDELTA_ISO8601 | TIMEDELTA_SECONDS;
+
+pub const DECIMAL_ANY: Opt = DECIMAL_AS_STRING | SERIALIZE_DECIMAL;
+
+pub const SPACE_ANY: Opt = SPACE_AFTER_COLON | SPACE_AFTER_COMMA;
+
+// options that would make output differ from RFC 8785
+pub const NOT_CANONICAL: Opt = ALLOW_NONFINITE
+    | ASCII
+    | CAPITALIZE_BOOL
+    | DICT_AS_PAIRS
+    | ESCAPE_FORWARD_SLASH
+    | FLOAT_FIXED
+    | INDENT_ANY
+    | NULL_AS_NIL
+    | PRESERVE_ORDERED_DICT
+    | SERIALIZE_DECIMAL
+    | SORT_KEYS_CASE_INSENSITIVE
+    | SPACE_ANY;
+
 pub const NOT_PASSTHROUGH: Opt =
-    !(PASSTHROUGH_DATETIME | PASSTHROUGH_DATACLASS | PASSTHROUGH_SUBCLASS);
+    !(PASSTHROUGH_DATETIME | PASSTHROUGH_DATACLASS | PASSTHROUGH_ENUM | PASSTHROUGH_SUBCLASS);
 
-pub const MAX_OPT: i32 = (APPEND_NEWLINE
+pub const MAX_OPT: Opt = ALLOW_NONFINITE
+    | APPEND_NEWLINE
+    | ASCII
+    | CAMELCASE
+    | CANONICAL
+    | CAPITALIZE_BOOL
+    | DATACLASS_FIELD_RENAME
+    | DATACLASS_SKIP_MISSING
+    | DECIMAL_AS_STRING
+    | DICT_AS_PAIRS
+    | ENUM_BY_NAME
+    | ESCAPE_FORWARD_SLASH
+    | FLOAT_FIXED
+    | INCLUDE_PRIVATE_FIELDS
     | INDENT_2
+    | INDENT_4
+    | INDENT_TAB
     | NAIVE_UTC
+    | NANOSECOND
     | NON_STR_KEYS
+    | NULL_AS_NIL
+    | NUMPY_NONFINITE_AS_NULL
     | OMIT_MICROSECONDS
     | PASSTHROUGH_DATETIME
     | PASSTHROUGH_DATACLASS
+    | PASSTHROUGH_ENUM
     | PASSTHROUGH_SUBCLASS
+    | PRESERVE_ORDERED_DICT
+    | REJECT_DUPLICATE_KEYS
+    | REQUIRE_AWARE_DATETIME
+    | SERIALIZE_ARRAY
+    | SERIALIZE_COMPLEX
     | SERIALIZE_DATACLASS
+    | SERIALIZE_DECIMAL
+    | SERIALIZE_EXCEPTION
+    | SERIALIZE_FRACTION
+    | SERIALIZE_IPADDRESS
+    | SERIALIZE_ITERATORS
+    | SERIALIZE_JSON_PROTOCOL
+    | SERIALIZE_NAMEDTUPLE_AS_OBJECT
     | SERIALIZE_NUMPY
+    | SERIALIZE_PANDAS
+    | SERIALIZE_PATH
+    | SERIALIZE_PROXY
+    | SERIALIZE_RANGE
+    | SERIALIZE_SET
     | SERIALIZE_UUID
+    | SKIP_NONE_VALUES
     | SORT_KEYS
+    | SORT_KEYS_CASE_INSENSITIVE
+    | SPACE_AFTER_COLON
+    | SPACE_AFTER_COMMA
     | STRICT_INTEGER
-    | UTC_Z) as i32;
+    | TIMEDELTA_ISO8601
+    | TIMEDELTA_SECONDS
+    | UTC_Z
+    | UTF8_BOM
+    | VALIDATE_FRAGMENT;
+
+pub const MAX_LOADS_OPT: Opt = ALLOW_COMMENTS
+    | ALLOW_NONFINITE
+    | ALLOW_TRAILING_COMMA
+    | DICT_AS_PAIRS
+    | INTERN_KEYS
+    | KEYS_AS_BYTES
+    | PARSE_DECIMAL
+    | PARSE_UUID
+    | REJECT_DUPLICATE_KEYS
+    | WHOLE_FLOAT_AS_INT;
@@ -4,22 +4,42 @@ use core::ffi::CStr;
 use core::ptr::NonNull;
 
 pub enum SerializeError {
+    ArrayUnsupportedTypecode,
+    BytesNotContiguous,
+    DataclassFieldMissing,
+    DataclassFieldUnreadable,
     DatetimeLibraryUnsupported,
+    DatetimeNaive(String),
+    DatetimeRfc2822Naive,
+    DatetimeRfc2822Year,
+    DecimalNotFinite,
     DefaultRecursionLimit,
+    EnumNoName,
+    ExceptionNotSerializable,
+    FloatNotFinite,
+    FractionIntStr,
+    FractionOverflow,
     Integer53Bits,
     Integer64Bits,
     InvalidStr,
     InvalidFragment,
-    KeyMustBeStr,
+    IteratorRaised,
+    FragmentNotValid,
+    KeyMustBeStr(NonNull<pyo3_ffi::PyObject>),
+    MemoryviewUnsupportedFormat,
+    OrderedDictIteration,
     RecursionLimit,
     TimeHasTzinfo,
     DictIntegerKey64Bit,
     DictKeyInvalidType,
+    DictKeyDuplicate(String, String),
+    FieldDuplicate(String, String, String),
     NumpyMalformed,
     NumpyNotCContiguous,
     NumpyNotNativeEndian,
     NumpyUnsupportedDatatype,
     UnsupportedType(NonNull<pyo3_ffi::PyObject>),
+    WeakrefDead,
 }
 
 impl std::fmt::Display for SerializeError {
@@ -27,15 +47,74 @@ impl std::fmt::Display for SerializeError {
     #[cfg_attr(feature = "optimize", optimize(size))]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
+            SerializeError::ArrayUnsupportedTypecode => write!(
+                f,
+                "array.array typecode is not supported; use array.tolist() in default"
+            ),
+            SerializeError::BytesNotContiguous => {
+                write!(f, "memoryview must be a C contiguous buffer that is not released")
+            }
+            SerializeError::DataclassFieldMissing => {
+                write!(f, "Dataclass field is dataclasses.MISSING")
+            }
+            SerializeError::DataclassFieldUnreadable => {
+                write!(f, "Dataclass field is not set or could not be read")
+            }
             SerializeError::DatetimeLibraryUnsupported => write!(f, "datetime's timezone library is not supported: use datetime.timezone.utc, pendulum, pytz, or dateutil"),
+            SerializeError::DatetimeNaive(ref value) => write!(
+                f,
+                "datetime.datetime must have tzinfo with OPT_REQUIRE_AWARE_DATETIME: {}",
+                value
+            ),
+            SerializeError::DatetimeRfc2822Naive => write!(
+                f,
+                "datetime must have tzinfo with datetime_format=\"rfc2822\" unless using OPT_NAIVE_UTC"
+            ),
+            SerializeError::DatetimeRfc2822Year => write!(
+                f,
+                "datetime must be in years 1 to 9999 in UTC with datetime_format=\"rfc2822\""
+            ),
+            SerializeError::DecimalNotFinite => write!(f, "decimal.Decimal must be finite"),
             SerializeError::DefaultRecursionLimit => {
                 write!(f, "default serializer exceeds recursion limit")
             }
+            SerializeError::EnumNoName => write!(
+                f,
+                "Enum member has no single name to serialize with OPT_ENUM_BY_NAME"
+            ),
+            SerializeError::ExceptionNotSerializable => {
+                write!(f, "Exception could not be serialized because reading it raised")
+            }
+            SerializeError::FloatNotFinite => {
+                write!(f, "Float must be finite with OPT_CANONICAL")
+            }
+            SerializeError::FractionIntStr => write!(
+                f,
+                "fractions.Fraction numerator or denominator exceeds the limit for int string conversion"
+            ),
+            SerializeError::FractionOverflow => write!(
+                f,
+                "fractions.Fraction is out of range of float with fraction_format=\"float\""
+            ),
             SerializeError::Integer53Bits => write!(f, "Integer exceeds 53-bit range"),
             SerializeError::Integer64Bits => write!(f, "Integer exceeds 64-bit range"),
             SerializeError::InvalidStr => write!(f, "{}", crate::util::INVALID_STR),
             SerializeError::InvalidFragment => write!(f, "orjson.Fragment's content is not of type bytes or str"),
-            SerializeError::KeyMustBeStr => write!(f, "Dict key must be str"),
+            SerializeError::FragmentNotValid => write!(
+                f,
+                "orjson.Fragment's content is not valid UTF-8 or has an unterminated string or unbalanced array or object"
+            ),
+            SerializeError::IteratorRaised => write!(f, "Iterator raised an exception"),
+            SerializeError::KeyMustBeStr(ptr) => {
+                write!(f, "Dict key must be str, not {}", qualified_type_name(ptr))
+            }
+            SerializeError::MemoryviewUnsupportedFormat => write!(
+                f,
+                "memoryview format is not supported; use memoryview.tolist() in default"
+            ),
+            SerializeError::OrderedDictIteration => {
+                write!(f, "collections.OrderedDict could not be iterated in order")
+            }
             SerializeError::RecursionLimit => write!(f, "Recursion limit reached"),
             SerializeError::TimeHasTzinfo => write!(f, "datetime.time must not have tzinfo set"),
             SerializeError::DictIntegerKey64Bit => {
@@ -44,6 +123,16 @@ impl std::fmt::Display for SerializeError {
             SerializeError::DictKeyInvalidType => {
                 write!(f, "Dict key must a type serializable with OPT_NON_STR_KEYS")
             }
+            SerializeError::DictKeyDuplicate(ref key, ref keys) => write!(
+                f,
+                "Dict key \"{}\" is not unique when serialized as str: {}",
+                key, keys
+            ),
+            SerializeError::FieldDuplicate(ref key, ref first, ref second) => write!(
+                f,
+                "Field \"{}\" is not unique when serialized with OPT_CAMELCASE: '{}' and '{}'",
+                key, first, second
+            ),
             SerializeError::NumpyMalformed => write!(f, "numpy array is malformed"),
             SerializeError::NumpyNotCContiguous => write!(
                 f,
@@ -57,9 +146,55 @@ impl std::fmt::Display for SerializeError {
                 write!(f, "unsupported datatype in numpy array")
             }
             SerializeError::UnsupportedType(ptr) => {
-                let name = unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
-                write!(f, "Type is not JSON serializable: {}", name)
+                write!(f, "Type is not JSON serializable: {}", qualified_type_name(ptr))
+            }
+            SerializeError::WeakrefDead => {
+                write!(f, "weakref.proxy referent no longer exists")
             }
         }
     }
 }
+
+/// The `module.qualname` of the type of `ptr`, or only `qualname` for
+/// builtins. This falls back to `tp_name` if either attribute is not a `str`.
+#[cold]
+#[inline(never)]
+fn qualified_type_name(ptr: NonNull<pyo3_ffi::PyObject>) -> String {
+    unsafe {
+        let ob_type = ob_type!(ptr.as_ptr());
+        // Preserve an exception raised by `default` so it is chained as the cause.
+        let mut ptype = core::ptr::null_mut();
+        let mut pvalue = core::ptr::null_mut();
+        let mut ptraceback = core::ptr::null_mut();
+        pyo3_ffi::PyErr_Fetch(&mut ptype, &mut pvalue, &mut ptraceback);
+        let module = type_attr_str(ob_type, "__module__\0");
+        let qualname = type_attr_str(ob_type, "__qualname__\0");
+        pyo3_ffi::PyErr_Restore(ptype, pvalue, ptraceback);
+        match (module, qualname) {
+            (Some(module), Some(qualname)) if module == "builtins" => qualname,
+            (Some(module), Some(qualname)) => format!("{}.{}", module, qualname),
+            _ => CStr::from_ptr((*ob_type).tp_name)
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+}
+
+#[cold]
+unsafe fn type_attr_str(ob_type: *mut pyo3_ffi::PyTypeObject, name: &str) -> Option<String> {
+    let attr = pyo3_ffi::PyObject_GetAttrString(
+        ob_type as *mut pyo3_ffi::PyObject,
+        name.as_ptr() as *const core::ffi::c_char,
+    );
+    if attr.is_null() {
+        pyo3_ffi::PyErr_Clear();
+        return None;
+    }
+    let ret = if is_type!(ob_type!(attr), crate::typeref::STR_TYPE) {
+        crate::str::unicode_to_str(attr).map(String::from)
+    } else {
+        None
+    };
+    pyo3_ffi::Py_DECREF(attr);
+    ret
+}
@@ -7,4 +7,15 @@ mod serializer;
 mod state;
 mod writer;
 
-pub use serializer::serialize;
+pub use per_type::{
+    with_dataclass_include, with_dataclass_layouts, DataclassLayouts, PyArrayInterface, PyCapsule,
+    NPY_ARRAY_NOTSWAPPED,
+};
+pub use serializer::{
+    serialize, serialize_array_element, serialize_array_end, serialize_into, serialize_lines,
+    serialize_str,
+};
+pub use state::{
+    take_exception_raised, with_formats, with_stats, Stats, MAX_RECURSION_LIMIT, RECURSION_LIMIT,
+};
+pub use writer::{with_float_precision, MAX_FLOAT_PRECISION};
@@ -1,12 +1,22 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
 use crate::opt::{
-    Opt, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME, PASSTHROUGH_SUBCLASS, SERIALIZE_NUMPY,
+    Opt, BYTES_BASE64, DECIMAL_ANY, ENUM_BY_NAME, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME,
+    PASSTHROUGH_ENUM, PASSTHROUGH_SUBCLASS, SERIALIZE_ARRAY, SERIALIZE_COMPLEX,
+    SERIALIZE_EXCEPTION, SERIALIZE_FRACTION, SERIALIZE_IPADDRESS, SERIALIZE_ITERATORS,
+    SERIALIZE_NAMEDTUPLE_AS_OBJECT, SERIALIZE_NUMPY, SERIALIZE_PANDAS, SERIALIZE_PATH,
+    SERIALIZE_RANGE, SERIALIZE_SET, TIMEDELTA_ANY,
 };
-use crate::serialize::per_type::{is_numpy_array, is_numpy_scalar};
+use crate::serialize::per_type::{
+    is_array, is_fraction, is_ipaddress, is_iterator, is_namedtuple, is_numpy_array,
+    is_numpy_scalar, is_pandas_nat, is_pandas_timestamp, is_path,
+};
+use crate::serialize::state::formats;
 use crate::typeref::{
-    BOOL_TYPE, DATACLASS_FIELDS_STR, DATETIME_TYPE, DATE_TYPE, DICT_TYPE, ENUM_TYPE, FLOAT_TYPE,
-    FRAGMENT_TYPE, INT_TYPE, LIST_TYPE, NONE_TYPE, STR_TYPE, TIME_TYPE, TUPLE_TYPE, UUID_TYPE,
+    BOOL_TYPE, BYTEARRAY_TYPE, BYTES_TYPE, COMPLEX_TYPE, DATACLASS_FIELDS_STR, DATETIME_TYPE,
+    DATE_TYPE, DECIMAL_TYPE, DICT_TYPE, ENUM_TYPE, FLOAT_TYPE, FRAGMENT_TYPE, FROZENSET_TYPE,
+    INT_TYPE, LIST_TYPE, MEMORYVIEW_TYPE, NONE_TYPE, RANGE_TYPE, SET_TYPE, STR_TYPE,
+    TIMEDELTA_TYPE, TIME_TYPE, TRACEBACK_TYPE, TUPLE_TYPE, UUID_TYPE,
 };
 
 #[repr(u32)]
@@ -29,6 +39,20 @@ pub enum ObType {
     Enum,
     StrSubclass,
     Fragment,
+    Decimal,
+    Set,
+    Path,
+    IpAddress,
+    Timedelta,
+    Array,
+    Bytes,
+    Complex,
+    Fraction,
+    Iterator,
+    NamedTuple,
+    Range,
+    Exception,
+    Traceback,
     Unknown,
 }
 
@@ -75,6 +99,20 @@ pub fn pyobject_to_obtype_unlikely(ob_type: *mut pyo3_ffi::PyTypeObject, opts: O
         }
     }
 
+    if opt_enabled!(opts, TIMEDELTA_ANY) && is_class_by_type!(ob_type, TIMEDELTA_TYPE) {
+        return ObType::Timedelta;
+    }
+
+    if unlikely!(opt_enabled!(opts, PASSTHROUGH_ENUM)) && is_subclass_by_type!(ob_type, ENUM_TYPE) {
+        // before subclasses so that IntEnum and StrEnum are also passed
+        return ObType::Unknown;
+    }
+
+    if unlikely!(opt_enabled!(opts, ENUM_BY_NAME)) && is_subclass_by_type!(ob_type, ENUM_TYPE) {
+        // IntEnum, IntFlag, and StrEnum are also subclasses of builtins
+        return ObType::Enum;
+    }
+
     if opt_disabled!(opts, PASSTHROUGH_SUBCLASS) {
         if is_subclass_by_flag!(ob_type, Py_TPFLAGS_UNICODE_SUBCLASS) {
             return ObType::StrSubclass;
@@ -87,6 +125,8 @@ pub fn pyobject_to_obtype_unlikely(ob_type: *mut pyo3_ffi::PyTypeObject, opts: O
         }
     }
 
+    // before dataclasses so that an enum with `@dataclass` or a dataclass
+    // mixin is serialized by its value or name
     if is_subclass_by_type!(ob_type, ENUM_TYPE) {
         return ObType::Enum;
     }
@@ -96,13 +136,105 @@ pub fn pyobject_to_obtype_unlikely(ob_type: *mut pyo3_ffi::PyTypeObject, opts: O
         return ObType::Dataclass;
     }
 
+    if opt_enabled!(opts, SERIALIZE_NAMEDTUPLE_AS_OBJECT) && is_namedtuple(ob_type) {
+        return ObType::NamedTuple;
+    }
+
+    if opt_enabled!(opts, DECIMAL_ANY) && is_class_by_type!(ob_type, DECIMAL_TYPE) {
+        return ObType::Decimal;
+    }
+
+    if (is_class_by_type!(ob_type, BYTES_TYPE)
+        || is_class_by_type!(ob_type, BYTEARRAY_TYPE)
+        || is_class_by_type!(ob_type, MEMORYVIEW_TYPE))
+        && opt_enabled!(formats(), BYTES_BASE64)
+    {
+        return ObType::Bytes;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_COMPLEX) && is_class_by_type!(ob_type, COMPLEX_TYPE) {
+        return ObType::Complex;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_FRACTION) && is_fraction(ob_type) {
+        return ObType::Fraction;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_RANGE) && is_class_by_type!(ob_type, RANGE_TYPE) {
+        return ObType::Range;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_EXCEPTION) {
+        if is_subclass_by_flag!(ob_type, Py_TPFLAGS_BASE_EXC_SUBCLASS) {
+            return ObType::Exception;
+        } else if is_class_by_type!(ob_type, TRACEBACK_TYPE) {
+            return ObType::Traceback;
+        }
+    }
+
+    if opt_enabled!(opts, SERIALIZE_SET)
+        && (is_class_by_type!(ob_type, SET_TYPE) || is_class_by_type!(ob_type, FROZENSET_TYPE))
+    {
+        return ObType::Set;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_PATH) && is_path(ob_type) {
+        return ObType::Path;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_IPADDRESS) && is_ipaddress(ob_type) {
+        return ObType::IpAddress;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_ARRAY)
+        && (is_class_by_type!(ob_type, MEMORYVIEW_TYPE) || is_array(ob_type))
+    {
+        return ObType::Array;
+    }
+
+    if opt_disabled!(opts, PASSTHROUGH_DATETIME) {
+        if ffi!(PyType_IsSubtype(ob_type, DATETIME_TYPE)) != 0 {
+            if let Some(obtype) = datetime_subclass_to_obtype(ob_type, opts) {
+                return obtype;
+            }
+        } else if opt_disabled!(opts, PASSTHROUGH_SUBCLASS) {
+            if ffi!(PyType_IsSubtype(ob_type, DATE_TYPE)) != 0 {
+                return ObType::Date;
+            } else if ffi!(PyType_IsSubtype(ob_type, TIME_TYPE)) != 0 {
+                return ObType::Time;
+            }
+        }
+    }
+
     if unlikely!(opt_enabled!(opts, SERIALIZE_NUMPY)) {
-        if is_numpy_scalar(ob_type) {
+        if is_numpy_scalar(ob_type, opts) {
             return ObType::NumpyScalar;
         } else if is_numpy_array(ob_type) {
             return ObType::NumpyArray;
         }
     }
 
+    if opt_enabled!(opts, SERIALIZE_ITERATORS) && is_iterator(ob_type) {
+        return ObType::Iterator;
+    }
+
     ObType::Unknown
 }
+
+/// The `ObType` of a subclass of `datetime.datetime`, if it is serialized
+/// natively. `pandas.Timestamp` and `pandas.NaT` are subclasses but are only
+/// serialized with `OPT_SERIALIZE_PANDAS`, and are looked up only once the
+/// subclass check has passed.
+#[cold]
+#[inline(never)]
+fn datetime_subclass_to_obtype(ob_type: *mut pyo3_ffi::PyTypeObject, opts: Opt) -> Option<ObType> {
+    if is_pandas_timestamp(ob_type) {
+        // Timestamp has the layout of datetime.datetime with nanoseconds
+        // appended, so it is serialized as one, truncated to microseconds.
+        opt_enabled!(opts, SERIALIZE_PANDAS).then_some(ObType::Datetime)
+    } else if is_pandas_nat(ob_type) {
+        opt_enabled!(opts, SERIALIZE_PANDAS).then_some(ObType::None)
+    } else {
+        opt_disabled!(opts, PASSTHROUGH_SUBCLASS).then_some(ObType::Datetime)
+    }
+}
@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{Opt, STRICT_INTEGER};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::numpy::{
+    NumpyF32Array, NumpyF64Array, NumpyI16Array, NumpyI32Array, NumpyI64Array, NumpyI8Array,
+    NumpyU16Array, NumpyU32Array, NumpyU64Array, NumpyU8Array,
+};
+use crate::serialize::per_type::{ZeroListSerializer, STRICT_INT_MAX, STRICT_INT_MIN};
+use crate::typeref::{load_array_type, ARRAY_TYPE, MEMORYVIEW_TYPE};
+use core::ffi::c_char;
+use core::mem::MaybeUninit;
+use pyo3_ffi::{PyTypeObject, Py_buffer};
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+/// Whether `ob_type` is `array.array`. `array` is imported on first use.
+#[cold]
+pub fn is_array(ob_type: *mut PyTypeObject) -> bool {
+    let array_type = unsafe { (*core::ptr::addr_of!(ARRAY_TYPE)).get_or_init(load_array_type) };
+    match array_type {
+        Some(array_type) => ob_type == array_type.as_ptr(),
+        None => false,
+    }
+}
+
+macro_rules! slice {
+    ($data:expr, $len:expr, $ty:ty) => {
+        unsafe { core::slice::from_raw_parts($data as *const $ty, $len) }
+    };
+}
+
+/// Whether `typecode` with items of `itemsize` bytes is a number that is
+/// serialized natively.
+fn is_numeric(typecode: u8, itemsize: isize) -> bool {
+    matches!(
+        (typecode, itemsize),
+        (b'b' | b'B', 1)
+            | (b'h' | b'H', 2)
+            | (b'i' | b'I' | b'l' | b'L' | b'f', 4)
+            | (b'l' | b'L' | b'q' | b'Q' | b'd', 8)
+    )
+}
+
+/// The typecode of a buffer's struct module `format` if it is a single item
+/// of native size and alignment, e.g., `i` or `@i`, of a numeric type.
+pub fn numeric_typecode(format: *const c_char, itemsize: isize) -> Option<u8> {
+    if format.is_null() {
+        return Some(b'B');
+    }
+    let format = unsafe { core::ffi::CStr::from_ptr(format) }.to_bytes();
+    let typecode = match format {
+        [typecode] | [b'@', typecode] => *typecode,
+        _ => return None,
+    };
+    if is_numeric(typecode, itemsize) {
+        Some(typecode)
+    } else {
+        None
+    }
+}
+
+/// Whether any of `len` items of `typecode` at `data` is an integer outside
+/// the 53-bit range of `OPT_STRICT_INTEGER`. Only 64-bit typecodes can be.
+fn exceeds_53_bits(typecode: u8, itemsize: isize, data: *const u8, len: usize) -> bool {
+    match (typecode, itemsize) {
+        (b'l' | b'q', 8) => slice!(data, len, i64)
+            .iter()
+            .any(|val| !(STRICT_INT_MIN..=STRICT_INT_MAX).contains(val)),
+        (b'L' | b'Q', 8) => slice!(data, len, u64)
+            .iter()
+            .any(|val| *val > STRICT_INT_MAX as u64),
+        _ => false,
+    }
+}
+
+/// Serialize `len` items of `typecode` at `data` as an array. `data` must be
+/// aligned and `typecode` and `itemsize` numeric.
+fn serialize_flat<S>(
+    typecode: u8,
+    itemsize: isize,
+    data: *const u8,
+    len: usize,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if len == 0 {
+        return ZeroListSerializer::new().serialize(serializer);
+    }
+    match (typecode, itemsize) {
+        (b'b', 1) => NumpyI8Array::new(slice!(data, len, i8)).serialize(serializer),
+        (b'B', 1) => NumpyU8Array::new(slice!(data, len, u8)).serialize(serializer),
+        (b'h', 2) => NumpyI16Array::new(slice!(data, len, i16)).serialize(serializer),
+        (b'H', 2) => NumpyU16Array::new(slice!(data, len, u16)).serialize(serializer),
+        (b'i' | b'l', 4) => NumpyI32Array::new(slice!(data, len, i32)).serialize(serializer),
+        (b'I' | b'L', 4) => NumpyU32Array::new(slice!(data, len, u32)).serialize(serializer),
+        (b'l' | b'q', 8) => NumpyI64Array::new(slice!(data, len, i64)).serialize(serializer),
+        (b'L' | b'Q', 8) => NumpyU64Array::new(slice!(data, len, u64)).serialize(serializer),
+        (b'f', 4) => NumpyF32Array::new(slice!(data, len, f32), false).serialize(serializer),
+        (b'd', 8) => NumpyF64Array::new(slice!(data, len, f64), false).serialize(serializer),
+        _ => unreachable!(),
+    }
+}
+
+/// Serialize the single item of `typecode` at `data` as a number.
+fn serialize_item<S>(
+    typecode: u8,
+    itemsize: isize,
+    data: *const u8,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    unsafe {
+        match (typecode, itemsize) {
+            (b'b', 1) => serializer.serialize_i8(*(data as *const i8)),
+            (b'B', 1) => serializer.serialize_u8(*data),
+            (b'h', 2) => serializer.serialize_i16(*(data as *const i16)),
+            (b'H', 2) => serializer.serialize_u16(*(data as *const u16)),
+            (b'i' | b'l', 4) => serializer.serialize_i32(*(data as *const i32)),
+            (b'I' | b'L', 4) => serializer.serialize_u32(*(data as *const u32)),
+            (b'l' | b'q', 8) => serializer.serialize_i64(*(data as *const i64)),
+            (b'L' | b'Q', 8) => serializer.serialize_u64(*(data as *const u64)),
+            (b'f', 4) => serializer.serialize_f32(*(data as *const f32)),
+            (b'd', 8) => serializer.serialize_f64(*(data as *const f64)),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A C contiguous buffer of items of `typecode` with `shape`, serialized as
+/// nested arrays, or as a number if it has no dimensions.
+struct BufferSerializer<'a> {
+    data: *const u8,
+    shape: &'a [isize],
+    typecode: u8,
+    itemsize: isize,
+}
+
+impl Serialize for BufferSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self.shape {
+            [] => serialize_item(self.typecode, self.itemsize, self.data, serializer),
+            [len] => serialize_flat(
+                self.typecode,
+                self.itemsize,
+                self.data,
+                len as usize,
+                serializer,
+            ),
+            [len, ref rest @ ..] => {
+                let stride = rest.iter().product::<isize>() as usize * self.itemsize as usize;
+                let mut seq = serializer.serialize_seq(None).unwrap();
+                for idx in 0..len as usize {
+                    seq.serialize_element(&BufferSerializer {
+                        data: unsafe { self.data.add(idx * stride) },
+                        shape: rest,
+                        typecode: self.typecode,
+                        itemsize: self.itemsize,
+                    })?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+/// Serialize a `memoryview` with a numeric format as nested arrays of its
+/// shape. The Python exception of a buffer that is released or not C
+/// contiguous is chained to the JSONEncodeError.
+fn serialize_memoryview<S>(
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut view: MaybeUninit<Py_buffer> = MaybeUninit::uninit();
+    if unlikely!(
+        ffi!(PyObject_GetBuffer(
+            ptr,
+            view.as_mut_ptr(),
+            pyo3_ffi::PyBUF_FORMAT | pyo3_ffi::PyBUF_C_CONTIGUOUS
+        )) != 0
+    ) {
+        err!(SerializeError::BytesNotContiguous)
+    }
+    let mut view = unsafe { view.assume_init() };
+    let ret = match numeric_typecode(view.format, view.itemsize) {
+        Some(typecode) => {
+            // a memoryview of bytes may be cast to a format it is not
+            // aligned for
+            let mut aligned: Vec<u64> = Vec::new();
+            let mut data = view.buf as *const u8;
+            if data as usize % view.itemsize as usize != 0 {
+                aligned.resize((view.len as usize + 7) / 8, 0);
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        data,
+                        aligned.as_mut_ptr() as *mut u8,
+                        view.len as usize,
+                    );
+                }
+                data = aligned.as_ptr() as *const u8;
+            }
+            let shape: &[isize] = if view.ndim == 0 {
+                &[]
+            } else {
+                unsafe { core::slice::from_raw_parts(view.shape, view.ndim as usize) }
+            };
+            if opt_enabled!(opts, STRICT_INTEGER)
+                && exceeds_53_bits(
+                    typecode,
+                    view.itemsize,
+                    data,
+                    (view.len / view.itemsize) as usize,
+                )
+            {
+                Err(serde::ser::Error::custom(SerializeError::Integer53Bits))
+            } else {
+                BufferSerializer {
+                    data: data,
+                    shape: shape,
+                    typecode: typecode,
+                    itemsize: view.itemsize,
+                }
+                .serialize(serializer)
+            }
+        }
+        None => Err(serde::ser::Error::custom(
+            SerializeError::MemoryviewUnsupportedFormat,
+        )),
+    };
+    ffi!(PyBuffer_Release(&mut view));
+    ret
+}
+
+pub struct ArraySerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl ArraySerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        ArraySerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for ArraySerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if ob_type!(self.ptr) == unsafe { MEMORYVIEW_TYPE } {
+            return serialize_memoryview(self.ptr, self.opts, serializer);
+        }
+        let mut view: MaybeUninit<Py_buffer> = MaybeUninit::uninit();
+        if unlikely!(
+            ffi!(PyObject_GetBuffer(
+                self.ptr,
+                view.as_mut_ptr(),
+                pyo3_ffi::PyBUF_FORMAT
+            )) != 0
+        ) {
+            ffi!(PyErr_Clear());
+            err!(SerializeError::ArrayUnsupportedTypecode)
+        }
+        let mut view = unsafe { view.assume_init() };
+        // the format of array.array is its typecode and the item size of
+        // "l" and "L" is platform-dependent
+        let typecode = unsafe { *view.format as u8 };
+        let len = view.len as usize / view.itemsize as usize;
+        let ret = if !is_numeric(typecode, view.itemsize) {
+            Err(serde::ser::Error::custom(
+                SerializeError::ArrayUnsupportedTypecode,
+            ))
+        } else if opt_enabled!(self.opts, STRICT_INTEGER)
+            && exceeds_53_bits(typecode, view.itemsize, view.buf as *const u8, len)
+        {
+            Err(serde::ser::Error::custom(SerializeError::Integer53Bits))
+        } else {
+            serialize_flat(
+                typecode,
+                view.itemsize,
+                view.buf as *const u8,
+                len,
+                serializer,
+            )
+        };
+        ffi!(PyBuffer_Release(&mut view));
+        ret
+    }
+}
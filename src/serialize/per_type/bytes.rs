@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::ffi::{PyBytes_AS_STRING, PyBytes_GET_SIZE};
+use crate::opt::{Opt, BYTES_BASE64_URLSAFE, SERIALIZE_ARRAY};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::array::{numeric_typecode, ArraySerializer};
+use crate::serialize::state::formats;
+use crate::typeref::{BYTES_TYPE, MEMORYVIEW_TYPE};
+use core::mem::MaybeUninit;
+use pyo3_ffi::Py_buffer;
+
+use serde::ser::{Serialize, Serializer};
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode `input` as base64 with padding and append it, quoted, to `out`.
+fn write_base64(input: &[u8], alphabet: &[u8; 64], out: &mut Vec<u8>) {
+    out.reserve((input.len() + 2) / 3 * 4 + 2);
+    out.push(b'"');
+    let mut chunks = input.chunks_exact(3);
+    for chunk in &mut chunks {
+        let val = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+        out.extend_from_slice(&[
+            alphabet[(val >> 18) as usize & 63],
+            alphabet[(val >> 12) as usize & 63],
+            alphabet[(val >> 6) as usize & 63],
+            alphabet[val as usize & 63],
+        ]);
+    }
+    match *chunks.remainder() {
+        [a] => {
+            let val = (a as u32) << 16;
+            out.extend_from_slice(&[
+                alphabet[(val >> 18) as usize & 63],
+                alphabet[(val >> 12) as usize & 63],
+                b'=',
+                b'=',
+            ]);
+        }
+        [a, b] => {
+            let val = (a as u32) << 16 | (b as u32) << 8;
+            out.extend_from_slice(&[
+                alphabet[(val >> 18) as usize & 63],
+                alphabet[(val >> 12) as usize & 63],
+                alphabet[(val >> 6) as usize & 63],
+                b'=',
+            ]);
+        }
+        _ => {}
+    }
+    out.push(b'"');
+}
+
+/// `bytes`, `bytearray`, or `memoryview` with a `bytes_format`.
+/// With `OPT_SERIALIZE_ARRAY`, a `memoryview` of numbers larger than a byte
+/// is serialized as an array instead.
+pub struct BytesSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl BytesSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        BytesSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+/// Serialize `input` as a base64 string, using the URL-safe alphabet if
+/// `urlsafe`.
+pub fn serialize_base64<S>(serializer: S, input: &[u8], urlsafe: bool) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let alphabet = if urlsafe { URL_SAFE } else { STANDARD };
+    let mut buf: Vec<u8> = Vec::new();
+    write_base64(input, alphabet, &mut buf);
+    serializer.serialize_bytes(&buf)
+}
+
+impl Serialize for BytesSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if ob_type!(self.ptr) == unsafe { BYTES_TYPE } {
+            let input = unsafe {
+                core::slice::from_raw_parts(
+                    PyBytes_AS_STRING(self.ptr) as *const u8,
+                    PyBytes_GET_SIZE(self.ptr) as usize,
+                )
+            };
+            return serialize_base64(
+                serializer,
+                input,
+                opt_enabled!(formats(), BYTES_BASE64_URLSAFE),
+            );
+        }
+        // A memoryview may be released or not contiguous. The Python
+        // exception is chained to the JSONEncodeError.
+        let mut view: MaybeUninit<Py_buffer> = MaybeUninit::uninit();
+        if unlikely!(
+            ffi!(PyObject_GetBuffer(
+                self.ptr,
+                view.as_mut_ptr(),
+                pyo3_ffi::PyBUF_FORMAT | pyo3_ffi::PyBUF_C_CONTIGUOUS
+            )) != 0
+        ) {
+            err!(SerializeError::BytesNotContiguous)
+        }
+        let mut view = unsafe { view.assume_init() };
+        if opt_enabled!(self.opts, SERIALIZE_ARRAY)
+            && ob_type!(self.ptr) == unsafe { MEMORYVIEW_TYPE }
+            && view.itemsize > 1
+            && numeric_typecode(view.format, view.itemsize).is_some()
+        {
+            ffi!(PyBuffer_Release(&mut view));
+            return ArraySerializer::new(self.ptr, self.opts).serialize(serializer);
+        }
+        let input =
+            unsafe { core::slice::from_raw_parts(view.buf as *const u8, view.len as usize) };
+        let ret = serialize_base64(
+            serializer,
+            input,
+            opt_enabled!(formats(), BYTES_BASE64_URLSAFE),
+        );
+        ffi!(PyBuffer_Release(&mut view));
+        ret
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{Opt, COMPLEX_AS_OBJECT, SORT_KEYS};
+use crate::serialize::state::formats;
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+pub struct ComplexSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl ComplexSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        ComplexSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for ComplexSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // components are serialized as float, so non-finite values are
+        // null or literals depending on OPT_ALLOW_NONFINITE
+        let real = ffi!(PyComplex_RealAsDouble(self.ptr));
+        let imag = ffi!(PyComplex_ImagAsDouble(self.ptr));
+        if opt_enabled!(formats(), COMPLEX_AS_OBJECT) {
+            let mut map = serializer.serialize_map(None).unwrap();
+            if opt_enabled!(self.opts, SORT_KEYS) {
+                map.serialize_key("imag").unwrap();
+                map.serialize_value(&imag)?;
+                map.serialize_key("real").unwrap();
+                map.serialize_value(&real)?;
+            } else {
+                map.serialize_key("real").unwrap();
+                map.serialize_value(&real)?;
+                map.serialize_key("imag").unwrap();
+                map.serialize_value(&imag)?;
+            }
+            map.end()
+        } else {
+            let mut seq = serializer.serialize_seq(None).unwrap();
+            seq.serialize_element(&real)?;
+            seq.serialize_element(&imag)?;
+            seq.end()
+        }
+    }
+}
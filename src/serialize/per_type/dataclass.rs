@@ -1,17 +1,228 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+use crate::opt::{
+    CAMELCASE, DATACLASS_FIELD_RENAME, DATACLASS_SKIP_MISSING, INCLUDE_PRIVATE_FIELDS,
+    SKIP_NONE_VALUES, SORT_KEYS,
+};
 use crate::serialize::error::SerializeError;
-use crate::serialize::per_type::dict::ZeroDictSerializer;
+use crate::serialize::per_type::dict::{check_camel_case_keys, sort_str_items, ZeroDictSerializer};
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::serialize::state::SerializerState;
+use crate::serialize::state::{error_at_key, start_error_path, SerializerState};
 use crate::str::unicode_to_str;
 use crate::typeref::{
-    DATACLASS_FIELDS_STR, DICT_STR, FIELD_TYPE, FIELD_TYPE_STR, SLOTS_STR, STR_TYPE,
+    DATACLASS_FIELDS_STR, DATACLASS_MISSING, DICT_STR, FIELD_TYPE, FIELD_TYPE_STR, METADATA_STR,
+    NONE, ORJSON_NAME_STR, SLOTS_STR, STR_TYPE,
 };
 
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use smallvec::SmallVec;
+
+use core::cell::Cell;
+use core::ptr::{null_mut, NonNull};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+std::thread_local! {
+    /// The layouts of the `orjson.Encoder` that is serializing, if any.
+    static LAYOUTS: Cell<*mut DataclassLayouts> = const { Cell::new(null_mut()) };
+
+    /// The `include` argument to `dumps()` that is serializing, if any. It is
+    /// taken while a dataclass is serialized so that it applies only to
+    /// dataclasses that are not within another.
+    static INCLUDE: Cell<*mut pyo3_ffi::PyObject> = const { Cell::new(null_mut()) };
+}
+
+/// The items of a dataclass, for `OPT_SORT_KEYS` or `OPT_CAMELCASE`, which
+/// are serialized once all have been read so that they can be sorted or
+/// checked for keys that are the same once converted.
+type SortedItems = SmallVec<[(&'static str, *mut pyo3_ffi::PyObject); 8]>;
+
+/// `Some` to buffer items if `OPT_SORT_KEYS` or `OPT_CAMELCASE`.
+#[inline(always)]
+fn sorted_items(state: SerializerState) -> Option<SortedItems> {
+    if unlikely!(opt_enabled!(state.opts(), SORT_KEYS | CAMELCASE)) {
+        Some(SmallVec::new())
+    } else {
+        None
+    }
+}
+
+/// Serialize `items` to `map`, sorted by key if `OPT_SORT_KEYS`.
+#[cold]
+#[inline(never)]
+fn serialize_sorted<M>(
+    map: &mut M,
+    mut items: SortedItems,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+) -> Result<(), M::Error>
+where
+    M: SerializeMap,
+{
+    if opt_enabled!(state.opts(), CAMELCASE) {
+        check_camel_case_keys(&items)?;
+    }
+    if opt_enabled!(state.opts(), SORT_KEYS) {
+        sort_str_items(&mut items, state.opts());
+    }
+    for (key, value) in items.iter() {
+        let pyvalue = PyObjectSerializer::new(*value, state, default);
+        map.serialize_key(key).unwrap();
+        map.serialize_value(&pyvalue)
+            .map_err(|err| error_at_field(err, key, *value))?;
+    }
+    Ok(())
+}
+
+/// The keys and fields of the dataclasses serialized by one `orjson.Encoder`,
+/// by type, so that serializing many instances of a type does not check and
+/// convert the same keys, or read the same fields, for each. The options of
+/// an encoder do not change, so neither does what is skipped. A layout is
+/// used only while its type is alive, as the address may then be reused.
+#[derive(Default)]
+pub struct DataclassLayouts {
+    layouts: HashMap<usize, Layout, ahash::RandomState>,
+}
+
+impl DataclassLayouts {
+    /// The layout of `ob_type`, which is empty if it was not yet read or its
+    /// type was garbage collected, or `None` if it cannot be weakly referenced.
+    fn get(&mut self, ob_type: *mut pyo3_ffi::PyTypeObject) -> Option<&mut Layout> {
+        let key = ob_type as usize;
+        let alive = match self.layouts.get(&key) {
+            Some(layout) => layout.is_of(ob_type),
+            None => false,
+        };
+        if !alive {
+            let weakref = ffi!(PyWeakref_NewRef(
+                ob_type as *mut pyo3_ffi::PyObject,
+                null_mut()
+            ));
+            if unlikely!(weakref.is_null()) {
+                ffi!(PyErr_Clear());
+                return None;
+            }
+            // Discard the layouts of any types that have been garbage collected.
+            self.layouts.retain(|_, layout| layout.is_alive());
+            self.layouts.insert(
+                key,
+                Layout {
+                    weakref: weakref,
+                    keys: Rc::new(LayoutKeys(Vec::new())),
+                    fields: None,
+                },
+            );
+        }
+        self.layouts.get_mut(&key)
+    }
+}
+
+/// Serialize with `layouts`, which is owned by an `orjson.Encoder`, used by
+/// the dataclass serializers during `f`.
+pub fn with_dataclass_layouts<T, F>(layouts: *mut DataclassLayouts, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = LAYOUTS.with(|current| current.replace(layouts));
+    let ret = f();
+    LAYOUTS.with(|current| current.set(previous));
+    ret
+}
+
+/// Serialize only the fields named in `include`, a `set` or `frozenset`, of
+/// each dataclass that is not within another dataclass during `f`.
+pub fn with_dataclass_include<T, F>(include: *mut pyo3_ffi::PyObject, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = INCLUDE.with(|current| current.replace(include));
+    let ret = f();
+    INCLUDE.with(|current| current.set(previous));
+    ret
+}
+
+/// Whether the field named `attr` is serialized given `include`.
+#[inline(always)]
+fn is_included(include: *mut pyo3_ffi::PyObject, attr: *mut pyo3_ffi::PyObject) -> bool {
+    include.is_null() || ffi!(PySet_Contains(include, attr)) == 1
+}
+
+/// Call `f` with the layout of `ob_type` if an `orjson.Encoder` is
+/// serializing. `f` must not call into Python, which could serialize again.
+fn with_layout<T, F>(ob_type: *mut pyo3_ffi::PyTypeObject, f: F) -> Option<T>
+where
+    F: FnOnce(&mut Layout) -> T,
+{
+    let layouts = LAYOUTS.with(|current| current.get());
+    if likely!(layouts.is_null()) {
+        return None;
+    }
+    unsafe { (*layouts).get(ob_type) }.map(f)
+}
+
+struct Layout {
+    weakref: *mut pyo3_ffi::PyObject,
+    /// The keys of `__dict__` of the last instance serialized by
+    /// `DataclassFastSerializer`, in order.
+    keys: Rc<LayoutKeys>,
+    /// The fields serialized by `DataclassFallbackSerializer`, in order, if
+    /// they have been read.
+    fields: Option<Rc<LayoutFields>>,
+}
+
+impl Layout {
+    fn is_of(&self, ob_type: *mut pyo3_ffi::PyTypeObject) -> bool {
+        ffi!(PyWeakref_GetObject(self.weakref)) == ob_type as *mut pyo3_ffi::PyObject
+    }
+
+    fn is_alive(&self) -> bool {
+        ffi!(PyWeakref_GetObject(self.weakref)) != unsafe { NONE }
+    }
+}
 
-use core::ptr::NonNull;
+impl Drop for Layout {
+    fn drop(&mut self) {
+        ffi!(Py_DECREF(self.weakref));
+    }
+}
+
+/// A key of `__dict__` and the key it is serialized as, or `None` if it is
+/// skipped. The `str` is of `key`, to which this holds a reference.
+struct LayoutKey {
+    key: *mut pyo3_ffi::PyObject,
+    name: Option<&'static str>,
+}
+
+struct LayoutKeys(Vec<LayoutKey>);
+
+impl Drop for LayoutKeys {
+    fn drop(&mut self) {
+        for each in self.0.iter() {
+            ffi!(Py_DECREF(each.key));
+        }
+    }
+}
+
+/// A field and the key it is serialized as. The `str` is of `name`, which is
+/// `attr` unless the field is renamed, and this holds a reference to both.
+/// `offset` is of its slot if it is read directly, as by `slot_offset()`.
+struct LayoutField {
+    attr: *mut pyo3_ffi::PyObject,
+    name_obj: *mut pyo3_ffi::PyObject,
+    name: &'static str,
+    offset: Option<usize>,
+}
+
+struct LayoutFields(Vec<LayoutField>);
+
+impl Drop for LayoutFields {
+    fn drop(&mut self) {
+        for each in self.0.iter() {
+            ffi!(Py_DECREF(each.attr));
+            ffi!(Py_DECREF(each.name_obj));
+        }
+    }
+}
 
 #[repr(transparent)]
 pub struct DataclassGenericSerializer<'a> {
@@ -33,6 +244,28 @@ impl<'a> Serialize for DataclassGenericSerializer<'a> {
         if unlikely!(self.previous.state.recursion_limit()) {
             err!(SerializeError::RecursionLimit)
         }
+        let include = INCLUDE.with(|current| current.get());
+        if likely!(include.is_null()) {
+            self.serialize_with_include(include, serializer)
+        } else {
+            INCLUDE.with(|current| current.set(null_mut()));
+            let ret = self.serialize_with_include(include, serializer);
+            INCLUDE.with(|current| current.set(include));
+            ret
+        }
+    }
+}
+
+impl<'a> DataclassGenericSerializer<'a> {
+    #[inline(always)]
+    fn serialize_with_include<S>(
+        &self,
+        include: *mut pyo3_ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
         let dict = ffi!(PyObject_GetAttr(self.previous.ptr, DICT_STR));
         let ob_type = ob_type!(self.previous.ptr);
         if unlikely!(dict.is_null()) {
@@ -41,21 +274,32 @@ impl<'a> Serialize for DataclassGenericSerializer<'a> {
                 self.previous.ptr,
                 self.previous.state,
                 self.previous.default,
+                include,
+                true,
             )
             .serialize(serializer)
-        } else if pydict_contains!(ob_type, SLOTS_STR) {
+        } else if pydict_contains!(ob_type, SLOTS_STR)
+            || opt_enabled!(self.previous.state.opts(), DATACLASS_FIELD_RENAME)
+        {
             let ret = DataclassFallbackSerializer::new(
                 self.previous.ptr,
                 self.previous.state,
                 self.previous.default,
+                include,
+                pydict_contains!(ob_type, SLOTS_STR),
             )
             .serialize(serializer);
             ffi!(Py_DECREF(dict));
             ret
         } else {
-            let ret =
-                DataclassFastSerializer::new(dict, self.previous.state, self.previous.default)
-                    .serialize(serializer);
+            let ret = DataclassFastSerializer::new(
+                dict,
+                ob_type,
+                self.previous.state,
+                self.previous.default,
+                include,
+            )
+            .serialize(serializer);
             ffi!(Py_DECREF(dict));
             ret
         }
@@ -64,20 +308,26 @@ impl<'a> Serialize for DataclassGenericSerializer<'a> {
 
 pub struct DataclassFastSerializer {
     ptr: *mut pyo3_ffi::PyObject,
+    ob_type: *mut pyo3_ffi::PyTypeObject,
     state: SerializerState,
     default: Option<NonNull<pyo3_ffi::PyObject>>,
+    include: *mut pyo3_ffi::PyObject,
 }
 
 impl DataclassFastSerializer {
     pub fn new(
         ptr: *mut pyo3_ffi::PyObject,
+        ob_type: *mut pyo3_ffi::PyTypeObject,
         state: SerializerState,
         default: Option<NonNull<pyo3_ffi::PyObject>>,
+        include: *mut pyo3_ffi::PyObject,
     ) -> Self {
         DataclassFastSerializer {
             ptr: ptr,
+            ob_type: ob_type,
             state: state.copy_for_recursive_call(),
             default: default,
+            include: include,
         }
     }
 }
@@ -94,56 +344,248 @@ impl Serialize for DataclassFastSerializer {
         }
         let mut map = serializer.serialize_map(None).unwrap();
 
+        // If an encoder is serializing, the keys of the last instance of the
+        // type, which are usually the same objects in the same order.
+        let cached = with_layout(self.ob_type, |layout| layout.keys.clone());
+        let mut learned: Option<Vec<LayoutKey>> = None;
+        let mut sorted = sorted_items(self.state);
+
         let mut pos = 0;
         let mut next_key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
         let mut next_value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
 
         pydict_next!(self.ptr, &mut pos, &mut next_key, &mut next_value);
 
-        for _ in 0..ffi!(Py_SIZE(self.ptr)) as usize {
+        for idx in 0..ffi!(Py_SIZE(self.ptr)) as usize {
             let key = next_key;
             let value = next_value;
 
             pydict_next!(self.ptr, &mut pos, &mut next_key, &mut next_value);
 
-            let key_as_str = {
-                let key_ob_type = ob_type!(key);
-                if unlikely!(!is_class_by_type!(key_ob_type, STR_TYPE)) {
-                    err!(SerializeError::KeyMustBeStr)
+            let name = match cached.as_ref().and_then(|keys| keys.0.get(idx)) {
+                Some(each) if each.key == key && learned.is_none() => each.name,
+                _ => {
+                    let name = match self.key_name(key) {
+                        Ok(name) => name,
+                        Err(err) => err!(err),
+                    };
+                    if let (Some(keys), None) = (cached.as_ref(), learned.as_ref()) {
+                        learned = Some(
+                            keys.0[..idx]
+                                .iter()
+                                .map(|each| LayoutKey::new(each.key, each.name))
+                                .collect(),
+                        );
+                    }
+                    name
                 }
-                let tmp = unicode_to_str(key);
-                if unlikely!(tmp.is_none()) {
-                    err!(SerializeError::InvalidStr)
-                };
-                tmp.unwrap()
             };
-            if unlikely!(key_as_str.as_bytes()[0] == b'_') {
+            if let Some(learned) = learned.as_mut() {
+                learned.push(LayoutKey::new(key, name));
+            }
+            let key_as_str = match name {
+                Some(name) => name,
+                None => continue,
+            };
+            if unlikely!(!is_included(self.include, key)) {
+                continue;
+            }
+            if unlikely!(value == unsafe { DATACLASS_MISSING }) {
+                if opt_enabled!(self.state.opts(), DATACLASS_SKIP_MISSING) {
+                    continue;
+                }
+                if self.default.is_none() {
+                    return Err(field_error(
+                        key_as_str,
+                        SerializeError::DataclassFieldMissing,
+                    ));
+                }
+            }
+            if unlikely!(value == unsafe { NONE })
+                && opt_enabled!(self.state.opts(), SKIP_NONE_VALUES)
+            {
+                continue;
+            }
+            if let Some(items) = sorted.as_mut() {
+                items.push((key_as_str, value));
                 continue;
             }
             let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
             map.serialize_key(key_as_str).unwrap();
-            map.serialize_value(&pyvalue)?;
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_field(err, key_as_str, value))?;
+        }
+        if let Some(learned) = learned {
+            with_layout(self.ob_type, |layout| {
+                layout.keys = Rc::new(LayoutKeys(learned));
+            });
+        }
+        if let Some(items) = sorted {
+            serialize_sorted(&mut map, items, self.state, self.default)?;
         }
         map.end()
     }
 }
 
+impl LayoutKey {
+    fn new(key: *mut pyo3_ffi::PyObject, name: Option<&'static str>) -> Self {
+        ffi!(Py_INCREF(key));
+        LayoutKey {
+            key: key,
+            name: name,
+        }
+    }
+}
+
+impl DataclassFastSerializer {
+    /// The key that `key` of `__dict__` is serialized as, or `None` if it is
+    /// skipped.
+    #[inline(always)]
+    fn key_name(
+        &self,
+        key: *mut pyo3_ffi::PyObject,
+    ) -> Result<Option<&'static str>, SerializeError> {
+        let key_ob_type = ob_type!(key);
+        if unlikely!(!is_class_by_type!(key_ob_type, STR_TYPE)) {
+            start_error_path();
+            return Err(SerializeError::KeyMustBeStr(nonnull!(key)));
+        }
+        let key_as_str = match unicode_to_str(key) {
+            Some(key_as_str) => key_as_str,
+            None => return Err(SerializeError::InvalidStr),
+        };
+        if unlikely!(key_as_str.as_bytes()[0] == b'_')
+            && opt_disabled!(self.state.opts(), INCLUDE_PRIVATE_FIELDS)
+        {
+            return Ok(None);
+        }
+        Ok(Some(key_as_str))
+    }
+}
+
+/// The offset of the slot of `attr` in instances of `ob_type` if looking it
+/// up finds a member descriptor of an object, as `__slots__` creates, so that
+/// it can be read directly rather than by `getattr()`.
+#[inline(never)]
+fn slot_offset(
+    ob_type: *mut pyo3_ffi::PyTypeObject,
+    attr: *mut pyo3_ffi::PyObject,
+) -> Option<usize> {
+    unsafe {
+        let getattro = (*ob_type)
+            .tp_getattro
+            .map(|func| func as *const () as usize);
+        if getattro != Some(pyo3_ffi::PyObject_GenericGetAttr as *const () as usize) {
+            return None;
+        }
+        let mro = (*ob_type).tp_mro;
+        if mro.is_null() {
+            return None;
+        }
+        for idx in 0..ffi!(Py_SIZE(mro)) {
+            let base = ffi!(PyTuple_GET_ITEM(mro, idx)) as *mut pyo3_ffi::PyTypeObject;
+            let dict = (*base).tp_dict;
+            if dict.is_null() {
+                continue;
+            }
+            let descr = ffi!(PyDict_GetItem(dict, attr));
+            if descr.is_null() {
+                continue;
+            }
+            if ob_type!(descr) != core::ptr::addr_of_mut!(pyo3_ffi::PyMemberDescr_Type) {
+                return None;
+            }
+            let member = (*(descr as *mut pyo3_ffi::PyMemberDescrObject)).d_member
+                as *mut pyo3_ffi::PyMemberDef;
+            if (*member).type_code != pyo3_ffi::Py_T_OBJECT_EX {
+                return None;
+            }
+            return Some((*member).offset as usize);
+        }
+        None
+    }
+}
+
+/// Read the field `attr` of `ptr`, from its slot at `offset` if given, as a
+/// borrowed reference, or null if it is not set or reading it raised, in
+/// which case an exception is set.
+#[inline(always)]
+fn read_field(
+    ptr: *mut pyo3_ffi::PyObject,
+    attr: *mut pyo3_ffi::PyObject,
+    offset: Option<usize>,
+) -> *mut pyo3_ffi::PyObject {
+    if let Some(offset) = offset {
+        let value = unsafe { *((ptr as *mut u8).add(offset) as *mut *mut pyo3_ffi::PyObject) };
+        if likely!(!value.is_null()) {
+            return value;
+        }
+        // getattr() raises AttributeError for the unset slot
+    }
+    let value = ffi!(PyObject_GetAttr(ptr, attr));
+    if unlikely!(value.is_null()) {
+        return value;
+    }
+    debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
+    ffi!(Py_DECREF(value));
+    value
+}
+
+/// The error for the field `key`, e.g., that `read_field()` could not read,
+/// in which case the exception is chained as the cause.
+#[cold]
+#[inline(never)]
+fn field_error<E: serde::ser::Error>(key: &str, err: SerializeError) -> E {
+    start_error_path();
+    error_at_key(E::custom(err), key)
+}
+
+/// Record that `err` occurred in the value of the field `key`, or, if `value`
+/// is `dataclasses.MISSING`, that `default` did not serialize it.
+#[cold]
+#[inline(never)]
+fn error_at_field<E: serde::ser::Error>(err: E, key: &str, value: *mut pyo3_ffi::PyObject) -> E {
+    if value == unsafe { DATACLASS_MISSING } {
+        field_error(key, SerializeError::DataclassFieldMissing)
+    } else {
+        error_at_key(err, key)
+    }
+}
+
 pub struct DataclassFallbackSerializer {
     ptr: *mut pyo3_ffi::PyObject,
     state: SerializerState,
     default: Option<NonNull<pyo3_ffi::PyObject>>,
+    include: *mut pyo3_ffi::PyObject,
+    slots: bool,
 }
 
 impl DataclassFallbackSerializer {
+    /// `slots` if the type defines `__slots__`, so that fields are read from
+    /// their slots where possible.
     pub fn new(
         ptr: *mut pyo3_ffi::PyObject,
         state: SerializerState,
         default: Option<NonNull<pyo3_ffi::PyObject>>,
+        include: *mut pyo3_ffi::PyObject,
+        slots: bool,
     ) -> Self {
         DataclassFallbackSerializer {
             ptr: ptr,
             state: state.copy_for_recursive_call(),
             default: default,
+            include: include,
+            slots: slots,
+        }
+    }
+
+    /// The offset to read `attr` from, if this reads slots.
+    #[inline(always)]
+    fn slot_offset(&self, attr: *mut pyo3_ffi::PyObject) -> Option<usize> {
+        if self.slots {
+            slot_offset(ob_type!(self.ptr), attr)
+        } else {
+            None
         }
     }
 }
@@ -152,6 +594,146 @@ impl Serialize for DataclassFallbackSerializer {
     #[cold]
     #[inline(never)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ob_type = ob_type!(self.ptr);
+        match with_layout(ob_type, |layout| layout.fields.clone()) {
+            None => self.serialize_fields(serializer),
+            Some(Some(fields)) => self.serialize_layout(&fields, serializer),
+            Some(None) => match self.read_layout() {
+                Some(fields) => {
+                    let fields = Rc::new(fields);
+                    with_layout(ob_type, |layout| layout.fields = Some(fields.clone()));
+                    self.serialize_layout(&fields, serializer)
+                }
+                None => self.serialize_fields(serializer),
+            },
+        }
+    }
+}
+
+impl DataclassFallbackSerializer {
+    /// Serialize the fields read by `read_layout()`.
+    fn serialize_layout<S>(&self, fields: &LayoutFields, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None).unwrap();
+        let mut sorted = sorted_items(self.state);
+        for field in fields.0.iter() {
+            if unlikely!(!is_included(self.include, field.attr)) {
+                continue;
+            }
+            let value = read_field(self.ptr, field.attr, field.offset);
+            if unlikely!(value.is_null()) {
+                return Err(field_error(
+                    field.name,
+                    SerializeError::DataclassFieldUnreadable,
+                ));
+            }
+            if unlikely!(value == unsafe { DATACLASS_MISSING }) {
+                if opt_enabled!(self.state.opts(), DATACLASS_SKIP_MISSING) {
+                    continue;
+                }
+                if self.default.is_none() {
+                    return Err(field_error(
+                        field.name,
+                        SerializeError::DataclassFieldMissing,
+                    ));
+                }
+            }
+            if unlikely!(value == unsafe { NONE })
+                && opt_enabled!(self.state.opts(), SKIP_NONE_VALUES)
+            {
+                continue;
+            }
+            if let Some(items) = sorted.as_mut() {
+                items.push((field.name, value));
+                continue;
+            }
+            let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
+            map.serialize_key(field.name).unwrap();
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_field(err, field.name, value))?;
+        }
+        if let Some(items) = sorted {
+            serialize_sorted(&mut map, items, self.state, self.default)?;
+        }
+        map.end()
+    }
+
+    /// Read the fields that `serialize_fields()` serializes and their keys, or
+    /// `None` if a key is invalid, in which case `serialize_fields()` raises
+    /// the error.
+    #[cold]
+    fn read_layout(&self) -> Option<LayoutFields> {
+        let fields = ffi!(PyObject_GetAttr(self.ptr, DATACLASS_FIELDS_STR));
+        debug_assert!(ffi!(Py_REFCNT(fields)) >= 2);
+        ffi!(Py_DECREF(fields));
+        let mut layout = LayoutFields(Vec::with_capacity(ffi!(Py_SIZE(fields)) as usize));
+
+        let mut pos = 0;
+        let mut next_key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        let mut next_value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+
+        pydict_next!(fields, &mut pos, &mut next_key, &mut next_value);
+
+        for _ in 0..ffi!(Py_SIZE(fields)) as usize {
+            let attr = next_key;
+            let field = next_value;
+
+            pydict_next!(fields, &mut pos, &mut next_key, &mut next_value);
+
+            let field_type = ffi!(PyObject_GetAttr(field, FIELD_TYPE_STR));
+            ffi!(Py_DECREF(field_type));
+            if unsafe { field_type as *mut pyo3_ffi::PyTypeObject != FIELD_TYPE } {
+                continue;
+            }
+            let key_as_str = unicode_to_str(attr)?;
+            if key_as_str.as_bytes()[0] == b'_'
+                && opt_disabled!(self.state.opts(), INCLUDE_PRIVATE_FIELDS)
+            {
+                continue;
+            }
+
+            let name_obj = if unlikely!(opt_enabled!(self.state.opts(), DATACLASS_FIELD_RENAME)) {
+                match field_metadata_name(field) {
+                    Some(name) => name.as_ptr(),
+                    None => {
+                        ffi!(Py_INCREF(attr));
+                        attr
+                    }
+                }
+            } else {
+                ffi!(Py_INCREF(attr));
+                attr
+            };
+            let name = if is_class_by_type!(ob_type!(name_obj), STR_TYPE) {
+                unicode_to_str(name_obj)
+            } else {
+                None
+            };
+            match name {
+                Some(name) => {
+                    ffi!(Py_INCREF(attr));
+                    layout.0.push(LayoutField {
+                        attr: attr,
+                        name_obj: name_obj,
+                        name: name,
+                        offset: self.slot_offset(attr),
+                    });
+                }
+                None => {
+                    ffi!(Py_DECREF(name_obj));
+                    return None;
+                }
+            }
+        }
+        Some(layout)
+    }
+
+    fn serialize_fields<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -163,6 +745,7 @@ impl Serialize for DataclassFallbackSerializer {
             return ZeroDictSerializer::new().serialize(serializer);
         }
         let mut map = serializer.serialize_map(None).unwrap();
+        let mut sorted = sorted_items(self.state);
 
         let mut pos = 0;
         let mut next_key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
@@ -190,18 +773,106 @@ impl Serialize for DataclassFallbackSerializer {
                 };
                 tmp.unwrap()
             };
-            if key_as_str.as_bytes()[0] == b'_' {
+            if key_as_str.as_bytes()[0] == b'_'
+                && opt_disabled!(self.state.opts(), INCLUDE_PRIVATE_FIELDS)
+            {
+                continue;
+            }
+            if unlikely!(!is_included(self.include, attr)) {
                 continue;
             }
 
-            let value = ffi!(PyObject_GetAttr(self.ptr, attr));
-            debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
-            ffi!(Py_DECREF(value));
+            let value = read_field(self.ptr, attr, self.slot_offset(attr));
+            if unlikely!(value.is_null()) {
+                return Err(field_error(
+                    key_as_str,
+                    SerializeError::DataclassFieldUnreadable,
+                ));
+            }
+            if unlikely!(value == unsafe { DATACLASS_MISSING }) {
+                if opt_enabled!(self.state.opts(), DATACLASS_SKIP_MISSING) {
+                    continue;
+                }
+                if self.default.is_none() {
+                    return Err(field_error(
+                        key_as_str,
+                        SerializeError::DataclassFieldMissing,
+                    ));
+                }
+            }
+            if unlikely!(value == unsafe { NONE })
+                && opt_enabled!(self.state.opts(), SKIP_NONE_VALUES)
+            {
+                continue;
+            }
             let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
 
+            if unlikely!(opt_enabled!(self.state.opts(), DATACLASS_FIELD_RENAME)) {
+                let renamed = field_metadata_name(field);
+                if let Some(name) = renamed {
+                    let name_as_str = {
+                        let name_ob_type = ob_type!(name.as_ptr());
+                        if unlikely!(!is_class_by_type!(name_ob_type, STR_TYPE)) {
+                            // the message names the type, so format it first
+                            start_error_path();
+                            let err = <S::Error as serde::ser::Error>::custom(
+                                SerializeError::KeyMustBeStr(name),
+                            );
+                            ffi!(Py_DECREF(name.as_ptr()));
+                            return Err(err);
+                        }
+                        let tmp = unicode_to_str(name.as_ptr());
+                        if unlikely!(tmp.is_none()) {
+                            ffi!(Py_DECREF(name.as_ptr()));
+                            err!(SerializeError::InvalidStr)
+                        };
+                        tmp.unwrap()
+                    };
+                    ffi!(Py_DECREF(name.as_ptr()));
+                    if let Some(items) = sorted.as_mut() {
+                        items.push((name_as_str, value));
+                        continue;
+                    }
+                    map.serialize_key(name_as_str).unwrap();
+                    map.serialize_value(&pyvalue)
+                        .map_err(|err| error_at_field(err, name_as_str, value))?;
+                    continue;
+                }
+            }
+
+            if let Some(items) = sorted.as_mut() {
+                items.push((key_as_str, value));
+                continue;
+            }
             map.serialize_key(key_as_str).unwrap();
-            map.serialize_value(&pyvalue)?
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_field(err, key_as_str, value))?;
+        }
+        if let Some(items) = sorted {
+            serialize_sorted(&mut map, items, self.state, self.default)?;
         }
         map.end()
     }
 }
+
+/// Return a new reference to `field.metadata["orjson_name"]` if present.
+#[cold]
+#[inline(never)]
+fn field_metadata_name(field: *mut pyo3_ffi::PyObject) -> Option<NonNull<pyo3_ffi::PyObject>> {
+    let metadata = ffi!(PyObject_GetAttr(field, METADATA_STR));
+    if unlikely!(metadata.is_null()) {
+        ffi!(PyErr_Clear());
+        return None;
+    }
+    if ffi!(PyObject_Size(metadata)) <= 0 {
+        ffi!(PyErr_Clear());
+        ffi!(Py_DECREF(metadata));
+        return None;
+    }
+    let name = ffi!(PyObject_GetItem(metadata, ORJSON_NAME_STR));
+    ffi!(Py_DECREF(metadata));
+    if name.is_null() {
+        ffi!(PyErr_Clear());
+    }
+    NonNull::new(name)
+}
@@ -5,10 +5,16 @@ use crate::serialize::error::SerializeError;
 use crate::serialize::per_type::datetimelike::{
     DateTimeBuffer, DateTimeError, DateTimeLike, Offset,
 };
+use crate::serialize::per_type::pandas::{is_pandas_timestamp, pandas_timestamp_nanosecond};
+use crate::serialize::state::{formats, start_error_path};
 #[cfg(Py_3_9)]
-use crate::typeref::ZONEINFO_TYPE;
-use crate::typeref::{CONVERT_METHOD_STR, DST_STR, NORMALIZE_METHOD_STR, UTCOFFSET_METHOD_STR};
-use serde::ser::{Serialize, Serializer};
+use crate::str::unicode_to_str;
+use crate::typeref::{
+    CONVERT_METHOD_STR, DATETIME_TYPE, DST_STR, NONE, NORMALIZE_METHOD_STR, UTCOFFSET_METHOD_STR,
+};
+#[cfg(Py_3_9)]
+use crate::typeref::{KEY_STR, STR_TYPE, ZONEINFO_TYPE};
+use serde::ser::{Serialize, SerializeMap, Serializer};
 
 macro_rules! write_double_digit {
     ($buf:ident, $value:ident) => {
@@ -32,6 +38,19 @@ macro_rules! write_microsecond {
     };
 }
 
+/// The number of days from 1970-01-01 to `year`-`month`-`day` in the
+/// proleptic Gregorian calendar, negative if before.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    // count years from March so that the leap day is the last of the year
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
 #[repr(transparent)]
 pub struct Date {
     ptr: *mut pyo3_ffi::PyObject,
@@ -42,6 +61,14 @@ impl Date {
         Date { ptr: ptr }
     }
 
+    pub fn epoch_day(&self) -> i64 {
+        days_from_civil(
+            ffi!(PyDateTime_GET_YEAR(self.ptr)),
+            ffi!(PyDateTime_GET_MONTH(self.ptr)) as u32,
+            ffi!(PyDateTime_GET_DAY(self.ptr)) as u32,
+        )
+    }
+
     #[inline(never)]
     pub fn write_buf(&self, buf: &mut DateTimeBuffer) {
         {
@@ -71,6 +98,9 @@ impl Serialize for Date {
     where
         S: Serializer,
     {
+        if unlikely!(opt_enabled!(formats(), DATE_AS_EPOCH_DAY)) {
+            return serializer.serialize_i64(self.epoch_day());
+        }
         let mut buf = DateTimeBuffer::new();
         self.write_buf(&mut buf);
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
@@ -109,7 +139,17 @@ impl Time {
         write_double_digit!(buf, second);
         if opt_disabled!(self.opts, OMIT_MICROSECONDS) {
             let microsecond = ffi!(PyDateTime_TIME_GET_MICROSECOND(self.ptr)) as u32;
-            write_microsecond!(buf, microsecond);
+            if unlikely!(opt_enabled!(formats(), TIME_MILLIS)) {
+                // truncated, as for OPT_OMIT_MICROSECONDS, so never 1000
+                let millisecond = microsecond / 1_000;
+                buf.push(b'.');
+                if millisecond < 100 {
+                    buf.push(b'0');
+                }
+                write_double_digit!(buf, millisecond);
+            } else {
+                write_microsecond!(buf, microsecond);
+            }
         }
         Ok(())
     }
@@ -140,6 +180,68 @@ impl DateTime {
             opts: opts,
         }
     }
+
+    /// Whether `tzinfo` is set but its `utcoffset()` is `None`, which Python
+    /// considers naive.
+    pub fn has_tz_without_offset(&self) -> bool {
+        if !self.has_tz() {
+            return false;
+        }
+        let py_offset = call_method!(self.ptr, UTCOFFSET_METHOD_STR);
+        if unlikely!(py_offset.is_null()) {
+            ffi!(PyErr_Clear());
+            return false;
+        }
+        ffi!(Py_DECREF(py_offset));
+        unsafe { py_offset == NONE }
+    }
+
+    /// A new reference to a copy of `self` without `tzinfo`.
+    #[cold]
+    pub fn without_tz(&self) -> *mut pyo3_ffi::PyObject {
+        unsafe {
+            ((*pyo3_ffi::PyDateTimeAPI()).DateTime_FromDateAndTime)(
+                self.year(),
+                self.month() as i32,
+                self.day() as i32,
+                self.hour() as i32,
+                self.minute() as i32,
+                self.second() as i32,
+                self.microsecond() as i32,
+                NONE,
+                (*pyo3_ffi::PyDateTimeAPI()).DateTimeType,
+            )
+        }
+    }
+
+    /// The IANA key, e.g., "Europe/Paris", if `tzinfo` is a
+    /// `zoneinfo.ZoneInfo` constructed with one.
+    #[cfg(Py_3_9)]
+    fn zoneinfo_key(&self) -> Option<&'static str> {
+        if !self.has_tz() {
+            return None;
+        }
+        let tzinfo = ffi!(PyDateTime_DATE_GET_TZINFO(self.ptr));
+        if unsafe { ob_type!(tzinfo) != ZONEINFO_TYPE } {
+            return None;
+        }
+        let key = ffi!(PyObject_GetAttr(tzinfo, KEY_STR));
+        if unlikely!(key.is_null()) {
+            ffi!(PyErr_Clear());
+            return None;
+        }
+        // the str is kept alive by tzinfo
+        ffi!(Py_DECREF(key));
+        if unsafe { ob_type!(key) != STR_TYPE } {
+            return None;
+        }
+        unicode_to_str(key)
+    }
+
+    #[cfg(not(Py_3_9))]
+    fn zoneinfo_key(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 macro_rules! pydatetime_get {
@@ -159,8 +261,19 @@ impl DateTimeLike for DateTime {
     pydatetime_get!(second, PyDateTime_DATE_GET_SECOND, u8);
     pydatetime_get!(microsecond, PyDateTime_DATE_GET_MICROSECOND, u32);
 
+    /// The microseconds of any `datetime.datetime`, and with `OPT_NANOSECOND`
+    /// the nanoseconds of a `pandas.Timestamp`.
     fn nanosecond(&self) -> u32 {
-        self.microsecond() * 1_000
+        let nanosecond = self.microsecond() * 1_000;
+        let ob_type = ob_type!(self.ptr);
+        if opt_enabled!(self.opts, NANOSECOND)
+            && unsafe { ob_type != DATETIME_TYPE }
+            && is_pandas_timestamp(ob_type)
+        {
+            nanosecond + pandas_timestamp_nanosecond(self.ptr)
+        } else {
+            nanosecond
+        }
     }
 
     fn has_tz(&self) -> bool {
@@ -210,7 +323,8 @@ impl DateTimeLike for DateTime {
         } else {
             let tzinfo = ffi!(PyDateTime_DATE_GET_TZINFO(self.ptr));
             if unsafe { ob_type!(tzinfo) == ZONEINFO_TYPE } {
-                // zoneinfo
+                // zoneinfo, whose offset depends on the datetime, including
+                // its fold, for DST
                 let py_offset = call_method!(tzinfo, UTCOFFSET_METHOD_STR, self.ptr);
                 let offset = Offset {
                     second: ffi!(PyDateTime_DELTA_GET_SECONDS(py_offset)),
@@ -239,10 +353,154 @@ impl Serialize for DateTime {
     where
         S: Serializer,
     {
+        if unlikely!(opt_enabled!(self.opts, REQUIRE_AWARE_DATETIME))
+            && self.has_tz_without_offset()
+        {
+            let naive = self.without_tz();
+            let result = DateTime::new(naive, self.opts).serialize(serializer);
+            ffi!(Py_DECREF(naive));
+            return result;
+        }
         let mut buf = DateTimeBuffer::new();
-        if self.write_buf(&mut buf, self.opts).is_err() {
-            err!(SerializeError::DatetimeLibraryUnsupported)
+        if let Err(err) = self.write_buf(&mut buf, self.opts) {
+            if !matches!(err, DateTimeError::LibraryUnsupported) {
+                start_error_path();
+            }
+            err!(SerializeError::from(err))
+        }
+        if unlikely!(opt_enabled!(self.opts, REQUIRE_AWARE_DATETIME)) && !self.has_tz() {
+            start_error_path();
+            err!(SerializeError::DatetimeNaive(String::from(
+                str_from_slice!(buf.as_ptr(), buf.len())
+            )))
+        }
+        if unlikely!(opt_enabled!(formats(), DATETIME_TZ_NAME)) {
+            if let Some(key) = self.zoneinfo_key() {
+                let mut map = serializer.serialize_map(None).unwrap();
+                map.serialize_key("dt").unwrap();
+                map.serialize_value(str_from_slice!(buf.as_ptr(), buf.len()))?;
+                map.serialize_key("tz").unwrap();
+                map.serialize_value(key)?;
+                return map.end();
+            }
         }
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
     }
 }
+
+pub struct Timedelta {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl Timedelta {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        Timedelta {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+
+    /// Only `days` may be negative; `seconds` and `microseconds` are
+    /// normalized to be positive, e.g., `-1us` is `-1d, 86399s, 999999us`.
+    fn total_microseconds(&self) -> i128 {
+        let days = ffi!(PyDateTime_DELTA_GET_DAYS(self.ptr)) as i128;
+        let seconds = ffi!(PyDateTime_DELTA_GET_SECONDS(self.ptr)) as i128;
+        let microseconds = ffi!(PyDateTime_DELTA_GET_MICROSECONDS(self.ptr)) as i128;
+        (days * 86400 + seconds) * 1_000_000 + microseconds
+    }
+
+    /// The same as `timedelta.total_seconds()`.
+    pub fn total_seconds(&self) -> f64 {
+        duration_seconds(self.total_microseconds(), 6)
+    }
+
+    /// ISO 8601 duration using days, hours, minutes, and seconds, e.g.,
+    /// `P1DT1H30M`, `PT0.5S`, `-PT1S`.
+    pub fn write_buf(&self, buf: &mut DateTimeBuffer) {
+        write_duration(buf, self.total_microseconds(), 6);
+    }
+}
+
+/// Seconds in a duration of `total` units of `10^-digits` seconds.
+pub fn duration_seconds(total: i128, digits: u32) -> f64 {
+    let per_second = 10_i128.pow(digits);
+    if likely!(total.unsigned_abs() < 1 << 53) {
+        total as f64 / per_second as f64
+    } else {
+        // the conversion to f64 would otherwise round whole seconds
+        (total / per_second) as f64 + (total % per_second) as f64 / per_second as f64
+    }
+}
+
+/// Write a duration of `total` units of `10^-digits` seconds, for `digits` of
+/// at most 9, as in `Timedelta::write_buf()`.
+#[inline(never)]
+pub fn write_duration(buf: &mut DateTimeBuffer, total: i128, digits: u32) {
+    debug_assert!(digits <= 9);
+    let per_second = 10_u128.pow(digits);
+    if total < 0 {
+        buf.push(b'-');
+    }
+    let total = total.unsigned_abs();
+    let subsecond = (total % per_second) as u32;
+    let days = total / per_second / 86400;
+    let seconds = (total / per_second % 86400) as u32;
+    buf.push(b'P');
+    if days != 0 {
+        buf.extend_from_slice(itoa::Buffer::new().format(days).as_bytes());
+        buf.push(b'D');
+        if seconds == 0 && subsecond == 0 {
+            return;
+        }
+    }
+    buf.push(b'T');
+    let hour = seconds / 3600;
+    let minute = seconds % 3600 / 60;
+    let second = seconds % 60;
+    if hour != 0 {
+        buf.extend_from_slice(itoa::Buffer::new().format(hour).as_bytes());
+        buf.push(b'H');
+    }
+    if minute != 0 {
+        buf.extend_from_slice(itoa::Buffer::new().format(minute).as_bytes());
+        buf.push(b'M');
+    }
+    if second != 0 || subsecond != 0 || (hour == 0 && minute == 0) {
+        buf.extend_from_slice(itoa::Buffer::new().format(second).as_bytes());
+        if subsecond != 0 {
+            let mut fraction = [b'0'; 10];
+            fraction[0] = b'.';
+            let fraction = &mut fraction[..digits as usize + 1];
+            let mut val = subsecond;
+            for digit in fraction[1..].iter_mut().rev() {
+                *digit = b'0' + (val % 10) as u8;
+                val /= 10;
+            }
+            let mut len = fraction.len();
+            while fraction[len - 1] == b'0' {
+                len -= 1;
+            }
+            buf.extend_from_slice(&fraction[..len]);
+        }
+        buf.push(b'S');
+    }
+}
+
+impl Serialize for Timedelta {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(self.opts, TIMEDELTA_ISO8601) {
+            let mut buf = DateTimeBuffer::new();
+            self.write_buf(&mut buf);
+            serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
+        } else {
+            serializer.serialize_f64(self.total_seconds())
+        }
+    }
+}
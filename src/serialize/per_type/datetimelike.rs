@@ -1,20 +1,41 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
 use crate::opt::*;
+use crate::serialize::error::SerializeError;
+use crate::serialize::state::formats;
+use chrono::{Datelike, NaiveDate, Timelike};
 
 pub enum DateTimeError {
     LibraryUnsupported,
+    /// Naive with `datetime_format="rfc2822"` but not `OPT_NAIVE_UTC`.
+    Rfc2822Naive,
+    /// Outside of years 1 to 9999 in UTC with `datetime_format="rfc2822"`.
+    Rfc2822Year,
 }
 
+impl From<DateTimeError> for SerializeError {
+    fn from(err: DateTimeError) -> Self {
+        match err {
+            DateTimeError::LibraryUnsupported => SerializeError::DatetimeLibraryUnsupported,
+            DateTimeError::Rfc2822Naive => SerializeError::DatetimeRfc2822Naive,
+            DateTimeError::Rfc2822Year => SerializeError::DatetimeRfc2822Year,
+        }
+    }
+}
+
+/// The longest output, e.g., `-262143-01-01T00:00:00.000000001+00:00` for a
+/// `numpy.datetime64` with `OPT_NANOSECOND` and `OPT_NAIVE_UTC`, is 38 bytes.
+const DATETIME_BUFFER_LEN: usize = 40;
+
 #[repr(transparent)]
 pub struct DateTimeBuffer {
-    buf: arrayvec::ArrayVec<u8, 32>,
+    buf: arrayvec::ArrayVec<u8, DATETIME_BUFFER_LEN>,
 }
 
 impl DateTimeBuffer {
     pub fn new() -> DateTimeBuffer {
         DateTimeBuffer {
-            buf: arrayvec::ArrayVec::<u8, 32>::new(),
+            buf: arrayvec::ArrayVec::<u8, DATETIME_BUFFER_LEN>::new(),
         }
     }
     pub fn push(&mut self, value: u8) {
@@ -96,6 +117,9 @@ pub trait DateTimeLike {
     /// customise if desired.
     #[inline(never)]
     fn write_buf(&self, buf: &mut DateTimeBuffer, opts: Opt) -> Result<(), DateTimeError> {
+        if unlikely!(opt_enabled!(formats(), RFC2822_DATETIME)) {
+            return self.write_rfc2822(buf, opts);
+        }
         {
             let year = self.year();
             let mut yearbuf = itoa::Buffer::new();
@@ -116,20 +140,20 @@ pub trait DateTimeLike {
         write_double_digit!(buf, self.minute());
         buf.push(b':');
         write_double_digit!(buf, self.second());
-        if opt_disabled!(opts, OMIT_MICROSECONDS) {
+        if unlikely!(opt_enabled!(opts, NANOSECOND)) && opt_disabled!(opts, OMIT_MICROSECONDS) {
+            let nanosecond = self.nanosecond();
+            if nanosecond != 0 {
+                buf.push(b'.');
+                write_triple_digit!(buf, nanosecond / 1_000_000);
+                write_triple_digit!(buf, nanosecond / 1_000 % 1_000);
+                write_triple_digit!(buf, nanosecond % 1_000);
+            }
+        } else if opt_disabled!(opts, OMIT_MICROSECONDS) {
             let microsecond = self.microsecond();
             if microsecond != 0 {
                 buf.push(b'.');
                 write_triple_digit!(buf, microsecond / 1_000);
                 write_triple_digit!(buf, microsecond % 1_000);
-                // Don't support writing nanoseconds for now.
-                // If requested, something like the following should work,
-                // and the `DateTimeBuffer` type alias should be changed to
-                // have length 35.
-                // let nanosecond = self.nanosecond();
-                // if nanosecond % 1_000 != 0 {
-                //     write_triple_digit!(buf, nanosecond % 1_000);
-                // }
             }
         }
         if self.has_tz() || opt_enabled!(opts, NAIVE_UTC) {
@@ -171,4 +195,53 @@ pub trait DateTimeLike {
         }
         Ok(())
     }
+
+    /// Write `self` converted to UTC to a buffer in the RFC 2822 format of
+    /// HTTP dates in RFC 7231, e.g., `Sun, 06 Nov 1994 08:49:37 GMT`.
+    #[cold]
+    #[inline(never)]
+    fn write_rfc2822(&self, buf: &mut DateTimeBuffer, opts: Opt) -> Result<(), DateTimeError> {
+        const DAYS: [&[u8; 3]; 7] = [b"Sun", b"Mon", b"Tue", b"Wed", b"Thu", b"Fri", b"Sat"];
+        const MONTHS: [&[u8; 3]; 12] = [
+            b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov",
+            b"Dec",
+        ];
+        if !self.has_tz() && opt_disabled!(opts, NAIVE_UTC) {
+            return Err(DateTimeError::Rfc2822Naive);
+        }
+        let offset = self.offset()?;
+        let offset_second = i64::from(offset.day) * 86400 + i64::from(offset.second);
+        let utc = NaiveDate::from_ymd_opt(self.year(), self.month() as u32, self.day() as u32)
+            .and_then(|date| {
+                date.and_hms_opt(
+                    self.hour() as u32,
+                    self.minute() as u32,
+                    self.second() as u32,
+                )
+            })
+            .and_then(|dt| dt.checked_sub_signed(chrono::Duration::seconds(offset_second)))
+            .filter(|dt| (1..=9999).contains(&dt.year()))
+            .ok_or(DateTimeError::Rfc2822Year)?;
+        buf.extend_from_slice(DAYS[utc.weekday().num_days_from_sunday() as usize]);
+        buf.extend_from_slice(b", ");
+        write_double_digit!(buf, utc.day());
+        buf.push(b' ');
+        buf.extend_from_slice(MONTHS[utc.month0() as usize]);
+        buf.push(b' ');
+        {
+            let year = utc.year();
+            let mut yearbuf = itoa::Buffer::new();
+            let formatted = yearbuf.format(year);
+            buf.extend_from_slice(&[b'0', b'0', b'0', b'0'][..(4 - formatted.len())]);
+            buf.extend_from_slice(formatted.as_bytes());
+        }
+        buf.push(b' ');
+        write_double_digit!(buf, utc.hour());
+        buf.push(b':');
+        write_double_digit!(buf, utc.minute());
+        buf.push(b':');
+        write_double_digit!(buf, utc.second());
+        buf.extend_from_slice(b" GMT");
+        Ok(())
+    }
 }
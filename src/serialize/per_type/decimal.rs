@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{Opt, ALLOW_NONFINITE, DECIMAL_AS_STRING};
+use crate::serialize::error::SerializeError;
+use crate::serialize::writer::nonfinite_literal;
+use crate::str::unicode_to_str;
+
+use serde::ser::{Serialize, Serializer};
+
+pub struct DecimalSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl DecimalSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        DecimalSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for DecimalSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // str() of a finite Decimal is always a valid JSON number, e.g.,
+        // "1.5", "-0", "1E+2"; "NaN", "sNaN", and "Infinity" are not
+        let pystr = ffi!(PyObject_Str(self.ptr));
+        if unlikely!(pystr.is_null()) {
+            ffi!(PyErr_Clear());
+            err!(SerializeError::InvalidStr)
+        }
+        let uni = unicode_to_str(pystr);
+        if unlikely!(uni.is_none()) {
+            ffi!(Py_DECREF(pystr));
+            err!(SerializeError::InvalidStr)
+        }
+        if opt_enabled!(self.opts, DECIMAL_AS_STRING) {
+            // "NaN", "sNaN", "Infinity", and "-Infinity" are valid as str
+            let ret = serializer.serialize_str(uni.unwrap());
+            ffi!(Py_DECREF(pystr));
+            return ret;
+        }
+        let buffer = uni.unwrap().as_bytes();
+        if unlikely!(buffer.iter().any(|&c| c == b'N' || c == b'I')) {
+            // "-NaN" and "sNaN" are NaN, as for float
+            let value = if !buffer.contains(&b'I') {
+                f64::NAN
+            } else if buffer[0] == b'-' {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            ffi!(Py_DECREF(pystr));
+            if opt_enabled!(self.opts, ALLOW_NONFINITE) {
+                return serializer.serialize_bytes(nonfinite_literal(value).as_bytes());
+            }
+            err!(SerializeError::DecimalNotFinite)
+        }
+        let ret = serializer.serialize_bytes(buffer);
+        ffi!(Py_DECREF(pystr));
+        ret
+    }
+}
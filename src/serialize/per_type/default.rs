@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+use crate::opt::{SERIALIZE_JSON_PROTOCOL, SERIALIZE_PROXY, STATS};
 use crate::serialize::error::SerializeError;
 use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::{count_default_call, set_exception_raised, start_error_path};
+use crate::typeref::{JSON_METHOD_STR, NONE, WRAPPED_STR};
 
 use serde::ser::{Serialize, Serializer};
 
@@ -23,11 +26,29 @@ impl<'a> Serialize for DefaultSerializer<'a> {
     where
         S: Serializer,
     {
+        if opt_enabled!(self.previous.state.opts(), SERIALIZE_JSON_PROTOCOL)
+            && has_json_method(ob_type!(self.previous.ptr))
+        {
+            return self.serialize_json_protocol(serializer);
+        }
+        if opt_enabled!(self.previous.state.opts(), SERIALIZE_PROXY) {
+            match unwrap_proxy(self.previous.ptr) {
+                Some(Ok(wrapped)) => return self.serialize_wrapped(wrapped, serializer),
+                Some(Err(err)) => {
+                    start_error_path();
+                    err!(err)
+                }
+                None => (),
+            }
+        }
         match self.previous.default {
             Some(callable) => {
                 if unlikely!(self.previous.state.default_calls_limit()) {
                     err!(SerializeError::DefaultRecursionLimit)
                 }
+                if opt_enabled!(self.previous.state.opts(), STATS) {
+                    count_default_call();
+                }
                 #[cfg(not(Py_3_10))]
                 let default_obj = ffi!(PyObject_CallFunctionObjArgs(
                     callable.as_ptr(),
@@ -44,6 +65,7 @@ impl<'a> Serialize for DefaultSerializer<'a> {
                     )
                 };
                 if unlikely!(default_obj.is_null()) {
+                    start_error_path();
                     err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
                 } else {
                     let res = PyObjectSerializer::new(
@@ -56,7 +78,108 @@ impl<'a> Serialize for DefaultSerializer<'a> {
                     res
                 }
             }
-            None => err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr))),
+            None => {
+                start_error_path();
+                err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
+            }
+        }
+    }
+}
+
+impl<'a> DefaultSerializer<'a> {
+    /// Serialize the return value of `obj.__json__()` in place of `obj`. An
+    /// exception it raises is propagated as it is.
+    #[cold]
+    #[inline(never)]
+    fn serialize_json_protocol<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.previous.state.default_calls_limit()) {
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let json_obj = call_method!(self.previous.ptr, JSON_METHOD_STR);
+        if unlikely!(json_obj.is_null()) {
+            set_exception_raised();
+            start_error_path();
+            err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
         }
+        let res = PyObjectSerializer::new(
+            json_obj,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(json_obj));
+        res
+    }
+
+    /// Serialize the object that a proxy refers to in place of the proxy,
+    /// releasing `wrapped`.
+    #[cold]
+    #[inline(never)]
+    fn serialize_wrapped<S>(
+        &self,
+        wrapped: *mut pyo3_ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.previous.state.default_calls_limit()) {
+            ffi!(Py_DECREF(wrapped));
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let res = PyObjectSerializer::new(
+            wrapped,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(wrapped));
+        res
+    }
+}
+
+/// Whether `ob_type` defines or inherits a callable `__json__` attribute. One
+/// that is not callable, e.g., `None`, falls through to `default`.
+#[cold]
+#[inline(never)]
+fn has_json_method(ob_type: *mut pyo3_ffi::PyTypeObject) -> bool {
+    let method = ffi!(PyObject_GetAttr(
+        ob_type as *mut pyo3_ffi::PyObject,
+        JSON_METHOD_STR
+    ));
+    if method.is_null() {
+        ffi!(PyErr_Clear());
+        return false;
+    }
+    let callable = ffi!(PyCallable_Check(method)) != 0;
+    ffi!(Py_DECREF(method));
+    callable
+}
+
+/// The object that `ptr` is a transparent proxy for, as a new reference, if
+/// it is a `weakref.proxy` or has a `__wrapped__` attribute.
+#[cold]
+#[inline(never)]
+fn unwrap_proxy(
+    ptr: *mut pyo3_ffi::PyObject,
+) -> Option<Result<*mut pyo3_ffi::PyObject, SerializeError>> {
+    if ffi!(PyWeakref_CheckProxy(ptr)) != 0 {
+        // a dead referent is None, which cannot be weakly referenced
+        let referent = ffi!(PyWeakref_GetObject(ptr));
+        if unlikely!(referent.is_null() || referent == unsafe { NONE }) {
+            ffi!(PyErr_Clear());
+            return Some(Err(SerializeError::WeakrefDead));
+        }
+        ffi!(Py_INCREF(referent));
+        return Some(Ok(referent));
+    }
+    let wrapped = ffi!(PyObject_GetAttr(ptr, WRAPPED_STR));
+    if wrapped.is_null() {
+        ffi!(PyErr_Clear());
+        return None;
     }
+    Some(Ok(wrapped))
 }
@@ -5,19 +5,29 @@ use crate::serialize::error::SerializeError;
 use crate::serialize::obtype::{pyobject_to_obtype, ObType};
 use crate::serialize::per_type::datetimelike::DateTimeLike;
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DateTimeBuffer, DefaultSerializer,
-    EnumSerializer, FloatSerializer, FragmentSerializer, Int53Serializer, IntSerializer,
-    ListTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer, StrSerializer,
-    StrSubclassSerializer, Time, ZeroListSerializer, UUID,
+    enum_name, ArraySerializer, BoolSerializer, BytesSerializer, ComplexSerializer,
+    DataclassGenericSerializer, Date, DateTime, DateTimeBuffer, DecimalSerializer,
+    DefaultSerializer, EnumSerializer, ExceptionSerializer, FloatSerializer, FractionSerializer,
+    FragmentSerializer, Int53Serializer, IntSerializer, IpAddressSerializer, IteratorSerializer,
+    ListTupleSerializer, NamedTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer,
+    PathSerializer, RangeSerializer, SetSerializer, StrSerializer, StrSubclassSerializer, Time,
+    Timedelta, TracebackSerializer, ZeroListSerializer, UUID,
 };
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::serialize::state::SerializerState;
+use crate::serialize::state::{
+    error_at_index, error_at_key, formats, start_error_path, SerializerState, RECURSION_LIMIT,
+};
+use crate::serialize::writer::{camel_case, nonfinite_literal, SORTED_SEQ};
 use crate::str::{unicode_to_str, unicode_to_str_via_ffi};
-use crate::typeref::{STR_TYPE, TRUE, VALUE_STR};
+use crate::typeref::{
+    load_ordered_dict_type, FROZENSET_TYPE, NONE, ORDERED_DICT_TYPE, STR_TYPE, TRUE, VALUE_STR,
+};
 use compact_str::CompactString;
 use core::ptr::NonNull;
-use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 pub struct ZeroDictSerializer;
 
@@ -70,20 +80,39 @@ impl Serialize for DictGenericSerializer {
 
         if unlikely!(ffi!(Py_SIZE(self.ptr)) == 0) {
             ZeroDictSerializer::new().serialize(serializer)
-        } else if likely!(opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS)) {
+        } else if likely!(opt_disabled!(
+            self.state.opts(),
+            SORT_OR_NON_STR_KEYS | PRESERVE_ORDERED_DICT | CAMELCASE | DICT_AS_PAIRS
+        )) {
             unsafe {
                 core::mem::transmute::<&DictGenericSerializer, &Dict>(self).serialize(serializer)
             }
-        } else if opt_enabled!(self.state.opts(), NON_STR_KEYS) {
+        } else if opt_enabled!(self.state.opts(), DICT_AS_PAIRS) && has_non_str_key(self.ptr) {
+            unsafe {
+                core::mem::transmute::<&DictGenericSerializer, &DictPairs>(self)
+                    .serialize(serializer)
+            }
+        } else if opt_enabled!(self.state.opts(), PRESERVE_ORDERED_DICT)
+            && is_ordered_dict(ob_type!(self.ptr))
+        {
+            unsafe {
+                core::mem::transmute::<&DictGenericSerializer, &DictOrdered>(self)
+                    .serialize(serializer)
+            }
+        } else if opt_enabled!(self.state.opts(), NON_STR_KEYS | CAMELCASE) {
             unsafe {
                 core::mem::transmute::<&DictGenericSerializer, &DictNonStrKey>(self)
                     .serialize(serializer)
             }
-        } else {
+        } else if opt_enabled!(self.state.opts(), SORT_KEYS) {
             unsafe {
                 core::mem::transmute::<&DictGenericSerializer, &DictSortedKey>(self)
                     .serialize(serializer)
             }
+        } else {
+            unsafe {
+                core::mem::transmute::<&DictGenericSerializer, &Dict>(self).serialize(serializer)
+            }
         }
     }
 }
@@ -109,8 +138,10 @@ macro_rules! impl_serialize_entry {
                 }
             }
             ObType::None => {
-                $map.serialize_key($key).unwrap();
-                $map.serialize_value(&NoneSerializer::new()).unwrap();
+                if opt_disabled!($self.state.opts(), SKIP_NONE_VALUES) {
+                    $map.serialize_key($key).unwrap();
+                    $map.serialize_value(&NoneSerializer::new()).unwrap();
+                }
             }
             ObType::Float => {
                 $map.serialize_key($key).unwrap();
@@ -122,7 +153,8 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Datetime => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&DateTime::new($value, $self.state.opts()))?;
+                $map.serialize_value(&DateTime::new($value, $self.state.opts()))
+                    .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::Date => {
                 $map.serialize_key($key).unwrap();
@@ -134,12 +166,13 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Uuid => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&UUID::new($value)).unwrap();
+                $map.serialize_value(&UUID::new($value, $self.state.opts()))?;
             }
             ObType::Dict => {
                 let pyvalue = DictGenericSerializer::new($value, $self.state, $self.default);
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&pyvalue)?;
+                $map.serialize_value(&pyvalue)
+                    .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::List => {
                 if ffi!(Py_SIZE($value)) == 0 {
@@ -149,7 +182,8 @@ macro_rules! impl_serialize_entry {
                     let pyvalue =
                         ListTupleSerializer::from_list($value, $self.state, $self.default);
                     $map.serialize_key($key).unwrap();
-                    $map.serialize_value(&pyvalue)?;
+                    $map.serialize_value(&pyvalue)
+                        .map_err(|err| error_at_key(err, $key))?;
                 }
             }
             ObType::Tuple => {
@@ -160,7 +194,8 @@ macro_rules! impl_serialize_entry {
                     let pyvalue =
                         ListTupleSerializer::from_tuple($value, $self.state, $self.default);
                     $map.serialize_key($key).unwrap();
-                    $map.serialize_value(&pyvalue)?;
+                    $map.serialize_value(&pyvalue)
+                        .map_err(|err| error_at_key(err, $key))?;
                 }
             }
             ObType::Dataclass => {
@@ -169,7 +204,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::Enum => {
                 $map.serialize_key($key).unwrap();
@@ -177,7 +213,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::NumpyArray => {
                 $map.serialize_key($key).unwrap();
@@ -185,7 +222,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::NumpyScalar => {
                 $map.serialize_key($key).unwrap();
@@ -193,7 +231,77 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Fragment => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&FragmentSerializer::new($value))?;
+                $map.serialize_value(&FragmentSerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Decimal => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&DecimalSerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Set => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&SetSerializer::new($value, $self.state, $self.default))
+                    .map_err(|err| error_at_key(err, $key))?;
+            }
+            ObType::Path => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&PathSerializer::new($value))?;
+            }
+            ObType::IpAddress => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&IpAddressSerializer::new($value))?;
+            }
+            ObType::Timedelta => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&Timedelta::new($value, $self.state.opts()))?;
+            }
+            ObType::Array => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&ArraySerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Bytes => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&BytesSerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Complex => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&ComplexSerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Fraction => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&FractionSerializer::new($value, $self.state.opts()))?;
+            }
+            ObType::Iterator => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&IteratorSerializer::new($value, $self.state, $self.default))
+                    .map_err(|err| error_at_key(err, $key))?;
+            }
+            ObType::NamedTuple => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&NamedTupleSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                ))
+                .map_err(|err| error_at_key(err, $key))?;
+            }
+            ObType::Range => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&RangeSerializer::new($value, $self.state))
+                    .map_err(|err| error_at_key(err, $key))?;
+            }
+            ObType::Exception => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&ExceptionSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                ))
+                .map_err(|err| error_at_key(err, $key))?;
+            }
+            ObType::Traceback => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&TracebackSerializer::new($value, $self.state))
+                    .map_err(|err| error_at_key(err, $key))?;
             }
             ObType::Unknown => {
                 $map.serialize_key($key).unwrap();
@@ -201,7 +309,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .map_err(|err| error_at_key(err, $key))?;
             }
         }
     };
@@ -240,7 +349,8 @@ impl Serialize for Dict {
             let key_as_str = {
                 let key_ob_type = ob_type!(key);
                 if unlikely!(!is_class_by_type!(key_ob_type, STR_TYPE)) {
-                    err!(SerializeError::KeyMustBeStr)
+                    start_error_path();
+                    err!(SerializeError::KeyMustBeStr(nonnull!(key)))
                 }
                 let tmp = unicode_to_str(key);
                 if unlikely!(tmp.is_none()) {
@@ -288,22 +398,29 @@ impl Serialize for DictSortedKey {
             pydict_next!(self.ptr, &mut pos, &mut next_key, &mut next_value);
 
             if unlikely!(unsafe { ob_type!(key) != STR_TYPE }) {
-                err!(SerializeError::KeyMustBeStr)
+                start_error_path();
+                err!(SerializeError::KeyMustBeStr(nonnull!(key)))
             }
             let data = unicode_to_str(key);
             if unlikely!(data.is_none()) {
                 err!(SerializeError::InvalidStr)
             }
+            if unlikely!(value == unsafe { NONE })
+                && opt_enabled!(self.state.opts(), SKIP_NONE_VALUES)
+            {
+                continue;
+            }
             items.push((data.unwrap(), value));
         }
 
-        items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        sort_str_items(&mut items, self.state.opts());
 
         let mut map = serializer.serialize_map(None).unwrap();
         for (key, val) in items.iter() {
             let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
             map.serialize_key(key).unwrap();
-            map.serialize_value(&pyvalue)?;
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_key(err, key))?;
         }
         map.end()
     }
@@ -331,10 +448,29 @@ fn non_str_str_subclass(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, S
     }
 }
 
+#[cold]
+#[inline(never)]
+fn non_str_object_str(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, SerializeError> {
+    let pystr = ffi!(PyObject_Str(key));
+    if unlikely!(pystr.is_null()) {
+        ffi!(PyErr_Clear());
+        return Err(SerializeError::InvalidStr);
+    }
+    let ret = non_str_str(pystr);
+    ffi!(Py_DECREF(pystr));
+    ret
+}
+
 #[inline(never)]
 fn non_str_date(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, SerializeError> {
+    let date = Date::new(key);
+    if opt_enabled!(formats(), DATE_AS_EPOCH_DAY) {
+        return Ok(CompactString::from(
+            itoa::Buffer::new().format(date.epoch_day()),
+        ));
+    }
     let mut buf = DateTimeBuffer::new();
-    Date::new(key).write_buf(&mut buf);
+    date.write_buf(&mut buf);
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
     Ok(CompactString::from(key_as_str))
 }
@@ -344,12 +480,21 @@ fn non_str_datetime(
     key: *mut pyo3_ffi::PyObject,
     opts: crate::opt::Opt,
 ) -> Result<CompactString, SerializeError> {
-    let mut buf = DateTimeBuffer::new();
     let dt = DateTime::new(key, opts);
-    if dt.write_buf(&mut buf, opts).is_err() {
-        return Err(SerializeError::DatetimeLibraryUnsupported);
+    if opt_enabled!(opts, REQUIRE_AWARE_DATETIME) && dt.has_tz_without_offset() {
+        let naive = dt.without_tz();
+        let result = non_str_datetime(naive, opts);
+        ffi!(Py_DECREF(naive));
+        return result;
+    }
+    let mut buf = DateTimeBuffer::new();
+    if let Err(err) = dt.write_buf(&mut buf, opts) {
+        return Err(SerializeError::from(err));
     }
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
+    if opt_enabled!(opts, REQUIRE_AWARE_DATETIME) && !dt.has_tz() {
+        return Err(SerializeError::DatetimeNaive(String::from(key_as_str)));
+    }
     Ok(CompactString::from(key_as_str))
 }
 
@@ -368,20 +513,48 @@ fn non_str_time(
     Ok(CompactString::from(key_as_str))
 }
 
+#[cold]
 #[inline(never)]
-fn non_str_uuid(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, SerializeError> {
+fn non_str_timedelta(
+    key: *mut pyo3_ffi::PyObject,
+    opts: crate::opt::Opt,
+) -> Result<CompactString, SerializeError> {
+    let delta = Timedelta::new(key, opts);
+    if opt_enabled!(opts, TIMEDELTA_ISO8601) {
+        let mut buf = DateTimeBuffer::new();
+        delta.write_buf(&mut buf);
+        Ok(CompactString::from(str_from_slice!(
+            buf.as_ptr(),
+            buf.len()
+        )))
+    } else {
+        Ok(CompactString::from(
+            ryu::Buffer::new().format_finite(delta.total_seconds()),
+        ))
+    }
+}
+
+#[inline(never)]
+fn non_str_uuid(key: *mut pyo3_ffi::PyObject, opts: Opt) -> Result<CompactString, SerializeError> {
     let mut buf = arrayvec::ArrayVec::<u8, 36>::new();
-    UUID::new(key).write_buf(&mut buf);
+    UUID::new(key, opts).write_buf(&mut buf);
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
     Ok(CompactString::from(key_as_str))
 }
 
 #[cold]
 #[inline(never)]
-fn non_str_float(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, SerializeError> {
+fn non_str_float(
+    key: *mut pyo3_ffi::PyObject,
+    opts: crate::opt::Opt,
+) -> Result<CompactString, SerializeError> {
     let val = ffi!(PyFloat_AS_DOUBLE(key));
     if !val.is_finite() {
-        Ok(CompactString::new_inline("null"))
+        if opt_enabled!(opts, ALLOW_NONFINITE) {
+            Ok(CompactString::new_inline(nonfinite_literal(val)))
+        } else {
+            Ok(CompactString::new_inline("null"))
+        }
     } else {
         Ok(CompactString::from(ryu::Buffer::new().format_finite(val)))
     }
@@ -402,11 +575,242 @@ fn non_str_int(key: *mut pyo3_ffi::PyObject) -> Result<CompactString, SerializeE
     }
 }
 
+/// Compare with ASCII letters lowercased, and then by bytes, so that keys
+/// differing only in case have a stable order. Other characters, including
+/// non-ASCII, compare by bytes.
+fn cmp_case_insensitive(a: &str, b: &str) -> core::cmp::Ordering {
+    a.bytes()
+        .map(|ch| ch.to_ascii_lowercase())
+        .cmp(b.bytes().map(|ch| ch.to_ascii_lowercase()))
+        .then_with(|| a.cmp(b))
+}
+
+/// Compare by UTF-16 code units, as RFC 8785 requires. This differs from
+/// comparing bytes only for characters above U+FFFF, which sort before
+/// U+E000 through U+FFFF.
+fn cmp_utf16(a: &str, b: &str) -> core::cmp::Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+/// Compare keys as written in the order of `OPT_SORT_KEYS`.
+fn cmp_written_keys(a: &str, b: &str, opts: Opt) -> core::cmp::Ordering {
+    if opt_enabled!(opts, SORT_KEYS_CASE_INSENSITIVE) {
+        cmp_case_insensitive(a, b)
+    } else if opt_enabled!(opts, CANONICAL) {
+        cmp_utf16(a, b)
+    } else {
+        a.cmp(b)
+    }
+}
+
+/// Compare keys in the order of `OPT_SORT_KEYS`, as they are written, so
+/// after `OPT_CAMELCASE`.
+pub fn cmp_keys(a: &str, b: &str, opts: Opt) -> core::cmp::Ordering {
+    if unlikely!(opt_enabled!(opts, CAMELCASE)) {
+        cmp_written_keys(&camel_case(a), &camel_case(b), opts)
+    } else {
+        cmp_written_keys(a, b, opts)
+    }
+}
+
+/// Sort the items of a `dict` or dataclass by key for `OPT_SORT_KEYS`.
+#[inline(never)]
+pub fn sort_str_items(items: &mut [(&str, *mut pyo3_ffi::PyObject)], opts: Opt) {
+    if unlikely!(opt_enabled!(opts, CAMELCASE)) {
+        items.sort_by(|a, b| cmp_keys(a.0, b.0, opts));
+    } else if opt_enabled!(opts, SORT_KEYS_CASE_INSENSITIVE) {
+        items.sort_unstable_by(|a, b| cmp_case_insensitive(a.0, b.0));
+    } else if opt_enabled!(opts, CANONICAL) {
+        items.sort_unstable_by(|a, b| cmp_utf16(a.0, b.0));
+    } else {
+        items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    }
+}
+
+/// The first key that is the same as an earlier one once serialized, e.g.,
+/// `1` and `"1"`, or `user_id` and `userId` with `OPT_CAMELCASE`, for
+/// `OPT_REJECT_DUPLICATE_KEYS`, with the indices in `items` of both.
+#[cold]
+#[inline(never)]
+fn duplicate_non_str_key(
+    items: &[(CompactString, *mut pyo3_ffi::PyObject)],
+    opts: Opt,
+) -> Option<(String, usize, usize)> {
+    let mut seen: HashMap<Cow<str>, usize, ahash::RandomState> =
+        HashMap::with_capacity_and_hasher(items.len(), ahash::RandomState::new());
+    for (idx, (key, _)) in items.iter().enumerate() {
+        let key = if opt_enabled!(opts, CAMELCASE) {
+            camel_case(key.as_str())
+        } else {
+            Cow::Borrowed(key.as_str())
+        };
+        if let Some(&first) = seen.get(&key) {
+            return Some((key.into_owned(), first, idx));
+        }
+        seen.insert(key, idx);
+    }
+    None
+}
+
+/// The first two of `keys` that are the same once converted by
+/// `OPT_CAMELCASE` but not before, e.g., `user_id` and `userId`, with their
+/// indices. These are rejected without `OPT_REJECT_DUPLICATE_KEYS`, as which
+/// key a value was for is not recoverable from the output.
+#[cold]
+#[inline(never)]
+pub fn duplicate_camel_case_key<'a, I>(keys: I) -> Option<(String, usize, usize)>
+where
+    I: Iterator<Item = &'a str> + Clone,
+{
+    // only a key with an underscore is converted
+    if !keys.clone().any(|key| key.contains('_')) {
+        return None;
+    }
+    let mut seen: HashMap<Cow<str>, (usize, &str), ahash::RandomState> =
+        HashMap::with_hasher(ahash::RandomState::new());
+    for (idx, key) in keys.enumerate() {
+        let converted = camel_case(key);
+        match seen.get(&converted) {
+            // e.g., `1` and `"1"` with `OPT_NON_STR_KEYS`
+            Some(&(_, first_key)) if first_key == key => (),
+            Some(&(first, _)) => return Some((converted.into_owned(), first, idx)),
+            None => {
+                seen.insert(converted, (idx, key));
+            }
+        }
+    }
+    None
+}
+
+/// Raise if any of the keys of `items` are the same once converted by
+/// `OPT_CAMELCASE`, e.g., `user_id` and `userId`.
+pub fn check_camel_case_keys<E>(items: &[(&str, *mut pyo3_ffi::PyObject)]) -> Result<(), E>
+where
+    E: serde::ser::Error,
+{
+    let keys = items.iter().map(|(key, _)| *key);
+    if let Some((key, first, second)) = duplicate_camel_case_key(keys) {
+        start_error_path();
+        let err = SerializeError::FieldDuplicate(
+            key,
+            String::from(items[first].0),
+            String::from(items[second].0),
+        );
+        return Err(E::custom(err));
+    }
+    Ok(())
+}
+
+/// The `repr()` of the two keys of `dict` that are the same once serialized,
+/// e.g., `None and 'null'`, where `first` and `second` index the items of
+/// `dict` not skipped by `OPT_SKIP_NONE_VALUES`.
+#[cold]
+#[inline(never)]
+fn duplicate_keys_repr(
+    dict: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+    first: usize,
+    second: usize,
+) -> String {
+    let mut pos = 0;
+    let mut key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+    let mut value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+    let mut idx = 0;
+    let mut reprs: SmallVec<[String; 2]> = SmallVec::new();
+    while pydict_next!(dict, &mut pos, &mut key, &mut value) != 0 {
+        if value == unsafe { NONE } && opt_enabled!(opts, SKIP_NONE_VALUES) {
+            continue;
+        }
+        if idx == first || idx == second {
+            reprs.push(key_repr(key));
+            if idx == second {
+                break;
+            }
+        }
+        idx += 1;
+    }
+    reprs.join(" and ")
+}
+
+/// The `repr()` of a key, or the name of its type if that raises.
+fn key_repr(key: *mut pyo3_ffi::PyObject) -> String {
+    let pyrepr = ffi!(PyObject_Repr(key));
+    if unlikely!(pyrepr.is_null()) {
+        ffi!(PyErr_Clear());
+        return unsafe { core::ffi::CStr::from_ptr((*ob_type!(key)).tp_name) }
+            .to_string_lossy()
+            .into_owned();
+    }
+    let repr = unicode_to_str(pyrepr).map_or_else(String::new, str::to_string);
+    ffi!(Py_DECREF(pyrepr));
+    repr
+}
+
+/// Sort the items of a `dict` with `OPT_NON_STR_KEYS` by their keys as
+/// serialized, not as Python objects, so that keys of different types compare.
+/// The sort is stable so that keys that are the same once serialized, e.g.,
+/// `1` and `"1"`, are in the order of the `dict`.
 #[inline(never)]
-fn sort_non_str_dict_items(items: &mut SmallVec<[(CompactString, *mut pyo3_ffi::PyObject); 8]>) {
-    items.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+fn sort_non_str_dict_items(
+    items: &mut SmallVec<[(CompactString, *mut pyo3_ffi::PyObject); 8]>,
+    opts: Opt,
+) {
+    if unlikely!(opt_enabled!(opts, CAMELCASE)) {
+        items.sort_by(|a, b| cmp_keys(&a.0, &b.0, opts));
+    } else if opt_enabled!(opts, SORT_KEYS_CASE_INSENSITIVE) {
+        items.sort_by(|a, b| cmp_case_insensitive(&a.0, &b.0));
+    } else if opt_enabled!(opts, CANONICAL) {
+        items.sort_by(|a, b| cmp_utf16(&a.0, &b.0));
+    } else {
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+}
+
+/// A `frozenset` key is a JSON array of its elements, each serialized as a
+/// key, sorted so that it does not depend on the order of iteration, e.g.,
+/// `frozenset({2, "a"})` is `["2","a"]`.
+#[cold]
+#[inline(never)]
+fn non_str_frozenset(
+    key: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+    depth: u16,
+) -> Result<CompactString, SerializeError> {
+    if unlikely!(depth >= RECURSION_LIMIT) {
+        return Err(SerializeError::RecursionLimit);
+    }
+    let mut elements: SmallVec<[CompactString; 8]> = SmallVec::new();
+    let iter = ffi!(PyObject_GetIter(key));
+    loop {
+        let item = ffi!(PyIter_Next(iter));
+        if item.is_null() {
+            break;
+        }
+        let element = if is_type!(ob_type!(item), FROZENSET_TYPE) {
+            non_str_frozenset(item, opts, depth + 1)
+        } else {
+            DictNonStrKey::pyobject_to_string(item, opts)
+        };
+        ffi!(Py_DECREF(item));
+        match element {
+            Ok(element) => elements.push(element),
+            Err(err) => {
+                ffi!(Py_DECREF(iter));
+                return Err(err);
+            }
+        }
+    }
+    ffi!(Py_DECREF(iter));
+    elements.sort_unstable();
+    let elements: Vec<&str> = elements.iter().map(|element| element.as_str()).collect();
+    Ok(CompactString::from(
+        serde_json::to_string(&elements).unwrap(),
+    ))
 }
 
+/// A `dict` with `OPT_NON_STR_KEYS` or `OPT_CAMELCASE`, whose keys are
+/// converted to `str` before any are serialized so that they can be compared
+/// as written.
 pub struct DictNonStrKey {
     ptr: *mut pyo3_ffi::PyObject,
     state: SerializerState,
@@ -428,11 +832,18 @@ impl DictNonStrKey {
                 }
             }
             ObType::Int => non_str_int(key),
-            ObType::Float => non_str_float(key),
+            ObType::Float => non_str_float(key, opts),
             ObType::Datetime => non_str_datetime(key, opts),
             ObType::Date => non_str_date(key),
             ObType::Time => non_str_time(key, opts),
-            ObType::Uuid => non_str_uuid(key),
+            ObType::Timedelta => non_str_timedelta(key, opts),
+            ObType::Uuid => non_str_uuid(key, opts),
+            ObType::Enum if opt_enabled!(opts, ENUM_BY_NAME) => {
+                let name = enum_name(key)?;
+                let ret = non_str_str(name);
+                ffi!(Py_DECREF(name));
+                ret
+            }
             ObType::Enum => {
                 let value = ffi!(PyObject_GetAttr(key, VALUE_STR));
                 debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
@@ -442,6 +853,10 @@ impl DictNonStrKey {
             }
             ObType::Str => non_str_str(key),
             ObType::StrSubclass => non_str_str_subclass(key),
+            ObType::Path | ObType::IpAddress => non_str_object_str(key),
+            ObType::Set | ObType::Unknown if is_type!(ob_type!(key), FROZENSET_TYPE) => {
+                non_str_frozenset(key, opts, 0)
+            }
             ObType::Tuple
             | ObType::NumpyScalar
             | ObType::NumpyArray
@@ -449,6 +864,17 @@ impl DictNonStrKey {
             | ObType::List
             | ObType::Dataclass
             | ObType::Fragment
+            | ObType::Decimal
+            | ObType::Set
+            | ObType::Array
+            | ObType::Bytes
+            | ObType::Complex
+            | ObType::Fraction
+            | ObType::Iterator
+            | ObType::NamedTuple
+            | ObType::Range
+            | ObType::Exception
+            | ObType::Traceback
             | ObType::Unknown => Err(SerializeError::DictKeyInvalidType),
         }
     }
@@ -480,29 +906,239 @@ impl Serialize for DictNonStrKey {
 
             pydict_next!(self.ptr, &mut pos, &mut next_key, &mut next_value);
 
+            if unlikely!(value == unsafe { NONE }) && opt_enabled!(opts, SKIP_NONE_VALUES) {
+                continue;
+            }
+
             if is_type!(ob_type!(key), STR_TYPE) {
                 let uni = unicode_to_str(key);
                 if unlikely!(uni.is_none()) {
                     err!(SerializeError::InvalidStr)
                 }
                 items.push((CompactString::from(uni.unwrap()), value));
-            } else {
+            } else if opt_enabled!(opts, NON_STR_KEYS) || is_class_by_type!(ob_type!(key), STR_TYPE)
+            {
                 match Self::pyobject_to_string(key, opts) {
                     Ok(key_as_str) => items.push((key_as_str, value)),
                     Err(err) => err!(err),
                 }
+            } else {
+                start_error_path();
+                err!(SerializeError::KeyMustBeStr(nonnull!(key)))
+            }
+        }
+
+        if unlikely!(opt_enabled!(opts, REJECT_DUPLICATE_KEYS)) {
+            if let Some((key, first, second)) = duplicate_non_str_key(&items, opts) {
+                let keys = duplicate_keys_repr(self.ptr, opts, first, second);
+                start_error_path();
+                err!(SerializeError::DictKeyDuplicate(key, keys))
+            }
+        } else if unlikely!(opt_enabled!(opts, CAMELCASE)) {
+            let keys = items.iter().map(|(key, _)| key.as_str());
+            if let Some((key, first, second)) = duplicate_camel_case_key(keys) {
+                let keys = duplicate_keys_repr(self.ptr, opts, first, second);
+                start_error_path();
+                err!(SerializeError::DictKeyDuplicate(key, keys))
             }
         }
 
         if opt_enabled!(opts, SORT_KEYS) {
-            sort_non_str_dict_items(&mut items);
+            sort_non_str_dict_items(&mut items, opts);
+        }
+
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, val) in items.iter() {
+            let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_key(err, key))?;
+        }
+        map.end()
+    }
+}
+
+/// Whether any key of `dict` is not exactly `str`.
+fn has_non_str_key(dict: *mut pyo3_ffi::PyObject) -> bool {
+    let mut pos = 0;
+    let mut key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+    let mut value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+    while pydict_next!(dict, &mut pos, &mut key, &mut value) == 1 {
+        if !is_type!(ob_type!(key), STR_TYPE) {
+            return true;
+        }
+    }
+    false
+}
+
+/// A `dict` with `OPT_DICT_AS_PAIRS` having a key that is not `str`,
+/// serialized as an array of `[key, value]` arrays in iteration order or,
+/// with `OPT_SORT_KEYS`, ordered by key.
+pub struct DictPairs {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl Serialize for DictPairs {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(opt_enabled!(self.state.opts(), SORT_KEYS)) {
+            // ordered by the serialized form of each pair, i.e., by key
+            return serializer.serialize_newtype_struct(SORTED_SEQ, &DictPairsInOrder(self));
+        }
+        DictPairsInOrder(self).serialize(serializer)
+    }
+}
+
+/// The pairs of `DictPairs` in iteration order.
+struct DictPairsInOrder<'a>(&'a DictPairs);
+
+impl Serialize for DictPairsInOrder<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut pos = 0;
+        let mut key: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        let mut value: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+
+        let skip_none = opt_enabled!(self.0.state.opts(), SKIP_NONE_VALUES);
+
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        let mut idx = 0;
+        while pydict_next!(self.0.ptr, &mut pos, &mut key, &mut value) == 1 {
+            if unlikely!(value == unsafe { NONE }) && skip_none {
+                continue;
+            }
+            seq.serialize_element(&DictPair {
+                key: key,
+                value: value,
+                state: self.0.state,
+                default: self.0.default,
+            })
+            .map_err(|err| error_at_index(err, idx))?;
+            idx += 1;
+        }
+        seq.end()
+    }
+}
+
+struct DictPair {
+    key: *mut pyo3_ffi::PyObject,
+    value: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl Serialize for DictPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        seq.serialize_element(&PyObjectSerializer::new(self.key, self.state, self.default))
+            .map_err(|err| error_at_index(err, 0))?;
+        seq.serialize_element(&PyObjectSerializer::new(
+            self.value,
+            self.state,
+            self.default,
+        ))
+        .map_err(|err| error_at_index(err, 1))?;
+        seq.end()
+    }
+}
+
+/// Whether `ob_type` is `collections.OrderedDict` or a subclass.
+#[cold]
+fn is_ordered_dict(ob_type: *mut pyo3_ffi::PyTypeObject) -> bool {
+    let ordered_dict =
+        unsafe { (*core::ptr::addr_of!(ORDERED_DICT_TYPE)).get_or_init(load_ordered_dict_type) };
+    match ordered_dict {
+        Some(ordered_dict) => ffi!(PyType_IsSubtype(ob_type, ordered_dict.as_ptr())) != 0,
+        None => false,
+    }
+}
+
+/// A `collections.OrderedDict` with `OPT_PRESERVE_ORDERED_DICT`. Its keys are
+/// iterated rather than read from the underlying `dict`, whose order does not
+/// reflect `move_to_end()`, and they are not sorted.
+pub struct DictOrdered {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl Serialize for DictOrdered {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let opts = self.state.opts() & NOT_PASSTHROUGH;
+
+        let len = ffi!(Py_SIZE(self.ptr)) as usize;
+        let mut items: SmallVec<[(CompactString, *mut pyo3_ffi::PyObject); 8]> =
+            SmallVec::with_capacity(len);
+
+        // The Python exception, if any, is chained to the JSONEncodeError.
+        let iter = ffi!(PyObject_GetIter(self.ptr));
+        if unlikely!(iter.is_null()) {
+            err!(SerializeError::OrderedDictIteration)
+        }
+        loop {
+            let key = ffi!(PyIter_Next(iter));
+            if key.is_null() {
+                break;
+            }
+            let value = ffi!(PyDict_GetItem(self.ptr, key));
+            let key_as_str = if unlikely!(value.is_null()) {
+                Err(SerializeError::OrderedDictIteration)
+            } else if is_type!(ob_type!(key), STR_TYPE) {
+                non_str_str(key)
+            } else if opt_enabled!(opts, NON_STR_KEYS) {
+                DictNonStrKey::pyobject_to_string(key, opts)
+            } else {
+                start_error_path();
+                Err(SerializeError::KeyMustBeStr(nonnull!(key)))
+            };
+            ffi!(Py_DECREF(key));
+            match key_as_str {
+                Ok(key_as_str) => {
+                    if unlikely!(value == unsafe { NONE }) && opt_enabled!(opts, SKIP_NONE_VALUES) {
+                        continue;
+                    }
+                    items.push((key_as_str, value));
+                }
+                Err(err) => {
+                    ffi!(Py_DECREF(iter));
+                    err!(err)
+                }
+            }
+        }
+        ffi!(Py_DECREF(iter));
+        if unlikely!(!ffi!(PyErr_Occurred()).is_null()) {
+            err!(SerializeError::OrderedDictIteration)
+        }
+        if unlikely!(opt_enabled!(opts, CAMELCASE)) {
+            let keys = items.iter().map(|(key, _)| key.as_str());
+            if let Some((key, first, second)) = duplicate_camel_case_key(keys) {
+                let keys = format!("'{}' and '{}'", items[first].0, items[second].0);
+                start_error_path();
+                err!(SerializeError::DictKeyDuplicate(key, keys))
+            }
         }
 
         let mut map = serializer.serialize_map(None).unwrap();
         for (key, val) in items.iter() {
             let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
             map.serialize_key(key).unwrap();
-            map.serialize_value(&pyvalue)?;
+            map.serialize_value(&pyvalue)
+                .map_err(|err| error_at_key(err, key))?;
         }
         map.end()
     }
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{EXCEPTION_TRACEBACK, SORT_KEYS};
+use crate::serialize::error::SerializeError;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::{error_at_key, formats, start_error_path, SerializerState};
+use crate::typeref::{
+    load_format_tb, ARGS_STR, EMPTY_UNICODE, FORMAT_TB, NONE, SUPPRESS_CONTEXT_STR, TRUE,
+};
+
+use core::ffi::{c_char, CStr};
+use core::ptr::{null_mut, NonNull};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use smallvec::SmallVec;
+
+/// `type(obj).__name__`, which for a static type is the part of `tp_name`
+/// after the module, e.g., `InvalidOperation` of `decimal.InvalidOperation`.
+fn type_name(ob_type: *mut pyo3_ffi::PyTypeObject) -> *mut pyo3_ffi::PyObject {
+    let name = unsafe { CStr::from_ptr((*ob_type).tp_name) }.to_bytes();
+    let name = match name.iter().rposition(|&ch| ch == b'.') {
+        Some(idx) => &name[idx + 1..],
+        None => name,
+    };
+    ffi!(PyUnicode_FromStringAndSize(
+        name.as_ptr() as *const c_char,
+        name.len() as pyo3_ffi::Py_ssize_t
+    ))
+}
+
+/// `"".join(traceback.format_tb(tb))`, or null if it raised.
+fn format_traceback(tb: *mut pyo3_ffi::PyObject) -> *mut pyo3_ffi::PyObject {
+    let format_tb = match unsafe { (*core::ptr::addr_of!(FORMAT_TB)).get_or_init(load_format_tb) } {
+        Some(format_tb) => format_tb.as_ptr(),
+        None => return null_mut(),
+    };
+    let lines = ffi!(PyObject_CallFunctionObjArgs(
+        format_tb,
+        tb,
+        null_mut::<pyo3_ffi::PyObject>()
+    ));
+    if lines.is_null() {
+        return null_mut();
+    }
+    let formatted = ffi!(PyUnicode_Join(EMPTY_UNICODE, lines));
+    ffi!(Py_DECREF(lines));
+    formatted
+}
+
+/// A new reference to `value` if it is neither null nor `None`.
+fn not_none(value: *mut pyo3_ffi::PyObject) -> Option<*mut pyo3_ffi::PyObject> {
+    if value.is_null() {
+        None
+    } else if unsafe { value == NONE } {
+        ffi!(Py_DECREF(value));
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Serialize a `BaseException` as an object of its `"type"`, `"message"`,
+/// and `"args"`, the exception it was chained from as `"cause"` or
+/// `"context"`, and, with `exception_format="traceback"`, its `"traceback"`.
+pub struct ExceptionSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl ExceptionSerializer {
+    pub fn new(
+        ptr: *mut pyo3_ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<pyo3_ffi::PyObject>>,
+    ) -> Self {
+        ExceptionSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+
+    /// Whether `raise ... from None` suppressed `__context__`.
+    fn suppress_context(&self) -> bool {
+        let value = ffi!(PyObject_GetAttr(self.ptr, SUPPRESS_CONTEXT_STR));
+        if value.is_null() {
+            ffi!(PyErr_Clear());
+            return false;
+        }
+        let ret = unsafe { value == TRUE };
+        ffi!(Py_DECREF(value));
+        ret
+    }
+
+    /// Push new references to the values to serialize to `items`, returning
+    /// false if reading one raised.
+    fn collect(&self, items: &mut SmallVec<[(&'static str, *mut pyo3_ffi::PyObject); 6]>) -> bool {
+        let name = type_name(ob_type!(self.ptr));
+        if name.is_null() {
+            return false;
+        }
+        items.push(("type", name));
+        let message = ffi!(PyObject_Str(self.ptr));
+        if message.is_null() {
+            return false;
+        }
+        items.push(("message", message));
+        let args = ffi!(PyObject_GetAttr(self.ptr, ARGS_STR));
+        if args.is_null() {
+            return false;
+        }
+        items.push(("args", args));
+        if let Some(cause) = not_none(ffi!(PyException_GetCause(self.ptr))) {
+            items.push(("cause", cause));
+        } else if !self.suppress_context() {
+            if let Some(context) = not_none(ffi!(PyException_GetContext(self.ptr))) {
+                items.push(("context", context));
+            }
+        }
+        if opt_enabled!(formats(), EXCEPTION_TRACEBACK) {
+            if let Some(tb) = not_none(ffi!(PyException_GetTraceback(self.ptr))) {
+                let formatted = format_traceback(tb);
+                ffi!(Py_DECREF(tb));
+                if formatted.is_null() {
+                    return false;
+                }
+                items.push(("traceback", formatted));
+            }
+        }
+        true
+    }
+}
+
+impl Serialize for ExceptionSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.state.recursion_limit()) {
+            err!(SerializeError::RecursionLimit)
+        }
+        let mut items: SmallVec<[(&'static str, *mut pyo3_ffi::PyObject); 6]> = SmallVec::new();
+        if unlikely!(!self.collect(&mut items)) {
+            for (_, value) in items {
+                ffi!(Py_DECREF(value));
+            }
+            // the exception is chained as the cause
+            start_error_path();
+            err!(SerializeError::ExceptionNotSerializable)
+        }
+        if opt_enabled!(self.state.opts(), SORT_KEYS) {
+            items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        }
+        let mut map = serializer.serialize_map(None).unwrap();
+        let mut res = Ok(());
+        for &(key, value) in items.iter() {
+            if res.is_ok() {
+                map.serialize_key(key).unwrap();
+                res = map
+                    .serialize_value(&PyObjectSerializer::new(value, self.state, self.default))
+                    .map_err(|err| error_at_key(err, key));
+            }
+            ffi!(Py_DECREF(value));
+        }
+        res?;
+        map.end()
+    }
+}
+
+/// Serialize a traceback as the `str` of `traceback.format_tb()`.
+pub struct TracebackSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+}
+
+impl TracebackSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, state: SerializerState) -> Self {
+        TracebackSerializer {
+            ptr: ptr,
+            state: state,
+        }
+    }
+}
+
+impl Serialize for TracebackSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let formatted = format_traceback(self.ptr);
+        if unlikely!(formatted.is_null()) {
+            // the exception is chained as the cause
+            start_error_path();
+            err!(SerializeError::ExceptionNotSerializable)
+        }
+        let res = PyObjectSerializer::new(formatted, self.state, None).serialize(serializer);
+        ffi!(Py_DECREF(formatted));
+        res
+    }
+}
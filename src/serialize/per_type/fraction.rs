@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{Opt, FRACTION_AS_FLOAT, FRACTION_AS_STRING, STRICT_INTEGER};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::Int53Serializer;
+use crate::serialize::state::formats;
+use crate::str::unicode_to_str;
+use crate::typeref::{load_fraction_type, DENOMINATOR_STR, FRACTION_TYPE, NUMERATOR_STR};
+use pyo3_ffi::PyTypeObject;
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+/// Whether `ob_type` is `fractions.Fraction`. `fractions` is imported on
+/// first use.
+#[cold]
+pub fn is_fraction(ob_type: *mut PyTypeObject) -> bool {
+    let fraction_type =
+        unsafe { (*core::ptr::addr_of!(FRACTION_TYPE)).get_or_init(load_fraction_type) };
+    match fraction_type {
+        Some(fraction_type) => ob_type == fraction_type.as_ptr(),
+        None => false,
+    }
+}
+
+/// Serialize a `fractions.Fraction` as an array of its `numerator` and
+/// `denominator` or, with `fraction_format="float"` or
+/// `fraction_format="string"`, as a float or a string of the form `"n/d"`.
+pub struct FractionSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl FractionSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        FractionSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for FractionSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(formats(), FRACTION_AS_FLOAT) {
+            // float() of a Fraction raises OverflowError rather than being
+            // infinite if it is out of range
+            let pyfloat = ffi!(PyNumber_Float(self.ptr));
+            if unlikely!(pyfloat.is_null()) {
+                ffi!(PyErr_Clear());
+                err!(SerializeError::FractionOverflow)
+            }
+            let val = ffi!(PyFloat_AS_DOUBLE(pyfloat));
+            ffi!(Py_DECREF(pyfloat));
+            return serializer.serialize_f64(val);
+        }
+        // the attributes of Fraction are always int and never fail
+        let numerator = ffi!(PyObject_GetAttr(self.ptr, NUMERATOR_STR));
+        debug_assert!(!numerator.is_null());
+        let denominator = ffi!(PyObject_GetAttr(self.ptr, DENOMINATOR_STR));
+        debug_assert!(!denominator.is_null());
+        let res = self.serialize_parts(numerator, denominator, serializer);
+        ffi!(Py_DECREF(numerator));
+        ffi!(Py_DECREF(denominator));
+        res
+    }
+}
+
+impl FractionSerializer {
+    fn serialize_parts<S>(
+        &self,
+        numerator: *mut pyo3_ffi::PyObject,
+        denominator: *mut pyo3_ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(formats(), FRACTION_AS_STRING) {
+            // always "n/d", unlike str(), which omits a denominator of 1
+            match (int_to_string(numerator), int_to_string(denominator)) {
+                (Some(num), Some(den)) => serializer.serialize_str(&format!("{}/{}", num, den)),
+                _ => err!(SerializeError::FractionIntStr),
+            }
+        } else {
+            let mut seq = serializer.serialize_seq(None).unwrap();
+            seq.serialize_element(&FractionIntSerializer::new(numerator, self.opts))?;
+            seq.serialize_element(&FractionIntSerializer::new(denominator, self.opts))?;
+            seq.end()
+        }
+    }
+}
+
+/// The decimal digits of an `int` of any size, or `None` if it exceeds
+/// `sys.get_int_max_str_digits()`.
+fn int_to_string(ptr: *mut pyo3_ffi::PyObject) -> Option<String> {
+    let pystr = ffi!(PyObject_Str(ptr));
+    if unlikely!(pystr.is_null()) {
+        ffi!(PyErr_Clear());
+        return None;
+    }
+    let val = unicode_to_str(pystr).map(String::from);
+    ffi!(Py_DECREF(pystr));
+    val
+}
+
+/// Serialize the numerator or denominator of a `Fraction`. These are not
+/// limited to 64 bits, so that the array is lossless, unless
+/// `OPT_STRICT_INTEGER` limits them to 53 bits.
+struct FractionIntSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
+}
+
+impl FractionIntSerializer {
+    fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        FractionIntSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for FractionIntSerializer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(self.opts, STRICT_INTEGER) {
+            return Int53Serializer::new(self.ptr).serialize(serializer);
+        }
+        // str() of an int is always a valid JSON number
+        match int_to_string(self.ptr) {
+            Some(val) => serializer.serialize_bytes(val.as_bytes()),
+            None => err!(SerializeError::FractionIntStr),
+        }
+    }
+}
@@ -1,23 +1,63 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+use crate::deserialize::is_valid_utf8;
 use crate::ffi::{Fragment, PyBytes_AS_STRING, PyBytes_GET_SIZE};
+use crate::opt::{Opt, VALIDATE_FRAGMENT};
 use crate::serialize::error::SerializeError;
 use crate::str::unicode_to_str;
 use crate::typeref::{BYTES_TYPE, STR_TYPE};
 
 use serde::ser::{Serialize, Serializer};
 
-#[repr(transparent)]
 pub struct FragmentSerializer {
     ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
 }
 
 impl FragmentSerializer {
-    pub fn new(ptr: *mut pyo3_ffi::PyObject) -> Self {
-        FragmentSerializer { ptr: ptr }
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        FragmentSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
     }
 }
 
+/// Check that every string is terminated and every array and object is
+/// closed in order. This does not check that the contents are one value or
+/// that numbers and literals are valid.
+#[cold]
+#[inline(never)]
+fn is_balanced(buffer: &[u8]) -> bool {
+    let mut stack: Vec<u8> = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut has_value = false;
+    for &each in buffer {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if each == b'\\' {
+                escaped = true;
+            } else if each == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match each {
+            b' ' | b'\t' | b'\n' | b'\r' => continue,
+            b'"' => in_string = true,
+            b'[' => stack.push(b']'),
+            b'{' => stack.push(b'}'),
+            // a matching close falls through to `_`
+            b']' | b'}' if stack.pop() != Some(each) => return false,
+            _ => (),
+        }
+        has_value = true;
+    }
+    has_value && !in_string && stack.is_empty()
+}
+
 impl Serialize for FragmentSerializer {
     #[cold]
     #[inline(never)]
@@ -35,6 +75,9 @@ impl Serialize for FragmentSerializer {
                     PyBytes_AS_STRING((*fragment).contents) as *const u8,
                     PyBytes_GET_SIZE((*fragment).contents) as usize,
                 );
+                if unlikely!(opt_enabled!(self.opts, VALIDATE_FRAGMENT) && !is_valid_utf8(buffer)) {
+                    err!(SerializeError::FragmentNotValid)
+                }
             } else if ob_type == STR_TYPE {
                 let uni = unicode_to_str((*fragment).contents);
                 if unlikely!(uni.is_none()) {
@@ -45,6 +88,9 @@ impl Serialize for FragmentSerializer {
                 err!(SerializeError::InvalidFragment)
             }
         }
+        if unlikely!(opt_enabled!(self.opts, VALIDATE_FRAGMENT) && !is_balanced(buffer)) {
+            err!(SerializeError::FragmentNotValid)
+        }
         serializer.serialize_bytes(buffer)
     }
 }
@@ -6,8 +6,8 @@ use serde::ser::{Serialize, Serializer};
 
 // https://tools.ietf.org/html/rfc7159#section-6
 // "[-(2**53)+1, (2**53)-1]"
-const STRICT_INT_MIN: i64 = -9007199254740991;
-const STRICT_INT_MAX: i64 = 9007199254740991;
+pub const STRICT_INT_MIN: i64 = -9007199254740991;
+pub const STRICT_INT_MAX: i64 = 9007199254740991;
 
 #[repr(transparent)]
 pub struct IntSerializer {
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::serialize::error::SerializeError;
+use crate::str::unicode_to_str;
+use crate::typeref::{load_ipaddress_types, IPADDRESS_TYPES};
+use pyo3_ffi::PyTypeObject;
+
+use serde::ser::{Serialize, Serializer};
+
+/// Whether `ob_type` is one of the `ipaddress` address, network, or
+/// interface types. `ipaddress` is imported on first use.
+#[cold]
+pub fn is_ipaddress(ob_type: *mut PyTypeObject) -> bool {
+    let ipaddress_types =
+        unsafe { (*core::ptr::addr_of!(IPADDRESS_TYPES)).get_or_init(load_ipaddress_types) };
+    match ipaddress_types {
+        Some(types) => {
+            let types = unsafe { types.as_ref() };
+            ob_type == types.ipv4_address
+                || ob_type == types.ipv6_address
+                || ob_type == types.ipv4_network
+                || ob_type == types.ipv6_network
+                || ob_type == types.ipv4_interface
+                || ob_type == types.ipv6_interface
+        }
+        None => false,
+    }
+}
+
+#[repr(transparent)]
+pub struct IpAddressSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+}
+
+impl IpAddressSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject) -> Self {
+        IpAddressSerializer { ptr: ptr }
+    }
+}
+
+impl Serialize for IpAddressSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // str() is the canonical form, e.g., IPv6 is compressed and lowercase
+        let pystr = ffi!(PyObject_Str(self.ptr));
+        if unlikely!(pystr.is_null()) {
+            ffi!(PyErr_Clear());
+            err!(SerializeError::InvalidStr)
+        }
+        let ret = match unicode_to_str(pystr) {
+            Some(uni) => serializer.serialize_str(uni),
+            None => Err(serde::ser::Error::custom(SerializeError::InvalidStr)),
+        };
+        ffi!(Py_DECREF(pystr));
+        ret
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::serialize::error::SerializeError;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::{
+    error_at_index, set_exception_raised, start_error_path, SerializerState,
+};
+
+use core::ptr::NonNull;
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+/// Whether instances of `ob_type` are iterators, i.e., implement `__next__`,
+/// as generators, `map()`, `zip()`, and `iter()` of a container do.
+#[inline(never)]
+pub fn is_iterator(ob_type: *mut pyo3_ffi::PyTypeObject) -> bool {
+    unsafe {
+        match (*ob_type).tp_iternext {
+            Some(iternext) => {
+                iternext as usize != pyo3_ffi::_PyObject_NextNotImplemented as *const () as usize
+            }
+            None => false,
+        }
+    }
+}
+
+/// Serialize an iterator as an array by consuming it, one item at a time,
+/// without first collecting the items. An exception it raises is propagated
+/// as it is.
+pub struct IteratorSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl IteratorSerializer {
+    pub fn new(
+        ptr: *mut pyo3_ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<pyo3_ffi::PyObject>>,
+    ) -> Self {
+        IteratorSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for IteratorSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.state.recursion_limit()) {
+            err!(SerializeError::RecursionLimit)
+        }
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        let mut idx = 0;
+        loop {
+            let item = ffi!(PyIter_Next(self.ptr));
+            if item.is_null() {
+                break;
+            }
+            let res = seq
+                .serialize_element(&PyObjectSerializer::new(item, self.state, self.default))
+                .map_err(|err| error_at_index(err, idx));
+            ffi!(Py_DECREF(item));
+            res?;
+            idx += 1;
+        }
+        if unlikely!(!ffi!(PyErr_Occurred()).is_null()) {
+            set_exception_raised();
+            start_error_path();
+            err!(SerializeError::IteratorRaised)
+        }
+        seq.end()
+    }
+}
@@ -4,13 +4,16 @@ use crate::opt::STRICT_INTEGER;
 use crate::serialize::error::SerializeError;
 use crate::serialize::obtype::{pyobject_to_obtype, ObType};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, Int53Serializer,
-    IntSerializer, NoneSerializer, NumpyScalar, NumpySerializer, StrSerializer,
-    StrSubclassSerializer, Time, UUID,
+    ArraySerializer, BoolSerializer, BytesSerializer, ComplexSerializer,
+    DataclassGenericSerializer, Date, DateTime, DecimalSerializer, DefaultSerializer,
+    DictGenericSerializer, EnumSerializer, ExceptionSerializer, FloatSerializer,
+    FractionSerializer, FragmentSerializer, Int53Serializer, IntSerializer, IpAddressSerializer,
+    IteratorSerializer, NamedTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer,
+    PathSerializer, RangeSerializer, SetSerializer, StrSerializer, StrSubclassSerializer, Time,
+    Timedelta, TracebackSerializer, UUID,
 };
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::serialize::state::SerializerState;
+use crate::serialize::state::{error_at_index, SerializerState};
 use crate::typeref::*;
 
 use core::ptr::NonNull;
@@ -81,6 +84,31 @@ impl ListTupleSerializer {
     }
 }
 
+impl ListTupleSerializer {
+    /// Serialize the elements from the first that are `int`, without
+    /// dispatching on the type of each, and return the index of the first
+    /// element of another type or the length if there is none.
+    #[inline(always)]
+    fn serialize_int_run<Seq>(&self, seq: &mut Seq) -> Result<usize, Seq::Error>
+    where
+        Seq: SerializeSeq,
+    {
+        let strict = opt_enabled!(self.state.opts(), STRICT_INTEGER);
+        for idx in 0..self.len {
+            let value = unsafe { *((self.data_ptr).add(idx)) };
+            if !is_type!(ob_type!(value), INT_TYPE) {
+                return Ok(idx);
+            }
+            if unlikely!(strict) {
+                seq.serialize_element(&Int53Serializer::new(value))?;
+            } else {
+                seq.serialize_element(&IntSerializer::new(value))?;
+            }
+        }
+        Ok(self.len)
+    }
+}
+
 impl Serialize for ListTupleSerializer {
     #[inline(never)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -92,7 +120,12 @@ impl Serialize for ListTupleSerializer {
         }
         debug_assert!(self.len >= 1);
         let mut seq = serializer.serialize_seq(None).unwrap();
-        for idx in 0..self.len {
+        let start = if is_type!(ob_type!(unsafe { *self.data_ptr }), INT_TYPE) {
+            self.serialize_int_run(&mut seq)?
+        } else {
+            0
+        };
+        for idx in start..self.len {
             let value = unsafe { *((self.data_ptr).add(idx)) };
             match pyobject_to_obtype(value, self.state.opts()) {
                 ObType::Str => {
@@ -118,7 +151,8 @@ impl Serialize for ListTupleSerializer {
                     seq.serialize_element(&BoolSerializer::new(value)).unwrap();
                 }
                 ObType::Datetime => {
-                    seq.serialize_element(&DateTime::new(value, self.state.opts()))?;
+                    seq.serialize_element(&DateTime::new(value, self.state.opts()))
+                        .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::Date => {
                     seq.serialize_element(&Date::new(value))?;
@@ -127,11 +161,12 @@ impl Serialize for ListTupleSerializer {
                     seq.serialize_element(&Time::new(value, self.state.opts()))?;
                 }
                 ObType::Uuid => {
-                    seq.serialize_element(&UUID::new(value)).unwrap();
+                    seq.serialize_element(&UUID::new(value, self.state.opts()))?;
                 }
                 ObType::Dict => {
                     let pyvalue = DictGenericSerializer::new(value, self.state, self.default);
-                    seq.serialize_element(&pyvalue)?;
+                    seq.serialize_element(&pyvalue)
+                        .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::List => {
                     if ffi!(Py_SIZE(value)) == 0 {
@@ -139,7 +174,8 @@ impl Serialize for ListTupleSerializer {
                     } else {
                         let pyvalue =
                             ListTupleSerializer::from_list(value, self.state, self.default);
-                        seq.serialize_element(&pyvalue)?;
+                        seq.serialize_element(&pyvalue)
+                            .map_err(|err| error_at_index(err, idx))?;
                     }
                 }
                 ObType::Tuple => {
@@ -148,40 +184,105 @@ impl Serialize for ListTupleSerializer {
                     } else {
                         let pyvalue =
                             ListTupleSerializer::from_tuple(value, self.state, self.default);
-                        seq.serialize_element(&pyvalue)?;
+                        seq.serialize_element(&pyvalue)
+                            .map_err(|err| error_at_index(err, idx))?;
                     }
                 }
                 ObType::Dataclass => {
                     seq.serialize_element(&DataclassGenericSerializer::new(
                         &PyObjectSerializer::new(value, self.state, self.default),
-                    ))?;
+                    ))
+                    .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::Enum => {
                     seq.serialize_element(&EnumSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::NumpyArray => {
                     seq.serialize_element(&NumpySerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::NumpyScalar => {
                     seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))?;
                 }
                 ObType::Fragment => {
-                    seq.serialize_element(&FragmentSerializer::new(value))?;
+                    seq.serialize_element(&FragmentSerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Decimal => {
+                    seq.serialize_element(&DecimalSerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Set => {
+                    seq.serialize_element(&SetSerializer::new(value, self.state, self.default))
+                        .map_err(|err| error_at_index(err, idx))?;
+                }
+                ObType::Path => {
+                    seq.serialize_element(&PathSerializer::new(value))?;
+                }
+                ObType::IpAddress => {
+                    seq.serialize_element(&IpAddressSerializer::new(value))?;
+                }
+                ObType::Timedelta => {
+                    seq.serialize_element(&Timedelta::new(value, self.state.opts()))?;
+                }
+                ObType::Array => {
+                    seq.serialize_element(&ArraySerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Bytes => {
+                    seq.serialize_element(&BytesSerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Complex => {
+                    seq.serialize_element(&ComplexSerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Fraction => {
+                    seq.serialize_element(&FractionSerializer::new(value, self.state.opts()))?;
+                }
+                ObType::Iterator => {
+                    seq.serialize_element(&IteratorSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .map_err(|err| error_at_index(err, idx))?;
+                }
+                ObType::NamedTuple => {
+                    seq.serialize_element(&NamedTupleSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .map_err(|err| error_at_index(err, idx))?;
+                }
+                ObType::Range => {
+                    seq.serialize_element(&RangeSerializer::new(value, self.state))
+                        .map_err(|err| error_at_index(err, idx))?;
+                }
+                ObType::Exception => {
+                    seq.serialize_element(&ExceptionSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .map_err(|err| error_at_index(err, idx))?;
+                }
+                ObType::Traceback => {
+                    seq.serialize_element(&TracebackSerializer::new(value, self.state))
+                        .map_err(|err| error_at_index(err, idx))?;
                 }
                 ObType::Unknown => {
                     seq.serialize_element(&DefaultSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .map_err(|err| error_at_index(err, idx))?;
                 }
             }
         }
@@ -1,34 +1,65 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+mod array;
+mod bytes;
+mod complex;
 mod dataclass;
 mod datetime;
 mod pybool;
 #[macro_use]
 mod datetimelike;
+mod decimal;
 mod default;
 mod dict;
+mod exception;
 mod float;
+mod fraction;
 mod fragment;
 mod int;
+mod ipaddress;
+mod iterator;
 mod list;
+mod namedtuple;
 mod none;
 mod numpy;
+mod pandas;
+mod path;
 mod pyenum;
+mod range;
+mod set;
 mod unicode;
 mod uuid;
 
-pub use dataclass::DataclassGenericSerializer;
-pub use datetime::{Date, DateTime, Time};
+pub use array::{is_array, ArraySerializer};
+pub use bytes::{serialize_base64, BytesSerializer};
+pub use complex::ComplexSerializer;
+pub use dataclass::{
+    with_dataclass_include, with_dataclass_layouts, DataclassGenericSerializer, DataclassLayouts,
+};
+pub use datetime::{duration_seconds, write_duration, Date, DateTime, Time, Timedelta};
 pub use datetimelike::{DateTimeBuffer, DateTimeError, DateTimeLike, Offset};
+pub use decimal::DecimalSerializer;
 pub use default::DefaultSerializer;
 pub use dict::DictGenericSerializer;
+pub use exception::{ExceptionSerializer, TracebackSerializer};
 pub use float::FloatSerializer;
+pub use fraction::{is_fraction, FractionSerializer};
 pub use fragment::FragmentSerializer;
-pub use int::{Int53Serializer, IntSerializer};
+pub use int::{Int53Serializer, IntSerializer, STRICT_INT_MAX, STRICT_INT_MIN};
+pub use ipaddress::{is_ipaddress, IpAddressSerializer};
+pub use iterator::{is_iterator, IteratorSerializer};
 pub use list::{ListTupleSerializer, ZeroListSerializer};
+pub use namedtuple::{is_namedtuple, NamedTupleSerializer};
 pub use none::NoneSerializer;
-pub use numpy::{is_numpy_array, is_numpy_scalar, NumpyScalar, NumpySerializer};
+pub use numpy::{
+    is_numpy_array, is_numpy_scalar, NumpyScalar, NumpySerializer, PyArrayInterface, PyCapsule,
+    NPY_ARRAY_NOTSWAPPED,
+};
+pub use pandas::{is_pandas_nat, is_pandas_timestamp};
+pub use path::{is_path, PathSerializer};
 pub use pybool::BoolSerializer;
-pub use pyenum::EnumSerializer;
+pub use pyenum::{enum_name, EnumSerializer};
+pub use range::RangeSerializer;
+pub use set::SetSerializer;
 pub use unicode::{StrSerializer, StrSubclassSerializer};
 pub use uuid::UUID;
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{CAMELCASE, SKIP_NONE_VALUES, SORT_KEYS};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::dict::{check_camel_case_keys, sort_str_items, ZeroDictSerializer};
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::{error_at_key, start_error_path, SerializerState};
+use crate::str::unicode_to_str;
+use crate::typeref::{NAMEDTUPLE_FIELDS_STR, NONE, STR_TYPE, TUPLE_TYPE};
+
+use core::ptr::NonNull;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use smallvec::SmallVec;
+
+/// Whether `ob_type` is a `tuple` subclass with a `tuple` of `_fields`, as
+/// created by `collections.namedtuple()` and `typing.NamedTuple`.
+#[cold]
+#[inline(never)]
+pub fn is_namedtuple(ob_type: *mut pyo3_ffi::PyTypeObject) -> bool {
+    if !is_subclass_by_flag!(ob_type, Py_TPFLAGS_TUPLE_SUBCLASS) {
+        return false;
+    }
+    let fields = ffi!(PyObject_GetAttr(
+        ob_type as *mut pyo3_ffi::PyObject,
+        NAMEDTUPLE_FIELDS_STR
+    ));
+    if fields.is_null() {
+        ffi!(PyErr_Clear());
+        return false;
+    }
+    let ret = is_class_by_type!(ob_type!(fields), TUPLE_TYPE);
+    ffi!(Py_DECREF(fields));
+    ret
+}
+
+/// Serialize a named tuple as an object of its fields, in order unless
+/// `OPT_SORT_KEYS`.
+pub struct NamedTupleSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl NamedTupleSerializer {
+    pub fn new(
+        ptr: *mut pyo3_ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<pyo3_ffi::PyObject>>,
+    ) -> Self {
+        NamedTupleSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for NamedTupleSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.state.recursion_limit()) {
+            err!(SerializeError::RecursionLimit)
+        }
+        let fields = ffi!(PyObject_GetAttr(
+            ob_type!(self.ptr) as *mut pyo3_ffi::PyObject,
+            NAMEDTUPLE_FIELDS_STR
+        ));
+        // checked by is_namedtuple(), so only a class attribute assigned since
+        // could make this fail
+        if unlikely!(fields.is_null() || !is_class_by_type!(ob_type!(fields), TUPLE_TYPE)) {
+            if fields.is_null() {
+                ffi!(PyErr_Clear());
+            } else {
+                ffi!(Py_DECREF(fields));
+            }
+            start_error_path();
+            err!(SerializeError::UnsupportedType(nonnull!(self.ptr)))
+        }
+        let len = core::cmp::min(ffi!(Py_SIZE(fields)), ffi!(Py_SIZE(self.ptr))) as usize;
+        let ret = if len == 0 {
+            ZeroDictSerializer::new().serialize(serializer)
+        } else {
+            self.serialize_fields(fields, len, serializer)
+        };
+        ffi!(Py_DECREF(fields));
+        ret
+    }
+}
+
+impl NamedTupleSerializer {
+    fn serialize_fields<S>(
+        &self,
+        fields: *mut pyo3_ffi::PyObject,
+        len: usize,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut items: SmallVec<[(&str, *mut pyo3_ffi::PyObject); 8]> =
+            SmallVec::with_capacity(len);
+        for idx in 0..len {
+            let field = ffi!(PyTuple_GET_ITEM(fields, idx as isize));
+            let value = ffi!(PyTuple_GET_ITEM(self.ptr, idx as isize));
+            if unlikely!(!is_class_by_type!(ob_type!(field), STR_TYPE)) {
+                start_error_path();
+                err!(SerializeError::KeyMustBeStr(nonnull!(field)))
+            }
+            let key = match unicode_to_str(field) {
+                Some(key) => key,
+                None => err!(SerializeError::InvalidStr),
+            };
+            if unlikely!(value == unsafe { NONE })
+                && opt_enabled!(self.state.opts(), SKIP_NONE_VALUES)
+            {
+                continue;
+            }
+            items.push((key, value));
+        }
+        if unlikely!(opt_enabled!(self.state.opts(), CAMELCASE)) {
+            check_camel_case_keys(&items)?;
+        }
+        if opt_enabled!(self.state.opts(), SORT_KEYS) {
+            sort_str_items(&mut items, self.state.opts());
+        }
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, value) in items {
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&PyObjectSerializer::new(value, self.state, self.default))
+                .map_err(|err| error_at_key(err, key))?;
+        }
+        map.end()
+    }
+}
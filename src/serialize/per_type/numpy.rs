@@ -1,16 +1,23 @@
 use crate::opt::*;
 
 use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::dict::{cmp_keys, ZeroDictSerializer};
 use crate::serialize::per_type::{
-    DateTimeBuffer, DateTimeError, DateTimeLike, DefaultSerializer, Offset, ZeroListSerializer,
+    duration_seconds, write_duration, DateTimeBuffer, DateTimeError, DateTimeLike,
+    DefaultSerializer, NoneSerializer, Offset, ZeroListSerializer,
 };
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::typeref::{load_numpy_types, ARRAY_STRUCT_STR, DESCR_STR, DTYPE_STR, NUMPY_TYPES};
+use crate::serialize::state::start_error_path;
+use crate::typeref::{
+    load_numpy_types, ARRAY_STRUCT_STR, DESCR_STR, DTYPE_STR, FIELDS_STR, ISNATIVE_STR,
+    ITEMSIZE_STR, KIND_STR, NAMES_STR, NONE, NUMPY_TYPES, SUBDTYPE_STR, TRUE,
+};
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 use core::ffi::{c_char, c_int, c_void};
 use pyo3_ffi::*;
-use serde::ser::{self, Serialize, SerializeSeq, Serializer};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, Serializer};
 use std::fmt;
+use std::rc::Rc;
 
 #[repr(transparent)]
 pub struct NumpySerializer<'a> {
@@ -59,7 +66,7 @@ macro_rules! slice {
 }
 
 #[cold]
-pub fn is_numpy_scalar(ob_type: *mut PyTypeObject) -> bool {
+pub fn is_numpy_scalar(ob_type: *mut PyTypeObject, opts: Opt) -> bool {
     let numpy_types = unsafe { NUMPY_TYPES.get_or_init(load_numpy_types) };
     if numpy_types.is_none() {
         false
@@ -78,6 +85,7 @@ pub fn is_numpy_scalar(ob_type: *mut PyTypeObject) -> bool {
             || ob_type == scalar_types.uint16
             || ob_type == scalar_types.bool_
             || ob_type == scalar_types.datetime64
+            || (ob_type == scalar_types.timedelta64 && opt_enabled!(opts, TIMEDELTA_ANY))
     }
 }
 
@@ -104,8 +112,8 @@ pub struct PyCapsule {
 
 // https://docs.scipy.org/doc/numpy/reference/arrays.interface.html#c.__array_struct__
 
-const NPY_ARRAY_C_CONTIGUOUS: c_int = 0x1;
-const NPY_ARRAY_NOTSWAPPED: c_int = 0x200;
+pub const NPY_ARRAY_C_CONTIGUOUS: c_int = 0x1;
+pub const NPY_ARRAY_NOTSWAPPED: c_int = 0x200;
 
 #[repr(C)]
 pub struct PyArrayInterface {
@@ -124,6 +132,7 @@ pub struct PyArrayInterface {
 pub enum ItemType {
     BOOL,
     DATETIME64(NumpyDatetimeUnit),
+    TIMEDELTA64(NumpyDatetimeUnit),
     F16,
     F32,
     F64,
@@ -131,6 +140,7 @@ pub enum ItemType {
     I16,
     I32,
     I64,
+    RECORD,
     U8,
     U16,
     U32,
@@ -138,13 +148,26 @@ pub enum ItemType {
 }
 
 impl ItemType {
-    fn find(array: *mut PyArrayInterface, ptr: *mut PyObject) -> Option<ItemType> {
-        match unsafe { ((*array).typekind, (*array).itemsize) } {
+    fn find(array: *mut PyArrayInterface, ptr: *mut PyObject, opts: Opt) -> Option<ItemType> {
+        let (typekind, itemsize) = unsafe { ((*array).typekind, (*array).itemsize) };
+        if typekind == 086 {
+            return Some(ItemType::RECORD);
+        }
+        Self::from_kind(typekind, itemsize, opts, || {
+            NumpyDatetimeUnit::from_pyobject(ptr)
+        })
+    }
+
+    /// Map `dtype.kind` and `dtype.itemsize` to an `ItemType`, calling `unit`
+    /// for the unit of `datetime64` and `timedelta64`.
+    fn from_kind<F>(typekind: c_char, itemsize: c_int, opts: Opt, unit: F) -> Option<ItemType>
+    where
+        F: FnOnce() -> NumpyDatetimeUnit,
+    {
+        match (typekind, itemsize) {
             (098, 1) => Some(ItemType::BOOL),
-            (077, 8) => {
-                let unit = NumpyDatetimeUnit::from_pyobject(ptr);
-                Some(ItemType::DATETIME64(unit))
-            }
+            (077, 8) => Some(ItemType::DATETIME64(unit())),
+            (109, 8) if opt_enabled!(opts, TIMEDELTA_ANY) => Some(ItemType::TIMEDELTA64(unit())),
             (102, 2) => Some(ItemType::F16),
             (102, 4) => Some(ItemType::F32),
             (102, 8) => Some(ItemType::F64),
@@ -182,6 +205,7 @@ pub struct NumpyArray {
     depth: usize,
     capsule: *mut PyCapsule,
     kind: ItemType,
+    fields: Option<Rc<Vec<NumpyField>>>,
     opts: Opt,
 }
 
@@ -203,16 +227,22 @@ impl NumpyArray {
             Err(PyArrayError::NotNativeEndian)
         } else {
             let num_dimensions = unsafe { (*array).nd as usize };
-            if num_dimensions == 0 {
-                ffi!(Py_DECREF(capsule));
-                return Err(PyArrayError::UnsupportedDataType);
-            }
-            match ItemType::find(array, ptr) {
+            match ItemType::find(array, ptr, opts) {
                 None => {
                     ffi!(Py_DECREF(capsule));
                     Err(PyArrayError::UnsupportedDataType)
                 }
                 Some(kind) => {
+                    let mut fields = None;
+                    if let ItemType::RECORD = kind {
+                        match NumpyField::from_array(ptr, opts) {
+                            Ok(val) => fields = Some(Rc::new(val)),
+                            Err(err) => {
+                                ffi!(Py_DECREF(capsule));
+                                return Err(err);
+                            }
+                        }
+                    }
                     let mut pyarray = NumpyArray {
                         array: array,
                         position: vec![0; num_dimensions],
@@ -220,6 +250,7 @@ impl NumpyArray {
                         depth: 0,
                         capsule: capsule as *mut PyCapsule,
                         kind: kind,
+                        fields: fields,
                         opts,
                     };
                     if pyarray.dimensions() > 1 {
@@ -240,6 +271,7 @@ impl NumpyArray {
             depth: self.depth + 1,
             capsule: self.capsule,
             kind: self.kind,
+            fields: self.fields.clone(),
             opts: self.opts,
         };
         arr.build();
@@ -309,28 +341,52 @@ impl Serialize for NumpyArray {
     where
         S: Serializer,
     {
+        if unlikely!(self.dimensions() == 0) {
+            // a 0-d array, e.g., of a reduction, is serialized as its scalar
+            // is, so `OPT_NUMPY_NONFINITE_AS_NULL` does not apply; its shape
+            // and strides may be null
+            let data = unsafe { (*self.array).data as *const u8 };
+            let opts = self.opts & !NUMPY_NONFINITE_AS_NULL;
+            return match self.fields {
+                Some(ref fields) => NumpyRecord {
+                    fields: fields,
+                    data: data,
+                    opts: opts,
+                }
+                .serialize(serializer),
+                None => NumpyFieldValue {
+                    kind: &NumpyFieldKind::Item(self.kind),
+                    data: data,
+                    opts: opts,
+                }
+                .serialize(serializer),
+            };
+        }
         if unlikely!(!(self.depth >= self.dimensions() || self.shape()[self.depth] != 0)) {
             ZeroListSerializer::new().serialize(serializer)
         } else if !self.children.is_empty() {
             let mut seq = serializer.serialize_seq(None).unwrap();
             for child in &self.children {
-                seq.serialize_element(child).unwrap();
+                seq.serialize_element(child)?;
             }
             seq.end()
         } else {
             match self.kind {
-                ItemType::F64 => {
-                    NumpyF64Array::new(slice!(self.data() as *const f64, self.num_items()))
-                        .serialize(serializer)
-                }
-                ItemType::F32 => {
-                    NumpyF32Array::new(slice!(self.data() as *const f32, self.num_items()))
-                        .serialize(serializer)
-                }
-                ItemType::F16 => {
-                    NumpyF16Array::new(slice!(self.data() as *const u16, self.num_items()))
-                        .serialize(serializer)
-                }
+                ItemType::F64 => NumpyF64Array::new(
+                    slice!(self.data() as *const f64, self.num_items()),
+                    opt_enabled!(self.opts, NUMPY_NONFINITE_AS_NULL),
+                )
+                .serialize(serializer),
+                ItemType::F32 => NumpyF32Array::new(
+                    slice!(self.data() as *const f32, self.num_items()),
+                    opt_enabled!(self.opts, NUMPY_NONFINITE_AS_NULL),
+                )
+                .serialize(serializer),
+                ItemType::F16 => NumpyF16Array::new(
+                    slice!(self.data() as *const u16, self.num_items()),
+                    opt_enabled!(self.opts, NUMPY_NONFINITE_AS_NULL),
+                )
+                .serialize(serializer),
                 ItemType::U64 => {
                     NumpyU64Array::new(slice!(self.data() as *const u64, self.num_items()))
                         .serialize(serializer)
@@ -373,19 +429,424 @@ impl Serialize for NumpyArray {
                     self.opts,
                 )
                 .serialize(serializer),
+                ItemType::TIMEDELTA64(unit) => NumpyTimedelta64Array::new(
+                    slice!(self.data() as *const i64, self.num_items()),
+                    unit,
+                    self.opts,
+                )
+                .serialize(serializer),
+                ItemType::RECORD => NumpyRecordArray {
+                    fields: self.fields.as_ref().unwrap(),
+                    data: self.data() as *const u8,
+                    len: self.num_items(),
+                    itemsize: unsafe { (*self.array).itemsize as usize },
+                    opts: self.opts,
+                }
+                .serialize(serializer),
             }
         }
     }
 }
 
-#[repr(transparent)]
-struct NumpyF64Array<'a> {
+/// A field of a structured datatype, e.g., `numpy.dtype([("x", "f8")])`.
+struct NumpyField {
+    name: String,
+    offset: usize,
+    kind: NumpyFieldKind,
+}
+
+enum NumpyFieldKind {
+    Item(ItemType),
+    /// `numpy.str_` of the given number of UCS4 code points.
+    Str(usize),
+    Record(Vec<NumpyField>),
+    SubArray {
+        shape: Vec<usize>,
+        itemsize: usize,
+        kind: Box<NumpyFieldKind>,
+    },
+}
+
+#[cold]
+fn dtype_attr(dtype: *mut PyObject, name: *mut PyObject) -> Result<*mut PyObject, PyArrayError> {
+    let val = ffi!(PyObject_GetAttr(dtype, name));
+    if unlikely!(val.is_null()) {
+        ffi!(PyErr_Clear());
+        return Err(PyArrayError::Malformed);
+    }
+    Ok(val)
+}
+
+#[cold]
+fn dtype_itemsize(dtype: *mut PyObject) -> Result<usize, PyArrayError> {
+    let val = dtype_attr(dtype, unsafe { ITEMSIZE_STR })?;
+    let itemsize = ffi!(PyLong_AsSsize_t(val));
+    ffi!(Py_DECREF(val));
+    Ok(itemsize as usize)
+}
+
+impl NumpyField {
+    /// Read the fields of the structured datatype of a numpy array.
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_array(ptr: *mut PyObject, opts: Opt) -> Result<Vec<NumpyField>, PyArrayError> {
+        let dtype = dtype_attr(ptr, unsafe { DTYPE_STR })?;
+        // `NPY_ARRAY_NOTSWAPPED` does not apply to the fields of a record,
+        // but `dtype.isnative` does, recursively
+        let ret = match dtype_attr(dtype, unsafe { ISNATIVE_STR }) {
+            Ok(isnative) => {
+                ffi!(Py_DECREF(isnative));
+                if isnative == unsafe { TRUE } {
+                    Self::from_dtype(dtype, opts)
+                } else {
+                    Err(PyArrayError::NotNativeEndian)
+                }
+            }
+            Err(err) => Err(err),
+        };
+        ffi!(Py_DECREF(dtype));
+        ret
+    }
+
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_dtype(dtype: *mut PyObject, opts: Opt) -> Result<Vec<NumpyField>, PyArrayError> {
+        let names = dtype_attr(dtype, unsafe { NAMES_STR })?;
+        if names == unsafe { NONE } {
+            ffi!(Py_DECREF(names));
+            return Err(PyArrayError::UnsupportedDataType);
+        }
+        let ret = match dtype_attr(dtype, unsafe { FIELDS_STR }) {
+            Ok(fields) => {
+                let num_fields = ffi!(Py_SIZE(names)) as usize;
+                let mut ret = Vec::with_capacity(num_fields);
+                let mut err = None;
+                for i in 0..num_fields {
+                    let name = ffi!(PyTuple_GET_ITEM(names, i as Py_ssize_t));
+                    match Self::from_name(name, fields, opts) {
+                        Ok(field) => ret.push(field),
+                        Err(val) => {
+                            err = Some(val);
+                            break;
+                        }
+                    }
+                }
+                ffi!(Py_DECREF(fields));
+                match err {
+                    None => {
+                        if opt_enabled!(opts, SORT_KEYS) {
+                            ret.sort_unstable_by(|a: &NumpyField, b| {
+                                cmp_keys(&a.name, &b.name, opts)
+                            });
+                        }
+                        Ok(ret)
+                    }
+                    Some(err) => Err(err),
+                }
+            }
+            Err(err) => Err(err),
+        };
+        ffi!(Py_DECREF(names));
+        ret
+    }
+
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_name(
+        name: *mut PyObject,
+        fields: *mut PyObject,
+        opts: Opt,
+    ) -> Result<NumpyField, PyArrayError> {
+        let name_str = match crate::str::unicode_to_str(name) {
+            Some(val) => val.to_string(),
+            None => return Err(PyArrayError::Malformed),
+        };
+        // `dtype.fields[name]` is `(dtype, offset)` or `(dtype, offset, title)`
+        let item = ffi!(PyObject_GetItem(fields, name));
+        if unlikely!(item.is_null()) {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        let offset = ffi!(PyLong_AsSsize_t(PyTuple_GET_ITEM(item, 1))) as usize;
+        let kind = NumpyFieldKind::from_dtype(ffi!(PyTuple_GET_ITEM(item, 0)), opts);
+        ffi!(Py_DECREF(item));
+        Ok(NumpyField {
+            name: name_str,
+            offset: offset,
+            kind: kind?,
+        })
+    }
+}
+
+impl NumpyFieldKind {
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_dtype(dtype: *mut PyObject, opts: Opt) -> Result<Self, PyArrayError> {
+        // `dtype.subdtype` is `(base, shape)` for a sub-array field
+        let subdtype = dtype_attr(dtype, unsafe { SUBDTYPE_STR })?;
+        if subdtype != unsafe { NONE } {
+            let ret = Self::from_subdtype(subdtype, opts);
+            ffi!(Py_DECREF(subdtype));
+            return ret;
+        }
+        ffi!(Py_DECREF(subdtype));
+        let names = dtype_attr(dtype, unsafe { NAMES_STR })?;
+        ffi!(Py_DECREF(names));
+        if names != unsafe { NONE } {
+            return NumpyField::from_dtype(dtype, opts).map(Self::Record);
+        }
+        let kind = dtype_attr(dtype, unsafe { KIND_STR })?;
+        let typekind = crate::str::unicode_to_str(kind)
+            .and_then(|val| val.bytes().next())
+            .unwrap_or(0);
+        ffi!(Py_DECREF(kind));
+        let itemsize = dtype_itemsize(dtype)?;
+        if typekind == b'U' {
+            return Ok(Self::Str(itemsize / 4));
+        }
+        match ItemType::from_kind(typekind as c_char, itemsize as c_int, opts, || {
+            NumpyDatetimeUnit::from_dtype(dtype)
+        }) {
+            Some(kind) => Ok(Self::Item(kind)),
+            None => Err(PyArrayError::UnsupportedDataType),
+        }
+    }
+
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_subdtype(subdtype: *mut PyObject, opts: Opt) -> Result<Self, PyArrayError> {
+        let base = ffi!(PyTuple_GET_ITEM(subdtype, 0));
+        let shape = ffi!(PyTuple_GET_ITEM(subdtype, 1));
+        let shape = (0..ffi!(Py_SIZE(shape)))
+            .map(|i| ffi!(PyLong_AsSsize_t(PyTuple_GET_ITEM(shape, i))) as usize)
+            .collect();
+        Ok(Self::SubArray {
+            shape: shape,
+            itemsize: dtype_itemsize(base)?,
+            kind: Box::new(Self::from_dtype(base, opts)?),
+        })
+    }
+}
+
+/// The innermost dimension of an array with a structured datatype.
+struct NumpyRecordArray<'a> {
+    fields: &'a [NumpyField],
+    data: *const u8,
+    len: usize,
+    itemsize: usize,
+    opts: Opt,
+}
+
+impl<'a> Serialize for NumpyRecordArray<'a> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for i in 0..self.len {
+            seq.serialize_element(&NumpyRecord {
+                fields: self.fields,
+                data: unsafe { self.data.add(i * self.itemsize) },
+                opts: self.opts,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct NumpyRecord<'a> {
+    fields: &'a [NumpyField],
+    data: *const u8,
+    opts: Opt,
+}
+
+impl<'a> Serialize for NumpyRecord<'a> {
+    #[cold]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.fields.is_empty()) {
+            return ZeroDictSerializer::new().serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None).unwrap();
+        for field in self.fields {
+            map.serialize_key(field.name.as_str()).unwrap();
+            map.serialize_value(&NumpyFieldValue {
+                kind: &field.kind,
+                data: unsafe { self.data.add(field.offset) },
+                opts: self.opts,
+            })?;
+        }
+        map.end()
+    }
+}
+
+struct NumpySubArray<'a> {
+    shape: &'a [usize],
+    itemsize: usize,
+    kind: &'a NumpyFieldKind,
+    data: *const u8,
+    opts: Opt,
+}
+
+impl<'a> Serialize for NumpySubArray<'a> {
+    #[cold]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.shape[0] == 0) {
+            return ZeroListSerializer::new().serialize(serializer);
+        }
+        let stride = self.shape[1..].iter().product::<usize>() * self.itemsize;
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for i in 0..self.shape[0] {
+            let data = unsafe { self.data.add(i * stride) };
+            if self.shape.len() == 1 {
+                seq.serialize_element(&NumpyFieldValue {
+                    kind: self.kind,
+                    data: data,
+                    opts: self.opts,
+                })?;
+            } else {
+                seq.serialize_element(&NumpySubArray {
+                    shape: &self.shape[1..],
+                    itemsize: self.itemsize,
+                    kind: self.kind,
+                    data: data,
+                    opts: self.opts,
+                })?;
+            }
+        }
+        seq.end()
+    }
+}
+
+/// A value of a field of a record, which may not be aligned.
+struct NumpyFieldValue<'a> {
+    kind: &'a NumpyFieldKind,
+    data: *const u8,
+    opts: Opt,
+}
+
+macro_rules! read_field {
+    ($data:expr, $ty:ty) => {
+        unsafe { core::ptr::read_unaligned($data as *const $ty) }
+    };
+}
+
+impl<'a> Serialize for NumpyFieldValue<'a> {
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let data = self.data;
+        let nonfinite_as_null = opt_enabled!(self.opts, NUMPY_NONFINITE_AS_NULL);
+        match self.kind {
+            NumpyFieldKind::Item(kind) => match kind {
+                ItemType::BOOL => serializer.serialize_bool(read_field!(data, u8) == 1),
+                ItemType::F16 => {
+                    let val = read_field!(data, u16);
+                    if unlikely!(nonfinite_as_null && !half::f16::from_bits(val).is_finite()) {
+                        return NoneSerializer::new().serialize(serializer);
+                    }
+                    DataTypeF16 { obj: val }.serialize(serializer)
+                }
+                ItemType::F32 => {
+                    let val = read_field!(data, f32);
+                    if unlikely!(nonfinite_as_null && !val.is_finite()) {
+                        return NoneSerializer::new().serialize(serializer);
+                    }
+                    serializer.serialize_f32(val)
+                }
+                ItemType::F64 => {
+                    let val = read_field!(data, f64);
+                    if unlikely!(nonfinite_as_null && !val.is_finite()) {
+                        return NoneSerializer::new().serialize(serializer);
+                    }
+                    serializer.serialize_f64(val)
+                }
+                ItemType::I8 => serializer.serialize_i32(read_field!(data, i8) as i32),
+                ItemType::I16 => serializer.serialize_i32(read_field!(data, i16) as i32),
+                ItemType::I32 => serializer.serialize_i32(read_field!(data, i32)),
+                ItemType::I64 => serializer.serialize_i64(read_field!(data, i64)),
+                ItemType::U8 => serializer.serialize_u32(read_field!(data, u8) as u32),
+                ItemType::U16 => serializer.serialize_u32(read_field!(data, u16) as u32),
+                ItemType::U32 => serializer.serialize_u32(read_field!(data, u32)),
+                ItemType::U64 => serializer.serialize_u64(read_field!(data, u64)),
+                ItemType::DATETIME64(unit) => {
+                    let val = read_field!(data, i64);
+                    if val == NUMPY_NAT {
+                        return NoneSerializer::new().serialize(serializer);
+                    }
+                    unit.datetime(val, self.opts)
+                        .map_err(NumpyDateTimeError::into_serde_err)?
+                        .serialize(serializer)
+                }
+                ItemType::TIMEDELTA64(unit) => {
+                    let val = read_field!(data, i64);
+                    if val == NUMPY_NAT {
+                        return NoneSerializer::new().serialize(serializer);
+                    }
+                    unit.timedelta(val, self.opts)
+                        .map_err(NumpyDateTimeError::into_serde_err)?
+                        .serialize(serializer)
+                }
+                ItemType::RECORD => unreachable!(),
+            },
+            NumpyFieldKind::Str(len) => {
+                // fixed-width and padded with trailing NUL, as `numpy.str_` strips
+                let mut len = *len;
+                while len > 0 && read_field!((data as *const u32).add(len - 1), u32) == 0 {
+                    len -= 1;
+                }
+                let mut buf = String::with_capacity(len);
+                for i in 0..len {
+                    match char::from_u32(read_field!((data as *const u32).add(i), u32)) {
+                        Some(val) => buf.push(val),
+                        None => err!(SerializeError::InvalidStr),
+                    }
+                }
+                serializer.serialize_str(&buf)
+            }
+            NumpyFieldKind::Record(fields) => NumpyRecord {
+                fields: fields,
+                data: data,
+                opts: self.opts,
+            }
+            .serialize(serializer),
+            NumpyFieldKind::SubArray {
+                shape,
+                itemsize,
+                kind,
+            } => NumpySubArray {
+                shape: shape,
+                itemsize: *itemsize,
+                kind: kind,
+                data: data,
+                opts: self.opts,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+pub struct NumpyF64Array<'a> {
     data: &'a [f64],
+    nonfinite_as_null: bool,
 }
 
 impl<'a> NumpyF64Array<'a> {
-    fn new(data: &'a [f64]) -> Self {
-        Self { data }
+    pub fn new(data: &'a [f64], nonfinite_as_null: bool) -> Self {
+        Self {
+            data,
+            nonfinite_as_null,
+        }
     }
 }
 
@@ -398,7 +859,11 @@ impl<'a> Serialize for NumpyF64Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeF64 { obj: each }).unwrap();
+            if unlikely!(self.nonfinite_as_null && !each.is_finite()) {
+                seq.serialize_element(&NoneSerializer::new())?;
+            } else {
+                seq.serialize_element(&DataTypeF64 { obj: each })?;
+            }
         }
         seq.end()
     }
@@ -419,14 +884,17 @@ impl Serialize for DataTypeF64 {
     }
 }
 
-#[repr(transparent)]
-struct NumpyF32Array<'a> {
+pub struct NumpyF32Array<'a> {
     data: &'a [f32],
+    nonfinite_as_null: bool,
 }
 
 impl<'a> NumpyF32Array<'a> {
-    fn new(data: &'a [f32]) -> Self {
-        Self { data }
+    pub fn new(data: &'a [f32], nonfinite_as_null: bool) -> Self {
+        Self {
+            data,
+            nonfinite_as_null,
+        }
     }
 }
 
@@ -439,7 +907,11 @@ impl<'a> Serialize for NumpyF32Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeF32 { obj: each }).unwrap();
+            if unlikely!(self.nonfinite_as_null && !each.is_finite()) {
+                seq.serialize_element(&NoneSerializer::new())?;
+            } else {
+                seq.serialize_element(&DataTypeF32 { obj: each })?;
+            }
         }
         seq.end()
     }
@@ -460,14 +932,17 @@ impl Serialize for DataTypeF32 {
     }
 }
 
-#[repr(transparent)]
 struct NumpyF16Array<'a> {
     data: &'a [u16],
+    nonfinite_as_null: bool,
 }
 
 impl<'a> NumpyF16Array<'a> {
-    fn new(data: &'a [u16]) -> Self {
-        Self { data }
+    fn new(data: &'a [u16], nonfinite_as_null: bool) -> Self {
+        Self {
+            data,
+            nonfinite_as_null,
+        }
     }
 }
 
@@ -480,7 +955,11 @@ impl<'a> Serialize for NumpyF16Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeF16 { obj: each }).unwrap();
+            if unlikely!(self.nonfinite_as_null && !half::f16::from_bits(each).is_finite()) {
+                seq.serialize_element(&NoneSerializer::new())?;
+            } else {
+                seq.serialize_element(&DataTypeF16 { obj: each })?;
+            }
         }
         seq.end()
     }
@@ -498,18 +977,19 @@ impl Serialize for DataTypeF16 {
     where
         S: Serializer,
     {
+        // widen to f64 so output is the same as for `astype(numpy.float64)`
         let as_f16 = half::f16::from_bits(self.obj);
-        serializer.serialize_f32(as_f16.to_f32())
+        serializer.serialize_f64(as_f16.to_f64())
     }
 }
 
 #[repr(transparent)]
-struct NumpyU64Array<'a> {
+pub struct NumpyU64Array<'a> {
     data: &'a [u64],
 }
 
 impl<'a> NumpyU64Array<'a> {
-    fn new(data: &'a [u64]) -> Self {
+    pub fn new(data: &'a [u64]) -> Self {
         Self { data }
     }
 }
@@ -523,7 +1003,7 @@ impl<'a> Serialize for NumpyU64Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeU64 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeU64 { obj: each })?;
         }
         seq.end()
     }
@@ -545,12 +1025,12 @@ impl Serialize for DataTypeU64 {
 }
 
 #[repr(transparent)]
-struct NumpyU32Array<'a> {
+pub struct NumpyU32Array<'a> {
     data: &'a [u32],
 }
 
 impl<'a> NumpyU32Array<'a> {
-    fn new(data: &'a [u32]) -> Self {
+    pub fn new(data: &'a [u32]) -> Self {
         Self { data }
     }
 }
@@ -564,7 +1044,7 @@ impl<'a> Serialize for NumpyU32Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeU32 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeU32 { obj: each })?;
         }
         seq.end()
     }
@@ -586,12 +1066,12 @@ impl Serialize for DataTypeU32 {
 }
 
 #[repr(transparent)]
-struct NumpyU16Array<'a> {
+pub struct NumpyU16Array<'a> {
     data: &'a [u16],
 }
 
 impl<'a> NumpyU16Array<'a> {
-    fn new(data: &'a [u16]) -> Self {
+    pub fn new(data: &'a [u16]) -> Self {
         Self { data }
     }
 }
@@ -605,7 +1085,7 @@ impl<'a> Serialize for NumpyU16Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeU16 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeU16 { obj: each })?;
         }
         seq.end()
     }
@@ -627,12 +1107,12 @@ impl Serialize for DataTypeU16 {
 }
 
 #[repr(transparent)]
-struct NumpyI64Array<'a> {
+pub struct NumpyI64Array<'a> {
     data: &'a [i64],
 }
 
 impl<'a> NumpyI64Array<'a> {
-    fn new(data: &'a [i64]) -> Self {
+    pub fn new(data: &'a [i64]) -> Self {
         Self { data }
     }
 }
@@ -646,7 +1126,7 @@ impl<'a> Serialize for NumpyI64Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeI64 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeI64 { obj: each })?;
         }
         seq.end()
     }
@@ -668,12 +1148,12 @@ impl Serialize for DataTypeI64 {
 }
 
 #[repr(transparent)]
-struct NumpyI32Array<'a> {
+pub struct NumpyI32Array<'a> {
     data: &'a [i32],
 }
 
 impl<'a> NumpyI32Array<'a> {
-    fn new(data: &'a [i32]) -> Self {
+    pub fn new(data: &'a [i32]) -> Self {
         Self { data }
     }
 }
@@ -687,7 +1167,7 @@ impl<'a> Serialize for NumpyI32Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeI32 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeI32 { obj: each })?;
         }
         seq.end()
     }
@@ -709,12 +1189,12 @@ impl Serialize for DataTypeI32 {
 }
 
 #[repr(transparent)]
-struct NumpyI16Array<'a> {
+pub struct NumpyI16Array<'a> {
     data: &'a [i16],
 }
 
 impl<'a> NumpyI16Array<'a> {
-    fn new(data: &'a [i16]) -> Self {
+    pub fn new(data: &'a [i16]) -> Self {
         Self { data }
     }
 }
@@ -728,7 +1208,7 @@ impl<'a> Serialize for NumpyI16Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeI16 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeI16 { obj: each })?;
         }
         seq.end()
     }
@@ -750,12 +1230,12 @@ impl Serialize for DataTypeI16 {
 }
 
 #[repr(transparent)]
-struct NumpyI8Array<'a> {
+pub struct NumpyI8Array<'a> {
     data: &'a [i8],
 }
 
 impl<'a> NumpyI8Array<'a> {
-    fn new(data: &'a [i8]) -> Self {
+    pub fn new(data: &'a [i8]) -> Self {
         Self { data }
     }
 }
@@ -769,7 +1249,7 @@ impl<'a> Serialize for NumpyI8Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeI8 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeI8 { obj: each })?;
         }
         seq.end()
     }
@@ -791,12 +1271,12 @@ impl Serialize for DataTypeI8 {
 }
 
 #[repr(transparent)]
-struct NumpyU8Array<'a> {
+pub struct NumpyU8Array<'a> {
     data: &'a [u8],
 }
 
 impl<'a> NumpyU8Array<'a> {
-    fn new(data: &'a [u8]) -> Self {
+    pub fn new(data: &'a [u8]) -> Self {
         Self { data }
     }
 }
@@ -810,7 +1290,7 @@ impl<'a> Serialize for NumpyU8Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeU8 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeU8 { obj: each })?;
         }
         seq.end()
     }
@@ -850,7 +1330,7 @@ impl<'a> Serialize for NumpyBoolArray<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeBool { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeBool { obj: each })?;
         }
         seq.end()
     }
@@ -919,12 +1399,25 @@ impl Serialize for NumpyScalar {
             } else if ob_type == scalar_types.bool_ {
                 (*(self.ptr as *mut NumpyBool)).serialize(serializer)
             } else if ob_type == scalar_types.datetime64 {
-                let unit = NumpyDatetimeUnit::from_pyobject(self.ptr);
                 let obj = &*(self.ptr as *mut NumpyDatetime64);
+                if obj.value == NUMPY_NAT {
+                    return NoneSerializer::new().serialize(serializer);
+                }
+                let unit = NumpyDatetimeUnit::from_pyobject(self.ptr);
                 let dt = unit
                     .datetime(obj.value, self.opts)
                     .map_err(NumpyDateTimeError::into_serde_err)?;
                 dt.serialize(serializer)
+            } else if ob_type == scalar_types.timedelta64 {
+                let obj = &*(self.ptr as *mut NumpyTimedelta64);
+                if obj.value == NUMPY_NAT {
+                    return NoneSerializer::new().serialize(serializer);
+                }
+                let unit = NumpyDatetimeUnit::from_pyobject(self.ptr);
+                let delta = unit
+                    .timedelta(obj.value, self.opts)
+                    .map_err(NumpyDateTimeError::into_serde_err)?;
+                delta.serialize(serializer)
             } else {
                 unreachable!()
             }
@@ -1082,7 +1575,7 @@ impl Serialize for NumpyFloat16 {
         S: Serializer,
     {
         let as_f16 = half::f16::from_bits(self.value);
-        serializer.serialize_f32(as_f16.to_f32())
+        serializer.serialize_f64(as_f16.to_f64())
     }
 }
 
@@ -1187,6 +1680,7 @@ impl fmt::Display for NumpyDatetimeUnit {
 #[derive(Clone, Copy)]
 enum NumpyDateTimeError {
     UnsupportedUnit(NumpyDatetimeUnit),
+    UnsupportedTimedeltaUnit(NumpyDatetimeUnit),
     Unrepresentable { unit: NumpyDatetimeUnit, val: i64 },
 }
 
@@ -1195,6 +1689,9 @@ impl NumpyDateTimeError {
     fn into_serde_err<T: ser::Error>(self) -> T {
         let err = match self {
             Self::UnsupportedUnit(unit) => format!("unsupported numpy.datetime64 unit: {}", unit),
+            Self::UnsupportedTimedeltaUnit(unit) => {
+                format!("unsupported numpy.timedelta64 unit: {}", unit)
+            }
             Self::Unrepresentable { unit, val } => {
                 format!("unrepresentable numpy.datetime64: {} {}", val, unit)
             }
@@ -1217,11 +1714,22 @@ impl NumpyDatetimeUnit {
     #[cfg_attr(feature = "optimize", optimize(size))]
     fn from_pyobject(ptr: *mut PyObject) -> Self {
         let dtype = ffi!(PyObject_GetAttr(ptr, DTYPE_STR));
+        let ret = Self::from_dtype(dtype);
+        ffi!(Py_DECREF(dtype));
+        ret
+    }
+
+    /// Create a `NumpyDatetimeUnit` from a `numpy.dtype` of `datetime64` or
+    /// `timedelta64`.
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_dtype(dtype: *mut PyObject) -> Self {
         let descr = ffi!(PyObject_GetAttr(dtype, DESCR_STR));
         let el0 = ffi!(PyList_GET_ITEM(descr, 0));
         let descr_str = ffi!(PyTuple_GET_ITEM(el0, 1));
         let uni = crate::str::unicode_to_str(descr_str).unwrap();
         if uni.len() < 5 {
+            ffi!(Py_DECREF(descr));
             return Self::NaT;
         }
         // unit descriptions are found at
@@ -1243,7 +1751,6 @@ impl NumpyDatetimeUnit {
             "generic" => Self::Generic,
             _ => unreachable!(),
         };
-        ffi!(Py_DECREF(dtype));
         ffi!(Py_DECREF(descr));
         ret
     }
@@ -1254,63 +1761,89 @@ impl NumpyDatetimeUnit {
     #[cold]
     #[cfg_attr(feature = "optimize", optimize(size))]
     fn datetime(&self, val: i64, opts: Opt) -> Result<NumpyDatetime64Repr, NumpyDateTimeError> {
-        match self {
-            Self::Years => Ok(NaiveDate::from_ymd_opt(
-                (val + 1970)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-                1,
-                1,
-            )
-            .unwrap()
-            .and_hms_opt(0, 0, 0)
-            .unwrap()),
-            Self::Months => Ok(NaiveDate::from_ymd_opt(
-                (val / 12 + 1970)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-                (val % 12 + 1)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-                1,
-            )
-            .unwrap()
-            .and_hms_opt(0, 0, 0)
-            .unwrap()),
-            Self::Weeks => {
-                Ok(NaiveDateTime::from_timestamp_opt(val * 7 * 24 * 60 * 60, 0).unwrap())
+        // values before the epoch are negative, so use euclidean division to
+        // keep the fractional part positive
+        let dt = match self {
+            Self::Years => i32::try_from(val)
+                .ok()
+                .and_then(|val| val.checked_add(1970))
+                .and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1)),
+            Self::Months => i32::try_from(val.div_euclid(12) + 1970)
+                .ok()
+                .and_then(|year| NaiveDate::from_ymd_opt(year, val.rem_euclid(12) as u32 + 1, 1)),
+            Self::Weeks => return self.timestamp(val.checked_mul(7 * 24 * 60 * 60), 0, val, opts),
+            Self::Days => return self.timestamp(val.checked_mul(24 * 60 * 60), 0, val, opts),
+            Self::Hours => return self.timestamp(val.checked_mul(60 * 60), 0, val, opts),
+            Self::Minutes => return self.timestamp(val.checked_mul(60), 0, val, opts),
+            Self::Seconds => return self.timestamp(Some(val), 0, val, opts),
+            Self::Milliseconds => {
+                let nsecs = val.rem_euclid(1_000) as u32 * 1_000_000;
+                return self.timestamp(Some(val.div_euclid(1_000)), nsecs, val, opts);
             }
-            Self::Days => Ok(NaiveDateTime::from_timestamp_opt(val * 24 * 60 * 60, 0).unwrap()),
-            Self::Hours => Ok(NaiveDateTime::from_timestamp_opt(val * 60 * 60, 0).unwrap()),
-            Self::Minutes => Ok(NaiveDateTime::from_timestamp_opt(val * 60, 0).unwrap()),
-            Self::Seconds => Ok(NaiveDateTime::from_timestamp_opt(val, 0).unwrap()),
-            Self::Milliseconds => Ok(NaiveDateTime::from_timestamp_opt(
-                val / 1_000,
-                (val % 1_000 * 1_000_000)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-            )
-            .unwrap()),
-            Self::Microseconds => Ok(NaiveDateTime::from_timestamp_opt(
-                val / 1_000_000,
-                (val % 1_000_000 * 1_000)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-            )
-            .unwrap()),
-            Self::Nanoseconds => Ok(NaiveDateTime::from_timestamp_opt(
-                val / 1_000_000_000,
-                (val % 1_000_000_000)
-                    .try_into()
-                    .map_err(|_| NumpyDateTimeError::Unrepresentable { unit: *self, val })?,
-            )
-            .unwrap()),
-            _ => Err(NumpyDateTimeError::UnsupportedUnit(*self)),
+            Self::Microseconds => {
+                let nsecs = val.rem_euclid(1_000_000) as u32 * 1_000;
+                return self.timestamp(Some(val.div_euclid(1_000_000)), nsecs, val, opts);
+            }
+            Self::Nanoseconds => {
+                let nsecs = val.rem_euclid(1_000_000_000) as u32;
+                return self.timestamp(Some(val.div_euclid(1_000_000_000)), nsecs, val, opts);
+            }
+            _ => return Err(NumpyDateTimeError::UnsupportedUnit(*self)),
+        };
+        match dt {
+            Some(date) => Ok(NumpyDatetime64Repr {
+                dt: date.and_hms_opt(0, 0, 0).unwrap(),
+                opts,
+            }),
+            None => Err(NumpyDateTimeError::Unrepresentable { unit: *self, val }),
+        }
+    }
+
+    /// Return a `NumpyDatetime64Repr` for seconds since the epoch, where
+    /// `secs` is `None` if converting `val` to seconds overflowed.
+    #[cold]
+    fn timestamp(
+        &self,
+        secs: Option<i64>,
+        nsecs: u32,
+        val: i64,
+        opts: Opt,
+    ) -> Result<NumpyDatetime64Repr, NumpyDateTimeError> {
+        match secs.and_then(|secs| NaiveDateTime::from_timestamp_opt(secs, nsecs)) {
+            Some(dt) => Ok(NumpyDatetime64Repr { dt, opts }),
+            None => Err(NumpyDateTimeError::Unrepresentable { unit: *self, val }),
         }
-        .map(|dt| NumpyDatetime64Repr { dt, opts })
+    }
+
+    /// Return a `NumpyTimedelta64Repr` for a value in array with this unit.
+    ///
+    /// Years and months are not a fixed duration and so are unsupported.
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn timedelta(&self, val: i64, opts: Opt) -> Result<NumpyTimedelta64Repr, NumpyDateTimeError> {
+        let val = val as i128;
+        let (total, digits) = match self {
+            Self::Weeks => (val * 7 * 24 * 60 * 60, 0),
+            Self::Days => (val * 24 * 60 * 60, 0),
+            Self::Hours => (val * 60 * 60, 0),
+            Self::Minutes => (val * 60, 0),
+            Self::Seconds => (val, 0),
+            Self::Milliseconds => (val, 3),
+            Self::Microseconds => (val, 6),
+            Self::Nanoseconds => (val, 9),
+            _ => return Err(NumpyDateTimeError::UnsupportedTimedeltaUnit(*self)),
+        };
+        Ok(NumpyTimedelta64Repr {
+            total,
+            digits,
+            opts,
+        })
     }
 }
 
+/// numpy represents `NaT` as the minimum `int64` regardless of unit.
+const NUMPY_NAT: i64 = i64::MIN;
+
 struct NumpyDatetime64Array<'a> {
     data: &'a [i64],
     unit: NumpyDatetimeUnit,
@@ -1331,11 +1864,15 @@ impl<'a> Serialize for NumpyDatetime64Array<'a> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
+            if each == NUMPY_NAT {
+                seq.serialize_element(&NoneSerializer::new())?;
+                continue;
+            }
             let dt = self
                 .unit
                 .datetime(each, self.opts)
                 .map_err(NumpyDateTimeError::into_serde_err)?;
-            seq.serialize_element(&dt).unwrap();
+            seq.serialize_element(&dt)?;
         }
         seq.end()
     }
@@ -1348,6 +1885,70 @@ pub struct NumpyDatetime64 {
     value: i64,
 }
 
+struct NumpyTimedelta64Array<'a> {
+    data: &'a [i64],
+    unit: NumpyDatetimeUnit,
+    opts: Opt,
+}
+
+impl<'a> NumpyTimedelta64Array<'a> {
+    fn new(data: &'a [i64], unit: NumpyDatetimeUnit, opts: Opt) -> Self {
+        Self { data, unit, opts }
+    }
+}
+
+impl<'a> Serialize for NumpyTimedelta64Array<'a> {
+    #[cold]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for &each in self.data.iter() {
+            if each == NUMPY_NAT {
+                seq.serialize_element(&NoneSerializer::new())?;
+                continue;
+            }
+            let delta = self
+                .unit
+                .timedelta(each, self.opts)
+                .map_err(NumpyDateTimeError::into_serde_err)?;
+            seq.serialize_element(&delta)?;
+        }
+        seq.end()
+    }
+}
+
+#[repr(C)]
+pub struct NumpyTimedelta64 {
+    ob_refcnt: Py_ssize_t,
+    ob_type: *mut PyTypeObject,
+    value: i64,
+}
+
+/// A duration of `total` units of `10^-digits` seconds.
+struct NumpyTimedelta64Repr {
+    total: i128,
+    digits: u32,
+    opts: Opt,
+}
+
+impl Serialize for NumpyTimedelta64Repr {
+    #[cold]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(self.opts, TIMEDELTA_ISO8601) {
+            let mut buf = DateTimeBuffer::new();
+            write_duration(&mut buf, self.total, self.digits);
+            serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
+        } else {
+            serializer.serialize_f64(duration_seconds(self.total, self.digits))
+        }
+    }
+}
+
 macro_rules! forward_inner {
     ($meth: ident, $ty: ident) => {
         fn $meth(&self) -> $ty {
@@ -1394,7 +1995,10 @@ impl Serialize for NumpyDatetime64Repr {
         S: Serializer,
     {
         let mut buf = DateTimeBuffer::new();
-        let _ = self.write_buf(&mut buf, self.opts);
+        if let Err(err) = self.write_buf(&mut buf, self.opts) {
+            start_error_path();
+            err!(SerializeError::from(err))
+        }
         serializer.collect_str(str_from_slice!(buf.as_ptr(), buf.len()))
     }
 }
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::typeref::{load_pandas_types, PandasTypes, DATETIME_TYPE, NANOSECOND_STR, PANDAS_TYPES};
+use pyo3_ffi::PyTypeObject;
+
+#[cold]
+fn pandas_types(ob_type: *mut PyTypeObject) -> Option<&'static PandasTypes> {
+    // Both are subclasses of datetime.datetime. Checking this first means
+    // pandas is not looked up for arbitrary unknown types.
+    if ffi!(PyType_IsSubtype(ob_type, DATETIME_TYPE)) == 0 {
+        return None;
+    }
+    unsafe { (*core::ptr::addr_of!(PANDAS_TYPES)).get_or_try_init(load_pandas_types) }.ok()
+}
+
+/// Whether `ob_type` is `pandas.Timestamp` or a subclass. `pandas` is not
+/// imported; the types are looked up once it has been.
+#[cold]
+pub fn is_pandas_timestamp(ob_type: *mut PyTypeObject) -> bool {
+    match pandas_types(ob_type) {
+        Some(types) => ffi!(PyType_IsSubtype(ob_type, types.timestamp)) != 0,
+        None => false,
+    }
+}
+
+/// The `nanosecond` of a `pandas.Timestamp`, i.e., the nanoseconds since its
+/// `microsecond`, or 0 if it cannot be read.
+#[cold]
+pub fn pandas_timestamp_nanosecond(ptr: *mut pyo3_ffi::PyObject) -> u32 {
+    let val = ffi!(PyObject_GetAttr(ptr, NANOSECOND_STR));
+    if unlikely!(val.is_null()) {
+        ffi!(PyErr_Clear());
+        return 0;
+    }
+    let nanosecond = ffi!(PyLong_AsLong(val));
+    ffi!(Py_DECREF(val));
+    if unlikely!(!(0..1_000).contains(&nanosecond)) {
+        ffi!(PyErr_Clear());
+        return 0;
+    }
+    nanosecond as u32
+}
+
+/// Whether `ob_type` is the type of `pandas.NaT`.
+#[cold]
+pub fn is_pandas_nat(ob_type: *mut PyTypeObject) -> bool {
+    match pandas_types(ob_type) {
+        Some(types) => ob_type == types.nat,
+        None => false,
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::serialize::error::SerializeError;
+use crate::str::unicode_to_str;
+use crate::typeref::{load_pure_path_type, PURE_PATH_TYPE};
+use pyo3_ffi::PyTypeObject;
+
+use serde::ser::{Serialize, Serializer};
+
+/// Whether `ob_type` is `pathlib.PurePath` or a subclass, e.g., `Path`.
+/// `pathlib` is imported on first use.
+#[cold]
+pub fn is_path(ob_type: *mut PyTypeObject) -> bool {
+    let pure_path =
+        unsafe { (*core::ptr::addr_of!(PURE_PATH_TYPE)).get_or_init(load_pure_path_type) };
+    match pure_path {
+        Some(pure_path) => ffi!(PyType_IsSubtype(ob_type, pure_path.as_ptr())) != 0,
+        None => false,
+    }
+}
+
+#[repr(transparent)]
+pub struct PathSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+}
+
+impl PathSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject) -> Self {
+        PathSerializer { ptr: ptr }
+    }
+}
+
+impl Serialize for PathSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // str() is the system's path representation, so on Windows the
+        // separator is a backslash and is escaped as any other
+        let pystr = ffi!(PyObject_Str(self.ptr));
+        if unlikely!(pystr.is_null()) {
+            ffi!(PyErr_Clear());
+            err!(SerializeError::InvalidStr)
+        }
+        let ret = match unicode_to_str(pystr) {
+            Some(uni) => serializer.serialize_str(uni),
+            None => Err(serde::ser::Error::custom(SerializeError::InvalidStr)),
+        };
+        ffi!(Py_DECREF(pystr));
+        ret
+    }
+}
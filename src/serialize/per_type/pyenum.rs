@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+use crate::opt::ENUM_BY_NAME;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::StrSerializer;
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::typeref::VALUE_STR;
+use crate::typeref::{MEMBER_MAP_STR, NAME_STR, STR_TYPE, VALUE_STR};
 use serde::ser::{Serialize, Serializer};
 
 #[repr(transparent)]
@@ -21,6 +24,15 @@ impl<'a> Serialize for EnumSerializer<'a> {
     where
         S: Serializer,
     {
+        if unlikely!(opt_enabled!(self.previous.state.opts(), ENUM_BY_NAME)) {
+            let name = match enum_name(self.previous.ptr) {
+                Ok(name) => name,
+                Err(err) => err!(err),
+            };
+            let ret = StrSerializer::new(name).serialize(serializer);
+            ffi!(Py_DECREF(name));
+            return ret;
+        }
         let value = ffi!(PyObject_GetAttr(self.previous.ptr, VALUE_STR));
         debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
         let ret = PyObjectSerializer::new(value, self.previous.state, self.previous.default)
@@ -29,3 +41,38 @@ impl<'a> Serialize for EnumSerializer<'a> {
         ret
     }
 }
+
+/// Return a new reference to the `name` of an enum member.
+///
+/// Composite `Flag` values have no name before Python 3.11 and a name
+/// joining their members, e.g., `"A|B"`, afterward. Neither is in the
+/// class's `_member_map_` and both are rejected.
+#[cold]
+#[inline(never)]
+pub fn enum_name(ptr: *mut pyo3_ffi::PyObject) -> Result<*mut pyo3_ffi::PyObject, SerializeError> {
+    let name = ffi!(PyObject_GetAttr(ptr, NAME_STR));
+    if unlikely!(name.is_null()) {
+        ffi!(PyErr_Clear());
+        return Err(SerializeError::EnumNoName);
+    }
+    if unlikely!(!is_class_by_type!(ob_type!(name), STR_TYPE)) {
+        ffi!(Py_DECREF(name));
+        return Err(SerializeError::EnumNoName);
+    }
+    let member_map = ffi!(PyObject_GetAttr(
+        ob_type!(ptr) as *mut pyo3_ffi::PyObject,
+        MEMBER_MAP_STR
+    ));
+    if unlikely!(member_map.is_null()) {
+        ffi!(PyErr_Clear());
+        return Ok(name);
+    }
+    let contains = ffi!(PyDict_Contains(member_map, name));
+    ffi!(Py_DECREF(member_map));
+    if unlikely!(contains != 1) {
+        ffi!(PyErr_Clear());
+        ffi!(Py_DECREF(name));
+        return Err(SerializeError::EnumNoName);
+    }
+    Ok(name)
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::{RANGE_AS_ARRAY, SORT_KEYS};
+use crate::serialize::error::SerializeError;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::{error_at_index, error_at_key, formats, SerializerState};
+use crate::typeref::{RANGE_START_STR, RANGE_STEP_STR, RANGE_STOP_STR};
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// Serialize a `range` as an object of its `start`, `stop`, and `step` or,
+/// with `range_format="array"`, as an array of the integers it produces.
+pub struct RangeSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+}
+
+impl RangeSerializer {
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, state: SerializerState) -> Self {
+        RangeSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+        }
+    }
+}
+
+impl Serialize for RangeSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.state.recursion_limit()) {
+            err!(SerializeError::RecursionLimit)
+        }
+        if opt_enabled!(formats(), RANGE_AS_ARRAY) {
+            self.serialize_items(serializer)
+        } else {
+            let mut attrs = [
+                ("start", unsafe { RANGE_START_STR }),
+                ("stop", unsafe { RANGE_STOP_STR }),
+                ("step", unsafe { RANGE_STEP_STR }),
+            ];
+            if opt_enabled!(self.state.opts(), SORT_KEYS) {
+                attrs.swap(1, 2);
+            }
+            let mut map = serializer.serialize_map(None).unwrap();
+            for (key, attr) in attrs {
+                // the attributes of range are always int
+                let value = ffi!(PyObject_GetAttr(self.ptr, attr));
+                debug_assert!(!value.is_null());
+                map.serialize_key(key).unwrap();
+                let res = map
+                    .serialize_value(&PyObjectSerializer::new(value, self.state, None))
+                    .map_err(|err| error_at_key(err, key));
+                ffi!(Py_DECREF(value));
+                res?;
+            }
+            map.end()
+        }
+    }
+}
+
+impl RangeSerializer {
+    fn serialize_items<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let iter = ffi!(PyObject_GetIter(self.ptr));
+        debug_assert!(!iter.is_null());
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        let mut idx = 0;
+        loop {
+            let item = ffi!(PyIter_Next(iter));
+            if item.is_null() {
+                break;
+            }
+            let res = seq
+                .serialize_element(&PyObjectSerializer::new(item, self.state, None))
+                .map_err(|err| error_at_index(err, idx));
+            ffi!(Py_DECREF(item));
+            if let Err(err) = res {
+                ffi!(Py_DECREF(iter));
+                return Err(err);
+            }
+            idx += 1;
+        }
+        ffi!(Py_DECREF(iter));
+        seq.end()
+    }
+}
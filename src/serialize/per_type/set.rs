@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::opt::SORT_KEYS;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::ZeroListSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::SerializerState;
+use crate::serialize::writer::SORTED_SEQ;
+
+use core::ptr::NonNull;
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use smallvec::SmallVec;
+
+pub struct SetSerializer {
+    ptr: *mut pyo3_ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl SetSerializer {
+    pub fn new(
+        ptr: *mut pyo3_ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<pyo3_ffi::PyObject>>,
+    ) -> Self {
+        SetSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for SetSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if unlikely!(self.state.recursion_limit()) {
+            err!(SerializeError::RecursionLimit)
+        }
+        let len = ffi!(PyObject_Size(self.ptr)) as usize;
+        if len == 0 {
+            return ZeroListSerializer::new().serialize(serializer);
+        }
+
+        // take references to all elements before serializing any so that
+        // default cannot invalidate the iterator by mutating the set
+        let mut items: SmallVec<[*mut pyo3_ffi::PyObject; 8]> = SmallVec::with_capacity(len);
+        let iter = ffi!(PyObject_GetIter(self.ptr));
+        loop {
+            let item = ffi!(PyIter_Next(iter));
+            if item.is_null() {
+                break;
+            }
+            items.push(item);
+        }
+        ffi!(Py_DECREF(iter));
+
+        let elements = SetElements {
+            items: &items,
+            state: self.state,
+            default: self.default,
+        };
+        let ret = if opt_enabled!(self.state.opts(), SORT_KEYS) {
+            // ordered by the serialized form of each element
+            serializer.serialize_newtype_struct(SORTED_SEQ, &elements)
+        } else {
+            elements.serialize(serializer)
+        };
+        for &item in items.iter() {
+            ffi!(Py_DECREF(item));
+        }
+        ret
+    }
+}
+
+struct SetElements<'a> {
+    items: &'a [*mut pyo3_ffi::PyObject],
+    state: SerializerState,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+}
+
+impl Serialize for SetElements<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for &item in self.items.iter() {
+            seq.serialize_element(&PyObjectSerializer::new(item, self.state, self.default))?;
+        }
+        seq.end()
+    }
+}
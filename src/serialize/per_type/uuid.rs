@@ -1,5 +1,9 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+use crate::opt::{Opt, STRICT_INTEGER, UUID_AS_BASE64, UUID_AS_INT, UUID_BASE64_URLSAFE};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::{serialize_base64, STRICT_INT_MAX};
+use crate::serialize::state::formats;
 use crate::typeref::INT_ATTR_STR;
 use core::ffi::c_uchar;
 use serde::ser::{Serialize, Serializer};
@@ -7,36 +11,40 @@ use std::io::Write;
 
 pub type UUIDBuffer = arrayvec::ArrayVec<u8, 36>;
 
-#[repr(transparent)]
 pub struct UUID {
     ptr: *mut pyo3_ffi::PyObject,
+    opts: Opt,
 }
 
 impl UUID {
-    pub fn new(ptr: *mut pyo3_ffi::PyObject) -> Self {
-        UUID { ptr: ptr }
+    pub fn new(ptr: *mut pyo3_ffi::PyObject, opts: Opt) -> Self {
+        UUID {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+
+    fn value(&self) -> u128 {
+        // test_uuid_immutable, test_uuid_int
+        let py_int = ffi!(PyObject_GetAttr(self.ptr, INT_ATTR_STR));
+        ffi!(Py_DECREF(py_int));
+        let buffer: [c_uchar; 16] = [0; 16];
+        unsafe {
+            // test_uuid_overflow
+            pyo3_ffi::_PyLong_AsByteArray(
+                py_int as *mut pyo3_ffi::PyLongObject,
+                buffer.as_ptr() as *mut c_uchar,
+                16,
+                1, // little_endian
+                0, // is_signed
+            )
+        };
+        u128::from_le_bytes(buffer)
     }
 
     #[inline(never)]
     pub fn write_buf(&self, buf: &mut UUIDBuffer) {
-        let value: u128;
-        {
-            // test_uuid_immutable, test_uuid_int
-            let py_int = ffi!(PyObject_GetAttr(self.ptr, INT_ATTR_STR));
-            ffi!(Py_DECREF(py_int));
-            let buffer: [c_uchar; 16] = [0; 16];
-            unsafe {
-                // test_uuid_overflow
-                pyo3_ffi::_PyLong_AsByteArray(
-                    py_int as *mut pyo3_ffi::PyLongObject,
-                    buffer.as_ptr() as *mut c_uchar,
-                    16,
-                    1, // little_endian
-                    0, // is_signed
-                )
-            };
-            value = u128::from_le_bytes(buffer);
-        }
+        let value = self.value();
 
         let mut hexadecimal = arrayvec::ArrayVec::<u8, 32>::new();
         write!(hexadecimal, "{:032x}", value).unwrap();
@@ -58,6 +66,18 @@ impl Serialize for UUID {
     where
         S: Serializer,
     {
+        let formats = formats();
+        if unlikely!(opt_enabled!(formats, UUID_AS_INT)) {
+            let value = self.value();
+            if unlikely!(opt_enabled!(self.opts, STRICT_INTEGER)) && value > STRICT_INT_MAX as u128
+            {
+                err!(SerializeError::Integer53Bits)
+            }
+            return serializer.serialize_u128(value);
+        } else if unlikely!(opt_enabled!(formats, UUID_AS_BASE64)) {
+            let urlsafe = opt_enabled!(formats, UUID_BASE64_URLSAFE);
+            return serialize_base64(serializer, &self.value().to_be_bytes(), urlsafe);
+        }
         let mut buf = arrayvec::ArrayVec::<u8, 36>::new();
         self.write_buf(&mut buf);
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
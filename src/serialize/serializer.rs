@@ -1,15 +1,25 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
-use crate::opt::{Opt, APPEND_NEWLINE, INDENT_2, STRICT_INTEGER};
+use crate::opt::{Opt, APPEND_NEWLINE, INDENT_ANY, STRICT_INTEGER, UTF8_BOM};
+use crate::serialize::error::SerializeError;
 use crate::serialize::obtype::{pyobject_to_obtype, ObType};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, Int53Serializer,
-    IntSerializer, ListTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer,
-    StrSerializer, StrSubclassSerializer, Time, ZeroListSerializer, UUID,
+    ArraySerializer, BoolSerializer, BytesSerializer, ComplexSerializer,
+    DataclassGenericSerializer, Date, DateTime, DecimalSerializer, DefaultSerializer,
+    DictGenericSerializer, EnumSerializer, ExceptionSerializer, FloatSerializer,
+    FractionSerializer, FragmentSerializer, Int53Serializer, IntSerializer, IpAddressSerializer,
+    IteratorSerializer, ListTupleSerializer, NamedTupleSerializer, NoneSerializer, NumpyScalar,
+    NumpySerializer, PathSerializer, RangeSerializer, SetSerializer, StrSerializer,
+    StrSubclassSerializer, Time, Timedelta, TracebackSerializer, ZeroListSerializer, UUID,
 };
-use crate::serialize::state::SerializerState;
-use crate::serialize::writer::{to_writer, to_writer_pretty, BytesWriter};
+use crate::serialize::state::{
+    error_at_index, set_exception_raised, take_error_path, SerializerState, RECURSION_LIMIT,
+};
+use crate::serialize::writer::{
+    to_writer, to_writer_array_element, to_writer_pretty, to_writer_pretty_array_element,
+    ByteArrayWriter, BytesWriter,
+};
+use crate::str::unicode_from_str;
 use core::ptr::NonNull;
 use serde::ser::{Serialize, Serializer};
 use std::io::Write;
@@ -18,13 +28,15 @@ pub fn serialize(
     ptr: *mut pyo3_ffi::PyObject,
     default: Option<NonNull<pyo3_ffi::PyObject>>,
     opts: Opt,
+    max_depth: u16,
 ) -> Result<NonNull<pyo3_ffi::PyObject>, String> {
     let mut buf = BytesWriter::default();
-    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts), default);
-    let res = if opt_disabled!(opts, INDENT_2) {
-        to_writer(&mut buf, &obj)
+    write_bom(&mut buf, opts);
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts, max_depth), default);
+    let res = if opt_disabled!(opts, INDENT_ANY) {
+        to_writer(&mut buf, &obj, opts)
     } else {
-        to_writer_pretty(&mut buf, &obj)
+        to_writer_pretty(&mut buf, &obj, opts)
     };
     match res {
         Ok(_) => {
@@ -34,12 +46,254 @@ pub fn serialize(
             Ok(buf.finish())
         }
         Err(err) => {
+            let msg = error_message(err, buf.written());
+            ffi!(_Py_Dealloc(buf.bytes_ptr().as_ptr()));
+            Err(msg)
+        }
+    }
+}
+
+/// Serialize `ptr` to a `str`. The output is known to be valid UTF-8, so the
+/// `str` is created from it without decoding.
+pub fn serialize_str(
+    ptr: *mut pyo3_ffi::PyObject,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+    opts: Opt,
+    max_depth: u16,
+) -> Result<NonNull<pyo3_ffi::PyObject>, String> {
+    let mut buf = BytesWriter::default();
+    write_bom(&mut buf, opts);
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts, max_depth), default);
+    let res = if opt_disabled!(opts, INDENT_ANY) {
+        to_writer(&mut buf, &obj, opts)
+    } else {
+        to_writer_pretty(&mut buf, &obj, opts)
+    };
+    let ret = match res {
+        Ok(_) => {
+            if opt_enabled!(opts, APPEND_NEWLINE) {
+                let _ = buf.write(b"\n");
+            }
+            Ok(nonnull!(unicode_from_str(buf.as_str())))
+        }
+        Err(err) => Err(error_message(err, buf.written())),
+    };
+    ffi!(_Py_Dealloc(buf.bytes_ptr().as_ptr()));
+    ret
+}
+
+/// Serialize `ptr` by appending to the `bytearray` `buffer`, returning the
+/// number of bytes written.
+pub fn serialize_into(
+    ptr: *mut pyo3_ffi::PyObject,
+    buffer: *mut pyo3_ffi::PyObject,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+    opts: Opt,
+    max_depth: u16,
+) -> Result<usize, String> {
+    let mut buf = ByteArrayWriter::new(buffer);
+    write_bom(&mut buf, opts);
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts, max_depth), default);
+    let res = if opt_disabled!(opts, INDENT_ANY) {
+        to_writer(&mut buf, &obj, opts)
+    } else {
+        to_writer_pretty(&mut buf, &obj, opts)
+    };
+    match res {
+        Ok(_) => {
+            if opt_enabled!(opts, APPEND_NEWLINE) {
+                let _ = buf.write(b"\n");
+            }
+            buf.finish()
+                .ok_or_else(|| String::from("dumps_into() could not resize buffer"))
+        }
+        Err(err) => {
+            let msg = error_message(err, buf.written());
+            buf.abort();
+            Err(msg)
+        }
+    }
+}
+
+/// Serialize each element of the iterable `ptr` as a JSON document on its own
+/// line, i.e., newline-delimited JSON. Each element is serialized as by
+/// `serialize()`, with its own recursion limit, and the path of an error
+/// begins with the index of the element.
+pub fn serialize_lines(
+    ptr: *mut pyo3_ffi::PyObject,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+    opts: Opt,
+    max_depth: u16,
+) -> Result<NonNull<pyo3_ffi::PyObject>, String> {
+    let iter = ffi!(PyObject_GetIter(ptr));
+    if unlikely!(iter.is_null()) {
+        // the TypeError is chained as the cause
+        return Err(String::from("dumps_lines() obj must be iterable"));
+    }
+    let mut buf = BytesWriter::default();
+    let mut res = Ok(());
+    let mut idx = 0;
+    loop {
+        let item = ffi!(PyIter_Next(iter));
+        if item.is_null() {
+            break;
+        }
+        if idx == 0 {
+            write_bom(&mut buf, opts);
+        } else {
+            let _ = buf.write(b"\n");
+        }
+        let obj = PyObjectSerializer::new(item, SerializerState::new(opts, max_depth), default);
+        res = to_writer(&mut buf, &obj, opts).map_err(|err| error_at_index(err, idx));
+        ffi!(Py_DECREF(item));
+        if res.is_err() {
+            break;
+        }
+        idx += 1;
+    }
+    ffi!(Py_DECREF(iter));
+    if res.is_ok() && unlikely!(!ffi!(PyErr_Occurred()).is_null()) {
+        set_exception_raised();
+        res = Err(serde::ser::Error::custom(SerializeError::IteratorRaised));
+    }
+    match res {
+        Ok(_) => {
+            if opt_enabled!(opts, APPEND_NEWLINE) && idx > 0 {
+                let _ = buf.write(b"\n");
+            }
+            Ok(buf.finish())
+        }
+        Err(err) => {
+            let msg = error_message(err, buf.written());
+            ffi!(_Py_Dealloc(buf.bytes_ptr().as_ptr()));
+            Err(msg)
+        }
+    }
+}
+
+/// Serialize `ptr` as an element of a top-level array that is written across
+/// calls. The output begins with the opening bracket if `first` and with a
+/// separator otherwise.
+pub fn serialize_array_element(
+    ptr: *mut pyo3_ffi::PyObject,
+    default: Option<NonNull<pyo3_ffi::PyObject>>,
+    opts: Opt,
+    first: bool,
+) -> Result<NonNull<pyo3_ffi::PyObject>, String> {
+    let mut buf = BytesWriter::default();
+    if first {
+        write_bom(&mut buf, opts);
+    }
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts, RECURSION_LIMIT), default);
+    let res = if opt_disabled!(opts, INDENT_ANY) {
+        to_writer_array_element(&mut buf, &obj, opts, first)
+    } else {
+        to_writer_pretty_array_element(&mut buf, &obj, opts, first)
+    };
+    match res {
+        Ok(_) => Ok(buf.finish()),
+        Err(err) => {
+            let msg = error_message(err, buf.written());
             ffi!(_Py_Dealloc(buf.bytes_ptr().as_ptr()));
-            Err(err.to_string())
+            Err(msg)
         }
     }
 }
 
+/// Close a top-level array written by `serialize_array_element()`.
+pub fn serialize_array_end(opts: Opt, empty: bool) -> NonNull<pyo3_ffi::PyObject> {
+    let mut buf = BytesWriter::default();
+    let _ = if empty {
+        write_bom(&mut buf, opts);
+        buf.write(b"[]")
+    } else if opt_disabled!(opts, INDENT_ANY) {
+        buf.write(b"]")
+    } else {
+        buf.write(b"\n]")
+    };
+    if opt_enabled!(opts, APPEND_NEWLINE) {
+        let _ = buf.write(b"\n");
+    }
+    buf.finish()
+}
+
+/// Begin the output with the UTF-8 byte order mark if `OPT_UTF8_BOM`.
+#[inline(always)]
+fn write_bom<W: Write>(buf: &mut W, opts: Opt) {
+    if unlikely!(opt_enabled!(opts, UTF8_BOM)) {
+        let _ = buf.write(b"\xef\xbb\xbf");
+    }
+}
+
+/// The message of a serialization error, with the path to the value that
+/// caused it if it was recorded and the length of the output when it occurred,
+/// if any was written.
+#[cold]
+#[inline(never)]
+fn error_message(err: impl core::fmt::Display, written: usize) -> String {
+    let msg = match take_error_path() {
+        Some(path) => {
+            add_path_note(&path);
+            format!("{} at {}", err, path)
+        }
+        None => err.to_string(),
+    };
+    if written == 0 {
+        msg
+    } else {
+        format!("{} (output byte {})", msg, written)
+    }
+}
+
+/// Add a note with `path` to the exception that is set, if any, e.g., one
+/// raised by `default`, so that its traceback identifies the value.
+#[cfg(Py_3_12)]
+#[cold]
+fn add_path_note(path: &str) {
+    unsafe {
+        let exc = pyo3_ffi::PyErr_GetRaisedException();
+        if exc.is_null() {
+            return;
+        }
+        add_note(exc, path);
+        pyo3_ffi::PyErr_SetRaisedException(exc);
+    }
+}
+
+#[cfg(all(Py_3_11, not(Py_3_12)))]
+#[cold]
+fn add_path_note(path: &str) {
+    unsafe {
+        let mut tp: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        let mut val: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        let mut traceback: *mut pyo3_ffi::PyObject = core::ptr::null_mut();
+        pyo3_ffi::PyErr_Fetch(&mut tp, &mut val, &mut traceback);
+        if tp.is_null() {
+            return;
+        }
+        pyo3_ffi::PyErr_NormalizeException(&mut tp, &mut val, &mut traceback);
+        add_note(val, path);
+        pyo3_ffi::PyErr_Restore(tp, val, traceback);
+    }
+}
+
+#[cfg(not(Py_3_11))]
+fn add_path_note(_path: &str) {}
+
+/// Call `exc.add_note()`, ignoring an error, as there is already one to raise.
+#[cfg(Py_3_11)]
+fn add_note(exc: *mut pyo3_ffi::PyObject, path: &str) {
+    let note = format!("orjson: raised while serializing the value at {}", path);
+    let note = unicode_from_str(&note);
+    let ret = call_method!(exc, crate::typeref::ADD_NOTE_STR, note);
+    ffi!(Py_DECREF(note));
+    if ret.is_null() {
+        ffi!(PyErr_Clear());
+    } else {
+        ffi!(Py_DECREF(ret));
+    }
+}
+
 pub struct PyObjectSerializer {
     pub ptr: *mut pyo3_ffi::PyObject,
     pub state: SerializerState,
@@ -81,7 +335,7 @@ impl Serialize for PyObjectSerializer {
             ObType::Datetime => DateTime::new(self.ptr, self.state.opts()).serialize(serializer),
             ObType::Date => Date::new(self.ptr).serialize(serializer),
             ObType::Time => Time::new(self.ptr, self.state.opts()).serialize(serializer),
-            ObType::Uuid => UUID::new(self.ptr).serialize(serializer),
+            ObType::Uuid => UUID::new(self.ptr, self.state.opts()).serialize(serializer),
             ObType::Dict => {
                 DictGenericSerializer::new(self.ptr, self.state, self.default).serialize(serializer)
             }
@@ -107,7 +361,43 @@ impl Serialize for PyObjectSerializer {
             ObType::NumpyScalar => {
                 NumpyScalar::new(self.ptr, self.state.opts()).serialize(serializer)
             }
-            ObType::Fragment => FragmentSerializer::new(self.ptr).serialize(serializer),
+            ObType::Fragment => {
+                FragmentSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Decimal => {
+                DecimalSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Set => {
+                SetSerializer::new(self.ptr, self.state, self.default).serialize(serializer)
+            }
+            ObType::Path => PathSerializer::new(self.ptr).serialize(serializer),
+            ObType::IpAddress => IpAddressSerializer::new(self.ptr).serialize(serializer),
+            ObType::Timedelta => Timedelta::new(self.ptr, self.state.opts()).serialize(serializer),
+            ObType::Array => {
+                ArraySerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Bytes => {
+                BytesSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Complex => {
+                ComplexSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Fraction => {
+                FractionSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+            }
+            ObType::Iterator => {
+                IteratorSerializer::new(self.ptr, self.state, self.default).serialize(serializer)
+            }
+            ObType::NamedTuple => {
+                NamedTupleSerializer::new(self.ptr, self.state, self.default).serialize(serializer)
+            }
+            ObType::Range => RangeSerializer::new(self.ptr, self.state).serialize(serializer),
+            ObType::Exception => {
+                ExceptionSerializer::new(self.ptr, self.state, self.default).serialize(serializer)
+            }
+            ObType::Traceback => {
+                TracebackSerializer::new(self.ptr, self.state).serialize(serializer)
+            }
             ObType::Unknown => DefaultSerializer::new(self).serialize(serializer),
         }
     }
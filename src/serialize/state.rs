@@ -2,58 +2,360 @@
 
 use crate::opt::*;
 
-const RECURSION_SHIFT: usize = 24;
-const RECURSION_MASK: u32 = 255 << RECURSION_SHIFT;
+/// The default limit of nested containers, e.g., `list` and `dict`, which is
+/// the same as before `max_depth`, i.e., a container nested 255 levels deep
+/// raises.
+pub const RECURSION_LIMIT: u16 = 254;
+/// The maximum `max_depth`, which is what the bits of `RECURSION_BITS` can
+/// count to. Serializing recurses, so this also stays well within the stack
+/// of the main thread.
+pub const MAX_RECURSION_LIMIT: u16 = 2046;
 
-const DEFAULT_SHIFT: usize = 16;
-const DEFAULT_MASK: u32 = 255 << DEFAULT_SHIFT;
+// The counters of `SerializerState` are kept in the bits of the options that
+// the writer or only loads() read, as these are not read from the state.
+// Each counter's bits need not be contiguous: incrementing carries across
+// the bits that are not in it.
+const RECURSION_BITS: Opt = ALLOW_TRAILING_COMMA
+    | INTERN_KEYS
+    | PARSE_UUID
+    | SPACE_AFTER_COLON
+    | SPACE_AFTER_COMMA
+    | FLOAT_FIXED
+    | UTF8_BOM
+    | KEYS_AS_BYTES
+    | WHOLE_FLOAT_AS_INT
+    | CAPITALIZE_BOOL
+    | NULL_AS_NIL;
+const RECURSION_MAX: u16 = (1 << RECURSION_BITS.count_ones()) - 1;
+
+const DEFAULT_BITS: Opt = INDENT_2
+    | APPEND_NEWLINE
+    | ASCII
+    | ESCAPE_FORWARD_SLASH
+    | INDENT_4
+    | INDENT_TAB
+    | PARSE_DECIMAL
+    | ALLOW_COMMENTS;
+
+const COUNTER_BITS: Opt = RECURSION_BITS | DEFAULT_BITS;
 
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 pub struct SerializerState {
-    // recursion: u8,
-    // default_calls: u8,
-    // opts: u16,
-    state: u32,
+    // opts: Opt, except COUNTER_BITS,
+    // recursion: RECURSION_BITS, counting up to all set,
+    // default_calls: DEFAULT_BITS, counting up to all set,
+    state: Opt,
 }
 
 impl SerializerState {
     #[inline(always)]
-    pub fn new(opts: Opt) -> Self {
-        debug_assert!(opts < u16::MAX as u32);
-        Self { state: opts }
+    pub fn new(opts: Opt, recursion_limit: u16) -> Self {
+        debug_assert!(recursion_limit <= MAX_RECURSION_LIMIT);
+        let recursion = deposit(recursion_start(recursion_limit), RECURSION_BITS);
+        Self {
+            state: (opts & !COUNTER_BITS) | recursion,
+        }
     }
 
     #[inline(always)]
-    pub fn opts(&self) -> u32 {
-        self.state
+    pub fn opts(&self) -> Opt {
+        self.state & !COUNTER_BITS
     }
 
     #[inline(always)]
     pub fn recursion_limit(&self) -> bool {
-        self.state & RECURSION_MASK == RECURSION_MASK
+        // one branch for both, so counting depth costs nothing without it
+        if unlikely!(
+            (self.state & RECURSION_BITS == RECURSION_BITS) | opt_enabled!(self.state, STATS)
+        ) {
+            self.recursion_limit_with_stats()
+        } else {
+            false
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn recursion_limit_with_stats(&self) -> bool {
+        if opt_enabled!(self.state, STATS) {
+            count_depth(extract(self.state, RECURSION_BITS));
+        }
+        self.state & RECURSION_BITS == RECURSION_BITS
     }
 
     #[inline(always)]
     pub fn default_calls_limit(&self) -> bool {
-        self.state & DEFAULT_MASK == DEFAULT_MASK
+        self.state & DEFAULT_BITS == DEFAULT_BITS
     }
 
     #[inline(always)]
     pub fn copy_for_recursive_call(&self) -> Self {
-        let opt = self.state & !RECURSION_MASK;
-        let recursion = (((self.state & RECURSION_MASK) >> RECURSION_SHIFT) + 1) << RECURSION_SHIFT;
         Self {
-            state: opt | recursion,
+            state: increment(self.state, RECURSION_BITS),
         }
     }
 
     #[inline(always)]
     pub fn copy_for_default_call(&self) -> Self {
-        let opt = self.state & !DEFAULT_MASK;
-        let default_calls = (((self.state & DEFAULT_MASK) >> DEFAULT_SHIFT) + 1) << DEFAULT_SHIFT;
         Self {
-            state: opt | default_calls,
+            state: increment(self.state, DEFAULT_BITS),
+        }
+    }
+}
+
+/// The recursion count of the top-level object, so that the count is all set
+/// at the container nested `recursion_limit + 1` levels deep.
+#[inline(always)]
+fn recursion_start(recursion_limit: u16) -> u16 {
+    RECURSION_MAX - recursion_limit - 1
+}
+
+/// Add one to the count in the bits of `mask` of `state` unless all are set.
+/// It stays all set, rather than wrapping, as not every count is checked,
+/// e.g., a dataclass's fields are counted one level deeper than its own.
+#[inline(always)]
+fn increment(state: Opt, mask: Opt) -> Opt {
+    if state & mask == mask {
+        state
+    } else {
+        (state & !mask) | ((state | !mask).wrapping_add(1) & mask)
+    }
+}
+
+/// Spread the low bits of `val` over the bits of `mask`, lowest first.
+#[inline]
+fn deposit(val: u16, mask: Opt) -> Opt {
+    let mut ret: Opt = 0;
+    let mut bits = mask;
+    let mut val = val;
+    while bits != 0 {
+        if val & 1 == 1 {
+            ret |= bits & bits.wrapping_neg();
+        }
+        val >>= 1;
+        bits &= bits - 1;
+    }
+    ret
+}
+
+/// Gather the bits of `mask` of `state` into the low bits, lowest first.
+fn extract(state: Opt, mask: Opt) -> u16 {
+    let mut ret: u16 = 0;
+    let mut bits = mask;
+    let mut idx = 0;
+    while bits != 0 {
+        if state & bits & bits.wrapping_neg() != 0 {
+            ret |= 1 << idx;
+        }
+        idx += 1;
+        bits &= bits - 1;
+    }
+    ret
+}
+
+/// Counts of what one call of `dumps_with_stats()` did.
+#[derive(Copy, Clone, Default)]
+pub struct Stats {
+    /// `str` values and keys written as they are.
+    pub fast_str_writes: usize,
+    /// `str` values and keys that needed escaping.
+    pub slow_str_writes: usize,
+    /// The deepest nesting of containers, as limited by `max_depth`.
+    pub max_depth: u16,
+    /// Calls of `default`.
+    pub default_calls: usize,
+}
+
+std::thread_local! {
+    /// The counts of the call with `OPT_STATS`, an option that only
+    /// `dumps_with_stats()` sets. These are kept here, as `SerializerState`
+    /// is copied into every container and its copies cannot be summed.
+    static STATS_COUNTS: core::cell::Cell<Stats> = const {
+        core::cell::Cell::new(Stats {
+            fast_str_writes: 0,
+            slow_str_writes: 0,
+            max_depth: 0,
+            default_calls: 0,
+        })
+    };
+}
+
+/// Call `f` and return what serializing with `OPT_STATS` and
+/// `recursion_limit` in it counted.
+pub fn with_stats<T, F>(recursion_limit: u16, f: F) -> (T, Stats)
+where
+    F: FnOnce() -> T,
+{
+    let previous = STATS_COUNTS.with(|stats| stats.replace(Stats::default()));
+    let ret = f();
+    let mut stats = STATS_COUNTS.with(|stats| stats.replace(previous));
+    // counted as the recursion count, which starts above 0
+    stats.max_depth = stats
+        .max_depth
+        .saturating_sub(recursion_start(recursion_limit));
+    (ret, stats)
+}
+
+/// Count a `str` written with `OPT_STATS`, as is or, if `escaped`, not.
+#[cold]
+pub fn count_str(escaped: bool) {
+    STATS_COUNTS.with(|stats| {
+        let mut val = stats.get();
+        if escaped {
+            val.slow_str_writes += 1;
+        } else {
+            val.fast_str_writes += 1;
+        }
+        stats.set(val);
+    });
+}
+
+/// Count a container at the recursion count `depth` with `OPT_STATS`.
+#[cold]
+fn count_depth(depth: u16) {
+    STATS_COUNTS.with(|stats| {
+        let mut val = stats.get();
+        val.max_depth = val.max_depth.max(depth);
+        stats.set(val);
+    });
+}
+
+/// Count a call of `default` with `OPT_STATS`.
+#[cold]
+pub fn count_default_call() {
+    STATS_COUNTS.with(|stats| {
+        let mut val = stats.get();
+        val.default_calls += 1;
+        stats.set(val);
+    });
+}
+
+std::thread_local! {
+    /// The `*_format` arguments of the call serializing.
+    static FORMATS: core::cell::Cell<Format> = const { core::cell::Cell::new(0) };
+}
+
+/// Call `f` with types written as `formats`, the `*_format` arguments.
+pub fn with_formats<T, F>(formats: Format, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = FORMATS.with(|current| current.replace(formats));
+    let ret = f();
+    FORMATS.with(|current| current.set(previous));
+    ret
+}
+
+/// The `*_format` arguments of the call serializing.
+#[inline]
+pub fn formats() -> Format {
+    FORMATS.with(|current| current.get())
+}
+
+/// A key or index on the path to a value that could not be serialized.
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+std::thread_local! {
+    /// The path to a value that could not be serialized, innermost first.
+    ///
+    /// `SerializerState` is copied into every container, so the path is kept
+    /// here rather than in it. It is `None` unless an error that reports its
+    /// path is propagating, so serializing successfully never touches it.
+    static ERROR_PATH: core::cell::RefCell<Option<Vec<PathSegment>>> =
+        const { core::cell::RefCell::new(None) };
+
+    /// Whether the error is an exception raised by Python code that is to be
+    /// propagated as it is rather than as the cause of `JSONEncodeError`.
+    static EXCEPTION_RAISED: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Propagate the exception that is set, e.g., raised by `__json__`, rather
+/// than raising `JSONEncodeError` for the error that is about to be returned.
+#[cold]
+pub fn set_exception_raised() {
+    EXCEPTION_RAISED.with(|raised| raised.set(true));
+}
+
+/// Whether the exception that is set is to be propagated, resetting it.
+#[cold]
+pub fn take_exception_raised() -> bool {
+    EXCEPTION_RAISED.with(|raised| raised.replace(false))
+}
+
+/// Begin recording the path of the error that is about to be returned.
+#[cold]
+pub fn start_error_path() {
+    ERROR_PATH.with(|path| *path.borrow_mut() = Some(Vec::new()));
+}
+
+/// Record that `err` occurred in the value of `key`.
+#[cold]
+#[inline(never)]
+pub fn error_at_key<E>(err: E, key: &str) -> E {
+    ERROR_PATH.with(|path| {
+        if let Some(path) = path.borrow_mut().as_mut() {
+            path.push(PathSegment::Key(String::from(key)));
+        }
+    });
+    err
+}
+
+/// Record that `err` occurred in the element at `idx`.
+#[cold]
+#[inline(never)]
+pub fn error_at_index<E>(err: E, idx: usize) -> E {
+    ERROR_PATH.with(|path| {
+        if let Some(path) = path.borrow_mut().as_mut() {
+            path.push(PathSegment::Index(idx));
+        }
+    });
+    err
+}
+
+/// Stop recording and return the path, e.g., `$.items[42].name`, if the
+/// error was not in the top-level object. A key that is not an identifier is
+/// written as a quoted JSON string in brackets, e.g., `$["a.b"][0]`, so that
+/// the path is not ambiguous.
+#[cold]
+pub fn take_error_path() -> Option<String> {
+    let segments = ERROR_PATH.with(|path| path.borrow_mut().take())?;
+    if segments.is_empty() {
+        return None;
+    }
+    let mut buf = String::from("$");
+    for segment in segments.iter().rev() {
+        match segment {
+            PathSegment::Key(key) if is_identifier(key) => {
+                buf.push('.');
+                buf.push_str(key);
+            }
+            PathSegment::Key(key) => {
+                buf.push_str("[\"");
+                for ch in key.chars() {
+                    match ch {
+                        '"' => buf.push_str("\\\""),
+                        '\\' => buf.push_str("\\\\"),
+                        '\u{0}'..='\u{1f}' => buf.push_str(&format!("\\u{:04x}", ch as u32)),
+                        _ => buf.push(ch),
+                    }
+                }
+                buf.push_str("\"]");
+            }
+            PathSegment::Index(idx) => {
+                buf.push_str(&format!("[{}]", idx));
+            }
         }
     }
+    Some(buf)
+}
+
+/// Whether `key` is an ASCII identifier, i.e., can follow a `.` in a path.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some('a'..='z' | 'A'..='Z' | '_'))
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
 }
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use crate::serialize::writer::WriteExt;
+use pyo3_ffi::{PyByteArrayObject, PyByteArray_Resize, PyErr_Clear, PyObject};
+use std::io::Error;
+
+const BUFFER_LENGTH: usize = 1024;
+
+/// Append to a `bytearray` owned by the caller.
+///
+/// The `bytearray` is marked as exported for the duration so that Python code
+/// run during serialization, e.g., `default`, cannot resize it and invalidate
+/// `data`. If it cannot be resized when growing, e.g., because a `memoryview`
+/// of it exists, output is discarded into `spill` and `finish()` fails.
+pub struct ByteArrayWriter {
+    bytearray: *mut PyByteArrayObject,
+    start: usize,
+    len: usize,
+    cap: usize,
+    data: *mut u8,
+    spill: Vec<u8>,
+    discarded: usize,
+    failed: bool,
+}
+
+impl ByteArrayWriter {
+    pub fn new(ptr: *mut PyObject) -> Self {
+        let bytearray = ptr as *mut PyByteArrayObject;
+        let start = ffi!(Py_SIZE(ptr)) as usize;
+        unsafe {
+            (*bytearray).ob_exports += 1;
+            ByteArrayWriter {
+                bytearray: bytearray,
+                start: start,
+                len: start,
+                cap: start,
+                data: (*bytearray).ob_start as *mut u8,
+                spill: Vec::new(),
+                discarded: 0,
+                failed: false,
+            }
+        }
+    }
+
+    /// Truncate the `bytearray` to the written length and return the number of
+    /// bytes appended, or `None` if it could not be resized.
+    pub fn finish(self) -> Option<usize> {
+        unsafe { (*self.bytearray).ob_exports -= 1 };
+        if unlikely!(self.failed || !self.truncate(self.len)) {
+            self.restore();
+            return None;
+        }
+        Some(self.len - self.start)
+    }
+
+    /// The number of bytes written so far, including any discarded.
+    pub fn written(&self) -> usize {
+        if self.failed {
+            self.discarded + self.len
+        } else {
+            self.len - self.start
+        }
+    }
+
+    /// Restore the `bytearray` to its length before serialization.
+    #[cold]
+    pub fn abort(self) {
+        unsafe { (*self.bytearray).ob_exports -= 1 };
+        self.restore();
+    }
+
+    /// Truncate to the original length or, if the `bytearray` cannot be
+    /// resized, zero what was appended rather than expose it uninitialized.
+    #[cold]
+    fn restore(&self) {
+        if !self.truncate(self.start) {
+            unsafe {
+                let size = ffi!(Py_SIZE(self.bytearray as *mut PyObject)) as usize;
+                let data = (*self.bytearray).ob_start as *mut u8;
+                core::ptr::write_bytes(data.add(self.start), 0, size - self.start);
+            }
+        }
+    }
+
+    fn truncate(&self, len: usize) -> bool {
+        let ptr = self.bytearray as *mut PyObject;
+        if ffi!(Py_SIZE(ptr)) as usize == len {
+            return true;
+        }
+        if unlikely!(ffi!(PyByteArray_Resize(ptr, len as isize)) != 0) {
+            ffi!(PyErr_Clear());
+            return false;
+        }
+        true
+    }
+
+    fn buffer_ptr(&self) -> *mut u8 {
+        unsafe { self.data.add(self.len) }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn grow(&mut self, len: usize) {
+        let written = self.written();
+        if !self.failed {
+            // Grow only the space for output, not what the caller had written.
+            let needed = len - self.start;
+            let mut cap = core::cmp::max(self.cap - self.start, BUFFER_LENGTH);
+            while needed >= cap {
+                if needed < 262144 {
+                    cap *= 4;
+                } else {
+                    cap *= 2;
+                }
+            }
+            unsafe {
+                (*self.bytearray).ob_exports -= 1;
+                let ret = PyByteArray_Resize(
+                    self.bytearray as *mut PyObject,
+                    (self.start + cap) as isize,
+                );
+                (*self.bytearray).ob_exports += 1;
+                if likely!(ret == 0) {
+                    self.cap = self.start + cap;
+                    self.data = (*self.bytearray).ob_start as *mut u8;
+                    return;
+                }
+                PyErr_Clear();
+                self.failed = true;
+            }
+        }
+        // The output will be discarded, so only the capacity matters.
+        let to_write = len - self.len;
+        self.spill = Vec::with_capacity(core::cmp::max(to_write * 2, BUFFER_LENGTH));
+        self.discarded = written;
+        self.len = 0;
+        self.cap = self.spill.capacity();
+        self.data = self.spill.as_mut_ptr();
+    }
+}
+
+impl std::io::Write for ByteArrayWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let _ = self.write_all(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let to_write = buf.len();
+        if unlikely!(self.len + to_write >= self.cap) {
+            self.grow(self.len + to_write);
+        }
+        unsafe {
+            core::ptr::copy_nonoverlapping(buf.as_ptr(), self.buffer_ptr(), to_write);
+        };
+        self.len += to_write;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl WriteExt for &mut ByteArrayWriter {
+    #[inline(always)]
+    fn as_mut_buffer_ptr(&mut self) -> *mut u8 {
+        self.buffer_ptr()
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, len: usize) {
+        let end_length = self.len + len;
+        if unlikely!(end_length >= self.cap) {
+            self.grow(end_length);
+        }
+    }
+
+    #[inline(always)]
+    fn set_written(&mut self, len: usize) {
+        self.len += len;
+    }
+
+    fn write_str(&mut self, val: &str) -> Result<(), Error> {
+        let to_write = val.len();
+        if unlikely!(self.len + to_write + 2 >= self.cap) {
+            self.grow(self.len + to_write + 2);
+        }
+        unsafe {
+            let ptr = self.buffer_ptr();
+            core::ptr::write(ptr, b'"');
+            core::ptr::copy_nonoverlapping(val.as_ptr(), ptr.add(1), to_write);
+            core::ptr::write(ptr.add(to_write + 1), b'"');
+        };
+        self.len += to_write + 2;
+        Ok(())
+    }
+
+    unsafe fn write_reserved_fragment(&mut self, val: &[u8]) -> Result<(), Error> {
+        let to_write = val.len();
+        unsafe {
+            core::ptr::copy_nonoverlapping(val.as_ptr(), self.buffer_ptr(), to_write);
+        };
+        self.len += to_write;
+        Ok(())
+    }
+
+    #[inline(always)]
+    unsafe fn write_reserved_punctuation(&mut self, val: u8) -> Result<(), Error> {
+        unsafe { core::ptr::write(self.buffer_ptr(), val) };
+        self.len += 1;
+        Ok(())
+    }
+
+    #[inline(always)]
+    unsafe fn write_reserved_indent(&mut self, val: u8, len: usize) -> Result<(), Error> {
+        unsafe {
+            core::ptr::write_bytes(self.buffer_ptr(), val, len);
+        };
+        self.len += len;
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 use core::ffi::c_char;
 use core::ptr::NonNull;
 use pyo3_ffi::{
-    PyBytesObject, PyBytes_FromStringAndSize, PyObject, PyVarObject, Py_ssize_t, _PyBytes_Resize,
+    _PyBytes_Resize, PyBytesObject, PyBytes_FromStringAndSize, PyObject, PyVarObject, Py_ssize_t,
 };
 use std::io::Error;
 
@@ -40,6 +40,19 @@ impl BytesWriter {
         }
     }
 
+    /// The number of bytes written so far.
+    pub fn written(&self) -> usize {
+        self.len
+    }
+
+    /// The output written so far. The serializer only writes UTF-8.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            let data = core::ptr::addr_of!((*self.bytes).ob_sval) as *const u8;
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(data, self.len))
+        }
+    }
+
     fn buffer_ptr(&self) -> *mut u8 {
         unsafe {
             core::mem::transmute::<*mut [c_char; 1], *mut u8>(core::ptr::addr_of_mut!(
@@ -137,8 +150,8 @@ pub trait WriteExt: std::io::Write {
     }
 
     #[inline]
-    unsafe fn write_reserved_indent(&mut self, len: usize) -> Result<(), Error> {
-        let _ = len;
+    unsafe fn write_reserved_indent(&mut self, val: u8, len: usize) -> Result<(), Error> {
+        let _ = (val, len);
         Ok(())
     }
 }
@@ -195,9 +208,9 @@ impl WriteExt for &mut BytesWriter {
     }
 
     #[inline(always)]
-    unsafe fn write_reserved_indent(&mut self, len: usize) -> Result<(), Error> {
+    unsafe fn write_reserved_indent(&mut self, val: u8, len: usize) -> Result<(), Error> {
         unsafe {
-            core::ptr::write_bytes(self.buffer_ptr(), b' ', len);
+            core::ptr::write_bytes(self.buffer_ptr(), val, len);
         };
         self.len += len;
         Ok(())
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use std::borrow::Cow;
+
+/// The key as written with `OPT_CAMELCASE`, e.g., `user_id` is `userId`.
+/// Each run of underscores between other characters is removed and the
+/// character following it uppercased if it is an ASCII letter. Leading and
+/// trailing underscores, e.g., of `_private` or `class_`, are kept, and other
+/// characters are unchanged, so a key without underscores is borrowed as is.
+pub fn camel_case(key: &str) -> Cow<'_, str> {
+    let bytes = key.as_bytes();
+    let start = bytes.iter().take_while(|&&ch| ch == b'_').count();
+    let end = bytes.len()
+        - bytes[start..]
+            .iter()
+            .rev()
+            .take_while(|&&ch| ch == b'_')
+            .count();
+    if !bytes[start..end].contains(&b'_') {
+        return Cow::Borrowed(key);
+    }
+    let mut out = String::with_capacity(key.len());
+    out.push_str(&key[..start]);
+    let mut upper = false;
+    for ch in key[start..end].chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            out.push(ch.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out.push_str(&key[end..]);
+    Cow::Owned(out)
+}
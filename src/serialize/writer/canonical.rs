@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use arrayvec::ArrayVec;
+
+/// The longest output, e.g., `-0.0000012345678901234567` or
+/// `-1.2345678901234567e-308`.
+pub const CANONICAL_F64_LEN: usize = 32;
+
+/// Format a finite `value` as ECMAScript's `Number.prototype.toString()`
+/// does, as RFC 8785 requires: the shortest digits that round-trip, in
+/// fixed notation if the decimal exponent is within `[-6, 21)` and otherwise
+/// in exponential notation with a sign, e.g., `1e+21`, and without a
+/// fraction if integral. `-0.0` is `0`.
+pub fn format_canonical_f64(value: f64) -> ArrayVec<u8, CANONICAL_F64_LEN> {
+    debug_assert!(value.is_finite());
+    let mut out = ArrayVec::<u8, CANONICAL_F64_LEN>::new();
+    if value == 0.0 {
+        out.push(b'0');
+        return out;
+    }
+    if value < 0.0 {
+        out.push(b'-');
+    }
+
+    // ryu produces the same shortest digits, but formats them differently,
+    // e.g., `1e21`, `1.5e-7`, `100.0`, or `0.001`
+    let mut buffer = ryu::Buffer::new();
+    let formatted = buffer.format_finite(value.abs()).as_bytes();
+    let (mantissa, exponent) = match formatted.iter().position(|&ch| ch == b'e') {
+        Some(idx) => {
+            let exponent = unsafe { core::str::from_utf8_unchecked(&formatted[idx + 1..]) };
+            (&formatted[..idx], exponent.parse::<i32>().unwrap())
+        }
+        None => (formatted, 0),
+    };
+    let mut digits = ArrayVec::<u8, 24>::new();
+    let mut int_len = mantissa.len() as i32;
+    for (idx, &ch) in mantissa.iter().enumerate() {
+        if ch == b'.' {
+            int_len = idx as i32;
+        } else {
+            digits.push(ch);
+        }
+    }
+    // value is `0.digits * 10**point`
+    let mut point = int_len + exponent;
+    let leading = digits.iter().take_while(|&&ch| ch == b'0').count();
+    digits.drain(..leading);
+    point -= leading as i32;
+    while digits.last() == Some(&b'0') {
+        digits.pop();
+    }
+
+    let len = digits.len() as i32;
+    if len <= point && point <= 21 {
+        out.try_extend_from_slice(&digits).unwrap();
+        for _ in 0..point - len {
+            out.push(b'0');
+        }
+    } else if 0 < point && point <= 21 {
+        out.try_extend_from_slice(&digits[..point as usize])
+            .unwrap();
+        out.push(b'.');
+        out.try_extend_from_slice(&digits[point as usize..])
+            .unwrap();
+    } else if -6 < point && point <= 0 {
+        out.try_extend_from_slice(b"0.").unwrap();
+        for _ in 0..-point {
+            out.push(b'0');
+        }
+        out.try_extend_from_slice(&digits).unwrap();
+    } else {
+        out.push(digits[0]);
+        if len > 1 {
+            out.push(b'.');
+            out.try_extend_from_slice(&digits[1..]).unwrap();
+        }
+        out.push(b'e');
+        let exponent = point - 1;
+        out.push(if exponent < 0 { b'-' } else { b'+' });
+        let mut buffer = itoa::Buffer::new();
+        out.try_extend_from_slice(buffer.format(exponent.unsigned_abs()).as_bytes())
+            .unwrap();
+    }
+    out
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+use core::cell::Cell;
+
+/// The largest `float_precision`.
+pub const MAX_FLOAT_PRECISION: u8 = 20;
+
+std::thread_local! {
+    /// The number of decimal places of floats for `OPT_FLOAT_FIXED`, given by
+    /// the `float_precision` argument of the call serializing.
+    static FLOAT_PRECISION: Cell<u8> = const { Cell::new(0) };
+}
+
+/// Call `f` with floats formatted to `precision` decimal places if
+/// `OPT_FLOAT_FIXED`.
+pub fn with_float_precision<T, F>(precision: u8, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = FLOAT_PRECISION.with(|current| current.replace(precision));
+    let ret = f();
+    FLOAT_PRECISION.with(|current| current.set(previous));
+    ret
+}
+
+/// The number of decimal places of floats for `OPT_FLOAT_FIXED`.
+#[inline]
+pub fn float_precision() -> usize {
+    FLOAT_PRECISION.with(|current| current.get()) as usize
+}
@@ -117,11 +117,12 @@ pub trait Formatter {
         Ok(())
     }
 
-    fn write_u128<W>(&mut self, _writer: &mut W, _value: u128) -> io::Result<()>
+    #[cold]
+    fn write_u128<W>(&mut self, writer: &mut W, value: u128) -> io::Result<()>
     where
         W: ?Sized + io::Write,
     {
-        unreachable!();
+        writer.write_all(itoa::Buffer::new().format(value).as_bytes())
     }
 
     #[inline]
@@ -289,19 +290,105 @@ pub struct CompactFormatter;
 
 impl Formatter for CompactFormatter {}
 
+/// Compact output with a space after the separator between items, the
+/// separator between an object key and value, or both.
+pub struct SpacedFormatter {
+    comma: bool,
+    colon: bool,
+}
+
+impl SpacedFormatter {
+    pub const fn new(comma: bool, colon: bool) -> Self {
+        SpacedFormatter {
+            comma: comma,
+            colon: colon,
+        }
+    }
+
+    #[inline]
+    fn write_comma<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write + WriteExt,
+    {
+        unsafe {
+            if self.comma {
+                writer.write_reserved_fragment(b", ")
+            } else {
+                writer.write_reserved_punctuation(b',')
+            }
+        }
+    }
+}
+
+impl Formatter for SpacedFormatter {
+    #[inline]
+    fn begin_array_value<W>(&mut self, writer: &mut W, first: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write + WriteExt,
+    {
+        reserve_minimum!(writer);
+        if !first {
+            self.write_comma(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn begin_object_key<W>(&mut self, writer: &mut W, first: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write + WriteExt,
+    {
+        reserve_minimum!(writer);
+        if !first {
+            self.write_comma(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn begin_object_value<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write + WriteExt,
+    {
+        reserve_minimum!(writer);
+        unsafe {
+            if self.colon {
+                writer.write_reserved_fragment(b": ")
+            } else {
+                writer.write_reserved_punctuation(b':')
+            }
+        }
+    }
+}
+
 pub struct PrettyFormatter {
     current_indent: usize,
     has_value: bool,
+    indent_width: usize,
+    indent_char: u8,
 }
 
 impl PrettyFormatter {
     #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
+        PrettyFormatter::with_indent(2, b' ')
+    }
+
+    /// Indent each level by `width` repetitions of `val`.
+    pub const fn with_indent(width: usize, val: u8) -> Self {
         PrettyFormatter {
             current_indent: 0,
             has_value: false,
+            indent_width: width,
+            indent_char: val,
         }
     }
+
+    /// Begin as if already nested in `depth` arrays or objects.
+    pub const fn with_depth(mut self, depth: usize) -> Self {
+        self.current_indent = depth;
+        self
+    }
 }
 
 impl Formatter for PrettyFormatter {
@@ -322,13 +409,13 @@ impl Formatter for PrettyFormatter {
         W: ?Sized + io::Write + WriteExt,
     {
         self.current_indent -= 1;
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent_width;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
             if self.has_value {
                 writer.write_reserved_punctuation(b'\n')?;
-                writer.write_reserved_indent(num_spaces)?;
+                writer.write_reserved_indent(self.indent_char, num_spaces)?;
             }
             writer.write_reserved_punctuation(b']')
         }
@@ -339,12 +426,12 @@ impl Formatter for PrettyFormatter {
     where
         W: ?Sized + io::Write + WriteExt,
     {
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent_width;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
             writer.write_reserved_fragment(if first { b"\n" } else { b",\n" })?;
-            writer.write_reserved_indent(num_spaces)?;
+            writer.write_reserved_indent(self.indent_char, num_spaces)?;
         };
         Ok(())
     }
@@ -376,13 +463,13 @@ impl Formatter for PrettyFormatter {
         W: ?Sized + io::Write + WriteExt,
     {
         self.current_indent -= 1;
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent_width;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
             if self.has_value {
                 writer.write_reserved_punctuation(b'\n')?;
-                writer.write_reserved_indent(num_spaces)?;
+                writer.write_reserved_indent(self.indent_char, num_spaces)?;
             }
 
             writer.write_reserved_punctuation(b'}')
@@ -394,11 +481,11 @@ impl Formatter for PrettyFormatter {
     where
         W: ?Sized + io::Write + WriteExt,
     {
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent_width;
         reserve_pretty!(writer, num_spaces);
         unsafe {
             writer.write_reserved_fragment(if first { b"\n" } else { b",\n" })?;
-            writer.write_reserved_indent(num_spaces)?;
+            writer.write_reserved_indent(self.indent_char, num_spaces)?;
         }
         Ok(())
     }
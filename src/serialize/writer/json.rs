@@ -1,9 +1,21 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 // This is an adaptation of `src/value/ser.rs` from serde-json.
 
-use crate::serialize::writer::formatter::{CompactFormatter, Formatter, PrettyFormatter};
+use crate::opt::{
+    Opt, ALLOW_NONFINITE, ASCII, CAMELCASE, CANONICAL, CAPITALIZE_BOOL, ESCAPE_FORWARD_SLASH,
+    FLOAT_FIXED, INDENT_4, INDENT_TAB, NULL_AS_NIL, SPACE_AFTER_COLON, SPACE_AFTER_COMMA,
+    SPACE_ANY, STATS,
+};
+use crate::serialize::error::SerializeError;
+use crate::serialize::state::count_str;
+use crate::serialize::writer::camel_case::camel_case;
+use crate::serialize::writer::canonical::format_canonical_f64;
+use crate::serialize::writer::fixed::float_precision;
+use crate::serialize::writer::formatter::{
+    CompactFormatter, Formatter, PrettyFormatter, SpacedFormatter,
+};
 use crate::serialize::writer::str::*;
-use crate::serialize::writer::WriteExt;
+use crate::serialize::writer::{BytesWriter, WriteExt};
 use serde::ser::{self, Impossible, Serialize};
 use serde_json::error::{Error, Result};
 use std::io;
@@ -11,6 +23,7 @@ use std::io;
 pub struct Serializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
+    opts: Opt,
 }
 
 impl<W> Serializer<W>
@@ -18,8 +31,22 @@ where
     W: io::Write + WriteExt,
 {
     #[inline]
-    pub fn new(writer: W) -> Self {
-        Serializer::with_formatter(writer, CompactFormatter)
+    pub fn new(writer: W, opts: Opt) -> Self {
+        Serializer::with_formatter(writer, CompactFormatter, opts)
+    }
+}
+
+impl<W> Serializer<W, SpacedFormatter>
+where
+    W: io::Write + WriteExt,
+{
+    #[inline]
+    pub fn spaced(writer: W, opts: Opt) -> Self {
+        let formatter = SpacedFormatter::new(
+            opt_enabled!(opts, SPACE_AFTER_COMMA),
+            opt_enabled!(opts, SPACE_AFTER_COLON),
+        );
+        Serializer::with_formatter(writer, formatter, opts)
     }
 }
 
@@ -28,8 +55,20 @@ where
     W: io::Write + WriteExt,
 {
     #[inline]
-    pub fn pretty(writer: W) -> Self {
-        Serializer::with_formatter(writer, PrettyFormatter::new())
+    pub fn pretty(writer: W, opts: Opt) -> Self {
+        Serializer::pretty_at_depth(writer, opts, 0)
+    }
+
+    #[inline]
+    pub fn pretty_at_depth(writer: W, opts: Opt, depth: usize) -> Self {
+        let formatter = if opt_enabled!(opts, INDENT_TAB) {
+            PrettyFormatter::with_indent(1, b'\t')
+        } else if opt_enabled!(opts, INDENT_4) {
+            PrettyFormatter::with_indent(4, b' ')
+        } else {
+            PrettyFormatter::new()
+        };
+        Serializer::with_formatter(writer, formatter.with_depth(depth), opts)
     }
 }
 
@@ -39,14 +78,331 @@ where
     F: Formatter,
 {
     #[inline]
-    pub fn with_formatter(writer: W, formatter: F) -> Self {
-        Serializer { writer, formatter }
+    pub fn with_formatter(writer: W, formatter: F, opts: Opt) -> Self {
+        Serializer {
+            writer,
+            formatter,
+            opts,
+        }
     }
 
     #[inline]
     pub fn into_inner(self) -> W {
         self.writer
     }
+
+    /// Write `value` as an element of an array whose brackets are written
+    /// separately, beginning the array if `first`.
+    #[inline]
+    fn serialize_array_element<T>(&mut self, value: &T, first: bool) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        if first {
+            reserve_minimum!(self.writer);
+            unsafe {
+                self.writer
+                    .write_reserved_punctuation(b'[')
+                    .map_err(Error::io)?
+            };
+        }
+        self.formatter
+            .begin_array_value(&mut self.writer, first)
+            .map_err(Error::io)?;
+        value.serialize(&mut *self)
+    }
+
+    /// Write `NaN`, `Infinity`, or `-Infinity` if `OPT_ALLOW_NONFINITE` and
+    /// otherwise `null`.
+    #[cold]
+    #[inline(never)]
+    fn serialize_nonfinite(&mut self, value: f64) -> Result<()> {
+        if opt_enabled!(self.opts, ALLOW_NONFINITE) {
+            self.write_literal(nonfinite_literal(value).as_bytes())
+        } else {
+            self.write_null()
+        }
+    }
+
+    /// Write `literal`, e.g., `NaN`, as is.
+    #[cold]
+    #[inline(never)]
+    fn write_literal(&mut self, literal: &[u8]) -> Result<()> {
+        reserve_minimum!(self.writer);
+        unsafe {
+            self.writer
+                .write_reserved_fragment(literal)
+                .map_err(Error::io)
+        }
+    }
+
+    /// Write `nil` if `OPT_NULL_AS_NIL` and otherwise `null`.
+    #[inline(always)]
+    fn write_null(&mut self) -> Result<()> {
+        if unlikely!(opt_enabled!(self.opts, NULL_AS_NIL)) {
+            self.write_literal(b"nil")
+        } else {
+            self.formatter
+                .write_null(&mut self.writer)
+                .map_err(Error::io)
+        }
+    }
+}
+
+/// The name of the newtype struct wrapping a sequence whose elements are
+/// written ordered by their serialized form, e.g., a `set` with
+/// `OPT_SORT_KEYS`.
+pub const SORTED_SEQ: &str = "$orjson::private::SortedSeq";
+
+impl<W, F> Serializer<W, F>
+where
+    W: io::Write + WriteExt,
+    F: Formatter,
+{
+    /// Serialize the sequence `value` once without formatting, order its
+    /// elements by those bytes, and write them formatted as any other array.
+    /// Each element, and so `default` for it, is serialized only once.
+    #[cold]
+    #[inline(never)]
+    fn serialize_sorted_seq<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut buf = BytesWriter::default();
+        let mut res = value.serialize(&mut Serializer::new(&mut buf, self.opts));
+        if res.is_ok() {
+            res = self.write_sorted_array(buf.as_str().as_bytes());
+        }
+        ffi!(_Py_Dealloc(buf.bytes_ptr().as_ptr()));
+        res
+    }
+
+    /// Write the elements of the compact array `data` in order of their
+    /// bytes.
+    fn write_sorted_array(&mut self, data: &[u8]) -> Result<()> {
+        let mut elements = array_elements(data);
+        elements.sort_unstable();
+        self.formatter
+            .begin_array(&mut self.writer)
+            .map_err(Error::io)?;
+        for (idx, element) in elements.iter().enumerate() {
+            self.formatter
+                .begin_array_value(&mut self.writer, idx == 0)
+                .map_err(Error::io)?;
+            self.write_formatted(element)?;
+            self.formatter
+                .end_array_value(&mut self.writer)
+                .map_err(Error::io)?;
+        }
+        self.formatter
+            .end_array(&mut self.writer)
+            .map_err(Error::io)
+    }
+
+    /// Write the compact JSON `data` as this serializer formats it, e.g.,
+    /// indented.
+    fn write_formatted(&mut self, data: &[u8]) -> Result<()> {
+        let mut stack: Vec<u8> = Vec::new();
+        let mut idx = 0;
+        while idx < data.len() {
+            match data[idx] {
+                b'[' if data.get(idx + 1) == Some(&b']') => {
+                    self.formatter
+                        .begin_array(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .end_array(&mut self.writer)
+                        .map_err(Error::io)?;
+                    idx += 1;
+                }
+                b'{' if data.get(idx + 1) == Some(&b'}') => {
+                    self.formatter
+                        .begin_object(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .end_object(&mut self.writer)
+                        .map_err(Error::io)?;
+                    idx += 1;
+                }
+                b'[' => {
+                    self.formatter
+                        .begin_array(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .begin_array_value(&mut self.writer, true)
+                        .map_err(Error::io)?;
+                    stack.push(b'[');
+                }
+                b'{' => {
+                    self.formatter
+                        .begin_object(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .begin_object_key(&mut self.writer, true)
+                        .map_err(Error::io)?;
+                    stack.push(b'{');
+                }
+                b',' if stack.last() == Some(&b'[') => {
+                    self.formatter
+                        .end_array_value(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .begin_array_value(&mut self.writer, false)
+                        .map_err(Error::io)?;
+                }
+                b',' => {
+                    self.formatter
+                        .end_object_value(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .begin_object_key(&mut self.writer, false)
+                        .map_err(Error::io)?;
+                }
+                b':' => {
+                    self.formatter
+                        .end_object_key(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .begin_object_value(&mut self.writer)
+                        .map_err(Error::io)?;
+                }
+                b']' => {
+                    self.formatter
+                        .end_array_value(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .end_array(&mut self.writer)
+                        .map_err(Error::io)?;
+                    stack.pop();
+                }
+                b'}' => {
+                    self.formatter
+                        .end_object_value(&mut self.writer)
+                        .map_err(Error::io)?;
+                    self.formatter
+                        .end_object(&mut self.writer)
+                        .map_err(Error::io)?;
+                    stack.pop();
+                }
+                each => {
+                    let end = if each == b'"' {
+                        string_end(data, idx)
+                    } else {
+                        data[idx..]
+                            .iter()
+                            .position(|ch| matches!(ch, b',' | b':' | b']' | b'}'))
+                            .map_or(data.len(), |len| idx + len)
+                    };
+                    self.write_literal(&data[idx..end])?;
+                    idx = end;
+                    continue;
+                }
+            }
+            idx += 1;
+        }
+        Ok(())
+    }
+}
+
+/// The elements of the compact array `data`.
+fn array_elements(data: &[u8]) -> Vec<&[u8]> {
+    let inner = &data[1..data.len() - 1];
+    let mut elements = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    let mut idx = 0;
+    while idx < inner.len() {
+        match inner[idx] {
+            b'"' => {
+                idx = string_end(inner, idx);
+                continue;
+            }
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => depth -= 1,
+            b',' if depth == 0 => {
+                elements.push(&inner[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+        idx += 1;
+    }
+    if !inner.is_empty() {
+        elements.push(&inner[start..]);
+    }
+    elements
+}
+
+/// The index after the end of the string whose opening quote is at `start`
+/// in `data`.
+fn string_end(data: &[u8], start: usize) -> usize {
+    let mut idx = start + 1;
+    while idx < data.len() {
+        match data[idx] {
+            b'\\' => idx += 2,
+            b'"' => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+    data.len()
+}
+
+/// The largest integer that RFC 8785, which reads numbers as IEEE 754 double
+/// precision, represents exactly, as for `OPT_STRICT_INTEGER`.
+const CANONICAL_INT_MAX: u64 = 9007199254740991;
+
+impl<W, F> Serializer<W, F>
+where
+    W: io::Write + WriteExt,
+    F: Formatter,
+{
+    /// Check that an integer is within the range of `OPT_CANONICAL`.
+    #[inline(always)]
+    fn check_canonical_int(&self, magnitude: u128) -> Result<()> {
+        if unlikely!(opt_enabled!(self.opts, CANONICAL))
+            && unlikely!(magnitude > CANONICAL_INT_MAX as u128)
+        {
+            return Err(ser::Error::custom(SerializeError::Integer53Bits));
+        }
+        Ok(())
+    }
+
+    /// Write a float as ECMAScript does for `OPT_CANONICAL`.
+    #[cold]
+    #[inline(never)]
+    fn serialize_canonical_f64(&mut self, value: f64) -> Result<()> {
+        if unlikely!(!value.is_finite()) {
+            return Err(ser::Error::custom(SerializeError::FloatNotFinite));
+        }
+        let buf = format_canonical_f64(value);
+        reserve_minimum!(self.writer);
+        unsafe {
+            self.writer
+                .write_reserved_fragment(buf.as_slice())
+                .map_err(Error::io)
+        }
+    }
+
+    /// Write a finite float with `float_precision` decimal places for
+    /// `OPT_FLOAT_FIXED`, rounding the exact value of the float half to even,
+    /// the same as Python's `f"{value:.{float_precision}f}"`.
+    #[cold]
+    #[inline(never)]
+    fn serialize_fixed_f64(&mut self, value: f64) -> Result<()> {
+        write!(self.writer, "{:.*}", float_precision(), value).map_err(Error::io)
+    }
+}
+
+/// The literal for a non-finite float, the same as the standard library.
+#[inline]
+pub fn nonfinite_literal(value: f64) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value.is_sign_positive() {
+        "Infinity"
+    } else {
+        "-Infinity"
+    }
 }
 
 impl<'a, W, F> ser::Serializer for &'a mut Serializer<W, F>
@@ -67,6 +423,9 @@ where
 
     #[inline]
     fn serialize_bool(self, value: bool) -> Result<()> {
+        if unlikely!(opt_enabled!(self.opts, CAPITALIZE_BOOL)) {
+            return self.write_literal(if value { b"True" } else { b"False" });
+        }
         self.formatter
             .write_bool(&mut self.writer, value)
             .map_err(Error::io)
@@ -89,6 +448,7 @@ where
 
     #[inline]
     fn serialize_i64(self, value: i64) -> Result<()> {
+        self.check_canonical_int(value.unsigned_abs() as u128)?;
         self.formatter
             .write_i64(&mut self.writer, value)
             .map_err(Error::io)
@@ -115,19 +475,28 @@ where
 
     #[inline]
     fn serialize_u64(self, value: u64) -> Result<()> {
+        self.check_canonical_int(value as u128)?;
         self.formatter
             .write_u64(&mut self.writer, value)
             .map_err(Error::io)
     }
 
-    fn serialize_u128(self, _value: u128) -> Result<()> {
-        unreachable!();
+    fn serialize_u128(self, value: u128) -> Result<()> {
+        self.check_canonical_int(value)?;
+        self.formatter
+            .write_u128(&mut self.writer, value)
+            .map_err(Error::io)
     }
 
     #[inline]
     fn serialize_f32(self, value: f32) -> Result<()> {
-        if unlikely!(value.is_infinite() || value.is_nan()) {
-            self.serialize_unit()
+        if unlikely!(opt_enabled!(self.opts, CANONICAL)) {
+            // the value as a double, not the shortest digits of the float
+            self.serialize_canonical_f64(value as f64)
+        } else if unlikely!(value.is_infinite() || value.is_nan()) {
+            self.serialize_nonfinite(value as f64)
+        } else if unlikely!(opt_enabled!(self.opts, FLOAT_FIXED)) {
+            self.serialize_fixed_f64(value as f64)
         } else {
             self.formatter
                 .write_f32(&mut self.writer, value)
@@ -136,8 +505,12 @@ where
     }
     #[inline(never)]
     fn serialize_f64(self, value: f64) -> Result<()> {
-        if unlikely!(value.is_infinite() || value.is_nan()) {
-            self.serialize_unit()
+        if unlikely!(opt_enabled!(self.opts, CANONICAL)) {
+            self.serialize_canonical_f64(value)
+        } else if unlikely!(value.is_infinite() || value.is_nan()) {
+            self.serialize_nonfinite(value)
+        } else if unlikely!(opt_enabled!(self.opts, FLOAT_FIXED)) {
+            self.serialize_fixed_f64(value)
         } else {
             self.formatter
                 .write_f64(&mut self.writer, value)
@@ -151,7 +524,14 @@ where
 
     #[inline(always)]
     fn serialize_str(self, value: &str) -> Result<()> {
-        format_escaped_str(&mut self.writer, value);
+        if unlikely!(opt_enabled!(
+            self.opts,
+            ASCII | ESCAPE_FORWARD_SLASH | STATS
+        )) {
+            format_escaped_str_with_opts(&mut self.writer, value, self.opts);
+        } else {
+            format_escaped_str(&mut self.writer, value);
+        }
         Ok(())
     }
 
@@ -164,9 +544,7 @@ where
 
     #[inline]
     fn serialize_unit(self) -> Result<()> {
-        self.formatter
-            .write_null(&mut self.writer)
-            .map_err(Error::io)
+        self.write_null()
     }
 
     #[inline(always)]
@@ -192,11 +570,12 @@ where
         unreachable!();
     }
 
-    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
-        unreachable!();
+        debug_assert!(name == SORTED_SEQ);
+        self.serialize_sorted_seq(value)
     }
 
     fn serialize_newtype_variant<T>(
@@ -411,7 +790,23 @@ where
 
     #[inline(always)]
     fn serialize_str(self, value: &str) -> Result<()> {
-        self.ser.serialize_str(value)
+        if unlikely!(opt_enabled!(self.ser.opts, CAMELCASE | STATS)) {
+            if opt_enabled!(self.ser.opts, CAMELCASE) {
+                self.ser.serialize_str(&camel_case(value))
+            } else {
+                self.ser.serialize_str(value)
+            }
+        } else if value.len() <= PLAIN_KEY_MAX_LEN
+            && is_plain_key(
+                value.as_bytes(),
+                opt_enabled!(self.ser.opts, ESCAPE_FORWARD_SLASH),
+            )
+        {
+            format_plain_key(&mut self.ser.writer, value);
+            Ok(())
+        } else {
+            self.ser.serialize_str(value)
+        }
     }
 
     fn serialize_unit_variant(
@@ -576,6 +971,27 @@ macro_rules! reserve_str {
     feature = "unstable-simd",
     any(not(target_arch = "x86_64"), not(feature = "avx512"))
 ))]
+#[inline(always)]
+unsafe fn format_escaped_str_impl(dst: *mut u8, value: &str) -> usize {
+    format_escaped_str_impl_128(dst, value.as_bytes().as_ptr(), value.len())
+}
+
+#[cfg(all(feature = "unstable-simd", target_arch = "x86_64", feature = "avx512"))]
+#[inline(always)]
+unsafe fn format_escaped_str_impl(dst: *mut u8, value: &str) -> usize {
+    if std::is_x86_feature_detected!("avx512vl") {
+        format_escaped_str_impl_512vl(dst, value.as_bytes().as_ptr(), value.len())
+    } else {
+        format_escaped_str_impl_128(dst, value.as_bytes().as_ptr(), value.len())
+    }
+}
+
+#[cfg(not(feature = "unstable-simd"))]
+#[inline(always)]
+unsafe fn format_escaped_str_impl(dst: *mut u8, value: &str) -> usize {
+    format_escaped_str_scalar(dst, value.as_bytes().as_ptr(), value.len())
+}
+
 #[inline(always)]
 fn format_escaped_str<W>(writer: &mut W, value: &str)
 where
@@ -584,77 +1000,112 @@ where
     unsafe {
         reserve_str!(writer, value);
 
-        let written = format_escaped_str_impl_128(
-            writer.as_mut_buffer_ptr(),
-            value.as_bytes().as_ptr(),
-            value.len(),
-        );
-
+        let written = format_escaped_str_impl(writer.as_mut_buffer_ptr(), value);
         writer.set_written(written);
     }
 }
 
-#[cfg(all(feature = "unstable-simd", target_arch = "x86_64", feature = "avx512"))]
+/// Write a key that `is_plain_key()` as is between quotes.
 #[inline(always)]
-fn format_escaped_str<W>(writer: &mut W, value: &str)
+fn format_plain_key<W>(writer: &mut W, value: &str)
 where
     W: ?Sized + io::Write + WriteExt,
 {
+    writer.reserve(value.len() + 2);
     unsafe {
-        reserve_str!(writer, value);
+        writer.write_reserved_punctuation(b'"').unwrap();
+        writer.write_reserved_fragment(value.as_bytes()).unwrap();
+        writer.write_reserved_punctuation(b'"').unwrap();
+    }
+}
 
-        if std::is_x86_feature_detected!("avx512vl") {
-            let written = format_escaped_str_impl_512vl(
-                writer.as_mut_buffer_ptr(),
-                value.as_bytes().as_ptr(),
-                value.len(),
-            );
-            writer.set_written(written);
-        } else {
-            let written = format_escaped_str_impl_128(
+/// Write a str whose escaping depends on `opts`, i.e., OPT_ASCII or
+/// OPT_ESCAPE_FORWARD_SLASH, or that is counted for OPT_STATS.
+///
+/// The longest prefix not needing the slow path is written by
+/// `format_escaped_str()` with its closing quote then overwritten.
+#[cold]
+#[inline(never)]
+fn format_escaped_str_with_opts<W>(writer: &mut W, value: &str, opts: Opt)
+where
+    W: ?Sized + io::Write + WriteExt,
+{
+    let ascii = opt_enabled!(opts, ASCII);
+    let slash = opt_enabled!(opts, ESCAPE_FORWARD_SLASH);
+    let split = value
+        .as_bytes()
+        .iter()
+        .position(|&ch| (ascii && ch > 0x7f) || (slash && ch == b'/'));
+    if opt_enabled!(opts, STATS) {
+        count_str(split.is_some() || !is_escape_free(value.as_bytes()));
+    }
+    match split {
+        None => format_escaped_str(writer, value),
+        Some(idx) => unsafe {
+            reserve_str!(writer, value);
+            let written =
+                format_escaped_str_impl(writer.as_mut_buffer_ptr(), value.get_unchecked(..idx));
+            writer.set_written(written - 1);
+            let written = format_escaped_str_remainder(
                 writer.as_mut_buffer_ptr(),
-                value.as_bytes().as_ptr(),
-                value.len(),
+                value.get_unchecked(idx..),
+                ascii,
+                slash,
             );
             writer.set_written(written);
-        };
+        },
     }
 }
 
-#[cfg(not(feature = "unstable-simd"))]
-#[inline(always)]
-fn format_escaped_str<W>(writer: &mut W, value: &str)
+#[inline]
+pub fn to_writer<W, T>(writer: W, value: &T, opts: Opt) -> Result<()>
 where
-    W: ?Sized + io::Write + WriteExt,
+    W: io::Write + WriteExt,
+    T: ?Sized + Serialize,
 {
-    unsafe {
-        reserve_str!(writer, value);
-
-        let written = format_escaped_str_scalar(
-            writer.as_mut_buffer_ptr(),
-            value.as_bytes().as_ptr(),
-            value.len(),
-        );
-        writer.set_written(written);
+    if unlikely!(opt_enabled!(opts, SPACE_ANY)) {
+        let mut ser = Serializer::spaced(writer, opts);
+        return value.serialize(&mut ser);
     }
+    let mut ser = Serializer::new(writer, opts);
+    value.serialize(&mut ser)
 }
 
 #[inline]
-pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+pub fn to_writer_pretty<W, T>(writer: W, value: &T, opts: Opt) -> Result<()>
 where
     W: io::Write + WriteExt,
     T: ?Sized + Serialize,
 {
-    let mut ser = Serializer::new(writer);
+    let mut ser = Serializer::pretty(writer, opts);
     value.serialize(&mut ser)
 }
 
 #[inline]
-pub fn to_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
+pub fn to_writer_array_element<W, T>(writer: W, value: &T, opts: Opt, first: bool) -> Result<()>
 where
     W: io::Write + WriteExt,
     T: ?Sized + Serialize,
 {
-    let mut ser = Serializer::pretty(writer);
-    value.serialize(&mut ser)
+    if unlikely!(opt_enabled!(opts, SPACE_ANY)) {
+        let mut ser = Serializer::spaced(writer, opts);
+        return ser.serialize_array_element(value, first);
+    }
+    let mut ser = Serializer::new(writer, opts);
+    ser.serialize_array_element(value, first)
+}
+
+#[inline]
+pub fn to_writer_pretty_array_element<W, T>(
+    writer: W,
+    value: &T,
+    opts: Opt,
+    first: bool,
+) -> Result<()>
+where
+    W: io::Write + WriteExt,
+    T: ?Sized + Serialize,
+{
+    let mut ser = Serializer::pretty_at_depth(writer, opts, 1);
+    ser.serialize_array_element(value, first)
 }
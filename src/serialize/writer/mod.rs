@@ -1,9 +1,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod bytearraywriter;
 mod byteswriter;
+mod camel_case;
+mod canonical;
+mod fixed;
 mod formatter;
 mod json;
 mod str;
 
+pub use bytearraywriter::ByteArrayWriter;
 pub use byteswriter::{BytesWriter, WriteExt};
-pub use json::{to_writer, to_writer_pretty};
+pub use camel_case::camel_case;
+pub use fixed::{with_float_precision, MAX_FLOAT_PRECISION};
+pub use json::{
+    nonfinite_literal, to_writer, to_writer_array_element, to_writer_pretty,
+    to_writer_pretty_array_element, SORTED_SEQ,
+};
@@ -274,6 +274,94 @@ pub unsafe fn format_escaped_str_impl_128(
     dst as usize - odst as usize
 }
 
+/// The longest key written by `format_plain_key()`. Longer keys are rare and
+/// are not worth a second scan if they turn out to need escaping.
+pub const PLAIN_KEY_MAX_LEN: usize = 64;
+
+/// Whether a key is ASCII and contains no `"`, `\`, control character, or,
+/// if `slash`, `/`, so that it is written as is with any option, scanned a
+/// word at a time.
+#[inline(always)]
+pub fn is_plain_key(value: &[u8], slash: bool) -> bool {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH: u64 = u64::from_ne_bytes([0x80; 8]);
+    const X20: u64 = u64::from_ne_bytes([0x20; 8]);
+    const QUOTE: u64 = u64::from_ne_bytes([b'"'; 8]);
+    const BLASH: u64 = u64::from_ne_bytes([b'\\'; 8]);
+    const SLASH: u64 = u64::from_ne_bytes([b'/'; 8]);
+
+    // the high bit of a byte is set if it is above 0x7f, less than 0x20, or,
+    // XORed with `"`, `\`, or `/`, is zero
+    let needs_escape = |word: u64| {
+        let quote = word ^ QUOTE;
+        let blash = word ^ BLASH;
+        let mut mask = word
+            | (word.wrapping_sub(X20) & !word)
+            | (quote.wrapping_sub(ONES) & !quote)
+            | (blash.wrapping_sub(ONES) & !blash);
+        if slash {
+            let slash = word ^ SLASH;
+            mask |= slash.wrapping_sub(ONES) & !slash;
+        }
+        mask & HIGH != 0
+    };
+
+    let len = value.len();
+    if len < 8 {
+        let mut buf = [b'a'; 8];
+        buf[..len].copy_from_slice(value);
+        return !needs_escape(u64::from_ne_bytes(buf));
+    }
+    let ptr = value.as_ptr();
+    let mut idx = 0;
+    while idx + 8 < len {
+        if needs_escape(unsafe { core::ptr::read_unaligned(ptr.add(idx) as *const u64) }) {
+            return false;
+        }
+        idx += 8;
+    }
+    // the last word overlaps the one before it unless `len` is a multiple of 8
+    !needs_escape(unsafe { core::ptr::read_unaligned(ptr.add(len - 8) as *const u64) })
+}
+
+/// Whether a str contains no `"`, `\`, or control character, i.e., is
+/// written as is without options affecting escaping.
+pub fn is_escape_free(value: &[u8]) -> bool {
+    value
+        .iter()
+        .all(|&ch| ch >= 0x20 && ch != b'"' && ch != b'\\')
+}
+
+/// The length of the longest prefix of `value_len` bytes at `value_ptr`
+/// containing no `"`, `\`, or control character, scanned a word at a time.
+/// Bytes above 0x7f, i.e., any codepoint outside ASCII, never need escaping.
+#[cfg(not(feature = "unstable-simd"))]
+#[inline(always)]
+unsafe fn escape_free_prefix_len(value_ptr: *const u8, value_len: usize) -> usize {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH: u64 = u64::from_ne_bytes([0x80; 8]);
+    const X20: u64 = u64::from_ne_bytes([0x20; 8]);
+    const QUOTE: u64 = u64::from_ne_bytes([b'"'; 8]);
+    const BLASH: u64 = u64::from_ne_bytes([b'\\'; 8]);
+
+    let mut idx = 0;
+    while idx + 8 <= value_len {
+        let word = core::ptr::read_unaligned(value_ptr.add(idx) as *const u64);
+        let quote = word ^ QUOTE;
+        let blash = word ^ BLASH;
+        // the high bit of a byte is set if it is less than 0x20 or, XORed
+        // with `"` or `\`, is zero
+        let mask = (word.wrapping_sub(X20) & !word)
+            | (quote.wrapping_sub(ONES) & !quote)
+            | (blash.wrapping_sub(ONES) & !blash);
+        if mask & HIGH != 0 {
+            break;
+        }
+        idx += 8;
+    }
+    idx
+}
+
 #[cfg(not(feature = "unstable-simd"))]
 pub unsafe fn format_escaped_str_scalar(
     odst: *mut u8,
@@ -286,7 +374,73 @@ pub unsafe fn format_escaped_str_scalar(
     core::ptr::write(dst, b'"');
     dst = dst.add(1);
 
-    impl_format_scalar!(dst, src, value_len);
+    let prefix_len = escape_free_prefix_len(src, value_len);
+    core::ptr::copy_nonoverlapping(src, dst, prefix_len);
+    src = src.add(prefix_len);
+    dst = dst.add(prefix_len);
+
+    impl_format_scalar!(dst, src, value_len - prefix_len);
+
+    core::ptr::write(dst, b'"');
+    dst = dst.add(1);
+
+    dst as usize - odst as usize
+}
+
+/// Write `\uXXXX` for a UTF-16 code unit.
+#[inline(always)]
+unsafe fn write_unicode_escape(dst: *mut u8, unit: u32) -> *mut u8 {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    core::ptr::write(dst, b'\\');
+    core::ptr::write(dst.add(1), b'u');
+    core::ptr::write(dst.add(2), HEX[((unit >> 12) & 0xf) as usize]);
+    core::ptr::write(dst.add(3), HEX[((unit >> 8) & 0xf) as usize]);
+    core::ptr::write(dst.add(4), HEX[((unit >> 4) & 0xf) as usize]);
+    core::ptr::write(dst.add(5), HEX[(unit & 0xf) as usize]);
+    dst.add(6)
+}
+
+/// Escape the remainder of a str after an opening quote and a prefix
+/// already written by `format_escaped_str_impl()`. If `ascii`, codepoints
+/// above 0x7f are written as `\uXXXX`, using a surrogate pair for those
+/// outside the Basic Multilingual Plane, as the standard library does. If
+/// `slash`, `/` is written as `\/`.
+///
+/// The caller must reserve 8 bytes per byte of `value` and 32 additional.
+#[cold]
+#[inline(never)]
+pub unsafe fn format_escaped_str_remainder(
+    odst: *mut u8,
+    value: &str,
+    ascii: bool,
+    slash: bool,
+) -> usize {
+    let mut dst = odst;
+    for ch in value.chars() {
+        let cp = ch as u32;
+        if cp < 0x80 {
+            if unlikely!(NEED_ESCAPED[cp as usize] > 0 || (slash && cp == 0x2f)) {
+                let escape = QUOTE_TAB[cp as usize];
+                write_escape!(escape, dst);
+                dst = dst.add(escape.1 as usize);
+            } else {
+                core::ptr::write(dst, cp as u8);
+                dst = dst.add(1);
+            }
+        } else if ascii {
+            if cp >= 0x10000 {
+                let surrogate = cp - 0x10000;
+                dst = write_unicode_escape(dst, 0xd800 | (surrogate >> 10));
+                dst = write_unicode_escape(dst, 0xdc00 | (surrogate & 0x3ff));
+            } else {
+                dst = write_unicode_escape(dst, cp);
+            }
+        } else {
+            let len = ch.len_utf8();
+            ch.encode_utf8(core::slice::from_raw_parts_mut(dst, len));
+            dst = dst.add(len);
+        }
+    }
 
     core::ptr::write(dst, b'"');
     dst = dst.add(1);
@@ -353,7 +507,7 @@ const QUOTE_TAB: [([u8; 7], u8); 96] = [
     ([0; 7], 0),
     ([0; 7], 0),
     ([0; 7], 0),
-    ([0; 7], 0),
+    (*b"\\/\0\0\0\0\0", 2),
     ([0; 7], 0),
     ([0; 7], 0),
     ([0; 7], 0),
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
-use crate::ffi::orjson_fragmenttype_new;
+use crate::ffi::{orjson_encodertype_new, orjson_fragmenttype_new, orjson_serializertype_new};
 use core::ffi::c_char;
 #[cfg(feature = "yyjson")]
 use core::ffi::c_void;
@@ -27,9 +27,45 @@ pub struct NumpyTypes {
     pub uint8: *mut PyTypeObject,
     pub bool_: *mut PyTypeObject,
     pub datetime64: *mut PyTypeObject,
+    pub timedelta64: *mut PyTypeObject,
+}
+
+pub struct IpAddressTypes {
+    pub ipv4_address: *mut PyTypeObject,
+    pub ipv6_address: *mut PyTypeObject,
+    pub ipv4_network: *mut PyTypeObject,
+    pub ipv6_network: *mut PyTypeObject,
+    pub ipv4_interface: *mut PyTypeObject,
+    pub ipv6_interface: *mut PyTypeObject,
+}
+
+/// `pandas.Timestamp` and the type of `pandas.NaT`.
+pub struct PandasTypes {
+    pub timestamp: *mut PyTypeObject,
+    pub nat: *mut PyTypeObject,
+}
+
+/// The functions and objects of `typing` and `dataclasses` used to read the
+/// field annotations of a dataclass in `loads_typed()`.
+pub struct TypingTypes {
+    pub get_type_hints: *mut PyObject,
+    pub get_origin: *mut PyObject,
+    pub get_args: *mut PyObject,
+    pub union: *mut PyObject,
+    pub union_type: *mut PyObject,
+    pub missing: *mut PyObject,
 }
 
 pub static mut DEFAULT: *mut PyObject = null_mut();
+pub static mut FLOAT_PRECISION: *mut PyObject = null_mut();
+pub static mut INCLUDE: *mut PyObject = null_mut();
+pub static mut MAX_DEPTH: *mut PyObject = null_mut();
+pub static mut OBJECT_HOOK: *mut PyObject = null_mut();
+pub static mut ARRAY_HOOK: *mut PyObject = null_mut();
+pub static mut READ_STR: *mut PyObject = null_mut();
+pub static mut JSON_METHOD_STR: *mut PyObject = null_mut();
+pub static mut WRAPPED_STR: *mut PyObject = null_mut();
+pub static mut STRICT: *mut PyObject = null_mut();
 pub static mut OPTION: *mut PyObject = null_mut();
 
 pub static mut NONE: *mut PyObject = null_mut();
@@ -38,6 +74,8 @@ pub static mut FALSE: *mut PyObject = null_mut();
 pub static mut EMPTY_UNICODE: *mut PyObject = null_mut();
 
 pub static mut BYTES_TYPE: *mut PyTypeObject = null_mut();
+pub static mut SET_TYPE: *mut PyTypeObject = null_mut();
+pub static mut FROZENSET_TYPE: *mut PyTypeObject = null_mut();
 pub static mut BYTEARRAY_TYPE: *mut PyTypeObject = null_mut();
 pub static mut MEMORYVIEW_TYPE: *mut PyTypeObject = null_mut();
 pub static mut STR_TYPE: *mut PyTypeObject = null_mut();
@@ -45,19 +83,43 @@ pub static mut INT_TYPE: *mut PyTypeObject = null_mut();
 pub static mut BOOL_TYPE: *mut PyTypeObject = null_mut();
 pub static mut NONE_TYPE: *mut PyTypeObject = null_mut();
 pub static mut FLOAT_TYPE: *mut PyTypeObject = null_mut();
+pub static mut COMPLEX_TYPE: *mut PyTypeObject = null_mut();
+pub static mut RANGE_TYPE: *mut PyTypeObject = null_mut();
+pub static mut TRACEBACK_TYPE: *mut PyTypeObject = null_mut();
 pub static mut LIST_TYPE: *mut PyTypeObject = null_mut();
 pub static mut DICT_TYPE: *mut PyTypeObject = null_mut();
 pub static mut DATETIME_TYPE: *mut PyTypeObject = null_mut();
 pub static mut DATE_TYPE: *mut PyTypeObject = null_mut();
 pub static mut TIME_TYPE: *mut PyTypeObject = null_mut();
+pub static mut TIMEDELTA_TYPE: *mut PyTypeObject = null_mut();
 pub static mut TUPLE_TYPE: *mut PyTypeObject = null_mut();
 pub static mut UUID_TYPE: *mut PyTypeObject = null_mut();
+pub static mut DECIMAL_TYPE: *mut PyTypeObject = null_mut();
 pub static mut ENUM_TYPE: *mut PyTypeObject = null_mut();
 pub static mut FIELD_TYPE: *mut PyTypeObject = null_mut();
+pub static mut DATACLASS_MISSING: *mut PyObject = null_mut();
 pub static mut FRAGMENT_TYPE: *mut PyTypeObject = null_mut();
+pub static mut ENCODER_TYPE: *mut PyTypeObject = null_mut();
+pub static mut SERIALIZER_TYPE: *mut PyTypeObject = null_mut();
 
 pub static mut NUMPY_TYPES: OnceBox<Option<NonNull<NumpyTypes>>> = OnceBox::new();
 
+pub static mut PURE_PATH_TYPE: OnceBox<Option<NonNull<PyTypeObject>>> = OnceBox::new();
+
+pub static mut ARRAY_TYPE: OnceBox<Option<NonNull<PyTypeObject>>> = OnceBox::new();
+
+pub static mut FRACTION_TYPE: OnceBox<Option<NonNull<PyTypeObject>>> = OnceBox::new();
+
+pub static mut ORDERED_DICT_TYPE: OnceBox<Option<NonNull<PyTypeObject>>> = OnceBox::new();
+
+pub static mut IPADDRESS_TYPES: OnceBox<Option<NonNull<IpAddressTypes>>> = OnceBox::new();
+
+pub static mut PANDAS_TYPES: OnceBox<PandasTypes> = OnceBox::new();
+
+pub static mut TYPING_TYPES: OnceBox<Option<NonNull<TypingTypes>>> = OnceBox::new();
+
+pub static mut FORMAT_TB: OnceBox<Option<NonNull<PyObject>>> = OnceBox::new();
+
 #[cfg(Py_3_9)]
 pub static mut ZONEINFO_TYPE: *mut PyTypeObject = null_mut();
 
@@ -70,11 +132,35 @@ pub static mut DICT_STR: *mut PyObject = null_mut();
 pub static mut DATACLASS_FIELDS_STR: *mut PyObject = null_mut();
 pub static mut SLOTS_STR: *mut PyObject = null_mut();
 pub static mut FIELD_TYPE_STR: *mut PyObject = null_mut();
+pub static mut METADATA_STR: *mut PyObject = null_mut();
+pub static mut ORJSON_NAME_STR: *mut PyObject = null_mut();
 pub static mut ARRAY_STRUCT_STR: *mut PyObject = null_mut();
 pub static mut DTYPE_STR: *mut PyObject = null_mut();
 pub static mut DESCR_STR: *mut PyObject = null_mut();
+pub static mut FIELDS_STR: *mut PyObject = null_mut();
+pub static mut ISNATIVE_STR: *mut PyObject = null_mut();
+pub static mut ITEMSIZE_STR: *mut PyObject = null_mut();
+pub static mut KIND_STR: *mut PyObject = null_mut();
+pub static mut NAMES_STR: *mut PyObject = null_mut();
+pub static mut SUBDTYPE_STR: *mut PyObject = null_mut();
 pub static mut VALUE_STR: *mut PyObject = null_mut();
+pub static mut NAME_STR: *mut PyObject = null_mut();
+pub static mut MEMBER_MAP_STR: *mut PyObject = null_mut();
 pub static mut INT_ATTR_STR: *mut PyObject = null_mut();
+pub static mut INIT_STR: *mut PyObject = null_mut();
+pub static mut DEFAULT_FACTORY_STR: *mut PyObject = null_mut();
+pub static mut KEY_STR: *mut PyObject = null_mut();
+pub static mut NANOSECOND_STR: *mut PyObject = null_mut();
+#[cfg(Py_3_11)]
+pub static mut ADD_NOTE_STR: *mut PyObject = null_mut();
+pub static mut NAMEDTUPLE_FIELDS_STR: *mut PyObject = null_mut();
+pub static mut RANGE_START_STR: *mut PyObject = null_mut();
+pub static mut RANGE_STOP_STR: *mut PyObject = null_mut();
+pub static mut RANGE_STEP_STR: *mut PyObject = null_mut();
+pub static mut NUMERATOR_STR: *mut PyObject = null_mut();
+pub static mut DENOMINATOR_STR: *mut PyObject = null_mut();
+pub static mut ARGS_STR: *mut PyObject = null_mut();
+pub static mut SUPPRESS_CONTEXT_STR: *mut PyObject = null_mut();
 
 #[cfg(feature = "yyjson")]
 pub const YYJSON_BUFFER_SIZE: usize = 1024 * 1024 * 8;
@@ -135,12 +221,12 @@ pub fn init_typerefs() {
 #[cfg_attr(feature = "optimize", optimize(size))]
 fn _init_typerefs_impl() -> bool {
     unsafe {
-        debug_assert!(crate::opt::MAX_OPT < u16::MAX as i32);
-
         assert!(crate::deserialize::KEY_MAP
             .set(crate::deserialize::KeyMap::default())
             .is_ok());
         FRAGMENT_TYPE = orjson_fragmenttype_new();
+        ENCODER_TYPE = orjson_encodertype_new();
+        SERIALIZER_TYPE = orjson_serializertype_new();
         PyDateTime_IMPORT();
         NONE = Py_None();
         TRUE = Py_True();
@@ -162,16 +248,24 @@ fn _init_typerefs_impl() -> bool {
         DICT_TYPE = (*PyDict_New()).ob_type;
         LIST_TYPE = (*PyList_New(0)).ob_type;
         TUPLE_TYPE = (*PyTuple_New(0)).ob_type;
+        SET_TYPE = core::ptr::addr_of_mut!(PySet_Type);
+        FROZENSET_TYPE = core::ptr::addr_of_mut!(PyFrozenSet_Type);
         NONE_TYPE = (*NONE).ob_type;
         BOOL_TYPE = (*TRUE).ob_type;
         INT_TYPE = (*PyLong_FromLongLong(0)).ob_type;
         FLOAT_TYPE = (*PyFloat_FromDouble(0.0)).ob_type;
+        COMPLEX_TYPE = core::ptr::addr_of_mut!(PyComplex_Type);
+        RANGE_TYPE = core::ptr::addr_of_mut!(PyRange_Type);
+        TRACEBACK_TYPE = core::ptr::addr_of_mut!(PyTraceBack_Type);
         DATETIME_TYPE = look_up_datetime_type();
         DATE_TYPE = look_up_date_type();
         TIME_TYPE = look_up_time_type();
+        TIMEDELTA_TYPE = look_up_timedelta_type();
         UUID_TYPE = look_up_uuid_type();
+        DECIMAL_TYPE = look_up_decimal_type();
         ENUM_TYPE = look_up_enum_type();
         FIELD_TYPE = look_up_field_type();
+        DATACLASS_MISSING = look_up_attr("dataclasses\0", "MISSING\0");
 
         #[cfg(Py_3_9)]
         {
@@ -188,12 +282,49 @@ fn _init_typerefs_impl() -> bool {
             PyUnicode_InternFromString("__dataclass_fields__\0".as_ptr() as *const c_char);
         SLOTS_STR = PyUnicode_InternFromString("__slots__\0".as_ptr() as *const c_char);
         FIELD_TYPE_STR = PyUnicode_InternFromString("_field_type\0".as_ptr() as *const c_char);
+        METADATA_STR = PyUnicode_InternFromString("metadata\0".as_ptr() as *const c_char);
+        ORJSON_NAME_STR = PyUnicode_InternFromString("orjson_name\0".as_ptr() as *const c_char);
         ARRAY_STRUCT_STR =
             PyUnicode_InternFromString("__array_struct__\0".as_ptr() as *const c_char);
         DTYPE_STR = PyUnicode_InternFromString("dtype\0".as_ptr() as *const c_char);
         DESCR_STR = PyUnicode_InternFromString("descr\0".as_ptr() as *const c_char);
+        FIELDS_STR = PyUnicode_InternFromString("fields\0".as_ptr() as *const c_char);
+        ISNATIVE_STR = PyUnicode_InternFromString("isnative\0".as_ptr() as *const c_char);
+        ITEMSIZE_STR = PyUnicode_InternFromString("itemsize\0".as_ptr() as *const c_char);
+        KIND_STR = PyUnicode_InternFromString("kind\0".as_ptr() as *const c_char);
+        NAMES_STR = PyUnicode_InternFromString("names\0".as_ptr() as *const c_char);
+        SUBDTYPE_STR = PyUnicode_InternFromString("subdtype\0".as_ptr() as *const c_char);
         VALUE_STR = PyUnicode_InternFromString("value\0".as_ptr() as *const c_char);
+        NAME_STR = PyUnicode_InternFromString("name\0".as_ptr() as *const c_char);
+        MEMBER_MAP_STR = PyUnicode_InternFromString("_member_map_\0".as_ptr() as *const c_char);
+        INIT_STR = PyUnicode_InternFromString("init\0".as_ptr() as *const c_char);
+        DEFAULT_FACTORY_STR =
+            PyUnicode_InternFromString("default_factory\0".as_ptr() as *const c_char);
+        KEY_STR = PyUnicode_InternFromString("key\0".as_ptr() as *const c_char);
+        NANOSECOND_STR = PyUnicode_InternFromString("nanosecond\0".as_ptr() as *const c_char);
+        #[cfg(Py_3_11)]
+        {
+            ADD_NOTE_STR = PyUnicode_InternFromString("add_note\0".as_ptr() as *const c_char);
+        }
+        NAMEDTUPLE_FIELDS_STR = PyUnicode_InternFromString("_fields\0".as_ptr() as *const c_char);
+        RANGE_START_STR = PyUnicode_InternFromString("start\0".as_ptr() as *const c_char);
+        RANGE_STOP_STR = PyUnicode_InternFromString("stop\0".as_ptr() as *const c_char);
+        RANGE_STEP_STR = PyUnicode_InternFromString("step\0".as_ptr() as *const c_char);
+        NUMERATOR_STR = PyUnicode_InternFromString("numerator\0".as_ptr() as *const c_char);
+        DENOMINATOR_STR = PyUnicode_InternFromString("denominator\0".as_ptr() as *const c_char);
+        ARGS_STR = PyUnicode_InternFromString("args\0".as_ptr() as *const c_char);
+        SUPPRESS_CONTEXT_STR =
+            PyUnicode_InternFromString("__suppress_context__\0".as_ptr() as *const c_char);
         DEFAULT = PyUnicode_InternFromString("default\0".as_ptr() as *const c_char);
+        FLOAT_PRECISION = PyUnicode_InternFromString("float_precision\0".as_ptr() as *const c_char);
+        INCLUDE = PyUnicode_InternFromString("include\0".as_ptr() as *const c_char);
+        MAX_DEPTH = PyUnicode_InternFromString("max_depth\0".as_ptr() as *const c_char);
+        OBJECT_HOOK = PyUnicode_InternFromString("object_hook\0".as_ptr() as *const c_char);
+        ARRAY_HOOK = PyUnicode_InternFromString("array_hook\0".as_ptr() as *const c_char);
+        READ_STR = PyUnicode_InternFromString("read\0".as_ptr() as *const c_char);
+        JSON_METHOD_STR = PyUnicode_InternFromString("__json__\0".as_ptr() as *const c_char);
+        WRAPPED_STR = PyUnicode_InternFromString("__wrapped__\0".as_ptr() as *const c_char);
+        STRICT = PyUnicode_InternFromString("strict\0".as_ptr() as *const c_char);
         OPTION = PyUnicode_InternFromString("option\0".as_ptr() as *const c_char);
         JsonEncodeError = pyo3_ffi::PyExc_TypeError;
         Py_INCREF(JsonEncodeError);
@@ -253,6 +384,7 @@ pub fn load_numpy_types() -> Box<Option<NonNull<NumpyTypes>>> {
             uint8: look_up_numpy_type(numpy_module_dict, "uint8\0"),
             bool_: look_up_numpy_type(numpy_module_dict, "bool_\0"),
             datetime64: look_up_numpy_type(numpy_module_dict, "datetime64\0"),
+            timedelta64: look_up_numpy_type(numpy_module_dict, "timedelta64\0"),
         });
         Py_XDECREF(numpy_module_dict);
         Py_XDECREF(numpy);
@@ -260,6 +392,162 @@ pub fn load_numpy_types() -> Box<Option<NonNull<NumpyTypes>>> {
     }
 }
 
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn look_up_type_lazily(module_name: &str, name: &str) -> Box<Option<NonNull<PyTypeObject>>> {
+    let module = PyImport_ImportModule(module_name.as_ptr() as *const c_char);
+    if module.is_null() {
+        PyErr_Clear();
+        return Box::new(None);
+    }
+    let ptr = PyObject_GetAttrString(module, name.as_ptr() as *const c_char);
+    Py_DECREF(module);
+    if ptr.is_null() {
+        PyErr_Clear();
+        return Box::new(None);
+    }
+    Box::new(Some(nonnull!(ptr as *mut PyTypeObject)))
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_pure_path_type() -> Box<Option<NonNull<PyTypeObject>>> {
+    unsafe { look_up_type_lazily("pathlib\0", "PurePath\0") }
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_array_type() -> Box<Option<NonNull<PyTypeObject>>> {
+    unsafe { look_up_type_lazily("array\0", "array\0") }
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_fraction_type() -> Box<Option<NonNull<PyTypeObject>>> {
+    unsafe { look_up_type_lazily("fractions\0", "Fraction\0") }
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_ordered_dict_type() -> Box<Option<NonNull<PyTypeObject>>> {
+    unsafe { look_up_type_lazily("collections\0", "OrderedDict\0") }
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn look_up_ipaddress_type(module: *mut PyObject, name: &str) -> *mut PyTypeObject {
+    let ptr = PyObject_GetAttrString(module, name.as_ptr() as *const c_char);
+    if ptr.is_null() {
+        PyErr_Clear();
+    }
+    Py_XDECREF(ptr);
+    ptr as *mut PyTypeObject
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_ipaddress_types() -> Box<Option<NonNull<IpAddressTypes>>> {
+    unsafe {
+        let module = PyImport_ImportModule("ipaddress\0".as_ptr() as *const c_char);
+        if module.is_null() {
+            PyErr_Clear();
+            return Box::new(None);
+        }
+        let types = Box::new(IpAddressTypes {
+            ipv4_address: look_up_ipaddress_type(module, "IPv4Address\0"),
+            ipv6_address: look_up_ipaddress_type(module, "IPv6Address\0"),
+            ipv4_network: look_up_ipaddress_type(module, "IPv4Network\0"),
+            ipv6_network: look_up_ipaddress_type(module, "IPv6Network\0"),
+            ipv4_interface: look_up_ipaddress_type(module, "IPv4Interface\0"),
+            ipv6_interface: look_up_ipaddress_type(module, "IPv6Interface\0"),
+        });
+        Py_DECREF(module);
+        Box::new(Some(nonnull!(Box::<IpAddressTypes>::into_raw(types))))
+    }
+}
+
+/// Look up the `pandas` types if `pandas` has already been imported. It is
+/// never imported here, so this fails, and is retried on the next call, until
+/// it has been.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_pandas_types() -> Result<Box<PandasTypes>, ()> {
+    unsafe {
+        let module = PyDict_GetItemString(
+            PyImport_GetModuleDict(),
+            "pandas\0".as_ptr() as *const c_char,
+        );
+        if module.is_null() {
+            return Err(());
+        }
+        let timestamp = PyObject_GetAttrString(module, "Timestamp\0".as_ptr() as *const c_char);
+        let nat = PyObject_GetAttrString(module, "NaT\0".as_ptr() as *const c_char);
+        if timestamp.is_null() || nat.is_null() {
+            // pandas is still being imported
+            PyErr_Clear();
+            Py_XDECREF(timestamp);
+            Py_XDECREF(nat);
+            return Err(());
+        }
+        let nat_type = ob_type!(nat);
+        Py_INCREF(nat_type as *mut PyObject);
+        Py_DECREF(nat);
+        Ok(Box::new(PandasTypes {
+            timestamp: timestamp as *mut PyTypeObject,
+            nat: nat_type,
+        }))
+    }
+}
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn look_up_attr(module_name: &str, name: &str) -> *mut PyObject {
+    let module = PyImport_ImportModule(module_name.as_ptr() as *const c_char);
+    if module.is_null() {
+        PyErr_Clear();
+        return null_mut();
+    }
+    let ptr = PyObject_GetAttrString(module, name.as_ptr() as *const c_char);
+    Py_DECREF(module);
+    if ptr.is_null() {
+        PyErr_Clear();
+    }
+    ptr
+}
+
+/// Look up `traceback.format_tb()`.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_format_tb() -> Box<Option<NonNull<PyObject>>> {
+    unsafe { Box::new(NonNull::new(look_up_attr("traceback\0", "format_tb\0"))) }
+}
+
+/// Look up `TypingTypes`. `union_type`, i.e., `types.UnionType`, is null
+/// before Python 3.10.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub fn load_typing_types() -> Box<Option<NonNull<TypingTypes>>> {
+    unsafe {
+        let types = Box::new(TypingTypes {
+            get_type_hints: look_up_attr("typing\0", "get_type_hints\0"),
+            get_origin: look_up_attr("typing\0", "get_origin\0"),
+            get_args: look_up_attr("typing\0", "get_args\0"),
+            union: look_up_attr("typing\0", "Union\0"),
+            union_type: look_up_attr("types\0", "UnionType\0"),
+            missing: look_up_attr("dataclasses\0", "MISSING\0"),
+        });
+        if types.get_type_hints.is_null()
+            || types.get_origin.is_null()
+            || types.get_args.is_null()
+            || types.union.is_null()
+            || types.missing.is_null()
+        {
+            return Box::new(None);
+        }
+        Box::new(Some(nonnull!(Box::<TypingTypes>::into_raw(types))))
+    }
+}
+
 #[cold]
 #[cfg_attr(feature = "optimize", optimize(size))]
 unsafe fn look_up_field_type() -> *mut PyTypeObject {
@@ -297,6 +585,18 @@ unsafe fn look_up_uuid_type() -> *mut PyTypeObject {
     ptr
 }
 
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn look_up_decimal_type() -> *mut PyTypeObject {
+    let module = PyImport_ImportModule("decimal\0".as_ptr() as *const c_char);
+    let module_dict = PyObject_GenericGetDict(module, null_mut());
+    let ptr = PyMapping_GetItemString(module_dict, "Decimal\0".as_ptr() as *const c_char)
+        as *mut PyTypeObject;
+    Py_DECREF(module_dict);
+    Py_DECREF(module);
+    ptr
+}
+
 #[cold]
 #[cfg_attr(feature = "optimize", optimize(size))]
 unsafe fn look_up_datetime_type() -> *mut PyTypeObject {
@@ -334,6 +634,15 @@ unsafe fn look_up_time_type() -> *mut PyTypeObject {
     ptr
 }
 
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+unsafe fn look_up_timedelta_type() -> *mut PyTypeObject {
+    let delta = ((*PyDateTimeAPI()).Delta_FromDelta)(0, 0, 0, 1, (*(PyDateTimeAPI())).DeltaType);
+    let ptr = (*delta).ob_type;
+    Py_DECREF(delta);
+    ptr
+}
+
 #[cfg(Py_3_9)]
 #[cold]
 #[cfg_attr(feature = "optimize", optimize(size))]
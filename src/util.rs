@@ -175,6 +175,33 @@ macro_rules! call_method {
     };
 }
 
+#[cfg(Py_3_10)]
+macro_rules! call_function {
+    ($callable:expr, $arg:expr) => {
+        unsafe {
+            pyo3_ffi::PyObject_Vectorcall(
+                $callable,
+                core::ptr::addr_of!($arg),
+                pyo3_ffi::PyVectorcall_NARGS(1) as usize,
+                core::ptr::null_mut(),
+            )
+        }
+    };
+}
+
+#[cfg(not(Py_3_10))]
+macro_rules! call_function {
+    ($callable:expr, $arg:expr) => {
+        unsafe {
+            pyo3_ffi::PyObject_CallFunctionObjArgs(
+                $callable,
+                $arg,
+                core::ptr::null_mut() as *mut pyo3_ffi::PyObject,
+            )
+        }
+    };
+}
+
 macro_rules! str_hash {
     ($op:expr) => {
         unsafe { (*$op.cast::<pyo3_ffi::PyASCIIObject>()).hash }
@@ -0,0 +1,231 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import json
+
+import pytest
+
+import orjson
+
+try:
+    import numpy
+except ImportError:
+    numpy = None  # type: ignore
+
+NONFINITE = [float("NaN"), float("Infinity"), float("-Infinity")]
+
+
+class TestAllowNonfinite:
+    def test_allow_nonfinite_default(self):
+        """
+        NaN, Infinity, -Infinity serialize as null by default
+        """
+        assert orjson.dumps(NONFINITE) == b"[null,null,null]"
+
+    def test_allow_nonfinite(self):
+        """
+        OPT_ALLOW_NONFINITE
+        """
+        assert (
+            orjson.dumps(NONFINITE, option=orjson.OPT_ALLOW_NONFINITE)
+            == b"[NaN,Infinity,-Infinity]"
+        )
+
+    def test_allow_nonfinite_root(self):
+        """
+        OPT_ALLOW_NONFINITE root value
+        """
+        for val, expected in zip(NONFINITE, (b"NaN", b"Infinity", b"-Infinity")):
+            assert orjson.dumps(val, option=orjson.OPT_ALLOW_NONFINITE) == expected
+
+    def test_allow_nonfinite_negative_nan(self):
+        """
+        OPT_ALLOW_NONFINITE NaN has no sign
+        """
+        assert orjson.dumps(-float("NaN"), option=orjson.OPT_ALLOW_NONFINITE) == b"NaN"
+
+    def test_allow_nonfinite_finite(self):
+        """
+        OPT_ALLOW_NONFINITE does not change finite floats
+        """
+        val = [0.0, -0.0, 1.5, 1e308, -5e-324]
+        assert orjson.dumps(val, option=orjson.OPT_ALLOW_NONFINITE) == orjson.dumps(
+            val
+        )
+
+    def test_allow_nonfinite_same_as_json(self):
+        """
+        OPT_ALLOW_NONFINITE is the same as json.dumps()
+        """
+        val = {"a": NONFINITE, "b": [{"c": float("Infinity")}]}
+        assert orjson.dumps(val, option=orjson.OPT_ALLOW_NONFINITE) == json.dumps(
+            val, separators=(",", ":")
+        ).encode("utf-8")
+
+    def test_allow_nonfinite_roundtrip_json(self):
+        """
+        OPT_ALLOW_NONFINITE output is parsed by json.loads()
+        """
+        val = json.loads(orjson.dumps(NONFINITE, option=orjson.OPT_ALLOW_NONFINITE))
+        assert val[0] != val[0]
+        assert val[1:] == [float("Infinity"), float("-Infinity")]
+
+    def test_allow_nonfinite_indent(self):
+        """
+        OPT_ALLOW_NONFINITE with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a": float("NaN")},
+                option=orjson.OPT_ALLOW_NONFINITE | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "a": NaN\n}'
+        )
+
+    def test_allow_nonfinite_dict_key(self):
+        """
+        OPT_ALLOW_NONFINITE float dict keys with OPT_NON_STR_KEYS
+        """
+        val = {float("NaN"): 1, float("Infinity"): 2, float("-Infinity"): 3}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"null":1,"null":2,"null":3}'
+        )
+        assert (
+            orjson.dumps(
+                val, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_ALLOW_NONFINITE
+            )
+            == b'{"NaN":1,"Infinity":2,"-Infinity":3}'
+        )
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_allow_nonfinite_numpy(self):
+        """
+        OPT_ALLOW_NONFINITE numpy floats
+        """
+        option = orjson.OPT_ALLOW_NONFINITE | orjson.OPT_SERIALIZE_NUMPY
+        for dtype in (numpy.float64, numpy.float32, numpy.float16):
+            assert (
+                orjson.dumps(numpy.array(NONFINITE, dtype), option=option)
+                == b"[NaN,Infinity,-Infinity]"
+            )
+            assert orjson.dumps(dtype("NaN"), option=option) == b"NaN"
+
+
+class TestAllowNonfiniteLoads:
+    def test_allow_nonfinite_loads(self):
+        """
+        loads() OPT_ALLOW_NONFINITE
+        """
+        val = orjson.loads(
+            "[NaN, Infinity, -Infinity]", option=orjson.OPT_ALLOW_NONFINITE
+        )
+        assert val[0] != val[0]
+        assert val[1:] == [float("Infinity"), float("-Infinity")]
+
+    def test_allow_nonfinite_loads_root(self):
+        """
+        loads() OPT_ALLOW_NONFINITE root value
+        """
+        assert orjson.loads("Infinity", option=orjson.OPT_ALLOW_NONFINITE) == float(
+            "Infinity"
+        )
+        assert orjson.loads(" -Infinity ", option=orjson.OPT_ALLOW_NONFINITE) == float(
+            "-Infinity"
+        )
+        val = orjson.loads("NaN", option=orjson.OPT_ALLOW_NONFINITE)
+        assert isinstance(val, float) and val != val
+
+    def test_allow_nonfinite_loads_object(self):
+        """
+        loads() OPT_ALLOW_NONFINITE object value
+        """
+        assert orjson.loads(
+            '{"Infinity": Infinity, "a": -Infinity}',
+            option=orjson.OPT_ALLOW_NONFINITE,
+        ) == {"Infinity": float("Infinity"), "a": float("-Infinity")}
+
+    def test_allow_nonfinite_loads_key(self):
+        """
+        loads() OPT_ALLOW_NONFINITE does not allow a literal as key
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("{NaN: 1}", option=orjson.OPT_ALLOW_NONFINITE)
+        assert exc_info.value.pos == 1
+
+    def test_allow_nonfinite_loads_numbers(self):
+        """
+        loads() OPT_ALLOW_NONFINITE does not change numbers
+        """
+        assert orjson.loads(
+            "[-1, -0.5, -0, 1e3]", option=orjson.OPT_ALLOW_NONFINITE
+        ) == [-1, -0.5, 0, 1000.0]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[1e999]", option=orjson.OPT_ALLOW_NONFINITE)
+
+    def test_allow_nonfinite_loads_decimal(self):
+        """
+        loads() OPT_ALLOW_NONFINITE with OPT_PARSE_DECIMAL is float
+        """
+        val = orjson.loads(
+            "[Infinity, 1.5]",
+            option=orjson.OPT_ALLOW_NONFINITE | orjson.OPT_PARSE_DECIMAL,
+        )
+        assert isinstance(val[0], float)
+        assert val[0] == float("Infinity")
+        assert str(val[1]) == "1.5"
+
+    def test_allow_nonfinite_loads_roundtrip(self):
+        """
+        loads() OPT_ALLOW_NONFINITE parses dumps() OPT_ALLOW_NONFINITE
+        """
+        val = {"a": [1.5, float("Infinity")], "b": float("-Infinity")}
+        assert (
+            orjson.loads(
+                orjson.dumps(val, option=orjson.OPT_ALLOW_NONFINITE),
+                option=orjson.OPT_ALLOW_NONFINITE,
+            )
+            == val
+        )
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            ("[nan]", 1),
+            ("[Nan]", 1),
+            ("[infinity]", 1),
+            ("[Inf]", 1),
+            ("[-Inf]", 1),
+            ("[NaNa]", 4),
+            ("[-Infinityx]", 10),
+            ("[+Infinity]", 1),
+            ("[- Infinity]", 2),
+        ],
+    )
+    def test_allow_nonfinite_loads_invalid(self, value):
+        """
+        loads() OPT_ALLOW_NONFINITE invalid literals
+        """
+        doc, pos = value
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(doc, option=orjson.OPT_ALLOW_NONFINITE)
+        assert exc_info.value.pos == pos
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            ("[NaN]", 1),
+            ("[Infinity]", 1),
+            ("[-Infinity]", 2),
+            ('{"a": NaN}', 6),
+            ("Infinity", 0),
+        ],
+    )
+    def test_allow_nonfinite_loads_disabled(self, value):
+        """
+        loads() without OPT_ALLOW_NONFINITE raises at the same position
+        """
+        doc, pos = value
+        for option in (None, orjson.OPT_PARSE_DECIMAL):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(doc, option=option)
+            assert exc_info.value.pos == pos
@@ -153,10 +153,10 @@ class TestApi:
 
     def test_option_invalid_int(self):
         """
-        dumps() option invalid 64-bit number
+        dumps() option invalid number wider than 64 bits
         """
         with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps(True, option=9223372036854775809)
+            orjson.dumps(True, option=(1 << 64) + 1)
 
     def test_option_range_low(self):
         """
@@ -169,8 +169,57 @@ class TestApi:
         """
         dumps() option out of range high
         """
+        for option in (1 << 31, 1 << 32, 1 << 127, 1 << 128):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(True, option=option)
+
+    def test_loads_option_none(self):
+        """
+        loads() option None
+        """
+        assert orjson.loads("[]", option=None) == []
+        assert orjson.loads("[]", None) == []
+
+    def test_loads_option_not_int(self):
+        """
+        loads() option not int or None
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[]", option=True)
+
+    def test_loads_option_dumps(self):
+        """
+        loads() and dumps() reject each other's options
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[]", option=orjson.OPT_SORT_KEYS)
         with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps(True, option=1 << 12)
+            orjson.dumps([], option=orjson.OPT_PARSE_DECIMAL)
+
+    def test_loads_option_range(self):
+        """
+        loads() option out of range
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[]", option=-1)
+        for option in (1 << 30, 1 << 63, 1 << 64):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads("[]", option=option)
+
+    def test_loads_arguments(self):
+        """
+        loads() invalid arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.loads()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads("[]", None, None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads("[]", None, option=None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads("[]", zxc=None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads(__obj="[]")  # type: ignore
 
     def test_opts_multiple(self):
         """
@@ -244,11 +293,17 @@ class TestApi:
         """
         assert (
             str(inspect.signature(orjson.dumps))
-            == "(obj, /, default=None, option=None)"
+            == "(obj, /, default=None, option=None, *, max_depth=None, include=None, "
+            "float_precision=None, bytes_format=None, complex_format=None, "
+            "date_format=None, datetime_format=None, exception_format=None, "
+            "fraction_format=None, range_format=None, time_format=None, "
+            "uuid_format=None)"
         )
         inspect.signature(orjson.dumps).bind("str")
         inspect.signature(orjson.dumps).bind("str", default=default, option=1)
         inspect.signature(orjson.dumps).bind("str", default=None, option=None)
+        inspect.signature(orjson.dumps).bind("str", max_depth=1)
+        inspect.signature(orjson.dumps).bind("str", uuid_format="int")
 
     def test_loads_signature(self):
         """
@@ -1,5 +1,7 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import pytest
+
 import orjson
 
 from .util import read_fixture_obj
@@ -39,3 +41,48 @@ class TestAppendNewline:
         """
         val = read_fixture_obj("github.json.xz")
         assert orjson.loads(orjson.dumps(val, option=orjson.OPT_APPEND_NEWLINE)) == val
+
+    def test_dumps_newline_indent(self):
+        """
+        dumps() OPT_APPEND_NEWLINE | OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a": [1]}, option=orjson.OPT_APPEND_NEWLINE | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "a": [\n    1\n  ]\n}\n'
+        )
+        assert (
+            orjson.dumps([], option=orjson.OPT_APPEND_NEWLINE | orjson.OPT_INDENT_2)
+            == b"[]\n"
+        )
+
+    def test_dumps_newline_scalar(self):
+        """
+        dumps() OPT_APPEND_NEWLINE applies to any root value
+        """
+        assert orjson.dumps("a", option=orjson.OPT_APPEND_NEWLINE) == b'"a"\n'
+        assert orjson.dumps(1, option=orjson.OPT_APPEND_NEWLINE) == b"1\n"
+        assert orjson.dumps(None, option=orjson.OPT_APPEND_NEWLINE) == b"null\n"
+        assert (
+            orjson.dumps(orjson.Fragment(b"[1]"), option=orjson.OPT_APPEND_NEWLINE)
+            == b"[1]\n"
+        )
+
+    def test_dumps_newline_default(self):
+        """
+        dumps() OPT_APPEND_NEWLINE applies once to output of default
+        """
+        assert (
+            orjson.dumps(
+                object(), default=lambda _: [1], option=orjson.OPT_APPEND_NEWLINE
+            )
+            == b"[1]\n"
+        )
+
+    def test_dumps_newline_error(self):
+        """
+        dumps() OPT_APPEND_NEWLINE does not affect an error
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(object(), option=orjson.OPT_APPEND_NEWLINE)
@@ -0,0 +1,338 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import array
+
+import pytest
+
+import orjson
+
+INT_TYPECODES = ["b", "B", "h", "H", "i", "I", "l", "L", "q", "Q"]
+
+
+class ArraySubclass(array.array):
+    pass
+
+
+class TestArray:
+    def test_array_default(self):
+        """
+        array.array is not serialized without OPT_SERIALIZE_ARRAY
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array.array("i", [1]))
+
+    @pytest.mark.parametrize("typecode", INT_TYPECODES)
+    def test_array_int(self, typecode):
+        """
+        array.array of integer typecodes
+        """
+        val = array.array(typecode, [0, 1, 100])
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[0,1,100]"
+
+    @pytest.mark.parametrize("typecode", INT_TYPECODES)
+    def test_array_int_range(self, typecode):
+        """
+        array.array of integer typecodes at their minimum and maximum
+        """
+        itemsize = array.array(typecode).itemsize * 8
+        if typecode.isupper():
+            low, high = 0, (1 << itemsize) - 1
+        else:
+            low, high = -(1 << (itemsize - 1)), (1 << (itemsize - 1)) - 1
+        val = array.array(typecode, [low, high])
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == orjson.dumps(
+            [low, high]
+        )
+
+    @pytest.mark.parametrize("typecode", INT_TYPECODES)
+    def test_array_int_strict(self, typecode):
+        """
+        array.array of integer typecodes OPT_STRICT_INTEGER
+        """
+        option = orjson.OPT_SERIALIZE_ARRAY | orjson.OPT_STRICT_INTEGER
+        val = array.array(typecode, [0, 1, 100])
+        assert orjson.dumps(val, option=option) == b"[0,1,100]"
+        if array.array(typecode).itemsize == 8:
+            val = array.array(typecode, [1, 9007199254740991])
+            assert orjson.dumps(val, option=option) == b"[1,9007199254740991]"
+            for item in (9007199254740992, -9007199254740992):
+                if item < 0 and typecode.isupper():
+                    continue
+                with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                    orjson.dumps([array.array(typecode, [1, item])], option=option)
+                with pytest.raises(orjson.JSONEncodeError) as list_exc_info:
+                    orjson.dumps([item], option=option)
+                assert str(exc_info.value) == str(list_exc_info.value)
+                with pytest.raises(orjson.JSONEncodeError):
+                    orjson.dumps(memoryview(array.array(typecode, [item])), option=option)
+
+    def test_array_double(self):
+        """
+        array.array of typecode d
+        """
+        val = array.array("d", [1.5, -0.0, 2.0, 1e308, 5e-324])
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[1.5,-0.0,2.0,1e308,5e-324]"
+        )
+
+    def test_array_float(self):
+        """
+        array.array of typecode f
+        """
+        val = array.array("f", [1.5, -2.25, 0.0])
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[1.5,-2.25,0.0]"
+        )
+
+    def test_array_nonfinite(self):
+        """
+        array.array non-finite floats follow OPT_ALLOW_NONFINITE
+        """
+        for typecode in ("f", "d"):
+            val = array.array(typecode, [float("NaN"), float("Infinity")])
+            assert (
+                orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[null,null]"
+            )
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_SERIALIZE_ARRAY | orjson.OPT_ALLOW_NONFINITE,
+                )
+                == b"[NaN,Infinity]"
+            )
+
+    def test_array_nonfinite_canonical(self):
+        """
+        array.array non-finite floats raise with OPT_CANONICAL
+        """
+        for typecode in ("f", "d"):
+            val = array.array(typecode, [1.0, float("NaN")])
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(
+                    val, option=orjson.OPT_SERIALIZE_ARRAY | orjson.OPT_CANONICAL
+                )
+            assert str(exc_info.value).startswith(
+                "Float must be finite with OPT_CANONICAL"
+            )
+
+    def test_array_nonfinite_numpy_option(self):
+        """
+        array.array is not affected by OPT_NUMPY_NONFINITE_AS_NULL
+        """
+        val = array.array("d", [float("NaN")])
+        assert (
+            orjson.dumps(
+                val,
+                option=orjson.OPT_SERIALIZE_ARRAY
+                | orjson.OPT_ALLOW_NONFINITE
+                | orjson.OPT_NUMPY_NONFINITE_AS_NULL,
+            )
+            == b"[NaN]"
+        )
+
+    def test_array_empty(self):
+        """
+        array.array empty
+        """
+        for typecode in INT_TYPECODES + ["f", "d"]:
+            assert (
+                orjson.dumps(array.array(typecode), option=orjson.OPT_SERIALIZE_ARRAY)
+                == b"[]"
+            )
+
+    def test_array_unicode(self):
+        """
+        array.array of typecode u raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(array.array("u", "abc"), option=orjson.OPT_SERIALIZE_ARRAY)
+        assert "typecode" in str(exc_info.value)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array.array("u"), option=orjson.OPT_SERIALIZE_ARRAY)
+
+    def test_array_unicode_default(self):
+        """
+        array.array of typecode u is not passed to default
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                array.array("u", "abc"),
+                option=orjson.OPT_SERIALIZE_ARRAY,
+                default=lambda obj: obj.tounicode(),
+            )
+
+    def test_array_subclass(self):
+        """
+        array.array subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(ArraySubclass("i", [1]), option=orjson.OPT_SERIALIZE_ARRAY)
+
+    def test_array_nested(self):
+        """
+        array.array in list and dict
+        """
+        val = {"a": [array.array("i", [1, 2]), array.array("d", [0.5])]}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY)
+            == b'{"a":[[1,2],[0.5]]}'
+        )
+        option = orjson.OPT_SERIALIZE_ARRAY | orjson.OPT_INDENT_2
+        assert orjson.dumps(val, option=option) == orjson.dumps(
+            {"a": [[1, 2], [0.5]]}, option=orjson.OPT_INDENT_2
+        )
+
+    def test_array_large(self):
+        """
+        array.array large
+        """
+        val = array.array("q", range(100000))
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == orjson.dumps(
+            list(range(100000))
+        )
+
+
+def view(typecode, values):
+    return memoryview(array.array(typecode, values))
+
+
+class TestMemoryview:
+    def test_memoryview_default(self):
+        """
+        memoryview is not serialized without OPT_SERIALIZE_ARRAY
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(view("i", [1]))
+
+    @pytest.mark.parametrize("typecode", INT_TYPECODES)
+    def test_memoryview_int(self, typecode):
+        """
+        memoryview of integer formats
+        """
+        val = view(typecode, [0, 1, 100])
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[0,1,100]"
+
+    def test_memoryview_float(self):
+        """
+        memoryview of float formats
+        """
+        assert (
+            orjson.dumps(view("d", [1.5, -0.0]), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[1.5,-0.0]"
+        )
+        assert (
+            orjson.dumps(view("f", [0.5, 2.0]), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[0.5,2.0]"
+        )
+
+    def test_memoryview_bytes(self):
+        """
+        memoryview of bytes is an array of integers
+        """
+        assert (
+            orjson.dumps(memoryview(b"ab"), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[97,98]"
+        )
+        assert (
+            orjson.dumps(memoryview(bytearray()), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[]"
+        )
+
+    def test_memoryview_shape(self):
+        """
+        memoryview of more than one dimension is nested arrays
+        """
+        val = view("i", range(6)).cast("B")
+        assert (
+            orjson.dumps(val.cast("i", shape=[2, 3]), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[[0,1,2],[3,4,5]]"
+        )
+        assert (
+            orjson.dumps(val.cast("i", shape=[3, 2]), option=orjson.OPT_SERIALIZE_ARRAY)
+            == b"[[0,1],[2,3],[4,5]]"
+        )
+        assert (
+            orjson.dumps(
+                val.cast("i", shape=[2, 1, 3]), option=orjson.OPT_SERIALIZE_ARRAY
+            )
+            == b"[[[0,1,2]],[[3,4,5]]]"
+        )
+        assert orjson.dumps(
+            val.cast("i", shape=[2, 3]),
+            option=orjson.OPT_SERIALIZE_ARRAY | orjson.OPT_INDENT_2,
+        ) == orjson.dumps([[0, 1, 2], [3, 4, 5]], option=orjson.OPT_INDENT_2)
+
+    def test_memoryview_scalar(self):
+        """
+        memoryview of no dimensions is a number
+        """
+        val = view("q", [-7]).cast("B").cast("q", shape=[])
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"-7"
+        val = view("d", [0.5]).cast("B").cast("d", shape=[])
+        assert orjson.dumps([val], option=orjson.OPT_SERIALIZE_ARRAY) == b"[0.5]"
+
+    def test_memoryview_unaligned(self):
+        """
+        memoryview not aligned to its format
+        """
+        buf = bytearray(1) + array.array("i", [1, -2, 3]).tobytes()
+        val = memoryview(buf)[1:].cast("i")
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[1,-2,3]"
+        val = memoryview(buf)[1:].cast("i", shape=[3, 1])
+        assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY) == b"[[1],[-2],[3]]"
+
+    def test_memoryview_unsupported_format(self):
+        """
+        memoryview of a format that is not a number raises
+        """
+        for val in (memoryview(b"\x01").cast("?"), memoryview(b"a").cast("c")):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY)
+            assert str(exc_info.value) == (
+                "memoryview format is not supported; use memoryview.tolist() in default"
+            )
+
+    def test_memoryview_not_contiguous(self):
+        """
+        memoryview not C contiguous raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(view("i", range(4))[::2], option=orjson.OPT_SERIALIZE_ARRAY)
+        assert isinstance(exc_info.value.__cause__, BufferError)
+
+    def test_memoryview_released(self):
+        """
+        memoryview released raises
+        """
+        val = view("i", [1])
+        val.release()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_memoryview_base64(self):
+        """
+        memoryview OPT_SERIALIZE_ARRAY bytes_format="base64" with a numeric
+        format is an array and of bytes is base64
+        """
+        kwargs = {"option": orjson.OPT_SERIALIZE_ARRAY, "bytes_format": "base64"}
+        assert orjson.dumps(view("i", [1, 2]), **kwargs) == b"[1,2]"
+        assert (
+            orjson.dumps(view("d", [0.5]).cast("B").cast("d", shape=[]), **kwargs)
+            == b"0.5"
+        )
+        assert orjson.dumps(memoryview(b"ab"), **kwargs) == b'"YWI="'
+        assert orjson.dumps(view("b", [97, 98]), **kwargs) == b'"YWI="'
+        assert orjson.dumps(memoryview(b"\x01").cast("?"), **kwargs) == b'"AQ=="'
+        assert orjson.dumps(bytearray(b"ab"), **kwargs) == b'"YWI="'
+
+    def test_memoryview_nested(self):
+        """
+        memoryview in list and dict
+        """
+        val = {"a": [view("i", [1, 2]), view("d", [0.5])]}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_ARRAY)
+            == b'{"a":[[1,2],[0.5]]}'
+        )
@@ -0,0 +1,218 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import collections
+import io
+import json
+import sys
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+Point = collections.namedtuple("Point", ["x", "y"])
+
+
+class TestArrayHook:
+    def test_array_hook(self):
+        """
+        loads() array_hook replaces each array
+        """
+
+        def hook(arr):
+            if len(arr) == 2 and all(isinstance(val, int) for val in arr):
+                return Point(*arr)
+            return arr
+
+        val = orjson.loads('{"path": [[0, 1], [2, 3]], "a": [1]}', array_hook=hook)
+        assert val == {"path": [Point(0, 1), Point(2, 3)], "a": [1]}
+        assert type(val["path"][0]) is Point
+        assert type(val["path"]) is list
+
+    def test_array_hook_bottom_up(self):
+        """
+        loads() array_hook is called with complete arrays, innermost first
+        """
+        calls = []
+
+        def hook(arr):
+            calls.append(list(arr))
+            return len(calls)
+
+        val = orjson.loads('{"a": [[1, [2]], []], "b": [{"c": [3]}]}', array_hook=hook)
+        assert val == {"a": 4, "b": 6}
+        assert calls == [[2], [1, 1], [], [2, 3], [3], [{"c": 5}]]
+
+    def test_array_hook_empty(self):
+        """
+        loads() array_hook is called for empty arrays
+        """
+        assert orjson.loads("[]", array_hook=lambda arr: "x") == "x"
+        assert orjson.loads(b"[]", array_hook=lambda arr: "x") == "x"
+        assert orjson.loads(b"[ ]", array_hook=lambda arr: "x") == "x"
+        assert orjson.loads("{}", array_hook=lambda arr: "x") == {}
+        assert orjson.loads('""', array_hook=lambda arr: "x") == ""
+
+    def test_array_hook_scalar(self):
+        """
+        loads() array_hook is not called without arrays
+        """
+
+        def hook(arr):
+            raise AssertionError
+
+        assert orjson.loads('{"a": {"b": 1.5}}', array_hook=hook) == {"a": {"b": 1.5}}
+
+    def test_array_hook_same_as_tuple(self):
+        """
+        loads() array_hook output is the same as converting arrays afterward
+        """
+
+        def to_tuple(obj):
+            if isinstance(obj, list):
+                return tuple(to_tuple(val) for val in obj)
+            if isinstance(obj, dict):
+                return {key: to_tuple(val) for key, val in obj.items()}
+            return obj
+
+        for filename in ("twitter.json.xz", "github.json.xz"):
+            doc = orjson.dumps(read_fixture_obj(filename))
+            assert orjson.loads(doc, array_hook=tuple) == to_tuple(json.loads(doc))
+
+    def test_array_hook_object_hook(self):
+        """
+        loads() array_hook and object_hook are called in document order
+        """
+        calls = []
+
+        def object_hook(obj):
+            calls.append("object")
+            return sorted(obj)
+
+        def array_hook(arr):
+            calls.append("array")
+            return tuple(arr)
+
+        val = orjson.loads(
+            '[{"b": [1], "a": 2}, [{}]]',
+            object_hook=object_hook,
+            array_hook=array_hook,
+        )
+        assert val == (["a", "b"], ([],))
+        assert calls == ["array", "object", "object", "array", "array"]
+
+    def test_array_hook_none(self):
+        """
+        loads() array_hook=None is the same as not specifying it
+        """
+        assert orjson.loads("[[]]", array_hook=None) == [[]]
+        assert orjson.loads("[]", array_hook=None) == []
+
+    def test_array_hook_option(self):
+        """
+        loads() array_hook with option and max_depth
+        """
+        val = orjson.loads(
+            "[1.5, 2]",
+            option=orjson.OPT_PARSE_DECIMAL,
+            max_depth=1,
+            array_hook=lambda arr: [type(val) for val in arr],
+        )
+        assert val == [type(orjson.loads("1.5", option=orjson.OPT_PARSE_DECIMAL)), int]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[[]]", max_depth=1, array_hook=tuple)
+
+    def test_array_hook_dict_as_pairs(self):
+        """
+        loads() array_hook OPT_DICT_AS_PAIRS is called with pairs, but not
+        with an array of pairs that is a dict
+        """
+        calls = []
+
+        def hook(arr):
+            calls.append(list(arr))
+            return arr
+
+        val = orjson.loads(
+            "[[1, 2], [3, 4]]", option=orjson.OPT_DICT_AS_PAIRS, array_hook=hook
+        )
+        assert val == {1: 2, 3: 4}
+        assert calls == [[1, 2], [3, 4]]
+        val = orjson.loads(
+            "[[1, 2], [3, 4]]", option=orjson.OPT_DICT_AS_PAIRS, array_hook=tuple
+        )
+        assert val == ((1, 2), (3, 4))
+        calls.clear()
+        assert orjson.loads(
+            "[[1, 2], 3]", option=orjson.OPT_DICT_AS_PAIRS, array_hook=hook
+        ) == [[1, 2], 3]
+        assert calls == [[1, 2], [[1, 2], 3]]
+
+    def test_array_hook_exception(self):
+        """
+        loads() array_hook exception propagates unchanged
+        """
+
+        class CustomError(Exception):
+            pass
+
+        def hook(arr):
+            if "b" in arr:
+                raise CustomError("zxc")
+            return arr
+
+        with pytest.raises(CustomError) as exc_info:
+            orjson.loads('[["a"], ["b", [1]], ["c"]]', array_hook=hook)
+        assert not isinstance(exc_info.value, orjson.JSONDecodeError)
+        assert str(exc_info.value) == "zxc"
+
+    def test_array_hook_exception_refcount(self):
+        """
+        loads() array_hook exception releases objects
+        """
+        sentinel = object()
+        ref = sys.getrefcount(sentinel)
+        calls = []
+
+        def hook(arr):
+            calls.append(arr)
+            if len(calls) == 3:
+                raise ValueError
+            return sentinel
+
+        with pytest.raises(ValueError):
+            orjson.loads('{"a": [[]], "b": [[1], {"c": []}]}', array_hook=hook)
+        calls.clear()
+        assert sys.getrefcount(sentinel) == ref
+
+    def test_array_hook_invalid_document(self):
+        """
+        loads() array_hook with invalid JSON raises JSONDecodeError
+        """
+        calls = []
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[[1], [2, ]", array_hook=calls.append)
+        assert calls == [[1]]
+
+    def test_array_hook_not_callable(self):
+        """
+        loads() array_hook that is not callable raises TypeError
+        """
+        with pytest.raises(TypeError):
+            orjson.loads("[]", array_hook=1)  # type: ignore
+        assert orjson.loads("{}", array_hook=1) == {}  # type: ignore
+
+    def test_array_hook_positional(self):
+        """
+        loads() array_hook is keyword-only
+        """
+        with pytest.raises(TypeError):
+            orjson.loads("[]", None, tuple)  # type: ignore
+
+    def test_array_hook_load(self):
+        """
+        load() array_hook
+        """
+        assert orjson.load(io.BytesIO(b"[[1], 2]"), array_hook=tuple) == ((1,), 2)
+        assert orjson.load(io.StringIO("[]"), array_hook=len) == 0
@@ -0,0 +1,120 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import json
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+def stdlib(obj):
+    return json.dumps(obj, ensure_ascii=True, separators=(",", ":")).encode("utf-8")
+
+
+class TestAscii:
+    def test_ascii_default(self):
+        """
+        dumps() does not escape non-ASCII by default
+        """
+        assert orjson.dumps("é") == b'"\xc3\xa9"'
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "",
+            "abc",
+            "é",
+            "café",
+            "\u2028\u2029",
+            "\ufeff",
+            "\uffff",
+            "😀",
+            "a😀b",
+            "\U0010ffff",
+            "日本語",
+            'é"\\\n\t\x00\x1f',
+            "x" * 64 + "é",
+            "é" + "x" * 64,
+            "x" * 15 + "é" + "y" * 17 + "\n",
+        ],
+    )
+    def test_ascii_str(self, val):
+        """
+        dumps() OPT_ASCII str matches json.dumps(ensure_ascii=True)
+        """
+        assert orjson.dumps(val, option=orjson.OPT_ASCII) == stdlib(val)
+
+    def test_ascii_surrogate_pair(self):
+        """
+        dumps() OPT_ASCII astral codepoint as surrogate pair
+        """
+        assert orjson.dumps("😀", option=orjson.OPT_ASCII) == b'"\\ud83d\\ude00"'
+
+    def test_ascii_lowercase_hex(self):
+        """
+        dumps() OPT_ASCII uses lowercase hex
+        """
+        assert orjson.dumps("\u00ff", option=orjson.OPT_ASCII) == b'"\\u00ff"'
+
+    def test_ascii_key(self):
+        """
+        dumps() OPT_ASCII dict key
+        """
+        assert (
+            orjson.dumps({"ключ": "é"}, option=orjson.OPT_ASCII)
+            == b'{"\\u043a\\u043b\\u044e\\u0447":"\\u00e9"}'
+        )
+
+    def test_ascii_non_str_keys(self):
+        """
+        dumps() OPT_ASCII dict key with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"é": 1, 2: 3}, option=orjson.OPT_ASCII | orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"\\u00e9":1,"2":3}'
+        )
+
+    def test_ascii_indent(self):
+        """
+        dumps() OPT_ASCII with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(["é"], option=orjson.OPT_ASCII | orjson.OPT_INDENT_2)
+            == b'[\n  "\\u00e9"\n]'
+        )
+
+    def test_ascii_output_is_ascii(self):
+        """
+        dumps() OPT_ASCII output contains only ASCII
+        """
+        val = read_fixture_obj("twitter.json.xz")
+        output = orjson.dumps(val, option=orjson.OPT_ASCII)
+        assert output.isascii()
+        assert orjson.loads(output) == val
+
+    def test_ascii_invalid_str(self):
+        """
+        dumps() OPT_ASCII still rejects invalid UTF-8
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps("\ud800", option=orjson.OPT_ASCII)
+
+    def test_ascii_fragment(self):
+        """
+        dumps() OPT_ASCII does not affect orjson.Fragment
+        """
+        assert (
+            orjson.dumps(orjson.Fragment('"é"'), option=orjson.OPT_ASCII)
+            == b'"\xc3\xa9"'
+        )
+
+    def test_ascii_roundtrip(self):
+        """
+        dumps() OPT_ASCII roundtrips through loads()
+        """
+        val = {"a": ["é", "😀", "日本語", "\x00"]}
+        assert orjson.loads(orjson.dumps(val, option=orjson.OPT_ASCII)) == val
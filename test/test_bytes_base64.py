@@ -0,0 +1,144 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import array
+import base64
+
+import pytest
+
+import orjson
+
+BASE64 = {"bytes_format": "base64"}
+URLSAFE = {"bytes_format": "base64url"}
+
+
+class BytesSubclass(bytes):
+    pass
+
+
+class TestBytesBase64:
+    def test_bytes_default(self):
+        """
+        bytes, bytearray, and memoryview are not serialized by default
+        """
+        for obj in (b"a", bytearray(b"a"), memoryview(b"a")):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj)
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, uuid_format="base64url")
+
+    def test_bytes_base64(self):
+        """
+        bytes_format="base64" bytes
+        """
+        assert orjson.dumps(b"orjson", **BASE64) == b'"b3Jqc29u"'
+        assert orjson.dumps(b"\x00\x01orjson", **BASE64) == b'"AAFvcmpzb24="'
+
+    def test_bytes_base64_padding(self):
+        """
+        bytes_format="base64" every length of padding
+        """
+        for length in range(64):
+            val = bytes(range(256 - length, 256))
+            expected = b'"' + base64.b64encode(val) + b'"'
+            assert orjson.dumps(val, **BASE64) == expected
+
+    def test_bytes_base64_all(self):
+        """
+        bytes_format="base64" every byte value
+        """
+        val = bytes(range(256)) * 4
+        assert orjson.dumps(val, **BASE64) == b'"' + base64.b64encode(val) + b'"'
+
+    def test_bytes_base64_empty(self):
+        """
+        bytes_format="base64" empty buffer is ""
+        """
+        for obj in (b"", bytearray(), memoryview(b"")):
+            assert orjson.dumps(obj, **BASE64) == b'""'
+
+    def test_bytes_base64_large(self):
+        """
+        bytes_format="base64" large buffer
+        """
+        val = bytes(range(256)) * 4096 + b"a"
+        assert orjson.dumps(val, **BASE64) == b'"' + base64.b64encode(val) + b'"'
+
+    def test_bytes_base64_urlsafe(self):
+        """
+        bytes_format="base64url"
+        """
+        assert orjson.dumps(b"\xfb\xff", **BASE64) == b'"+/8="'
+        assert orjson.dumps(b"\xfb\xff", **URLSAFE) == b'"-_8="'
+        val = bytes(range(256)) * 3
+        assert (
+            orjson.dumps(val, **URLSAFE)
+            == b'"' + base64.urlsafe_b64encode(val) + b'"'
+        )
+
+    def test_bytearray_base64(self):
+        """
+        bytes_format="base64" bytearray
+        """
+        assert orjson.dumps(bytearray(b"orjson"), **BASE64) == b'"b3Jqc29u"'
+
+    def test_memoryview_base64(self):
+        """
+        bytes_format="base64" memoryview
+        """
+        assert orjson.dumps(memoryview(b"orjson"), **BASE64) == b'"b3Jqc29u"'
+        assert (
+            orjson.dumps(memoryview(bytearray(b"orjson"))[1:4], **BASE64)
+            == b'"cmpz"'
+        )
+
+    def test_memoryview_base64_format(self):
+        """
+        bytes_format="base64" memoryview of another format is raw bytes
+        """
+        val = array.array("i", [1, 2])
+        assert (
+            orjson.dumps(memoryview(val), **BASE64)
+            == b'"' + base64.b64encode(val.tobytes()) + b'"'
+        )
+
+    def test_memoryview_base64_not_contiguous(self):
+        """
+        bytes_format="base64" memoryview not C contiguous
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(memoryview(b"abcd")[::2], **BASE64)
+        assert isinstance(exc_info.value.__cause__, BufferError)
+
+    def test_memoryview_base64_released(self):
+        """
+        bytes_format="base64" released memoryview
+        """
+        view = memoryview(b"abcd")
+        view.release()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(view, **BASE64)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_bytes_base64_nested(self):
+        """
+        bytes_format="base64" in list and dict values
+        """
+        obj = {"a": [b"a", bytearray(b"bc")], "b": (memoryview(b"def"),)}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS, **BASE64)
+            == b'{"a":["YQ==","YmM="],"b":["ZGVm"]}'
+        )
+
+    def test_bytes_base64_subclass(self):
+        """
+        bytes_format="base64" bytes subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(BytesSubclass(b"a"), **BASE64)
+
+    def test_bytes_base64_dict_key(self):
+        """
+        bytes_format="base64" bytes is not a valid OPT_NON_STR_KEYS key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({b"a": 1}, option=orjson.OPT_NON_STR_KEYS, **BASE64)
@@ -0,0 +1,227 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import collections
+import dataclasses
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class User:
+    user_id: int
+    display_name: str
+    _private_field: int = 0
+
+
+Point = collections.namedtuple("Point", ["x_pos", "y_pos"])
+
+
+def camel(obj, option=0):
+    return orjson.dumps(obj, option=orjson.OPT_CAMELCASE | option)
+
+
+class TestCamelCase:
+    def test_camelcase(self):
+        """
+        OPT_CAMELCASE converts snake_case keys
+        """
+        assert camel({"user_id": 1, "a_b_c": 2}) == b'{"userId":1,"aBC":2}'
+
+    @pytest.mark.parametrize(
+        "case",
+        [
+            ("a", "a"),
+            ("", ""),
+            ("userId", "userId"),
+            ("User_id", "UserId"),
+            ("a__b", "aB"),
+            ("_private_name", "_privateName"),
+            ("class_", "class_"),
+            ("__dunder__", "__dunder__"),
+            ("_", "_"),
+            ("___", "___"),
+            ("field_1", "field1"),
+            ("ab_éf", "abéf"),
+            ("a_ß", "aß"),
+            ("a_\U0001f408", "a\U0001f408"),
+        ],
+    )
+    def test_camelcase_key(self, case):
+        """
+        OPT_CAMELCASE underscores
+        """
+        key, expected = case
+        assert orjson.loads(camel({key: 1})) == {expected: 1}
+
+    def test_camelcase_default(self):
+        """
+        keys are unchanged without OPT_CAMELCASE
+        """
+        assert orjson.dumps({"user_id": 1}) == b'{"user_id":1}'
+
+    def test_camelcase_values(self):
+        """
+        OPT_CAMELCASE does not change values
+        """
+        assert camel({"a_b": ["c_d", {"e_f": "g_h"}]}) == (
+            b'{"aB":["c_d",{"eF":"g_h"}]}'
+        )
+
+    def test_camelcase_dataclass(self):
+        """
+        OPT_CAMELCASE dataclass fields
+        """
+        obj = User(1, "a")
+        assert camel(obj) == b'{"userId":1,"displayName":"a"}'
+        assert (
+            camel(obj, orjson.OPT_INCLUDE_PRIVATE_FIELDS)
+            == b'{"userId":1,"displayName":"a","_privateField":0}'
+        )
+        assert camel([obj], orjson.OPT_SORT_KEYS) == (
+            b'[{"displayName":"a","userId":1}]'
+        )
+
+    def test_camelcase_dataclass_rename(self):
+        """
+        OPT_CAMELCASE applies to names from OPT_DATACLASS_FIELD_RENAME
+        """
+
+        @dataclasses.dataclass
+        class Renamed:
+            a: int = dataclasses.field(metadata={"orjson_name": "first_value"})
+
+        assert camel(Renamed(1), orjson.OPT_DATACLASS_FIELD_RENAME) == (
+            b'{"firstValue":1}'
+        )
+
+    def test_camelcase_namedtuple(self):
+        """
+        OPT_CAMELCASE OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+        """
+        assert (
+            camel(Point(1, 2), orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+            == b'{"xPos":1,"yPos":2}'
+        )
+
+    def test_camelcase_non_str_keys(self):
+        """
+        OPT_CAMELCASE OPT_NON_STR_KEYS
+        """
+        assert camel({1: 1, None: 2, "a_b": 3}, orjson.OPT_NON_STR_KEYS) == (
+            b'{"1":1,"null":2,"aB":3}'
+        )
+
+    def test_camelcase_sort_keys(self):
+        """
+        OPT_CAMELCASE OPT_SORT_KEYS sorts keys as written
+        """
+        # "aC" < "a_b" but "aB" < "aC"
+        obj = {"aC": 1, "a_b": 2}
+        assert camel(obj, orjson.OPT_SORT_KEYS) == b'{"aB":2,"aC":1}'
+        assert camel(obj, orjson.OPT_SORT_KEYS | orjson.OPT_NON_STR_KEYS) == (
+            b'{"aB":2,"aC":1}'
+        )
+        assert camel(obj, orjson.OPT_CANONICAL) == b'{"aB":2,"aC":1}'
+        assert camel(User(1, "a"), orjson.OPT_SORT_KEYS_CASE_INSENSITIVE) == (
+            b'{"displayName":"a","userId":1}'
+        )
+
+    def test_camelcase_duplicate(self):
+        """
+        OPT_CAMELCASE keys that are the same once converted raise
+        """
+        for option in (0, orjson.OPT_SORT_KEYS, orjson.OPT_NON_STR_KEYS):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                camel({"a": {"user_id": 1, "userId": 2}}, option)
+            assert str(exc_info.value) == (
+                'Dict key "userId" is not unique when serialized as str: '
+                "'user_id' and 'userId' at $.a (output byte 5)"
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            camel({"a_b": 1, "x": 2, "a__b": 3})
+        obj = collections.OrderedDict([("aB", 1), ("a_b", 2)])
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            camel(obj, orjson.OPT_PRESERVE_ORDERED_DICT)
+        assert str(exc_info.value) == (
+            'Dict key "aB" is not unique when serialized as str: '
+            "'aB' and 'a_b'"
+        )
+
+    def test_camelcase_duplicate_not_converted(self):
+        """
+        OPT_CAMELCASE keys that are the same before being converted are as
+        with OPT_NON_STR_KEYS
+        """
+        assert camel({1: 1, "1": 2, "a_b": 3}, orjson.OPT_NON_STR_KEYS) == (
+            b'{"1":1,"1":2,"aB":3}'
+        )
+        assert camel({"user_id": 1, "userid": 2}) == b'{"userId":1,"userid":2}'
+
+    def test_camelcase_duplicate_field(self):
+        """
+        OPT_CAMELCASE dataclass and named tuple fields that are the same once
+        converted raise
+        """
+
+        @dataclasses.dataclass
+        class Fields:
+            user_id: int
+            userId: int
+
+        for option in (0, orjson.OPT_SORT_KEYS):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                camel([Fields(1, 2)], option)
+            assert str(exc_info.value) == (
+                'Field "userId" is not unique when serialized with OPT_CAMELCASE: '
+                "'user_id' and 'userId' at $[0] (output byte 2)"
+            )
+        encoder = orjson.Encoder(option=orjson.OPT_CAMELCASE)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk(Fields(1, 2))
+        obj = collections.namedtuple("Fields", ["user_id", "userId"])(1, 2)
+        with pytest.raises(orjson.JSONEncodeError):
+            camel(obj, orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+
+    def test_camelcase_reject_duplicate(self):
+        """
+        OPT_CAMELCASE OPT_REJECT_DUPLICATE_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            camel({"a": {"user_id": 1, "userId": 2}}, orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert str(exc_info.value) == (
+            'Dict key "userId" is not unique when serialized as str: '
+            "'user_id' and 'userId' at $.a (output byte 5)"
+        )
+        assert (
+            camel({"user_id": 1, "name": 2}, orjson.OPT_REJECT_DUPLICATE_KEYS)
+            == b'{"userId":1,"name":2}'
+        )
+
+    def test_camelcase_reject_duplicate_key_type(self):
+        """
+        OPT_CAMELCASE OPT_REJECT_DUPLICATE_KEYS still requires str keys
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            camel({"a_b": {1: 1}}, orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert str(exc_info.value) == (
+            "Dict key must be str, not int at $.a_b (output byte 6)"
+        )
+
+    def test_camelcase_indent(self):
+        """
+        OPT_CAMELCASE OPT_INDENT_2
+        """
+        assert camel({"a_b": {"c_d": 1}}, orjson.OPT_INDENT_2) == (
+            b'{\n  "aB": {\n    "cD": 1\n  }\n}'
+        )
+
+    def test_camelcase_encoder(self):
+        """
+        orjson.Encoder() OPT_CAMELCASE
+        """
+        encoder = orjson.Encoder(option=orjson.OPT_CAMELCASE)
+        assert encoder.write_chunk(User(1, "a")) == b'[{"userId":1,"displayName":"a"}'
+        assert encoder.write_chunk({"x_y": 1}) == b',{"xY":1}'
+        assert encoder.close() == b"]"
@@ -1,5 +1,12 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import array
+import collections
+import dataclasses
+import struct
+
+import pytest
+
 import orjson
 
 
@@ -24,3 +31,240 @@ class TestCanonicalTests:
             orjson.dumps({"spaces": "\u2028 \u2029"})
             == b'{"spaces":"\xe2\x80\xa8 \xe2\x80\xa9"}'
         )
+
+
+def _double(val):
+    return struct.unpack(">d", bytes.fromhex(val))[0]
+
+
+class TestOptCanonical:
+    @pytest.mark.parametrize(
+        "case",
+        [
+            # RFC 8785 Appendix B
+            ("0000000000000000", b"0"),
+            ("8000000000000000", b"0"),
+            ("0000000000000001", b"5e-324"),
+            ("8000000000000001", b"-5e-324"),
+            ("7fefffffffffffff", b"1.7976931348623157e+308"),
+            ("ffefffffffffffff", b"-1.7976931348623157e+308"),
+            ("4340000000000000", b"9007199254740992"),
+            ("c340000000000000", b"-9007199254740992"),
+            ("4430000000000000", b"295147905179352830000"),
+            ("44b52d02c7e14af5", b"9.999999999999997e+22"),
+            ("44b52d02c7e14af6", b"1e+23"),
+            ("44b52d02c7e14af7", b"1.0000000000000001e+23"),
+            ("444b1ae4d6e2ef4e", b"999999999999999700000"),
+            ("444b1ae4d6e2ef4f", b"999999999999999900000"),
+            ("444b1ae4d6e2ef50", b"1e+21"),
+            ("3eb0c6f7a0b5ed8c", b"9.999999999999997e-7"),
+            ("3eb0c6f7a0b5ed8d", b"0.000001"),
+            ("41b3de4355555553", b"333333333.3333332"),
+            ("41b3de4355555554", b"333333333.33333325"),
+            ("41b3de4355555555", b"333333333.3333333"),
+            ("41b3de4355555556", b"333333333.3333334"),
+            ("41b3de4355555557", b"333333333.33333343"),
+            ("becbf647612f3696", b"-0.0000033333333333333333"),
+            ("43143ff3c1cb0959", b"1424953923781206.2"),
+        ],
+    )
+    def test_canonical_float(self, case):
+        """
+        OPT_CANONICAL float is formatted as ECMAScript
+        """
+        val, expected = case
+        assert orjson.dumps(_double(val), option=orjson.OPT_CANONICAL) == expected
+
+    def test_canonical_float_integral(self):
+        """
+        OPT_CANONICAL float that is integral has no fraction
+        """
+        assert (
+            orjson.dumps([100.0, -1.0, 1e20], option=orjson.OPT_CANONICAL)
+            == b"[100,-1,100000000000000000000]"
+        )
+        assert orjson.dumps([100.0, -1.0]) == b"[100.0,-1.0]"
+
+    def test_canonical_float_nonfinite(self):
+        """
+        OPT_CANONICAL float that is not finite raises
+        """
+        for val in (float("nan"), float("inf"), float("-inf")):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps([val], option=orjson.OPT_CANONICAL)
+            assert (
+                str(exc_info.value)
+                == "Float must be finite with OPT_CANONICAL (output byte 1)"
+            )
+
+    def test_canonical_int(self):
+        """
+        OPT_CANONICAL int must be exactly representable as a double
+        """
+        assert (
+            orjson.dumps(
+                [0, 9007199254740991, -9007199254740991], option=orjson.OPT_CANONICAL
+            )
+            == b"[0,9007199254740991,-9007199254740991]"
+        )
+        for val in (9007199254740992, -9007199254740992, 2**64):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(val, option=orjson.OPT_CANONICAL)
+            assert str(exc_info.value).startswith("Integer exceeds 53-bit range")
+
+    def test_canonical_float32(self):
+        """
+        OPT_CANONICAL single precision float is formatted as a double
+        """
+        assert (
+            orjson.dumps(
+                array.array("f", [0.1, 1.5]),
+                option=orjson.OPT_CANONICAL | orjson.OPT_SERIALIZE_ARRAY,
+            )
+            == b"[0.10000000149011612,1.5]"
+        )
+
+    def test_canonical_example(self):
+        """
+        OPT_CANONICAL RFC 8785 section 3.2.2 example
+        """
+        obj = orjson.loads(
+            '{"numbers": [333333333.33333329, 1E30, 4.50, 2e-3, '
+            "0.000000000000000000000000001], "
+            '"string": "\\u20ac$\\u000F\\u000aA\'\\u0042\\u0022\\u005c\\\\\\"\\/", '
+            '"literals": [null, true, false]}'
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_CANONICAL) == (
+            b'{"literals":[null,true,false],'
+            b'"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],'
+            b'"string":"\xe2\x82\xac$\\u000f\\nA\'B\\"\\\\\\\\\\"/"}'
+        )
+
+    def test_canonical_sort_utf16(self):
+        """
+        OPT_CANONICAL sorts keys by UTF-16 code units, RFC 8785 section 3.2.3
+        """
+        obj = {
+            "€": "Euro Sign",
+            "\r": "Carriage Return",
+            "דּ": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\U0001f600": "Emoji: Grinning Face",
+            "\u0080": "Control",
+            "ö": "Latin Small Letter O With Diaeresis",
+        }
+        expected = [
+            "\r",
+            "1",
+            "\u0080",
+            "ö",
+            "€",
+            "\U0001f600",
+            "דּ",
+        ]
+        for option in (
+            orjson.OPT_CANONICAL,
+            orjson.OPT_CANONICAL | orjson.OPT_NON_STR_KEYS,
+        ):
+            assert list(orjson.loads(orjson.dumps(obj, option=option))) == expected
+        # OPT_SORT_KEYS sorts by UTF-8
+        assert list(orjson.loads(orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)))[
+            -2:
+        ] == ["דּ", "\U0001f600"]
+
+    def test_canonical_nested(self):
+        """
+        OPT_CANONICAL nested dict, dataclass, and list
+        """
+
+        @dataclasses.dataclass
+        class Dataclass:
+            b: int
+            a: dict
+
+        obj = {"b": [{"d": 1.0, "c": -0.0}], "a": Dataclass(1, {"z": 1, "y": 2})}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_CANONICAL)
+            == b'{"a":{"a":{"y":2,"z":1},"b":1},"b":[{"c":0,"d":1}]}'
+        )
+
+    def test_canonical_fixed_keys(self):
+        """
+        OPT_CANONICAL sorts the keys of complex, range, and named tuple objects
+        """
+        Point = collections.namedtuple("Point", ["y", "x"])
+        assert (
+            orjson.dumps(
+                [1 + 2j, range(1, 5, 2), Point(1, 2)],
+                option=orjson.OPT_CANONICAL
+                | orjson.OPT_SERIALIZE_COMPLEX
+                | orjson.OPT_SERIALIZE_RANGE
+                | orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT,
+                complex_format="object",
+            )
+            == b'[{"imag":2,"real":1},{"start":1,"step":2,"stop":5},{"x":2,"y":1}]'
+        )
+
+    def test_canonical_fragment(self):
+        """
+        OPT_CANONICAL does not change orjson.Fragment
+        """
+        assert (
+            orjson.dumps(
+                [orjson.Fragment(b'{"b": 1.0, "a": 2}')], option=orjson.OPT_CANONICAL
+            )
+            == b'[{"b": 1.0, "a": 2}]'
+        )
+
+    def test_canonical_compatible_options(self):
+        """
+        OPT_CANONICAL with options that do not conflict
+        """
+        for option in (
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_STRICT_INTEGER,
+            orjson.OPT_NON_STR_KEYS,
+        ):
+            assert (
+                orjson.dumps({"b": 1, "a": 2}, option=orjson.OPT_CANONICAL | option)
+                == b'{"a":2,"b":1}'
+            )
+        assert (
+            orjson.dumps(
+                {"b": 1, "a": 2},
+                option=orjson.OPT_CANONICAL | orjson.OPT_APPEND_NEWLINE,
+            )
+            == b'{"a":2,"b":1}\n'
+        )
+
+    @pytest.mark.parametrize(
+        "option",
+        [
+            orjson.OPT_ALLOW_NONFINITE,
+            orjson.OPT_ASCII,
+            orjson.OPT_ESCAPE_FORWARD_SLASH,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_INDENT_4,
+            orjson.OPT_INDENT_TAB,
+            orjson.OPT_PRESERVE_ORDERED_DICT,
+            orjson.OPT_SERIALIZE_DECIMAL,
+            orjson.OPT_SORT_KEYS_CASE_INSENSITIVE,
+            orjson.OPT_SPACE_AFTER_COLON,
+            orjson.OPT_SPACE_AFTER_COMMA,
+        ],
+    )
+    def test_canonical_invalid_options(self, option):
+        """
+        OPT_CANONICAL with options that would change the output is invalid
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({}, option=orjson.OPT_CANONICAL | option)
+        assert str(exc_info.value) == "Invalid opts"
+
+    def test_canonical_invalid_uuid_format(self):
+        """
+        OPT_CANONICAL with uuid_format="int" is invalid
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({}, option=orjson.OPT_CANONICAL, uuid_format="int")
+        assert str(exc_info.value) == "Invalid uuid_format"
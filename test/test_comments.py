@@ -0,0 +1,111 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+def loads(val):
+    return orjson.loads(val, option=orjson.OPT_ALLOW_COMMENTS)
+
+
+class TestAllowComments:
+    def test_comments_default(self):
+        """
+        loads() comments are an error by default
+        """
+        for val in ("// a\n[]", "[] // a", "/* a */ []", "[1 /* a */]", "/[]", "[]/"):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(val)
+
+    def test_comments_line(self):
+        """
+        loads() OPT_ALLOW_COMMENTS // comment
+        """
+        assert loads("// a\n[1]") == [1]
+        assert loads("[1] // a") == [1]
+        assert loads("[1, // a\n2]") == [1, 2]
+        assert loads('{"a" // a\n: // b\n1 // c\n}') == {"a": 1}
+        assert loads("[1] //") == [1]
+        assert loads("[1] // a\r\n// b") == [1]
+
+    def test_comments_block(self):
+        """
+        loads() OPT_ALLOW_COMMENTS /* */ comment
+        """
+        assert loads("/* a */ [1]") == [1]
+        assert loads("[1/* a */,/**/2/***/]") == [1, 2]
+        assert loads('{/* a */"a"/* b */:/* c */true/* d */}') == {"a": True}
+        assert loads("[/* a\n b\n */ null]") == [None]
+        assert loads("[/* // */ 1]") == [1]
+        assert loads("[// /* \n 1]") == [1]
+        assert loads("1/* a */") == 1
+
+    def test_comments_in_string(self):
+        """
+        loads() OPT_ALLOW_COMMENTS does not change strings
+        """
+        assert loads('["// a", "/* b */"]') == ["// a", "/* b */"]
+        assert loads('{"/*": "*/"}') == {"/*": "*/"}
+
+    def test_comments_unterminated(self):
+        """
+        loads() OPT_ALLOW_COMMENTS unterminated /* comment
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            loads("[1, /* a ]")
+        assert exc_info.value.msg == "unclosed multiline comment"
+        assert exc_info.value.pos == 4
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            loads("[1] /* a */ /* b *")
+        assert exc_info.value.pos == 12
+
+    def test_comments_invalid(self):
+        """
+        loads() OPT_ALLOW_COMMENTS / not starting a comment
+        """
+        for val in ("/", "[1 / 2]", "[1]/", "/ / []", "{/a/: 1}"):
+            with pytest.raises(orjson.JSONDecodeError):
+                loads(val)
+
+    def test_comments_only(self):
+        """
+        loads() OPT_ALLOW_COMMENTS document of only comments
+        """
+        for val in ("// a", "/* a */", " /* a */ // b\n "):
+            with pytest.raises(orjson.JSONDecodeError):
+                loads(val)
+
+    def test_comments_not_value(self):
+        """
+        loads() OPT_ALLOW_COMMENTS comment does not separate values
+        """
+        for val in ("[1 /* a */ 2]", "1 // a\n 2", '{"a" /* b */ "c": 1}'):
+            with pytest.raises(orjson.JSONDecodeError):
+                loads(val)
+
+    def test_comments_fixture(self):
+        """
+        loads() OPT_ALLOW_COMMENTS is the same as loads() without comments
+        """
+        obj = read_fixture_obj("twitter.json.xz")
+        assert loads(orjson.dumps(obj)) == obj
+        doc = orjson.dumps(obj, option=orjson.OPT_INDENT_2).replace(
+            b"\n", b" // comment\n"
+        )
+        assert loads(doc) == obj
+
+    def test_comments_with_options(self):
+        """
+        loads() OPT_ALLOW_COMMENTS with other options
+        """
+        val = orjson.loads(
+            "[NaN /* nan */, 1.5]",
+            option=orjson.OPT_ALLOW_COMMENTS
+            | orjson.OPT_ALLOW_NONFINITE
+            | orjson.OPT_PARSE_DECIMAL,
+        )
+        assert val[0] != val[0]
+        assert str(val[1]) == "1.5"
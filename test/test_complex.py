@@ -0,0 +1,147 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import math
+
+import pytest
+
+import orjson
+
+
+class ComplexSubclass(complex):
+    pass
+
+
+class TestComplex:
+    def test_complex_default(self):
+        """
+        complex is not serialized without OPT_SERIALIZE_COMPLEX
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(1j)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(1j, complex_format="object")
+
+    def test_complex_array(self):
+        """
+        complex as [real, imag]
+        """
+        assert (
+            orjson.dumps(1.5 - 2j, option=orjson.OPT_SERIALIZE_COMPLEX)
+            == b"[1.5,-2.0]"
+        )
+        assert orjson.dumps(0j, option=orjson.OPT_SERIALIZE_COMPLEX) == b"[0.0,0.0]"
+
+    def test_complex_object(self):
+        """
+        complex as {"real": r, "imag": i}
+        """
+        assert (
+            orjson.dumps(
+                1.5 - 2j,
+                option=orjson.OPT_SERIALIZE_COMPLEX,
+                complex_format="object",
+            )
+            == b'{"real":1.5,"imag":-2.0}'
+        )
+
+    def test_complex_object_sort_keys(self):
+        """
+        complex as {"imag", "real"} with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                1.5 - 2j,
+                option=orjson.OPT_SERIALIZE_COMPLEX | orjson.OPT_SORT_KEYS,
+                complex_format="object",
+            )
+            == b'{"imag":-2.0,"real":1.5}'
+        )
+
+    def test_complex_nested(self):
+        """
+        complex in list, tuple, and dict values
+        """
+        obj = {"a": [1j, (2 + 0j,)], "b": 3 + 4j}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_COMPLEX)
+            == b'{"a":[[0.0,1.0],[[2.0,0.0]]],"b":[3.0,4.0]}'
+        )
+        assert orjson.loads(
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_COMPLEX,
+                complex_format="object",
+            )
+        ) == {
+            "a": [{"real": 0.0, "imag": 1.0}, [{"real": 2.0, "imag": 0.0}]],
+            "b": {"real": 3.0, "imag": 4.0},
+        }
+
+    def test_complex_indent(self):
+        """
+        complex with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                [1j], option=orjson.OPT_SERIALIZE_COMPLEX | orjson.OPT_INDENT_2
+            )
+            == b"[\n  [\n    0.0,\n    1.0\n  ]\n]"
+        )
+
+    def test_complex_nonfinite(self):
+        """
+        complex non-finite parts are null like float
+        """
+        val = complex(math.inf, math.nan)
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_COMPLEX) == b"[null,null]"
+        )
+        assert orjson.dumps(
+            complex(-math.inf, 1), option=orjson.OPT_SERIALIZE_COMPLEX
+        ) == orjson.dumps([-math.inf, 1.0])
+
+    def test_complex_nonfinite_allow(self):
+        """
+        complex non-finite parts with OPT_ALLOW_NONFINITE
+        """
+        val = complex(math.inf, -math.inf)
+        assert (
+            orjson.dumps(
+                val,
+                option=orjson.OPT_SERIALIZE_COMPLEX | orjson.OPT_ALLOW_NONFINITE,
+            )
+            == b"[Infinity,-Infinity]"
+        )
+        assert (
+            orjson.dumps(
+                complex(math.nan, 0),
+                option=orjson.OPT_SERIALIZE_COMPLEX | orjson.OPT_ALLOW_NONFINITE,
+                complex_format="object",
+            )
+            == b'{"real":NaN,"imag":0.0}'
+        )
+
+    def test_complex_subclass(self):
+        """
+        complex subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(ComplexSubclass(1j), option=orjson.OPT_SERIALIZE_COMPLEX)
+        assert (
+            orjson.dumps(
+                ComplexSubclass(1j),
+                option=orjson.OPT_SERIALIZE_COMPLEX,
+                default=lambda obj: [obj.real, obj.imag],
+            )
+            == b"[0.0,1.0]"
+        )
+
+    def test_complex_dict_key(self):
+        """
+        complex dict key is not supported by OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {1j: 1},
+                option=orjson.OPT_SERIALIZE_COMPLEX | orjson.OPT_NON_STR_KEYS,
+            )
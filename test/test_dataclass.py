@@ -1,8 +1,10 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
 import abc
+import functools
+import sys
 import uuid
-from dataclasses import InitVar, asdict, dataclass, field
+from dataclasses import MISSING, InitVar, asdict, dataclass, field
 from enum import Enum
 from typing import ClassVar, Dict, Optional
 
@@ -97,6 +99,38 @@ class InitDataclass:
         self.ab = f"{a} {b}"
 
 
+@dataclass
+class PrivateDataclass:
+    a: str
+    _b: int
+    __c: str
+
+
+@dataclass
+class PrivateSlotsDataclass:
+    __slots__ = ("a", "_b", "__c")
+    a: str
+    _b: int
+    __c: str
+
+
+@dataclass
+class RenamedDataclass:
+    user_id: int = field(metadata={"orjson_name": "userId"})
+    name: str = field(default="", metadata={"unrelated": True})
+    _private: int = field(default=0, metadata={"orjson_name": "private"})
+
+
+@dataclass
+class RenamedInvalidDataclass:
+    a: int = field(metadata={"orjson_name": 1})
+
+
+@dataclass
+class RenamedSurrogateDataclass:
+    a: int = field(metadata={"orjson_name": "\ud800"})
+
+
 class AbstractBase(abc.ABC):
     @abc.abstractmethod
     def key(self):
@@ -223,12 +257,13 @@ class TestDataclass:
 
     def test_dataclass_sort(self):
         """
-        OPT_SORT_KEYS has no effect on dataclasses
+        dumps() dataclass OPT_SORT_KEYS sorts fields
         """
         obj = UnsortedDataclass(1, 2, 3, None)
+        assert orjson.dumps(obj) == b'{"c":1,"b":2,"a":3,"d":null}'
         assert (
             orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
-            == b'{"c":1,"b":2,"a":3,"d":null}'
+            == b'{"a":3,"b":2,"c":1,"d":null}'
         )
 
     def test_dataclass_sort_sub(self):
@@ -238,9 +273,102 @@ class TestDataclass:
         obj = UnsortedDataclass(1, 2, 3, {"f": 2, "e": 1})
         assert (
             orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
-            == b'{"c":1,"b":2,"a":3,"d":{"e":1,"f":2}}'
+            == b'{"a":3,"b":2,"c":1,"d":{"e":1,"f":2}}'
         )
 
+    def test_dataclass_sort_slots(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS via __dataclass_fields__
+        """
+        obj = Slotsdataclass("a", 1, "c", "d")
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SORT_KEYS | orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"_c":"c","a":"a","b":1}'
+        )
+
+    def test_dataclass_sort_private(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS with OPT_INCLUDE_PRIVATE_FIELDS
+        """
+        assert (
+            orjson.dumps(
+                PrivateDataclass("a", 1, "c"),
+                option=orjson.OPT_SORT_KEYS | orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"_PrivateDataclass__c":"c","_b":1,"a":"a"}'
+        )
+
+    def test_dataclass_sort_rename(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS sorts by renamed field
+        """
+        assert (
+            orjson.dumps(
+                RenamedDataclass(1, "a", 2),
+                option=orjson.OPT_SORT_KEYS
+                | orjson.OPT_DATACLASS_FIELD_RENAME
+                | orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"name":"a","private":2,"userId":1}'
+        )
+
+    def test_dataclass_sort_case_insensitive(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS_CASE_INSENSITIVE
+        """
+        assert (
+            orjson.dumps(
+                RenamedDataclass(1, "a"),
+                option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE
+                | orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b'{"name":"a","userId":1}'
+        )
+        assert (
+            orjson.dumps(
+                RenamedDataclass(1, "a"),
+                option=orjson.OPT_SORT_KEYS | orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b'{"name":"a","userId":1}'
+        )
+
+    def test_dataclass_sort_skip_none(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS with OPT_SKIP_NONE_VALUES
+        """
+        obj = UnsortedDataclass(1, None, 3, None)
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SORT_KEYS | orjson.OPT_SKIP_NONE_VALUES
+            )
+            == b'{"a":3,"c":1}'
+        )
+
+    def test_dataclass_sort_error(self):
+        """
+        dumps() dataclass OPT_SORT_KEYS error path is the field
+        """
+        obj = UnsortedDataclass(1, 2, 3, {"e": object()})
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
+        assert (
+            str(exc_info.value)
+            == "Type is not JSON serializable: object at $.d.e (output byte 28)"
+        )
+
+    def test_dataclass_sort_encoder(self):
+        """
+        Encoder dataclass OPT_SORT_KEYS
+        """
+        encoder = orjson.Encoder(option=orjson.OPT_SORT_KEYS)
+        obj = UnsortedDataclass(1, 2, 3, None)
+        assert encoder.write_chunk(obj) == b'[{"a":3,"b":2,"c":1,"d":null}'
+        assert encoder.write_chunk(obj) == b',{"a":3,"b":2,"c":1,"d":null}'
+        assert encoder.close() == b"]"
+
     def test_dataclass_under(self):
         """
         dumps() does not include under attributes, InitVar, or ClassVar
@@ -259,6 +387,300 @@ class TestDataclass:
         )
 
 
+class TestDataclassPrivateFields:
+    def test_dataclass_private_default(self):
+        """
+        dumps() dataclass omits under attributes by default
+        """
+        assert orjson.dumps(PrivateDataclass("a", 1, "c")) == b'{"a":"a"}'
+        assert orjson.dumps(PrivateSlotsDataclass("a", 1, "c")) == b'{"a":"a"}'
+
+    def test_dataclass_private_fast(self):
+        """
+        dumps() dataclass OPT_INCLUDE_PRIVATE_FIELDS via __dict__
+        """
+        assert (
+            orjson.dumps(
+                PrivateDataclass("a", 1, "c"),
+                option=orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"a":"a","_b":1,"_PrivateDataclass__c":"c"}'
+        )
+
+    def test_dataclass_private_slots(self):
+        """
+        dumps() dataclass OPT_INCLUDE_PRIVATE_FIELDS via __dataclass_fields__
+        """
+        obj = PrivateSlotsDataclass("a", 1, "c")
+        assert "__dict__" not in dir(obj)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_INCLUDE_PRIVATE_FIELDS)
+            == b'{"a":"a","_b":1,"_PrivateSlotsDataclass__c":"c"}'
+        )
+
+    def test_dataclass_private_slots_excludes_initvar_classvar(self):
+        """
+        dumps() dataclass OPT_INCLUDE_PRIVATE_FIELDS still omits InitVar and ClassVar
+        """
+        assert (
+            orjson.dumps(
+                Slotsdataclass("a", 1, "c", "d"),
+                option=orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"a":"a","b":1,"_c":"c"}'
+        )
+
+
+class TestDataclassFieldRename:
+    def test_dataclass_rename_default(self):
+        """
+        dumps() dataclass ignores orjson_name metadata by default
+        """
+        assert (
+            orjson.dumps(RenamedDataclass(1, "a"))
+            == b'{"user_id":1,"name":"a"}'
+        )
+
+    def test_dataclass_rename(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME uses orjson_name metadata
+        """
+        assert (
+            orjson.dumps(
+                RenamedDataclass(1, "a"), option=orjson.OPT_DATACLASS_FIELD_RENAME
+            )
+            == b'{"userId":1,"name":"a"}'
+        )
+
+    def test_dataclass_rename_slots(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME with __slots__ and no metadata
+        """
+        assert (
+            orjson.dumps(
+                Slotsdataclass("a", 1, "c", "d"),
+                option=orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b'{"a":"a","b":1}'
+        )
+
+    def test_dataclass_rename_private(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME composes with OPT_INCLUDE_PRIVATE_FIELDS
+        """
+        assert (
+            orjson.dumps(
+                RenamedDataclass(1, "a", 2),
+                option=orjson.OPT_DATACLASS_FIELD_RENAME
+                | orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"userId":1,"name":"a","private":2}'
+        )
+
+    def test_dataclass_rename_nested(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME applies to nested dataclasses
+        """
+        assert (
+            orjson.dumps(
+                {"a": [RenamedDataclass(1, "a")]},
+                option=orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b'{"a":[{"userId":1,"name":"a"}]}'
+        )
+
+    def test_dataclass_rename_not_str(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME orjson_name must be str
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                RenamedInvalidDataclass(1), option=orjson.OPT_DATACLASS_FIELD_RENAME
+            )
+
+    def test_dataclass_rename_invalid_str(self):
+        """
+        dumps() dataclass OPT_DATACLASS_FIELD_RENAME orjson_name must be valid UTF-8
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                RenamedSurrogateDataclass(1),
+                option=orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+
+
+class TestDataclassInclude:
+    def test_dataclass_include(self):
+        """
+        dumps() include serializes only the fields named
+        """
+        obj = Dataclass3("a", 1, {}, True, 1.5, [], ())
+        assert (
+            orjson.dumps(obj, include=frozenset({"a", "d"}))
+            == b'{"a":"a","d":true}'
+        )
+        assert orjson.dumps(obj, include={"e"}) == b'{"e":1.5}'
+
+    def test_dataclass_include_empty(self):
+        """
+        dumps() include empty serializes no fields
+        """
+        assert orjson.dumps(Dataclass1("a", 1, None), include=frozenset()) == b"{}"
+
+    def test_dataclass_include_none(self):
+        """
+        dumps() include=None serializes all fields
+        """
+        assert (
+            orjson.dumps(Dataclass2("a"), include=None)
+            == orjson.dumps(Dataclass2("a"))
+            == b'{"name":"a"}'
+        )
+
+    def test_dataclass_include_missing(self):
+        """
+        dumps() include names that are not fields are ignored
+        """
+        assert (
+            orjson.dumps(
+                Dataclass1("a", 1, None), include=frozenset({"name", "missing", 1})
+            )
+            == b'{"name":"a"}'
+        )
+
+    def test_dataclass_include_nested(self):
+        """
+        dumps() include does not apply to a dataclass within a dataclass
+        """
+        obj = Dataclass1("a", 1, Dataclass1("b", 2, None))
+        assert (
+            orjson.dumps(obj, include=frozenset({"name", "sub"}))
+            == b'{"name":"a","sub":{"name":"b","number":2,"sub":null}}'
+        )
+
+    def test_dataclass_include_container(self):
+        """
+        dumps() include applies to each dataclass in a list or dict
+        """
+        obj = {"items": [Dataclass2("a"), Dataclass1("b", 2, None)], "n": 2}
+        assert (
+            orjson.dumps(obj, include=frozenset({"name"}))
+            == b'{"items":[{"name":"a"},{"name":"b"}],"n":2}'
+        )
+
+    def test_dataclass_include_slots(self):
+        """
+        dumps() include __slots__
+        """
+        obj = Slotsdataclass("a", 1, "c", "d")
+        assert orjson.dumps(obj, include=frozenset({"b", "_c"})) == b'{"b":1}'
+        assert (
+            orjson.dumps(
+                obj,
+                include=frozenset({"b", "_c"}),
+                option=orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            )
+            == b'{"b":1,"_c":"c"}'
+        )
+
+    def test_dataclass_include_rename(self):
+        """
+        dumps() include names fields, not their OPT_DATACLASS_FIELD_RENAME name
+        """
+        obj = RenamedDataclass(1, "a")
+        assert (
+            orjson.dumps(
+                obj,
+                include=frozenset({"user_id", "name"}),
+                option=orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b'{"userId":1,"name":"a"}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                include=frozenset({"userId"}),
+                option=orjson.OPT_DATACLASS_FIELD_RENAME,
+            )
+            == b"{}"
+        )
+
+    def test_dataclass_include_sort_keys(self):
+        """
+        dumps() include OPT_SORT_KEYS
+        """
+        obj = UnsortedDataclass(1, 2, 3, None)
+        assert (
+            orjson.dumps(
+                obj, include=frozenset({"d", "b"}), option=orjson.OPT_SORT_KEYS
+            )
+            == b'{"b":2,"d":null}'
+        )
+
+    def test_dataclass_include_default(self):
+        """
+        dumps() include does not apply within default of a dataclass field
+        """
+
+        def default(obj):
+            if isinstance(obj, set):
+                return [Dataclass2("x")]
+            raise TypeError
+
+        obj = Dataclass1("a", 1, {1})  # type: ignore
+        assert (
+            orjson.dumps(obj, default=default, include=frozenset({"sub"}))
+            == b'{"sub":[{"name":"x"}]}'
+        )
+
+    def test_dataclass_include_reentrant(self):
+        """
+        dumps() include does not apply to dumps() called by default
+        """
+
+        def default(obj):
+            return orjson.Fragment(orjson.dumps(Dataclass1("x", 2, None)))
+
+        assert (
+            orjson.dumps([object(), Dataclass2("a")], default=default, include={"x"})
+            == b'[{"name":"x","number":2,"sub":null},{}]'
+        )
+
+    def test_dataclass_include_error(self):
+        """
+        dumps() include after an error
+        """
+        obj = Dataclass1("a", 1, object())  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([obj], include=frozenset({"sub"}))
+        assert orjson.dumps(obj, default=str, include={"name"}) == b'{"name":"a"}'
+
+    def test_dataclass_include_dumps_str_into(self):
+        """
+        dumps_str() and dumps_into() include
+        """
+        obj = Dataclass1("a", 1, None)
+        assert orjson.dumps_str(obj, include=frozenset({"number"})) == '{"number":1}'
+        buffer = bytearray()
+        orjson.dumps_into(obj, buffer, include=frozenset({"number"}))
+        assert buffer == b'{"number":1}'
+
+    def test_dataclass_include_invalid(self):
+        """
+        dumps() include that is not a set or frozenset
+        """
+        for include in (["a"], ("a",), "a", {"a": 1}):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(Dataclass2("a"), include=include)  # type: ignore
+            assert str(exc_info.value) == "Invalid include"
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps_str(Dataclass2("a"), include=include)  # type: ignore
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps_into(
+                    Dataclass2("a"), bytearray(), include=include  # type: ignore
+                )
+
+
 class TestDataclassPassthrough:
     def test_dataclass_passthrough_raise(self):
         """
@@ -293,6 +715,294 @@ class TestDataclassPassthrough:
         )
 
 
+@dataclass
+class SlotsBase:
+    __slots__ = ("a", "b")
+    a: int
+    b: Optional[str]
+
+
+@dataclass
+class SlotsSubclass(SlotsBase):
+    __slots__ = ("c",)
+    c: list
+
+
+@dataclass
+class SlotsGetattribute:
+    __slots__ = ("a",)
+    a: int
+
+    def __getattribute__(self, name):
+        value = object.__getattribute__(self, name)
+        return value * 2 if name == "a" else value
+
+
+@dataclass
+class SlotsAndDict:
+    __slots__ = ("a", "__dict__")
+    a: int
+    b: int
+
+
+class TestSlotsDataclass:
+    def test_dataclass_slots_same_as_asdict(self):
+        """
+        dumps() dataclass with __slots__ is the same as asdict()
+        """
+        obj = SlotsSubclass(1, "x", [SlotsBase(2, None)])
+        for option in (0, orjson.OPT_SORT_KEYS, orjson.OPT_SKIP_NONE_VALUES):
+            assert orjson.dumps(obj, option=option) == orjson.dumps(
+                asdict(obj), option=option
+            )
+        assert (
+            orjson.dumps(obj) == b'{"a":1,"b":"x","c":[{"a":2,"b":null}]}'
+        )
+
+    def test_dataclass_slots_inherited(self):
+        """
+        dumps() dataclass with __slots__ reads slots of base classes
+        """
+        assert orjson.dumps(SlotsSubclass(1, "x", [])) == b'{"a":1,"b":"x","c":[]}'
+        assert (
+            orjson.dumps(SlotsSubclass(1, "x", []), include={"c"}) == b'{"c":[]}'
+        )
+
+    def test_dataclass_slots_getattribute(self):
+        """
+        dumps() dataclass with __slots__ and __getattribute__ reads by getattr()
+        """
+        assert orjson.dumps(SlotsGetattribute(1)) == b'{"a":2}'
+
+    def test_dataclass_slots_and_dict(self):
+        """
+        dumps() dataclass with __slots__ and __dict__
+        """
+        assert orjson.dumps(SlotsAndDict(1, 2)) == b'{"a":1,"b":2}'
+
+    @pytest.mark.skipif(sys.version_info < (3, 10), reason="slots=True")
+    def test_dataclass_slots_true(self):
+        """
+        dumps() dataclass(slots=True)
+        """
+
+        @dataclass(slots=True)  # type: ignore
+        class Slots:
+            a: int
+            b: str = "b"
+
+        assert not hasattr(Slots(1), "__dict__")
+        assert orjson.dumps(Slots(1)) == b'{"a":1,"b":"b"}'
+        assert (
+            orjson.dumps(Slots(1), option=orjson.OPT_SORT_KEYS) == b'{"a":1,"b":"b"}'
+        )
+
+    def test_dataclass_slots_unset(self):
+        """
+        dumps() dataclass with __slots__ field that is not set
+        """
+        obj = SlotsBase.__new__(SlotsBase)
+        obj.a = 1
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"x": obj})
+        assert str(exc_info.value) == (
+            "Dataclass field is not set or could not be read at $.x.b "
+            "(output byte 11)"
+        )
+        assert isinstance(exc_info.value.__cause__, AttributeError)
+
+    def test_dataclass_slots_unset_encoder(self):
+        """
+        orjson.Encoder() dataclass with __slots__ field that is not set
+        """
+        encoder = orjson.Encoder()
+        assert encoder.write_chunk(SlotsBase(1, "x")) == b'[{"a":1,"b":"x"}'
+        obj = SlotsBase.__new__(SlotsBase)
+        obj.b = "y"
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.write_chunk(obj)
+        assert isinstance(exc_info.value.__cause__, AttributeError)
+        obj.a = 2
+        assert encoder.write_chunk(obj) == b',{"a":2,"b":"y"}'
+        assert encoder.close() == b"]"
+
+    @pytest.mark.skipif(sys.version_info < (3, 10), reason="slots=True")
+    def test_dataclass_slots_rename(self):
+        """
+        dumps() dataclass(slots=True) OPT_DATACLASS_FIELD_RENAME
+        """
+
+        @dataclass(slots=True)  # type: ignore
+        class Renamed:
+            user_id: int = field(metadata={"orjson_name": "userId"})
+
+        assert (
+            orjson.dumps(Renamed(1), option=orjson.OPT_DATACLASS_FIELD_RENAME)
+            == b'{"userId":1}'
+        )
+
+
+class TestDataclassMissing:
+    def test_dataclass_missing(self):
+        """
+        dumps() dataclass field that is dataclasses.MISSING names the field
+        """
+        objs = (Dataclass1("a", MISSING, None), SlotsBase(1, MISSING))  # type: ignore
+        for obj in objs:
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps({"x": [obj]})
+            assert str(exc_info.value).startswith(
+                "Dataclass field is dataclasses.MISSING at $.x[0]."
+            )
+            assert exc_info.value.__cause__ is None
+
+    def test_dataclass_missing_path(self):
+        """
+        dumps() dataclass field that is dataclasses.MISSING error path
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Dataclass1("a", MISSING, None))  # type: ignore
+        assert str(exc_info.value) == (
+            "Dataclass field is dataclasses.MISSING at $.number (output byte 11)"
+        )
+
+    def test_dataclass_missing_default(self):
+        """
+        dumps() dataclass field that is dataclasses.MISSING is serialized by
+        default
+        """
+
+        @dataclass
+        class Missing:
+            a: int
+            b: int
+
+        def default(obj):
+            if obj is MISSING:
+                return "D"
+            raise TypeError
+
+        obj = Missing(MISSING, 1)  # type: ignore
+        assert orjson.dumps(obj, default=default) == b'{"a":"D","b":1}'
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_SORT_KEYS)
+            == b'{"a":"D","b":1}'
+        )
+        assert (
+            orjson.dumps(
+                obj, default=default, option=orjson.OPT_DATACLASS_SKIP_MISSING
+            )
+            == b'{"b":1}'
+        )
+        obj = SlotsBase(MISSING, "b")  # type: ignore
+        assert orjson.dumps(obj, default=default) == b'{"a":"D","b":"b"}'
+
+    def test_dataclass_missing_default_error(self):
+        """
+        dumps() dataclass field that is dataclasses.MISSING names the field if
+        default does not serialize it
+        """
+
+        @dataclass
+        class Missing:
+            a: int
+            b: int
+
+        def default(obj):
+            raise TypeError
+
+        obj = Missing(MISSING, 1)  # type: ignore
+        for option in (0, orjson.OPT_SORT_KEYS):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, default=default, option=option)
+            assert str(exc_info.value).startswith(
+                "Dataclass field is dataclasses.MISSING at $.a"
+            )
+            assert isinstance(exc_info.value.__cause__, TypeError)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, default=lambda obj: [object()])
+        assert str(exc_info.value).startswith(
+            "Dataclass field is dataclasses.MISSING at $.a"
+        )
+
+    def test_dataclass_missing_skip(self):
+        """
+        dumps() OPT_DATACLASS_SKIP_MISSING skips fields that are
+        dataclasses.MISSING
+        """
+        option = orjson.OPT_DATACLASS_SKIP_MISSING
+        assert (
+            orjson.dumps(Dataclass1("a", MISSING, None), option=option)  # type: ignore
+            == b'{"name":"a","sub":null}'
+        )
+        assert (
+            orjson.dumps(SlotsBase(MISSING, "b"), option=option)  # type: ignore
+            == b'{"b":"b"}'
+        )
+        assert (
+            orjson.dumps(
+                Dataclass1("a", MISSING, None),  # type: ignore
+                option=option | orjson.OPT_SORT_KEYS | orjson.OPT_SKIP_NONE_VALUES,
+            )
+            == b'{"name":"a"}'
+        )
+
+    def test_dataclass_missing_rename(self):
+        """
+        dumps() dataclass field that is dataclasses.MISSING
+        OPT_DATACLASS_FIELD_RENAME
+        """
+
+        @dataclass
+        class Renamed:
+            user_id: int = field(metadata={"orjson_name": "userId"})
+            name: str = "a"
+
+        obj = Renamed(MISSING)  # type: ignore
+        option = orjson.OPT_DATACLASS_FIELD_RENAME
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, option=option)
+        assert str(exc_info.value).startswith(
+            "Dataclass field is dataclasses.MISSING at $.user_id"
+        )
+        assert (
+            orjson.dumps(obj, option=option | orjson.OPT_DATACLASS_SKIP_MISSING)
+            == b'{"name":"a"}'
+        )
+
+    def test_dataclass_missing_encoder(self):
+        """
+        orjson.Encoder() dataclass field that is dataclasses.MISSING
+        """
+        encoder = orjson.Encoder()
+        assert encoder.write_chunk(SlotsBase(1, "b")) == b'[{"a":1,"b":"b"}'
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk(SlotsBase(MISSING, "b"))  # type: ignore
+        encoder = orjson.Encoder(option=orjson.OPT_DATACLASS_SKIP_MISSING)
+        assert encoder.write_chunk(SlotsBase(1, "b")) == b'[{"a":1,"b":"b"}'
+        obj = SlotsBase(MISSING, "b")  # type: ignore
+        assert encoder.write_chunk(obj) == b',{"b":"b"}'
+        assert encoder.close() == b"]"
+
+    def test_dataclass_missing_not_field(self):
+        """
+        dumps() dataclasses.MISSING that is not a dataclass field is not
+        serializable, as are other sentinels
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([MISSING], option=orjson.OPT_DATACLASS_SKIP_MISSING)
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: dataclasses._MISSING_TYPE at $[0] "
+            "(output byte 1)"
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Dataclass1("a", functools.partial(int), None))  # type: ignore
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: functools.partial at $.number "
+            "(output byte 21)"
+        )
+
+
 class TestAbstractDataclass:
     def test_dataclass_abc(self):
         obj = ConcreteAbc(1.0)
@@ -1,6 +1,7 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
 import datetime
+import os
 
 import pytest
 
@@ -675,6 +676,150 @@ class TestDatetime:
             assert getattr(obj, attr) == getattr(parsed, attr)
 
 
+@pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+class TestDatetimeDst:
+    def test_datetime_zoneinfo_spring_forward(self):
+        """
+        datetime.datetime zoneinfo offset on either side of the DST start
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 3, 11, 1, 59, 59, tzinfo=tzinfo),
+                    datetime.datetime(2018, 3, 11, 3, 0, 0, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-03-11T01:59:59-05:00","2018-03-11T03:00:00-04:00"]'
+        )
+
+    def test_datetime_zoneinfo_spring_forward_nonexistent(self):
+        """
+        datetime.datetime zoneinfo in the gap of the DST start uses fold
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 3, 11, 2, 30, tzinfo=tzinfo),
+                    datetime.datetime(2018, 3, 11, 2, 30, fold=1, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-03-11T02:30:00-05:00","2018-03-11T02:30:00-04:00"]'
+        )
+
+    def test_datetime_zoneinfo_fall_back(self):
+        """
+        datetime.datetime zoneinfo offset on either side of the DST end
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 11, 4, 0, 59, 59, tzinfo=tzinfo),
+                    datetime.datetime(2018, 11, 4, 2, 0, 0, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-11-04T00:59:59-04:00","2018-11-04T02:00:00-05:00"]'
+        )
+
+    def test_datetime_zoneinfo_fall_back_ambiguous(self):
+        """
+        datetime.datetime zoneinfo in the repeated hour of the DST end uses fold
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 11, 4, 1, 30, tzinfo=tzinfo),
+                    datetime.datetime(2018, 11, 4, 1, 30, fold=1, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-11-04T01:30:00-04:00","2018-11-04T01:30:00-05:00"]'
+        )
+
+    def test_datetime_zoneinfo_fall_back_positive(self):
+        """
+        datetime.datetime zoneinfo DST end east of UTC
+        """
+        tzinfo = zoneinfo.ZoneInfo("Europe/Amsterdam")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 10, 28, 2, 30, tzinfo=tzinfo),
+                    datetime.datetime(2018, 10, 28, 2, 30, fold=1, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-10-28T02:30:00+02:00","2018-10-28T02:30:00+01:00"]'
+        )
+
+    def test_datetime_zoneinfo_transition_isoformat(self):
+        """
+        datetime.datetime zoneinfo is the same as isoformat() every 15 minutes
+        across the DST start and end
+        """
+        for key in ("America/New_York", "Europe/Amsterdam", "Australia/Adelaide"):
+            tzinfo = zoneinfo.ZoneInfo(key)
+            for day in (
+                datetime.datetime(2018, 3, 11),
+                datetime.datetime(2018, 3, 25),
+                datetime.datetime(2018, 4, 1),
+                datetime.datetime(2018, 10, 7),
+                datetime.datetime(2018, 10, 28),
+                datetime.datetime(2018, 11, 4),
+            ):
+                for minutes in range(0, 24 * 60, 15):
+                    for fold in (0, 1):
+                        obj = (day + datetime.timedelta(minutes=minutes)).replace(
+                            tzinfo=tzinfo, fold=fold
+                        )
+                        assert orjson.dumps(obj) == f'"{obj.isoformat()}"'.encode()
+
+    def test_datetime_zoneinfo_utc_z(self):
+        """
+        datetime.datetime zoneinfo DST offset is not affected by OPT_UTC_Z
+        """
+        tzinfo = zoneinfo.ZoneInfo("Europe/London")
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 3, 25, 0, 59, tzinfo=tzinfo),
+                    datetime.datetime(2018, 3, 25, 2, 0, tzinfo=tzinfo),
+                ],
+                option=orjson.OPT_UTC_Z,
+            )
+            == b'["2018-03-25T00:59:00Z","2018-03-25T02:00:00+01:00"]'
+        )
+
+    def test_datetime_zoneinfo_dict_key(self):
+        """
+        datetime.datetime zoneinfo DST offset as a dict key
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        assert (
+            orjson.dumps(
+                {datetime.datetime(2018, 11, 4, 1, 30, fold=1, tzinfo=tzinfo): 1},
+                option=orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2018-11-04T01:30:00-05:00":1}'
+        )
+
+    def test_datetime_fixed_offset_fold(self):
+        """
+        datetime.datetime fixed offset is not affected by the date or fold
+        """
+        tzinfo = datetime.timezone(datetime.timedelta(hours=-5))
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2018, 7, 1, 1, 30, tzinfo=tzinfo),
+                    datetime.datetime(2018, 11, 4, 1, 30, fold=1, tzinfo=tzinfo),
+                ]
+            )
+            == b'["2018-07-01T01:30:00-05:00","2018-11-04T01:30:00-05:00"]'
+        )
+
+
 class TestDate:
     def test_date(self):
         """
@@ -720,6 +865,95 @@ class TestDate:
             == b'["0046-01-01"]'
         )
 
+    def test_date_epoch_day(self):
+        """
+        datetime.date date_format="epoch_day"
+        """
+        kwargs = {"date_format": "epoch_day"}
+        assert orjson.dumps(datetime.date(1970, 1, 1), **kwargs) == b"0"
+        assert orjson.dumps(datetime.date(1970, 1, 2), **kwargs) == b"1"
+        assert orjson.dumps(datetime.date(1969, 12, 31), **kwargs) == b"-1"
+        assert orjson.dumps([datetime.date(2000, 1, 13)], **kwargs) == b"[10969]"
+        assert (
+            orjson.dumps({"a": datetime.date(2024, 6, 1)}, **kwargs)
+            == b'{"a":19875}'
+        )
+
+    def test_date_epoch_day_leap(self):
+        """
+        datetime.date date_format="epoch_day" leap years
+        """
+        kwargs = {"date_format": "epoch_day"}
+        for year in (1600, 1700, 1900, 1972, 2000, 2023, 2024, 2100):
+            for date in (
+                datetime.date(year, 2, 28),
+                datetime.date(year, 3, 1),
+                datetime.date(year, 12, 31),
+            ):
+                expected = (date - datetime.date(1970, 1, 1)).days
+                assert orjson.dumps(date, **kwargs) == str(expected).encode()
+        assert orjson.dumps(datetime.date(2000, 2, 29), **kwargs) == b"11016"
+        assert orjson.dumps(datetime.date(2000, 3, 1), **kwargs) == b"11017"
+        assert orjson.dumps(datetime.date(1900, 3, 1), **kwargs) == b"-25508"
+
+    def test_date_epoch_day_min_max(self):
+        """
+        datetime.date date_format="epoch_day" MINYEAR and MAXYEAR
+        """
+        kwargs = {"date_format": "epoch_day"}
+        assert (
+            orjson.dumps(datetime.date(datetime.MINYEAR, 1, 1), **kwargs)
+            == b"-719162"
+        )
+        assert (
+            orjson.dumps(datetime.date(datetime.MAXYEAR, 12, 31), **kwargs)
+            == b"2932896"
+        )
+        assert orjson.dumps(datetime.date(46, 1, 1), **kwargs) == b"-702726"
+
+    def test_date_epoch_day_ordinal(self):
+        """
+        datetime.date date_format="epoch_day" is the same as toordinal()
+        """
+        kwargs = {"date_format": "epoch_day"}
+        epoch = datetime.date(1970, 1, 1).toordinal()
+        for ordinal in range(1, datetime.date.max.toordinal() + 1, 997):
+            date = datetime.date.fromordinal(ordinal)
+            assert orjson.dumps(date, **kwargs) == str(ordinal - epoch).encode()
+
+    def test_date_epoch_day_datetime(self):
+        """
+        datetime.date date_format="epoch_day" does not change datetime.datetime
+        """
+        assert (
+            orjson.dumps(datetime.datetime(1970, 1, 1), date_format="epoch_day")
+            == b'"1970-01-01T00:00:00"'
+        )
+
+    def test_date_epoch_day_non_str_keys(self):
+        """
+        datetime.date date_format="epoch_day" OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {datetime.date(1969, 12, 31): 1},
+                option=orjson.OPT_NON_STR_KEYS,
+                date_format="epoch_day",
+            )
+            == b'{"-1":1}'
+        )
+
+    def test_date_epoch_day_passthrough(self):
+        """
+        datetime.date date_format="epoch_day" OPT_PASSTHROUGH_DATETIME
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.date(1970, 1, 1),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                date_format="epoch_day",
+            )
+
 
 class TestTime:
     def test_time(self):
@@ -755,6 +989,77 @@ class TestTime:
         """
         assert orjson.dumps(datetime.time(0, 0, 0, 1)) == b'"00:00:00.000001"'
 
+    def test_time_omit_microseconds(self):
+        """
+        datetime.time OPT_OMIT_MICROSECONDS
+        """
+        assert (
+            orjson.dumps(
+                datetime.time(12, 15, 59, 111), option=orjson.OPT_OMIT_MICROSECONDS
+            )
+            == b'"12:15:59"'
+        )
+
+    def test_time_millis(self):
+        """
+        datetime.time time_format="millis" truncates to three digits
+        """
+        for microsecond, expected in (
+            (0, b'"12:15:59.000"'),
+            (1, b'"12:15:59.000"'),
+            (999, b'"12:15:59.000"'),
+            (1000, b'"12:15:59.001"'),
+            (12345, b'"12:15:59.012"'),
+            (123456, b'"12:15:59.123"'),
+            (500000, b'"12:15:59.500"'),
+            (999999, b'"12:15:59.999"'),
+        ):
+            assert (
+                orjson.dumps(
+                    datetime.time(12, 15, 59, microsecond),
+                    time_format="millis",
+                )
+                == expected
+            )
+
+    def test_time_millis_omit_microseconds(self):
+        """
+        datetime.time time_format="millis" OPT_OMIT_MICROSECONDS
+        """
+        assert (
+            orjson.dumps(
+                datetime.time(12, 15, 59, 123456),
+                option=orjson.OPT_OMIT_MICROSECONDS,
+                time_format="millis",
+            )
+            == b'"12:15:59"'
+        )
+
+    def test_time_millis_non_str_keys(self):
+        """
+        datetime.time time_format="millis" dict key
+        """
+        assert (
+            orjson.dumps(
+                {datetime.time(12, 15, 59, 123456): [datetime.time(0)]},
+                option=orjson.OPT_NON_STR_KEYS,
+                time_format="millis",
+            )
+            == b'{"12:15:59.123":["00:00:00.000"]}'
+        )
+
+    def test_time_millis_datetime(self):
+        """
+        time_format="millis" does not affect datetime.datetime
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(2000, 1, 1, 12, 15, 59, 123456),
+                time_format="millis",
+            )
+            == b'"2000-01-01T12:15:59.123456"'
+        )
+
 
 class TestDateclassPassthrough:
     def test_passthrough_datetime(self):
@@ -787,3 +1092,681 @@ class TestDateclassPassthrough:
             )
             == b'"Thu, 01 Jan 1970 00:00:00 GMT"'
         )
+
+
+class DatetimeSubclass(datetime.datetime):
+    pass
+
+
+class DateSubclass(datetime.date):
+    pass
+
+
+class TimeSubclass(datetime.time):
+    pass
+
+
+class TestDatetimeSubclass:
+    def test_datetime_subclass(self):
+        """
+        datetime.datetime subclass
+        """
+        assert (
+            orjson.dumps([DatetimeSubclass(2000, 1, 1, 2, 3, 4, 123)])
+            == b'["2000-01-01T02:03:04.000123"]'
+        )
+
+    def test_datetime_subclass_tz(self):
+        """
+        datetime.datetime subclass with tzinfo and options
+        """
+        assert (
+            orjson.dumps(
+                DatetimeSubclass(2000, 1, 1, 2, 3, 4, tzinfo=datetime.timezone.utc),
+                option=orjson.OPT_UTC_Z,
+            )
+            == b'"2000-01-01T02:03:04Z"'
+        )
+        assert (
+            orjson.dumps(
+                DatetimeSubclass(2000, 1, 1, 2, 3, 4), option=orjson.OPT_NAIVE_UTC
+            )
+            == b'"2000-01-01T02:03:04+00:00"'
+        )
+
+    def test_date_subclass(self):
+        """
+        datetime.date subclass
+        """
+        assert orjson.dumps([DateSubclass(2000, 1, 1)]) == b'["2000-01-01"]'
+
+    def test_time_subclass(self):
+        """
+        datetime.time subclass
+        """
+        assert orjson.dumps([TimeSubclass(12, 0, 15, 290)]) == b'["12:00:15.000290"]'
+
+    def test_datetime_subclass_dict(self):
+        """
+        datetime subclasses as dict values and OPT_NON_STR_KEYS keys
+        """
+        assert (
+            orjson.dumps(
+                {"a": DateSubclass(2000, 1, 1), "b": TimeSubclass(12, 0, 0)}
+            )
+            == b'{"a":"2000-01-01","b":"12:00:00"}'
+        )
+        assert (
+            orjson.dumps(
+                {DatetimeSubclass(2000, 1, 1): 1}, option=orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"2000-01-01T00:00:00":1}'
+        )
+
+    def test_datetime_subclass_passthrough(self):
+        """
+        datetime subclasses OPT_PASSTHROUGH_DATETIME
+        """
+        for val in (
+            DatetimeSubclass(2000, 1, 1),
+            DateSubclass(2000, 1, 1),
+            TimeSubclass(12, 0, 0),
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(val, option=orjson.OPT_PASSTHROUGH_DATETIME)
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_PASSTHROUGH_DATETIME,
+                    default=lambda obj: type(obj).__name__,
+                )
+                == f'"{type(val).__name__}"'.encode()
+            )
+
+    def test_datetime_subclass_passthrough_subclass(self):
+        """
+        datetime subclasses OPT_PASSTHROUGH_SUBCLASS are passed to default as
+        before they were serialized natively
+        """
+        for val in (
+            DatetimeSubclass(2000, 1, 1),
+            DateSubclass(2000, 1, 1),
+            TimeSubclass(12, 0, 0),
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(val, option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_PASSTHROUGH_SUBCLASS,
+                    default=lambda obj: type(obj).__name__,
+                )
+                == f'"{type(val).__name__}"'.encode()
+            )
+        assert (
+            orjson.dumps(
+                [datetime.datetime(2000, 1, 1), datetime.date(2000, 1, 1)],
+                option=orjson.OPT_PASSTHROUGH_SUBCLASS,
+            )
+            == b'["2000-01-01T00:00:00","2000-01-01"]'
+        )
+
+
+@pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+class TestDatetimeTzName:
+    def test_datetime_tz_name(self):
+        """
+        datetime_format="tz_name" zoneinfo.ZoneInfo
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(
+                    2024, 1, 1, tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam")
+                ),
+                datetime_format="tz_name",
+            )
+            == b'{"dt":"2024-01-01T00:00:00+01:00","tz":"Europe/Amsterdam"}'
+        )
+
+    def test_datetime_tz_name_dst(self):
+        """
+        datetime_format="tz_name" offset is that of the datetime
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(
+                        2024, 7, 1, tzinfo=zoneinfo.ZoneInfo("America/New_York")
+                    )
+                ],
+                option=orjson.OPT_OMIT_MICROSECONDS,
+                datetime_format="tz_name",
+            )
+            == b'[{"dt":"2024-07-01T00:00:00-04:00","tz":"America/New_York"}]'
+        )
+
+    def test_datetime_tz_name_utc_z(self):
+        """
+        datetime_format="tz_name" OPT_UTC_Z
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(2024, 1, 1, tzinfo=zoneinfo.ZoneInfo("UTC")),
+                option=orjson.OPT_UTC_Z,
+                datetime_format="tz_name",
+            )
+            == b'{"dt":"2024-01-01T00:00:00Z","tz":"UTC"}'
+        )
+
+    def test_datetime_tz_name_indent(self):
+        """
+        datetime_format="tz_name" OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {
+                    "a": datetime.datetime(
+                        2024, 1, 1, tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam")
+                    )
+                },
+                option=orjson.OPT_INDENT_2,
+                datetime_format="tz_name",
+            )
+            == b'{\n  "a": {\n    "dt": "2024-01-01T00:00:00+01:00",\n'
+            b'    "tz": "Europe/Amsterdam"\n  }\n}'
+        )
+
+    def test_datetime_tz_name_fixed_offset(self):
+        """
+        datetime_format="tz_name" does not affect other tzinfo or naive datetime
+        """
+        for tzinfo in (
+            None,
+            datetime.timezone.utc,
+            datetime.timezone(datetime.timedelta(hours=1), "CET"),
+        ):
+            val = datetime.datetime(2024, 1, 1, tzinfo=tzinfo)
+            assert orjson.dumps(val, datetime_format="tz_name") == (
+                orjson.dumps(val)
+            )
+
+    @pytest.mark.skipif(pytz is None, reason="pytz optional")
+    def test_datetime_tz_name_pytz(self):
+        """
+        datetime_format="tz_name" does not affect pytz
+        """
+        val = pytz.timezone("Europe/Amsterdam").localize(datetime.datetime(2024, 1, 1))
+        assert (
+            orjson.dumps(val, datetime_format="tz_name")
+            == b'"2024-01-01T00:00:00+01:00"'
+        )
+
+    def test_datetime_tz_name_no_key(self):
+        """
+        datetime_format="tz_name" zoneinfo.ZoneInfo without a key
+        """
+        paths = [
+            os.path.join(path, "Europe", "Amsterdam")
+            for path in zoneinfo.TZPATH
+            if os.path.isfile(os.path.join(path, "Europe", "Amsterdam"))
+        ]
+        if not paths:
+            pytest.skip("tz database file not available")
+        with open(paths[0], "rb") as fileobj:
+            nokey = zoneinfo.ZoneInfo.from_file(fileobj)
+        assert nokey.key is None
+        assert (
+            orjson.dumps(
+                datetime.datetime(2024, 1, 1, tzinfo=nokey),
+                datetime_format="tz_name",
+            )
+            == b'"2024-01-01T00:00:00+01:00"'
+        )
+
+    def test_datetime_tz_name_time(self):
+        """
+        datetime_format="tz_name" does not affect datetime.time
+        """
+        assert (
+            orjson.dumps(datetime.time(12), datetime_format="tz_name")
+            == b'"12:00:00"'
+        )
+
+    def test_datetime_tz_name_non_str_keys(self):
+        """
+        datetime_format="tz_name" does not affect dict keys
+        """
+        val = datetime.datetime(
+            2024, 1, 1, tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam")
+        )
+        assert (
+            orjson.dumps(
+                {val: 1},
+                option=orjson.OPT_NON_STR_KEYS,
+                datetime_format="tz_name",
+            )
+            == b'{"2024-01-01T00:00:00+01:00":1}'
+        )
+
+    def test_datetime_tz_name_passthrough(self):
+        """
+        datetime_format="tz_name" OPT_PASSTHROUGH_DATETIME
+        """
+        val = datetime.datetime(
+            2024, 1, 1, tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam")
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                val,
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                datetime_format="tz_name",
+            )
+
+
+class TestRequireAwareDatetime:
+    def test_require_aware_datetime(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME naive datetime raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                datetime.datetime(2020, 1, 2, 3, 4, 5, 6),
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME,
+            )
+        assert str(exc_info.value) == (
+            "datetime.datetime must have tzinfo with OPT_REQUIRE_AWARE_DATETIME: "
+            "2020-01-02T03:04:05.000006"
+        )
+
+    def test_require_aware_datetime_path(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME error has the path of the datetime
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": [1, datetime.datetime(1970, 1, 1)]},
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME,
+            )
+        assert str(exc_info.value) == (
+            "datetime.datetime must have tzinfo with OPT_REQUIRE_AWARE_DATETIME: "
+            "1970-01-01T00:00:00 at $.a[1] (output byte 8)"
+        )
+
+    def test_require_aware_datetime_aware(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME aware datetime
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc),
+                    datetime.datetime(
+                        1970,
+                        1,
+                        1,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=-5)),
+                    ),
+                ],
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME,
+            )
+            == b'["1970-01-01T00:00:00+00:00","1970-01-01T00:00:00-05:00"]'
+        )
+
+    def test_require_aware_datetime_no_utcoffset(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME raises if tzinfo.utcoffset() is None
+        """
+
+        class NoOffset(datetime.tzinfo):
+            def utcoffset(self, dt):
+                return None
+
+            def dst(self, dt):
+                return None
+
+        val = datetime.datetime(1970, 1, 1, 2, tzinfo=NoOffset())
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([val], option=orjson.OPT_REQUIRE_AWARE_DATETIME)
+        assert str(exc_info.value) == (
+            "datetime.datetime must have tzinfo with OPT_REQUIRE_AWARE_DATETIME: "
+            "1970-01-01T02:00:00 at $[0] (output byte 1)"
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {val: 1},
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME | orjson.OPT_NON_STR_KEYS,
+            )
+        assert "1970-01-01T02:00:00" in str(exc_info.value)
+        assert "+00:00" not in str(exc_info.value)
+
+    def test_require_aware_datetime_default(self):
+        """
+        naive datetime is serialized without OPT_REQUIRE_AWARE_DATETIME
+        """
+        assert orjson.dumps(datetime.datetime(1970, 1, 1)) == b'"1970-01-01T00:00:00"'
+
+    def test_require_aware_datetime_date_time(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME does not affect datetime.date or
+        datetime.time
+        """
+        assert (
+            orjson.dumps(
+                [datetime.date(1970, 1, 1), datetime.time(1, 2)],
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME,
+            )
+            == b'["1970-01-01","01:02:00"]'
+        )
+
+    def test_require_aware_datetime_naive_utc(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME cannot be combined with OPT_NAIVE_UTC
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc),
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME | orjson.OPT_NAIVE_UTC,
+            )
+
+    def test_require_aware_datetime_non_str_keys(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME OPT_NON_STR_KEYS
+        """
+        option = orjson.OPT_REQUIRE_AWARE_DATETIME | orjson.OPT_NON_STR_KEYS
+        val = datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps({val: 1}, option=option) == b'{"1970-01-01T00:00:00+00:00":1}'
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({datetime.datetime(1970, 1, 1): 1}, option=option)
+        assert "1970-01-01T00:00:00" in str(exc_info.value)
+
+    def test_require_aware_datetime_passthrough(self):
+        """
+        OPT_REQUIRE_AWARE_DATETIME OPT_PASSTHROUGH_DATETIME passes naive
+        datetime to default
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(1970, 1, 1),
+                option=orjson.OPT_REQUIRE_AWARE_DATETIME
+                | orjson.OPT_PASSTHROUGH_DATETIME,
+                default=lambda obj: obj.isoformat() + "Z",
+            )
+            == b'"1970-01-01T00:00:00Z"'
+        )
+
+
+class TestNanosecond:
+    def test_nanosecond(self):
+        """
+        OPT_NANOSECOND datetime.datetime has nine fractional digits
+        """
+        for val, ref in (
+            (
+                datetime.datetime(1970, 1, 1, 0, 0, 0, 1),
+                b'"1970-01-01T00:00:00.000001000"',
+            ),
+            (
+                datetime.datetime(2020, 1, 2, 3, 4, 5, 123456),
+                b'"2020-01-02T03:04:05.123456000"',
+            ),
+            (
+                datetime.datetime(9999, 12, 31, 23, 59, 59, 999999),
+                b'"9999-12-31T23:59:59.999999000"',
+            ),
+        ):
+            assert orjson.dumps(val, option=orjson.OPT_NANOSECOND) == ref
+
+    def test_nanosecond_zero(self):
+        """
+        OPT_NANOSECOND datetime.datetime without microseconds has no fraction
+        """
+        assert (
+            orjson.dumps(datetime.datetime(1970, 1, 1), option=orjson.OPT_NANOSECOND)
+            == b'"1970-01-01T00:00:00"'
+        )
+
+    def test_nanosecond_tz(self):
+        """
+        OPT_NANOSECOND aware datetime.datetime, OPT_NAIVE_UTC, and OPT_UTC_Z
+        """
+        val = datetime.datetime(2020, 1, 2, 3, 4, 5, 6, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps(val, option=orjson.OPT_NANOSECOND)
+            == b'"2020-01-02T03:04:05.000006000+00:00"'
+        )
+        assert (
+            orjson.dumps(val, option=orjson.OPT_NANOSECOND | orjson.OPT_UTC_Z)
+            == b'"2020-01-02T03:04:05.000006000Z"'
+        )
+        assert (
+            orjson.dumps(
+                datetime.datetime(2020, 1, 2, 3, 4, 5, 6),
+                option=orjson.OPT_NANOSECOND | orjson.OPT_NAIVE_UTC,
+            )
+            == b'"2020-01-02T03:04:05.000006000+00:00"'
+        )
+
+    def test_nanosecond_omit_microseconds(self):
+        """
+        OPT_OMIT_MICROSECONDS takes precedence over OPT_NANOSECOND
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(1970, 1, 1, 0, 0, 0, 1),
+                option=orjson.OPT_NANOSECOND | orjson.OPT_OMIT_MICROSECONDS,
+            )
+            == b'"1970-01-01T00:00:00"'
+        )
+
+    def test_nanosecond_time(self):
+        """
+        OPT_NANOSECOND does not affect datetime.time
+        """
+        assert (
+            orjson.dumps(datetime.time(0, 0, 0, 1), option=orjson.OPT_NANOSECOND)
+            == b'"00:00:00.000001"'
+        )
+
+    def test_nanosecond_non_str_keys(self):
+        """
+        OPT_NANOSECOND datetime.datetime dict key
+        """
+        assert (
+            orjson.dumps(
+                {datetime.datetime(1970, 1, 1, 0, 0, 0, 1): 1},
+                option=orjson.OPT_NANOSECOND | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1970-01-01T00:00:00.000001000":1}'
+        )
+
+
+UTC = datetime.timezone.utc
+
+
+def rfc2822(obj, option=0):
+    return orjson.dumps(obj, option=option, datetime_format="rfc2822")
+
+
+class TestRfc2822Datetime:
+    def test_rfc2822_datetime(self):
+        """
+        datetime_format="rfc2822"
+        """
+        assert (
+            rfc2822(datetime.datetime(1994, 11, 6, 8, 49, 37, tzinfo=UTC))
+            == b'"Sun, 06 Nov 1994 08:49:37 GMT"'
+        )
+
+    def test_rfc2822_datetime_days_months(self):
+        """
+        datetime_format="rfc2822" English day and month abbreviations
+        """
+        start = datetime.datetime(2024, 1, 1, tzinfo=UTC)
+        for day in range(1, 366, 9):
+            obj = start + datetime.timedelta(days=day)
+            assert rfc2822(obj) == obj.strftime('"%a, %d %b %Y %H:%M:%S GMT"').encode()
+
+    def test_rfc2822_datetime_microsecond(self):
+        """
+        datetime_format="rfc2822" truncates microseconds
+        """
+        obj = datetime.datetime(1970, 1, 1, 0, 0, 59, 999999, tzinfo=UTC)
+        assert rfc2822(obj) == b'"Thu, 01 Jan 1970 00:00:59 GMT"'
+        assert rfc2822(obj, orjson.OPT_NANOSECOND) == rfc2822(obj)
+
+    def test_rfc2822_datetime_offset(self):
+        """
+        datetime_format="rfc2822" converts to UTC
+        """
+        for hours, expected in (
+            (-5, b'"Sun, 06 Nov 1994 08:49:37 GMT"'),
+            (5.5, b'"Sat, 05 Nov 1994 22:19:37 GMT"'),
+        ):
+            obj = datetime.datetime(
+                1994,
+                11,
+                6,
+                3,
+                49,
+                37,
+                tzinfo=datetime.timezone(datetime.timedelta(hours=hours)),
+            )
+            assert rfc2822(obj) == expected
+
+    def test_rfc2822_datetime_offset_seconds(self):
+        """
+        datetime_format="rfc2822" converts an offset of seconds exactly
+        """
+        obj = datetime.datetime(
+            2000,
+            1,
+            1,
+            tzinfo=datetime.timezone(datetime.timedelta(minutes=19, seconds=32)),
+        )
+        assert rfc2822(obj) == b'"Fri, 31 Dec 1999 23:40:28 GMT"'
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_rfc2822_datetime_zoneinfo(self):
+        """
+        datetime_format="rfc2822" zoneinfo.ZoneInfo converts to UTC
+        """
+        obj = datetime.datetime(
+            2018, 12, 1, 2, 3, 4, tzinfo=zoneinfo.ZoneInfo("Australia/Adelaide")
+        )
+        assert rfc2822(obj) == b'"Fri, 30 Nov 2018 15:33:04 GMT"'
+
+    def test_rfc2822_datetime_roundtrip(self):
+        """
+        datetime_format="rfc2822" is parsed by email.utils
+        """
+        import email.utils
+
+        obj = datetime.datetime(
+            2021,
+            7,
+            4,
+            23,
+            15,
+            tzinfo=datetime.timezone(datetime.timedelta(hours=-7)),
+        )
+        val = orjson.loads(rfc2822(obj))
+        assert val == email.utils.format_datetime(
+            obj.astimezone(UTC), usegmt=True
+        )
+        assert email.utils.parsedate_to_datetime(val) == obj
+
+    def test_rfc2822_datetime_year(self):
+        """
+        datetime_format="rfc2822" year of four digits
+        """
+        assert (
+            rfc2822(datetime.datetime(5, 3, 1, tzinfo=UTC))
+            == b'"Tue, 01 Mar 0005 00:00:00 GMT"'
+        )
+        assert (
+            rfc2822(datetime.datetime(9999, 12, 31, 23, 59, 59, tzinfo=UTC))
+            == b'"Fri, 31 Dec 9999 23:59:59 GMT"'
+        )
+
+    def test_rfc2822_datetime_year_range(self):
+        """
+        datetime_format="rfc2822" raises if outside years 1 to 9999 in UTC
+        """
+        for obj in (
+            datetime.datetime(
+                1, 1, 1, tzinfo=datetime.timezone(datetime.timedelta(hours=1))
+            ),
+            datetime.datetime(
+                9999,
+                12,
+                31,
+                23,
+                tzinfo=datetime.timezone(datetime.timedelta(hours=-1)),
+            ),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                rfc2822({"a": [obj]})
+            assert str(exc_info.value) == (
+                "datetime must be in years 1 to 9999 in UTC with "
+                'datetime_format="rfc2822" at $.a[0] (output byte 6)'
+            )
+
+    def test_rfc2822_datetime_naive(self):
+        """
+        datetime_format="rfc2822" naive datetime raises unless OPT_NAIVE_UTC
+        """
+        obj = datetime.datetime(1970, 1, 1)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            rfc2822({"a": obj})
+        assert str(exc_info.value) == (
+            'datetime must have tzinfo with datetime_format="rfc2822" unless using '
+            "OPT_NAIVE_UTC at $.a (output byte 5)"
+        )
+        assert (
+            rfc2822(obj, orjson.OPT_NAIVE_UTC) == b'"Thu, 01 Jan 1970 00:00:00 GMT"'
+        )
+
+    def test_rfc2822_datetime_non_str_keys(self):
+        """
+        datetime_format="rfc2822" datetime.datetime dict key
+        """
+        obj = {datetime.datetime(1970, 1, 1, tzinfo=UTC): 1}
+        assert (
+            rfc2822(obj, orjson.OPT_NON_STR_KEYS)
+            == b'{"Thu, 01 Jan 1970 00:00:00 GMT":1}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            rfc2822({datetime.datetime(1970, 1, 1): 1}, orjson.OPT_NON_STR_KEYS)
+
+    def test_rfc2822_datetime_utc_z(self):
+        """
+        datetime_format="rfc2822" is not affected by OPT_UTC_Z
+        """
+        obj = datetime.datetime(1970, 1, 1, tzinfo=UTC)
+        assert rfc2822(obj, orjson.OPT_UTC_Z) == rfc2822(obj)
+
+    def test_rfc2822_datetime_date_time(self):
+        """
+        datetime_format="rfc2822" does not affect datetime.date and datetime.time
+        """
+        assert (
+            rfc2822([datetime.date(1970, 1, 1), datetime.time(1, 2)])
+            == b'["1970-01-01","01:02:00"]'
+        )
+
+    def test_rfc2822_datetime_passthrough(self):
+        """
+        datetime_format="rfc2822" OPT_PASSTHROUGH_DATETIME
+        """
+        obj = datetime.datetime(1970, 1, 1, tzinfo=UTC)
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                datetime_format="rfc2822",
+                default=lambda obj: obj.isoformat(),
+            )
+            == b'"1970-01-01T00:00:00+00:00"'
+        )
@@ -0,0 +1,220 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import decimal
+
+import pytest
+
+import orjson
+
+
+class DecimalSubclass(decimal.Decimal):
+    pass
+
+
+class TestDecimal:
+    def test_decimal_default(self):
+        """
+        dumps() decimal.Decimal without OPT_SERIALIZE_DECIMAL is unsupported
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(decimal.Decimal("1.5"))
+
+    def test_decimal_default_callable(self):
+        """
+        dumps() decimal.Decimal without OPT_SERIALIZE_DECIMAL passes to default
+        """
+        assert orjson.dumps(decimal.Decimal("1.5"), default=str) == b'"1.5"'
+
+    def test_decimal(self):
+        """
+        dumps() decimal.Decimal
+        """
+        assert (
+            orjson.dumps(decimal.Decimal("1.5"), option=orjson.OPT_SERIALIZE_DECIMAL)
+            == b"1.5"
+        )
+
+    def test_decimal_precision(self):
+        """
+        dumps() decimal.Decimal does not lose precision
+        """
+        assert (
+            orjson.dumps(
+                decimal.Decimal("0.0842389659712649442845"),
+                option=orjson.OPT_SERIALIZE_DECIMAL,
+            )
+            == b"0.0842389659712649442845"
+        )
+
+    def test_decimal_representations(self):
+        """
+        dumps() decimal.Decimal exponent, negative, and zero forms
+        """
+        for val, expected in (
+            ("0", b"0"),
+            ("-0", b"-0"),
+            ("-1.25", b"-1.25"),
+            ("1E+2", b"1E+2"),
+            ("1.5e-10", b"1.5E-10"),
+            ("100", b"100"),
+        ):
+            assert (
+                orjson.dumps(decimal.Decimal(val), option=orjson.OPT_SERIALIZE_DECIMAL)
+                == expected
+            )
+
+    def test_decimal_nested(self):
+        """
+        dumps() decimal.Decimal in list and dict
+        """
+        assert (
+            orjson.dumps(
+                {"a": [decimal.Decimal("1.1"), decimal.Decimal("2")]},
+                option=orjson.OPT_SERIALIZE_DECIMAL,
+            )
+            == b'{"a":[1.1,2]}'
+        )
+
+    def test_decimal_indent(self):
+        """
+        dumps() decimal.Decimal with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                [decimal.Decimal("1.1")],
+                option=orjson.OPT_SERIALIZE_DECIMAL | orjson.OPT_INDENT_2,
+            )
+            == b"[\n  1.1\n]"
+        )
+
+    def test_decimal_nonfinite(self):
+        """
+        dumps() decimal.Decimal NaN and Infinity raise
+        """
+        for val in ("NaN", "-NaN", "sNaN", "Infinity", "-Infinity"):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(decimal.Decimal(val), option=orjson.OPT_SERIALIZE_DECIMAL)
+
+    def test_decimal_nonfinite_allow_nonfinite(self):
+        """
+        dumps() decimal.Decimal NaN and Infinity OPT_ALLOW_NONFINITE are the
+        same literals as float
+        """
+        option = orjson.OPT_SERIALIZE_DECIMAL | orjson.OPT_ALLOW_NONFINITE
+        for val in ("NaN", "-NaN", "sNaN", "-sNaN", "Infinity", "-Infinity"):
+            assert orjson.dumps(decimal.Decimal(val), option=option) == orjson.dumps(
+                float(val.replace("s", "")), option=orjson.OPT_ALLOW_NONFINITE
+            )
+        assert (
+            orjson.dumps(
+                [decimal.Decimal("-Infinity"), decimal.Decimal("1.5")], option=option
+            )
+            == b"[-Infinity,1.5]"
+        )
+
+    def test_decimal_subclass(self):
+        """
+        dumps() decimal.Decimal subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(DecimalSubclass("1.5"), option=orjson.OPT_SERIALIZE_DECIMAL)
+
+    def test_decimal_dict_key(self):
+        """
+        dumps() decimal.Decimal is not a valid OPT_NON_STR_KEYS key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {decimal.Decimal("1.5"): 1},
+                option=orjson.OPT_SERIALIZE_DECIMAL | orjson.OPT_NON_STR_KEYS,
+            )
+
+
+def as_string(obj, option=0, **kwargs):
+    return orjson.dumps(obj, option=orjson.OPT_DECIMAL_AS_STRING | option, **kwargs)
+
+
+class TestDecimalAsString:
+    def test_decimal_as_string(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING
+        """
+        assert as_string(decimal.Decimal("1.5")) == b'"1.5"'
+
+    def test_decimal_as_string_representations(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING is str() of the instance
+        """
+        for val in (
+            "0",
+            "-0",
+            "-1.25",
+            "1.50",
+            "1E+2",
+            "1.5E-10",
+            "0.0842389659712649442845",
+            "123456789012345678901234567890.123456789",
+        ):
+            obj = decimal.Decimal(val)
+            assert as_string(obj) == f'"{obj}"'.encode()
+            assert decimal.Decimal(orjson.loads(as_string(obj))) == obj
+
+    def test_decimal_as_string_nonfinite(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING NaN and Infinity
+        """
+        assert as_string(
+            [decimal.Decimal(val) for val in ("NaN", "-NaN", "sNaN", "Infinity")]
+        ) == b'["NaN","-NaN","sNaN","Infinity"]'
+        assert as_string(decimal.Decimal("-Infinity")) == b'"-Infinity"'
+
+    def test_decimal_as_string_nested(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING in list and dict
+        """
+        obj = {"a": [decimal.Decimal("1.1"), decimal.Decimal("2")]}
+        assert as_string(obj) == b'{"a":["1.1","2"]}'
+        assert as_string(obj, orjson.OPT_INDENT_2) == (
+            b'{\n  "a": [\n    "1.1",\n    "2"\n  ]\n}'
+        )
+
+    def test_decimal_as_string_canonical(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING OPT_CANONICAL
+        """
+        obj = {"b": decimal.Decimal("1.0"), "a": 1}
+        assert as_string(obj, orjson.OPT_CANONICAL) == b'{"a":1,"b":"1.0"}'
+
+    def test_decimal_as_string_subclass(self):
+        """
+        dumps() decimal.Decimal subclass OPT_DECIMAL_AS_STRING is not
+        serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            as_string(DecimalSubclass("1.5"))
+        assert as_string(DecimalSubclass("1.5"), default=float) == b"1.5"
+
+    def test_decimal_as_string_dict_key(self):
+        """
+        dumps() decimal.Decimal OPT_DECIMAL_AS_STRING is not a valid
+        OPT_NON_STR_KEYS key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            as_string({decimal.Decimal("1.5"): 1}, orjson.OPT_NON_STR_KEYS)
+
+    def test_decimal_as_string_serialize_decimal(self):
+        """
+        dumps() OPT_DECIMAL_AS_STRING and OPT_SERIALIZE_DECIMAL are mutually
+        exclusive
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            as_string(decimal.Decimal("1.5"), orjson.OPT_SERIALIZE_DECIMAL)
+        with pytest.raises(orjson.JSONEncodeError):
+            as_string([], orjson.OPT_SERIALIZE_DECIMAL)
+
+    def test_decimal_as_string_loads(self):
+        """
+        loads() does not accept OPT_DECIMAL_AS_STRING
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("1.5", option=orjson.OPT_DECIMAL_AS_STRING)
@@ -101,7 +101,7 @@ class TestType:
             orjson.dumps(Custom(), default=default)
         except Exception as err:
             assert isinstance(err, orjson.JSONEncodeError)
-            assert str(err) == "Type is not JSON serializable: Custom"
+            assert str(err) == f"Type is not JSON serializable: {__name__}.Custom"
             ran = True
         assert ran
 
@@ -114,6 +114,36 @@ class TestType:
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(ref, default=default_raises)
 
+    @pytest.mark.skipif(sys.version_info < (3, 11), reason="add_note() is 3.11+")
+    def test_default_exception_note(self):
+        """
+        dumps() exception in default() has a note with the path of the value
+        """
+
+        def default(obj):
+            raise ValueError("invalid")
+
+        obj = {"items": [{"timestamp": 1}] * 42 + [{"timestamp": Custom()}]}
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, default=default)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.__cause__.__notes__ == [
+            "orjson: raised while serializing the value at $.items[42].timestamp"
+        ]
+
+    @pytest.mark.skipif(sys.version_info < (3, 11), reason="add_note() is 3.11+")
+    def test_default_exception_note_top_level(self):
+        """
+        dumps() exception in default() for the top-level value has no note
+        """
+
+        def default(obj):
+            raise ValueError("invalid")
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Custom(), default=default)
+        assert not hasattr(exc_info.value.__cause__, "__notes__")
+
     def test_default_vectorcall_str(self):
         """
         dumps() default function vectorcall str
@@ -194,7 +224,7 @@ class TestType:
             orjson.dumps(ref, default=default)
         except Exception as err:
             assert isinstance(err, orjson.JSONEncodeError)
-            assert str(err) == "Type is not JSON serializable: Custom"
+            assert str(err) == f"Type is not JSON serializable: {__name__}.Custom"
             ran = True
         assert ran
 
@@ -0,0 +1,282 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import collections
+import datetime
+import uuid
+
+import pytest
+
+import orjson
+
+
+def pairs(obj, option=0, **kwargs):
+    return orjson.dumps(obj, option=orjson.OPT_DICT_AS_PAIRS | option, **kwargs)
+
+
+class TestDictAsPairs:
+    def test_dict_as_pairs(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS
+        """
+        assert pairs({1: "a", 2: "b"}) == b'[[1,"a"],[2,"b"]]'
+
+    def test_dict_as_pairs_key_types(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS keys are serialized as values
+        """
+        obj = {
+            (1, "a"): 1,
+            None: 2,
+            True: 3,
+            1.5: 4,
+            datetime.date(2020, 1, 2): 5,
+            uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece"): 6,
+            "str": 7,
+        }
+        assert pairs(obj) == (
+            b'[[[1,"a"],1],[null,2],[true,3],[1.5,4],["2020-01-02",5],'
+            b'["7202d115-7ff3-4c81-a7c1-2a1f067b1ece",6],["str",7]]'
+        )
+
+    def test_dict_as_pairs_str_keys(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS dict with only str keys is an object
+        """
+        assert pairs({}) == b"{}"
+        assert pairs({"a": 1, "b": [2]}) == b'{"a":1,"b":[2]}'
+        assert pairs({"a": 1}, orjson.OPT_SORT_KEYS) == b'{"a":1}'
+
+    def test_dict_as_pairs_nested(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS nested
+        """
+        obj = {"a": {1: {2: [3]}}, "b": [{(4,): {"c": 5}}]}
+        assert pairs(obj) == b'{"a":[[1,[[2,[3]]]]],"b":[[[[4],{"c":5}]]]}'
+
+    def test_dict_as_pairs_order(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS is in iteration order
+        """
+        assert pairs({2: "a", 1: "b"}) == b'[[2,"a"],[1,"b"]]'
+
+    def test_dict_as_pairs_sort_keys(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_SORT_KEYS orders pairs by the serialized
+        form of their key
+        """
+        assert pairs({2: "a", 1: "b"}, orjson.OPT_SORT_KEYS) == b'[[1,"b"],[2,"a"]]'
+        obj = {(2,): 1, None: 2, 10: 3, "b": 4, 9: 5, "a": 6}
+        assert pairs(obj, orjson.OPT_SORT_KEYS) == (
+            b'[["a",6],["b",4],[10,3],[9,5],[[2],1],[null,2]]'
+        )
+        option = orjson.OPT_SORT_KEYS | orjson.OPT_SKIP_NONE_VALUES
+        assert pairs({2: None, 1: "a"}, option) == b'[[1,"a"]]'
+        assert pairs({2: "a", 1: "b"}, option | orjson.OPT_INDENT_2) == (
+            b'[\n  [\n    1,\n    "b"\n  ],\n  [\n    2,\n    "a"\n  ]\n]'
+        )
+
+    def test_dict_as_pairs_sort_keys_error(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_SORT_KEYS key that cannot be serialized
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            pairs({1: 2, frozenset(): 1}, orjson.OPT_SORT_KEYS)
+        assert str(exc_info.value).startswith(
+            "Type is not JSON serializable: frozenset"
+        )
+        obj = {frozenset([2]): "a", frozenset([1]): "b"}
+        assert pairs(obj, orjson.OPT_SORT_KEYS, default=sorted) == (
+            b'[[[1],"b"],[[2],"a"]]'
+        )
+
+    def test_dict_as_pairs_sort_keys_default_once(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_SORT_KEYS calls default once for each key
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            return sorted(obj)
+
+        obj = {frozenset([2]): "a", frozenset([1]): "b"}
+        assert pairs(obj, orjson.OPT_SORT_KEYS, default=default) == (
+            b'[[[1],"b"],[[2],"a"]]'
+        )
+        assert len(calls) == 2
+        _, stats = orjson.dumps_with_stats(
+            obj, option=orjson.OPT_DICT_AS_PAIRS | orjson.OPT_SORT_KEYS, default=default
+        )
+        assert stats["default_calls"] == 2
+
+    def test_dict_as_pairs_sort_keys_iterator(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_SORT_KEYS consumes a generator key once
+        """
+        obj = {(each for each in [1, 2]): 1, 0: 2}
+        option = orjson.OPT_SORT_KEYS | orjson.OPT_SERIALIZE_ITERATORS
+        assert pairs(obj, option) == b"[[0,2],[[1,2],1]]"
+
+    def test_dict_as_pairs_non_str_keys(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS takes precedence over OPT_NON_STR_KEYS
+        """
+        assert pairs({1: 2}, orjson.OPT_NON_STR_KEYS) == b"[[1,2]]"
+        assert orjson.dumps({1: 2}, option=orjson.OPT_NON_STR_KEYS) == b'{"1":2}'
+
+    def test_dict_as_pairs_ordered_dict(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS collections.OrderedDict
+        """
+        obj = collections.OrderedDict([(2, "a"), (1, "b")])
+        assert pairs(obj) == b'[[2,"a"],[1,"b"]]'
+        assert pairs(obj, orjson.OPT_PRESERVE_ORDERED_DICT) == b'[[2,"a"],[1,"b"]]'
+
+    def test_dict_as_pairs_skip_none(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_SKIP_NONE_VALUES
+        """
+        obj = {1: None, 2: "a", None: None}
+        assert pairs(obj, orjson.OPT_SKIP_NONE_VALUES) == b'[[2,"a"]]'
+        assert pairs({1: None}, orjson.OPT_SKIP_NONE_VALUES) == b"[]"
+
+    def test_dict_as_pairs_indent(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS OPT_INDENT_2
+        """
+        assert (
+            pairs({1: "a"}, orjson.OPT_INDENT_2)
+            == b'[\n  [\n    1,\n    "a"\n  ]\n]'
+        )
+
+    def test_dict_as_pairs_default(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS default applies to keys and values
+        """
+        obj = {frozenset([1]): object()}
+        assert pairs(obj, default=lambda _: "x") == b'[["x","x"]]'
+
+    def test_dict_as_pairs_error(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS error gives the path of the key or value
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            pairs({"a": {1: 2, 3: object()}})
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: object at $.a[1][1] (output byte 15)"
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            pairs({frozenset(): 1})
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: frozenset at $[0][0] (output byte 2)"
+        )
+
+    def test_dict_as_pairs_canonical(self):
+        """
+        dumps() OPT_DICT_AS_PAIRS cannot be combined with OPT_CANONICAL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            pairs({1: 2}, orjson.OPT_CANONICAL)
+
+
+def load_pairs(data, option=0, **kwargs):
+    return orjson.loads(data, option=orjson.OPT_DICT_AS_PAIRS | option, **kwargs)
+
+
+class TestLoadsDictAsPairs:
+    def test_loads_dict_as_pairs(self):
+        """
+        loads() OPT_DICT_AS_PAIRS
+        """
+        assert load_pairs('[[1, "a"], [2, "b"]]') == {1: "a", 2: "b"}
+        assert orjson.loads('[[1, "a"], [2, "b"]]') == [[1, "a"], [2, "b"]]
+
+    def test_loads_dict_as_pairs_key_types(self):
+        """
+        loads() OPT_DICT_AS_PAIRS keys are of their type in the document
+        """
+        assert load_pairs('[[null, 1], [true, 2], [1.5, 3], ["a", 4]]') == {
+            None: 1,
+            True: 2,
+            1.5: 3,
+            "a": 4,
+        }
+
+    def test_loads_dict_as_pairs_array_key(self):
+        """
+        loads() OPT_DICT_AS_PAIRS array key is tuple
+        """
+        val = load_pairs('[[[1, [2, []]], "a"]]')
+        assert val == {(1, (2, ())): "a"}
+        assert isinstance([*val][0], tuple)
+
+    def test_loads_dict_as_pairs_unhashable(self):
+        """
+        loads() OPT_DICT_AS_PAIRS key that is or contains an object is a list
+        """
+        assert load_pairs('[[{"a": 1}, 2]]') == [[{"a": 1}, 2]]
+        assert load_pairs('[[[1, {}], 2], [3, 4]]') == [[[1, {}], 2], [3, 4]]
+
+    def test_loads_dict_as_pairs_not_pairs(self):
+        """
+        loads() OPT_DICT_AS_PAIRS array without only pairs is a list
+        """
+        for data, val in (
+            ("[]", []),
+            ("[1, 2]", [1, 2]),
+            ("[[1, 2], [3]]", [[1, 2], [3]]),
+            ("[[1, 2], [3, 4, 5]]", [[1, 2], [3, 4, 5]]),
+            ('[[1, 2], "a"]', [[1, 2], "a"]),
+            ("[[]]", [[]]),
+        ):
+            assert load_pairs(data) == val
+
+    def test_loads_dict_as_pairs_nested(self):
+        """
+        loads() OPT_DICT_AS_PAIRS nested, and values are deserialized as is
+        """
+        assert load_pairs('{"a": [[1, [[2, 3]]]], "b": [[4, [5, 6]]]}') == {
+            "a": {1: {2: 3}},
+            "b": {4: [5, 6]},
+        }
+
+    def test_loads_dict_as_pairs_duplicate(self):
+        """
+        loads() OPT_DICT_AS_PAIRS duplicate keys
+        """
+        assert load_pairs("[[1, 2], [1, 3]]") == {1: 3}
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            load_pairs(
+                " [[1, 2], [[1], 3], [[1], 4]]", orjson.OPT_REJECT_DUPLICATE_KEYS
+            )
+        assert str(exc_info.value).startswith(
+            "duplicate key [1] in array of pairs at byte 21:"
+        )
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            load_pairs("[[1,2],[1,3]]", orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert exc_info.value.pos == 8
+        assert str(exc_info.value).startswith(
+            "duplicate key 1 in array of pairs at byte 8:"
+        )
+
+    def test_loads_dict_as_pairs_object_hook(self):
+        """
+        loads() OPT_DICT_AS_PAIRS object_hook is not called with pairs
+        """
+        assert load_pairs('[[1, {"a": 2}]]', object_hook=lambda obj: len(obj)) == {
+            1: 1
+        }
+
+    @pytest.mark.parametrize(
+        "obj",
+        [
+            {1: "a", (2, (3, "b")): [4], None: {5: 6}},
+            {"a": {1.5: True}, "b": []},
+            [{(1,): 2}, {}],
+        ],
+    )
+    def test_loads_dict_as_pairs_roundtrip(self, obj):
+        """
+        loads() OPT_DICT_AS_PAIRS of dumps() OPT_DICT_AS_PAIRS
+        """
+        assert load_pairs(pairs(obj)) == obj
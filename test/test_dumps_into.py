@@ -0,0 +1,199 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+class TestDumpsInto:
+    def test_dumps_into(self):
+        """
+        dumps_into() appends to bytearray and returns length
+        """
+        buffer = bytearray()
+        assert orjson.dumps_into({"a": [1, 2]}, buffer) == 11
+        assert buffer == b'{"a":[1,2]}'
+
+    def test_dumps_into_append(self):
+        """
+        dumps_into() does not truncate existing contents
+        """
+        buffer = bytearray(b"abc")
+        assert orjson.dumps_into([1], buffer) == 3
+        assert orjson.dumps_into("d", buffer) == 3
+        assert buffer == b'abc[1]"d"'
+
+    def test_dumps_into_batch(self):
+        """
+        dumps_into() multiple documents
+        """
+        buffer = bytearray()
+        for i in range(100):
+            orjson.dumps_into({"i": i}, buffer, option=orjson.OPT_APPEND_NEWLINE)
+        lines = buffer.split(b"\n")
+        assert lines[-1] == b""
+        assert [orjson.loads(line) for line in lines[:-1]] == [
+            {"i": i} for i in range(100)
+        ]
+
+    def test_dumps_into_large(self):
+        """
+        dumps_into() grows buffer
+        """
+        for obj in (
+            "a" * 100000,
+            read_fixture_obj("twitter.json.xz"),
+            read_fixture_obj("canada.json.xz"),
+        ):
+            buffer = bytearray(b"x" * 5000)
+            assert orjson.dumps_into(obj, buffer) == len(orjson.dumps(obj))
+            assert buffer == b"x" * 5000 + orjson.dumps(obj)
+
+    def test_dumps_into_same_as_dumps(self):
+        """
+        dumps_into() output is the same as dumps()
+        """
+        obj = {"b": [1, {"c": "é/"}], "a": None}
+        for option in (
+            None,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_INDENT_TAB | orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_SORT_KEYS | orjson.OPT_ESCAPE_FORWARD_SLASH,
+        ):
+            buffer = bytearray()
+            orjson.dumps_into(obj, buffer, option=option)
+            assert buffer == orjson.dumps(obj, option=option)
+
+    def test_dumps_into_default(self):
+        """
+        dumps_into() default
+        """
+        buffer = bytearray()
+        assert orjson.dumps_into({1}, buffer, list) == 3
+        assert orjson.dumps_into({2}, buffer, default=list) == 3
+        assert orjson.dumps_into({3}, buffer, list, orjson.OPT_APPEND_NEWLINE) == 4
+        assert buffer == b"[1][2][3]\n"
+
+    def test_dumps_into_error(self):
+        """
+        dumps_into() error leaves buffer unchanged
+        """
+        buffer = bytearray(b"abc")
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(["a" * 10000, object()], buffer)
+        assert buffer == b"abc"
+
+    def test_dumps_into_default_error(self):
+        """
+        dumps_into() default raising leaves buffer unchanged
+        """
+
+        def default(obj):
+            raise ValueError
+
+        buffer = bytearray(b"abc")
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into(["a" * 10000, object()], buffer, default=default)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert buffer == b"abc"
+
+    def test_dumps_into_default_resize(self):
+        """
+        dumps_into() buffer cannot be resized by default
+        """
+        buffer = bytearray(b"abc")
+
+        def default(obj):
+            buffer.extend(b"def")
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into([object()], buffer, default=default)
+        assert isinstance(exc_info.value.__cause__, BufferError)
+        assert buffer == b"abc"
+        buffer.extend(b"def")
+        assert buffer == b"abcdef"
+
+    def test_dumps_into_exported(self):
+        """
+        dumps_into() buffer with an exported memoryview cannot be resized
+        """
+        buffer = bytearray(b"abc")
+        view = memoryview(buffer)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into([1], buffer)
+        assert buffer == b"abc"
+        view.release()
+        assert orjson.dumps_into([1], buffer) == 3
+        assert buffer == b"abc[1]"
+
+    def test_dumps_into_exported_during(self):
+        """
+        dumps_into() buffer exported from default
+        """
+        buffer = bytearray(b"abc")
+        views = []
+
+        def default(obj):
+            views.append(memoryview(buffer))
+            return "x" * 10000
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into([object()], buffer, default=default)
+        assert len(buffer) in (3, len(views[0]))
+        assert buffer[:3] == b"abc"
+        assert not buffer[3:].strip(b"\x00")
+        views[0].release()
+
+    def test_dumps_into_bytearray_subclass(self):
+        """
+        dumps_into() bytearray subclass
+        """
+
+        class Subclass(bytearray):
+            pass
+
+        buffer = Subclass(b"a")
+        assert orjson.dumps_into(1, buffer) == 1
+        assert buffer == b"a1"
+
+    def test_dumps_into_buffer_type(self):
+        """
+        dumps_into() buffer must be a bytearray
+        """
+        for buffer in (b"", memoryview(bytearray()), [], None):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps_into(1, buffer)  # type: ignore
+
+    def test_dumps_into_invalid_option(self):
+        """
+        dumps_into() invalid option
+        """
+        buffer = bytearray()
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1, buffer, option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(
+                1, buffer, option=orjson.OPT_INDENT_2 | orjson.OPT_INDENT_4
+            )
+        assert buffer == b""
+
+    def test_dumps_into_arguments(self):
+        """
+        dumps_into() invalid arguments
+        """
+        buffer = bytearray()
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into()  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1, buffer, None, None, None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1, buffer, None, default=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1, buffer, None, None, option=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into(1, buffer, zxc=None)  # type: ignore
+        assert buffer == b""
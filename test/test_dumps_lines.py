@@ -0,0 +1,237 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import inspect
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Record:
+    a: int
+    b: str
+
+
+class TestDumpsLines:
+    def test_dumps_lines(self):
+        """
+        dumps_lines() list
+        """
+        val = orjson.dumps_lines([{"a": 1}, [2], "c", None])
+        assert isinstance(val, bytes)
+        assert val == b'{"a":1}\n[2]\n"c"\nnull'
+
+    def test_dumps_lines_empty(self):
+        """
+        dumps_lines() empty iterable is empty bytes
+        """
+        assert orjson.dumps_lines([]) == b""
+        assert orjson.dumps_lines(iter(())) == b""
+        assert orjson.dumps_lines([], option=orjson.OPT_APPEND_NEWLINE) == b""
+        assert orjson.dumps_lines([], option=orjson.OPT_UTF8_BOM) == b""
+
+    def test_dumps_lines_one(self):
+        """
+        dumps_lines() one element
+        """
+        assert orjson.dumps_lines([[1, 2]]) == b"[1,2]"
+
+    def test_dumps_lines_iterables(self):
+        """
+        dumps_lines() tuple, generator, dict keys, and set
+        """
+        assert orjson.dumps_lines((1, 2)) == b"1\n2"
+        assert orjson.dumps_lines(val * 2 for val in range(3)) == b"0\n2\n4"
+        assert orjson.dumps_lines({"a": 1, "b": 2}) == b'"a"\n"b"'
+        assert orjson.dumps_lines({1}) == b"1"
+
+    def test_dumps_lines_same_as_dumps(self):
+        """
+        dumps_lines() each line is the same as dumps()
+        """
+        records = [Record(idx, "é" * idx) for idx in range(100)]
+        option = orjson.OPT_SORT_KEYS
+        assert orjson.dumps_lines(records, option=option) == b"\n".join(
+            orjson.dumps(record, option=option) for record in records
+        )
+
+    def test_dumps_lines_roundtrip(self):
+        """
+        dumps_lines() each line deserializes to the element
+        """
+        obj = [{"a": [1, 2.5, None]}, "line\nbreak", [], {}]
+        output = orjson.dumps_lines(obj)
+        assert output.count(b"\n") == len(obj) - 1
+        assert [orjson.loads(line) for line in output.split(b"\n")] == obj
+
+    def test_dumps_lines_append_newline(self):
+        """
+        dumps_lines() OPT_APPEND_NEWLINE terminates the last line
+        """
+        assert (
+            orjson.dumps_lines([1, 2], option=orjson.OPT_APPEND_NEWLINE) == b"1\n2\n"
+        )
+
+    def test_dumps_lines_utf8_bom(self):
+        """
+        dumps_lines() OPT_UTF8_BOM is written once
+        """
+        assert (
+            orjson.dumps_lines([1, 2], option=orjson.OPT_UTF8_BOM)
+            == b"\xef\xbb\xbf1\n2"
+        )
+
+    def test_dumps_lines_option(self):
+        """
+        dumps_lines() option applies to each element
+        """
+        assert (
+            orjson.dumps_lines(
+                [{"b": 1, "a": 2}, {"d": 3, "c": 4}], option=orjson.OPT_SORT_KEYS
+            )
+            == b'{"a":2,"b":1}\n{"c":4,"d":3}'
+        )
+        assert (
+            orjson.dumps_lines([{"a": [1, 2]}], option=orjson.OPT_SPACE_AFTER_COMMA)
+            == b'{"a":[1, 2]}'
+        )
+
+    @pytest.mark.parametrize(
+        "option", [orjson.OPT_INDENT_2, orjson.OPT_INDENT_4, orjson.OPT_INDENT_TAB]
+    )
+    def test_dumps_lines_indent(self, option):
+        """
+        dumps_lines() does not support indentation
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines([{"a": 1}], option=option)
+        assert str(exc_info.value) == "Invalid opts"
+
+    def test_dumps_lines_default(self):
+        """
+        dumps_lines() default
+        """
+        assert orjson.dumps_lines([{1}, {2}], list) == b"[1]\n[2]"
+        assert orjson.dumps_lines([{1}], default=list) == b"[1]"
+
+    def test_dumps_lines_max_depth(self):
+        """
+        dumps_lines() max_depth applies to each element
+        """
+        obj = [[[1]], [[2]], [[3]]]
+        assert orjson.dumps_lines(obj, max_depth=2) == b"[[1]]\n[[2]]\n[[3]]"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_lines(obj, max_depth=1)
+
+    def test_dumps_lines_recursion_per_element(self):
+        """
+        dumps_lines() the depth of the iterable does not count
+        """
+        nested: list = []
+        for _ in range(254):
+            nested = [nested]
+        assert orjson.dumps_lines([nested, nested]) == b"\n".join(
+            [orjson.dumps(nested)] * 2
+        )
+
+    def test_dumps_lines_include(self):
+        """
+        dumps_lines() include
+        """
+        assert (
+            orjson.dumps_lines([Record(1, "x"), Record(2, "y")], include={"a"})
+            == b'{"a":1}\n{"a":2}'
+        )
+
+    def test_dumps_lines_float_precision(self):
+        """
+        dumps_lines() float_precision
+        """
+        assert (
+            orjson.dumps_lines(
+                [0.5, [1.25]], option=orjson.OPT_FLOAT_FIXED, float_precision=1
+            )
+            == b"0.5\n[1.2]"
+        )
+
+    def test_dumps_lines_error_path(self):
+        """
+        dumps_lines() error path begins with the index of the element
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines([1, {"a": [2, object()]}])
+        assert (
+            str(exc_info.value)
+            == "Type is not JSON serializable: object at $[1].a[1] (output byte 10)"
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines([object()])
+        assert str(exc_info.value) == "Type is not JSON serializable: object at $[0]"
+
+    def test_dumps_lines_not_iterable(self):
+        """
+        dumps_lines() obj must be iterable
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines(1)
+        assert str(exc_info.value) == "dumps_lines() obj must be iterable"
+        assert isinstance(exc_info.value.__cause__, TypeError)
+
+    def test_dumps_lines_iterator_raises(self):
+        """
+        dumps_lines() iterator raising is propagated as it is
+        """
+
+        def gen():
+            yield 1
+            raise ValueError("gen")
+
+        with pytest.raises(ValueError) as exc_info:
+            orjson.dumps_lines(gen())
+        assert not isinstance(exc_info.value, orjson.JSONEncodeError)
+        assert str(exc_info.value) == "gen"
+        assert orjson.dumps_lines([1]) == b"1"
+
+    def test_dumps_lines_default_error(self):
+        """
+        dumps_lines() default raising is the cause
+        """
+
+        def default(obj):
+            raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines([1, object()], default=default)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_dumps_lines_arguments(self):
+        """
+        dumps_lines() invalid arguments
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_lines()  # type: ignore
+        assert (
+            str(exc_info.value)
+            == "dumps_lines() missing 1 required positional argument: 'obj'"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_lines([], zxc=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_lines([], option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_lines([], max_depth=0)
+
+    def test_dumps_lines_signature(self):
+        """
+        dumps_lines() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.dumps_lines))
+            == "(obj, /, default=None, option=None, *, max_depth=None, include=None, "
+            "float_precision=None, bytes_format=None, complex_format=None, "
+            "date_format=None, datetime_format=None, exception_format=None, "
+            "fraction_format=None, range_format=None, time_format=None, "
+            "uuid_format=None)"
+        )
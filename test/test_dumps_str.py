@@ -0,0 +1,137 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import inspect
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+class TestDumpsStr:
+    def test_dumps_str(self):
+        """
+        dumps_str() returns str
+        """
+        val = orjson.dumps_str({"a": [1, 2]})
+        assert isinstance(val, str)
+        assert val == '{"a":[1,2]}'
+
+    def test_dumps_str_empty(self):
+        """
+        dumps_str() empty str
+        """
+        assert orjson.dumps_str("") == '""'
+
+    @pytest.mark.parametrize(
+        "obj",
+        [
+            "a",
+            "é",
+            "中文",
+            "\U0001f408",
+            ["a", "é", "中文", "\U0001f408"],
+        ],
+    )
+    def test_dumps_str_non_ascii(self, obj):
+        """
+        dumps_str() str of each width
+        """
+        assert orjson.dumps_str(obj) == orjson.dumps(obj).decode("utf-8")
+
+    def test_dumps_str_fixture(self):
+        """
+        dumps_str() output is the same as dumps().decode()
+        """
+        for name in ("twitter.json.xz", "github.json.xz", "canada.json.xz"):
+            obj = read_fixture_obj(name)
+            assert orjson.dumps_str(obj) == orjson.dumps(obj).decode("utf-8")
+
+    def test_dumps_str_option(self):
+        """
+        dumps_str() option
+        """
+        obj = {"b": 1, "a": [2]}
+        for option in (
+            orjson.OPT_INDENT_2,
+            orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_ASCII,
+        ):
+            assert orjson.dumps_str(obj, option=option) == orjson.dumps(
+                obj, option=option
+            ).decode("utf-8")
+        assert orjson.dumps_str([1], option=orjson.OPT_APPEND_NEWLINE) == "[1]\n"
+
+    def test_dumps_str_default(self):
+        """
+        dumps_str() default
+        """
+        assert orjson.dumps_str({1}, list) == "[1]"
+        assert orjson.dumps_str({1}, default=list) == "[1]"
+        assert orjson.dumps_str({1}, list, orjson.OPT_APPEND_NEWLINE) == "[1]\n"
+
+    def test_dumps_str_max_depth(self):
+        """
+        dumps_str() max_depth
+        """
+        assert orjson.dumps_str([[1]], max_depth=2) == "[[1]]"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str([[1]], max_depth=1)
+
+    def test_dumps_str_error(self):
+        """
+        dumps_str() error
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_str(["a" * 10000, object()])
+        assert (
+            str(exc_info.value)
+            == "Type is not JSON serializable: object at $[1] (output byte 10004)"
+        )
+
+    def test_dumps_str_default_error(self):
+        """
+        dumps_str() default raising is the cause
+        """
+
+        def default(obj):
+            raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_str(object(), default=default)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_dumps_str_arguments(self):
+        """
+        dumps_str() invalid arguments
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_str()  # type: ignore
+        assert (
+            str(exc_info.value)
+            == "dumps_str() missing 1 required positional argument: 'obj'"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str(1, None, default=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str(1, None, None, option=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str(1, zxc=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str(1, option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_str(1, max_depth=0)
+
+    def test_dumps_str_signature(self):
+        """
+        dumps_str() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.dumps_str))
+            == "(obj, /, default=None, option=None, *, max_depth=None, include=None, "
+            "float_precision=None, bytes_format=None, complex_format=None, "
+            "date_format=None, datetime_format=None, exception_format=None, "
+            "fraction_format=None, range_format=None, time_format=None, "
+            "uuid_format=None)"
+        )
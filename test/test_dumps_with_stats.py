@@ -0,0 +1,172 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import inspect
+
+import pytest
+
+import orjson
+
+
+def stats(fast=0, slow=0, depth=0, default=0):
+    return {
+        "fast_str_writes": fast,
+        "slow_str_writes": slow,
+        "max_depth": depth,
+        "default_calls": default,
+    }
+
+
+@dataclasses.dataclass
+class Record:
+    name: str
+    tags: list
+
+
+class TestDumpsWithStats:
+    def test_dumps_with_stats(self):
+        """
+        dumps_with_stats() returns the output of dumps() and stats
+        """
+        obj = {"a": ["x", "y\n", {"b": [1]}], 'c"': None}
+        val, counts = orjson.dumps_with_stats(obj)
+        assert val == orjson.dumps(obj)
+        assert counts == stats(fast=3, slow=2, depth=4)
+
+    def test_dumps_with_stats_scalar(self):
+        """
+        dumps_with_stats() scalar
+        """
+        assert orjson.dumps_with_stats(1) == (b"1", stats())
+        assert orjson.dumps_with_stats("a") == (b'"a"', stats(fast=1))
+        assert orjson.dumps_with_stats("\t") == (b'"\\t"', stats(slow=1))
+
+    def test_dumps_with_stats_str(self):
+        """
+        dumps_with_stats() str needing escaping, including non-ASCII that
+        does not
+        """
+        obj = ["", "a", "é", "\U0001f408", '"', "\\", "\x00", "a\x1fb", "/"]
+        assert orjson.dumps_with_stats(obj)[1] == stats(fast=5, slow=4, depth=1)
+
+    def test_dumps_with_stats_str_opts(self):
+        """
+        dumps_with_stats() str escaped by OPT_ASCII or OPT_ESCAPE_FORWARD_SLASH
+        """
+        obj = ["é", "/", "a"]
+        assert orjson.dumps_with_stats(obj, option=orjson.OPT_ASCII)[1] == stats(
+            fast=2, slow=1, depth=1
+        )
+        assert orjson.dumps_with_stats(
+            obj, option=orjson.OPT_ESCAPE_FORWARD_SLASH
+        )[1] == stats(fast=2, slow=1, depth=1)
+
+    def test_dumps_with_stats_keys(self):
+        """
+        dumps_with_stats() dict keys and dataclass fields are str writes
+        """
+        obj = {"a": 1, "b\n": 2, "user_id": Record("c", [])}
+        assert orjson.dumps_with_stats(obj)[1] == stats(fast=5, slow=1, depth=1)
+        assert orjson.dumps_with_stats(obj, option=orjson.OPT_CAMELCASE) == (
+            orjson.dumps(obj, option=orjson.OPT_CAMELCASE),
+            stats(fast=5, slow=1, depth=1),
+        )
+
+    def test_dumps_with_stats_depth(self):
+        """
+        dumps_with_stats() max_depth is the least max_depth that succeeds
+        """
+        for obj, depth in (
+            ([], 0),
+            ([1], 1),
+            ([[]], 1),
+            ([[1]], 2),
+            ({"a": [{"b": (1,)}]}, 4),
+            ([[1], [[[1]]], [1]], 4),
+            (Record("a", [1]), 2),
+            ([Record("a", [])], 1),
+        ):
+            assert orjson.dumps_with_stats(obj)[1]["max_depth"] == depth
+            if depth:
+                orjson.dumps(obj, max_depth=depth)
+                with pytest.raises(orjson.JSONEncodeError):
+                    orjson.dumps(obj, max_depth=depth - 1)
+
+    def test_dumps_with_stats_default(self):
+        """
+        dumps_with_stats() default calls, including for default's return value
+        """
+
+        def default(obj):
+            if isinstance(obj, frozenset):
+                return sorted(obj)
+            return frozenset([1, 2])
+
+        val, counts = orjson.dumps_with_stats([object(), frozenset()], default)
+        assert val == b"[[1,2],[]]"
+        assert counts == stats(depth=2, default=3)
+
+    def test_dumps_with_stats_reentrant(self):
+        """
+        dumps_with_stats() in default counts separately
+        """
+
+        def default(obj):
+            return orjson.dumps_with_stats(["a", "\n"])[1]
+
+        val, counts = orjson.dumps_with_stats([object(), "b"], default=default)
+        assert orjson.loads(val) == [stats(fast=1, slow=1, depth=1), "b"]
+        assert counts == stats(fast=5, depth=2, default=1)
+        assert orjson.dumps_with_stats(["a"])[1] == stats(fast=1, depth=1)
+
+    def test_dumps_with_stats_option(self):
+        """
+        dumps_with_stats() option, max_depth, include, and float_precision
+        """
+        obj = {"b": [Record("x", [])], "a": 1.5}
+        val, counts = orjson.dumps_with_stats(
+            obj,
+            option=orjson.OPT_SORT_KEYS | orjson.OPT_INDENT_2 | orjson.OPT_FLOAT_FIXED,
+            max_depth=3,
+            include={"name"},
+            float_precision=2,
+        )
+        assert orjson.loads(val) == {"a": 1.5, "b": [{"name": "x"}]}
+        assert val.startswith(b'{\n  "a": 1.50,')
+        assert counts == stats(fast=4, depth=2)
+
+    def test_dumps_with_stats_error(self):
+        """
+        dumps_with_stats() raises as dumps() does
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_with_stats({"a": [object()]})
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: object at $.a[0] (output byte 6)"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_with_stats([[1]], max_depth=1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_with_stats(1, option=1 << 127)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_with_stats()  # type: ignore
+
+    def test_dumps_with_stats_not_dumps(self):
+        """
+        dumps() is not affected by dumps_with_stats()
+        """
+        orjson.dumps_with_stats(["a"])
+        assert orjson.dumps(["a", "\n"]) == b'["a","\\n"]'
+
+    def test_dumps_with_stats_signature(self):
+        """
+        dumps_with_stats() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.dumps_with_stats))
+            == "(obj, /, default=None, option=None, *, max_depth=None, include=None, "
+            "float_precision=None, bytes_format=None, complex_format=None, "
+            "date_format=None, datetime_format=None, exception_format=None, "
+            "fraction_format=None, range_format=None, time_format=None, "
+            "uuid_format=None)"
+        )
@@ -0,0 +1,86 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+
+class TestRejectDuplicateKeys:
+    def test_duplicate_keys_default(self):
+        """
+        loads() last value wins by default
+        """
+        assert orjson.loads('{"a": 1, "a": 2}') == {"a": 2}
+
+    def test_duplicate_keys_none(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS without duplicates
+        """
+        val = '{"a": 1, "b": {"a": 2, "b": [{"a": 3}, {"a": 4}]}}'
+        obj = orjson.loads(val, option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert obj == orjson.loads(val)
+
+    def test_duplicate_keys(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"a": 1, "a": 2}', option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert exc_info.value.msg == 'duplicate key "a" in object at byte 9'
+        assert exc_info.value.pos == 9
+
+    def test_duplicate_keys_nested(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS in a nested object
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(
+                '[{"a": {"b": 1, "c": [], "b": 2}}]',
+                option=orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+        assert exc_info.value.msg == 'duplicate key "b" in object at byte 25'
+
+    def test_duplicate_keys_escaped(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS compares unescaped keys
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(
+                '{"a": 1, "\\u0061": 2}', option=orjson.OPT_REJECT_DUPLICATE_KEYS
+            )
+        assert exc_info.value.msg == 'duplicate key "\\u0061" in object at byte 9'
+
+    def test_duplicate_keys_pos_non_ascii(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS byte offset and char position differ
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"é": 1, "é": 2}', option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert exc_info.value.msg == 'duplicate key "é" in object at byte 10'
+        assert exc_info.value.pos == 9
+
+    def test_duplicate_keys_long(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS key not cached
+        """
+        key = "a" * 100
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(
+                f'{{"{key}": 1, "{key}": 2}}', option=orjson.OPT_REJECT_DUPLICATE_KEYS
+            )
+
+    def test_duplicate_keys_value_types(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS duplicate with container value
+        """
+        for val in ('{"a": [], "a": {}}', '{"a": {"b": 1}, "a": [1]}'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(val, option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+
+    def test_duplicate_keys_different_objects(self):
+        """
+        loads() OPT_REJECT_DUPLICATE_KEYS same key in sibling objects
+        """
+        val = '[{"a": 1}, {"a": 2}]'
+        obj = orjson.loads(val, option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+        assert obj == [{"a": 1}, {"a": 2}]
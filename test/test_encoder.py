@@ -0,0 +1,366 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import datetime
+import gc
+import inspect
+import sys
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+    b: str
+
+
+@dataclasses.dataclass
+class Node:
+    name: str
+    children: list
+    _private: int = 0
+
+
+@dataclasses.dataclass
+class Slotted:
+    __slots__ = ("a", "_b", "c")
+    a: int
+    _b: int
+    c: object
+
+
+@dataclasses.dataclass
+class Renamed:
+    a: int = dataclasses.field(metadata={"orjson_name": "A"})
+    b: int = 2
+
+
+ITEMS = [
+    {"a": [1, {"b": 2}], "c": None},
+    [],
+    {},
+    3,
+    "é/",
+    [[1, 2], [3.5, True]],
+    Dataclass(1, "a"),
+]
+
+
+def encode(items, **kwargs):
+    encoder = orjson.Encoder(**kwargs)
+    output = b"".join(encoder.write_chunk(item) for item in items)
+    return output + encoder.close()
+
+
+class TestEncoder:
+    def test_encoder(self):
+        """
+        Encoder chunks
+        """
+        encoder = orjson.Encoder()
+        assert encoder.write_chunk({"a": 1}) == b'[{"a":1}'
+        assert encoder.write_chunk([2]) == b",[2]"
+        assert encoder.write_chunk("3") == b',"3"'
+        assert encoder.close() == b"]"
+
+    def test_encoder_empty(self):
+        """
+        Encoder without elements
+        """
+        assert orjson.Encoder().close() == b"[]"
+        encoder = orjson.Encoder(option=orjson.OPT_INDENT_2)
+        assert encoder.close() == b"[]"
+
+    def test_encoder_single(self):
+        """
+        Encoder with one element
+        """
+        assert encode([1]) == b"[1]"
+        assert encode([1], option=orjson.OPT_INDENT_2) == b"[\n  1\n]"
+
+    @pytest.mark.parametrize(
+        "option",
+        [
+            None,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_INDENT_4,
+            orjson.OPT_INDENT_TAB,
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_INDENT_2 | orjson.OPT_SORT_KEYS,
+            orjson.OPT_ESCAPE_FORWARD_SLASH | orjson.OPT_ASCII,
+            orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA,
+        ],
+    )
+    def test_encoder_dumps(self, option):
+        """
+        Encoder output is the same as dumps() of a list
+        """
+        assert encode(ITEMS, option=option) == orjson.dumps(ITEMS, option=option)
+
+    @pytest.mark.parametrize(
+        "option",
+        [
+            orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_APPEND_NEWLINE | orjson.OPT_INDENT_2,
+        ],
+    )
+    def test_encoder_append_newline(self, option):
+        """
+        Encoder OPT_APPEND_NEWLINE applies to the end of the array
+        """
+        encoder = orjson.Encoder(option=option)
+        assert not encoder.write_chunk(1).endswith(b"\n")
+        assert encoder.close().endswith(b"]\n")
+        assert encode(ITEMS, option=option) == orjson.dumps(ITEMS, option=option)
+        assert encode([], option=option) == b"[]\n"
+
+    def test_encoder_fixture(self):
+        """
+        Encoder output of fixture elements
+        """
+        items = read_fixture_obj("twitter.json.xz")["statuses"]
+        assert encode(items) == orjson.dumps(items)
+        output = encode(items, option=orjson.OPT_INDENT_2)
+        assert output == orjson.dumps(items, option=orjson.OPT_INDENT_2)
+        assert orjson.loads(output) == items
+
+    def test_encoder_default(self):
+        """
+        Encoder default
+        """
+
+        def default(obj):
+            if isinstance(obj, set):
+                return sorted(obj)
+            raise TypeError
+
+        items = [{1, 2}, {"a": {3}}]
+        output = encode(items, default=default)
+        assert output == orjson.dumps(items, default=default)
+        assert output == b'[[1,2],{"a":[3]}]'
+
+    def test_encoder_option_per_element(self):
+        """
+        Encoder option applies to each element
+        """
+        val = datetime.datetime(2000, 1, 1, 2, 3, 4, 5)
+        items = [val, {"b": 1, "a": 2}]
+        option = orjson.OPT_NAIVE_UTC | orjson.OPT_SORT_KEYS
+        assert encode(items, option=option) == orjson.dumps(items, option=option)
+
+    def test_encoder_element_error(self):
+        """
+        Encoder element error writes nothing and the encoder continues
+        """
+        encoder = orjson.Encoder()
+        assert encoder.write_chunk(1) == b"[1"
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk(object())
+        assert encoder.write_chunk(2) == b",2"
+        assert encoder.close() == b"]"
+
+    def test_encoder_first_element_error(self):
+        """
+        Encoder error on the first element
+        """
+        encoder = orjson.Encoder()
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk({1: 2})
+        assert encoder.write_chunk(1) == b"[1"
+        assert encoder.close() == b"]"
+
+    def test_encoder_default_error(self):
+        """
+        Encoder default raising propagates as the cause
+        """
+
+        def default(obj):
+            raise ValueError("zxc")
+
+        encoder = orjson.Encoder(default=default)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.write_chunk(object())
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_encoder_closed(self):
+        """
+        Encoder raises after close()
+        """
+        encoder = orjson.Encoder()
+        encoder.write_chunk(1)
+        assert encoder.close() == b"]"
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk(2)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.close()
+
+    def test_encoder_independent(self):
+        """
+        Encoder instances are independent
+        """
+        first = orjson.Encoder()
+        second = orjson.Encoder(option=orjson.OPT_INDENT_2)
+        assert first.write_chunk(1) == b"[1"
+        assert second.write_chunk(1) == b"[\n  1"
+        assert first.write_chunk(2) == b",2"
+        assert second.close() == b"\n]"
+        assert first.close() == b"]"
+
+    def test_encoder_default_refcount(self):
+        """
+        Encoder holds a reference to default until deallocated
+        """
+
+        def default(obj):
+            return str(obj)
+
+        ref = sys.getrefcount(default)
+        encoder = orjson.Encoder(default=default)
+        assert sys.getrefcount(default) == ref + 1
+        encoder.write_chunk(object())
+        del encoder
+        assert sys.getrefcount(default) == ref
+
+    def test_encoder_arguments(self):
+        """
+        Encoder positional and keyword arguments
+        """
+        assert encode([{1}], default=list) == b"[[1]]"
+        encoder = orjson.Encoder(list, orjson.OPT_INDENT_2)
+        assert encoder.write_chunk({1}) == b"[\n  [\n    1\n  ]"
+        encoder = orjson.Encoder(None, None)
+        assert encoder.write_chunk(1) == b"[1"
+
+    def test_encoder_format(self):
+        """
+        Encoder *_format arguments apply to every element
+        """
+        items = [datetime.date(1970, 1, 2), [datetime.time(1, 2, 3, 4567)]]
+        kwargs = {"date_format": "epoch_day", "time_format": "millis"}
+        assert encode(items, **kwargs) == b'[1,["01:02:03.004"]]'
+        assert encode(items, **kwargs) == orjson.dumps(items, **kwargs)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(time_format="micros")
+
+    def test_encoder_invalid_arguments(self):
+        """
+        Encoder invalid arguments
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(None, None, None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(None, default=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(None, None, option=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(zxc=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(option="1")  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(option=orjson.OPT_INDENT_2 | orjson.OPT_INDENT_4)
+
+    def test_encoder_write_chunk_arguments(self):
+        """
+        Encoder.write_chunk() takes exactly one argument
+        """
+        encoder = orjson.Encoder()
+        with pytest.raises(TypeError):
+            encoder.write_chunk()  # type: ignore
+        with pytest.raises(TypeError):
+            encoder.write_chunk(1, 2)  # type: ignore
+        with pytest.raises(TypeError):
+            encoder.close(1)  # type: ignore
+
+    def test_encoder_dataclass_repeated(self):
+        """
+        Encoder instances of the same dataclass are the same as dumps()
+        """
+        tree = Node("a", [Node("b", [Node("c", [])]), Node("d", [])])
+        extra = Node("e", [])
+        extra.extra = 1  # type: ignore
+        missing = Node("f", [])
+        del missing._private
+        items = [tree, Node("x", []), extra, tree, missing, Node("y", []), extra]
+        for option in (
+            None,
+            orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            orjson.OPT_SKIP_NONE_VALUES | orjson.OPT_INDENT_2,
+        ):
+            assert encode(items, option=option) == orjson.dumps(items, option=option)
+
+    def test_encoder_dataclass_slots(self):
+        """
+        Encoder instances of the same dataclass with __slots__
+        """
+        items = [Slotted(1, 2, None), Slotted(3, 4, [Slotted(5, 6, 7)])]
+        for option in (
+            None,
+            orjson.OPT_INCLUDE_PRIVATE_FIELDS,
+            orjson.OPT_SKIP_NONE_VALUES,
+        ):
+            assert encode(items, option=option) == orjson.dumps(items, option=option)
+
+    def test_encoder_dataclass_field_rename(self):
+        """
+        Encoder instances of the same dataclass OPT_DATACLASS_FIELD_RENAME
+        """
+        items = [Renamed(1), Node("a", [Renamed(3, 4)]), Renamed(5)]
+        option = orjson.OPT_DATACLASS_FIELD_RENAME
+        assert encode(items, option=option) == orjson.dumps(items, option=option)
+        assert encode(items) == orjson.dumps(items)
+
+    def test_encoder_dataclass_type_collected(self):
+        """
+        Encoder dataclass types that are garbage collected
+        """
+        encoder = orjson.Encoder()
+        for i in range(100):
+            fast = dataclasses.make_dataclass("Fast", [(f"a{i}", int), ("b", int)])
+            slotted = dataclasses.make_dataclass(
+                "Slotted", [("b", int), (f"c{i}", int)], slots=True
+            )
+            assert encoder.write_chunk(fast(i, 1)) == (
+                b"[" if i == 0 else b","
+            ) + orjson.dumps({f"a{i}": i, "b": 1})
+            assert encoder.write_chunk(slotted(2, i)) == b"," + orjson.dumps(
+                {"b": 2, f"c{i}": i}
+            )
+            del fast, slotted
+            gc.collect()
+
+    def test_encoder_dataclass_error(self):
+        """
+        Encoder error in a dataclass does not affect the next instance
+        """
+        invalid = Node("a", [])
+        invalid.__dict__[1] = 2
+        encoder = orjson.Encoder()
+        assert encoder.write_chunk(Node("b", [])) == b'[{"name":"b","children":[]}'
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.write_chunk(invalid)
+        assert encoder.write_chunk(Node("c", [])) == b',{"name":"c","children":[]}'
+
+        @dataclasses.dataclass
+        class InvalidRename:
+            a: int = dataclasses.field(metadata={"orjson_name": 1})
+
+        encoder = orjson.Encoder(option=orjson.OPT_DATACLASS_FIELD_RENAME)
+        for _ in range(2):
+            with pytest.raises(orjson.JSONEncodeError):
+                encoder.write_chunk(InvalidRename(1))
+
+    def test_encoder_doc(self):
+        """
+        Encoder has a docstring and valid __text_signature__
+        """
+        assert orjson.Encoder.__doc__ == "Serialize a JSON array one element at a time."
+        signature = inspect.signature(orjson.Encoder)
+        assert list(signature.parameters)[:2] == ["default", "option"]
+        signature.bind(str, 1, uuid_format="int")
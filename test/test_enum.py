@@ -1,5 +1,6 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import dataclasses
 import datetime
 import enum
 
@@ -56,6 +57,25 @@ class UnspecifiedEnum(enum.Enum):
     F = datetime.datetime(1970, 1, 1)
 
 
+@dataclasses.dataclass(frozen=True)
+class Point:
+    x: int
+    y: int
+
+
+class PointEnum(Point, enum.Enum):
+    ORIGIN = 0, 0
+
+
+@dataclasses.dataclass(eq=False)
+class DataclassEnum(enum.Enum):
+    A = 1
+
+
+class PointValueEnum(enum.Enum):
+    ORIGIN = Point(0, 0)
+
+
 class TestEnum:
     def test_cannot_subclass(self):
         """
@@ -118,3 +138,214 @@ class TestEnum:
         assert (
             orjson.dumps({IntEnum.ONE: 1}, option=orjson.OPT_NON_STR_KEYS) == b'{"1":1}'
         )
+
+
+class ColorFlag(enum.Flag):
+    RED = 1
+    GREEN = 2
+    BLUE = 4
+
+
+class AliasEnum(enum.Enum):
+    A = 1
+    B = 1
+
+
+class TestEnumByName:
+    def test_enum_by_name(self):
+        assert (
+            orjson.dumps(UnspecifiedEnum.B, option=orjson.OPT_ENUM_BY_NAME) == b'"B"'
+        )
+        assert (
+            orjson.dumps(UnspecifiedEnum.E, option=orjson.OPT_ENUM_BY_NAME) == b'"E"'
+        )
+
+    def test_enum_by_name_nested(self):
+        assert (
+            orjson.dumps(
+                {"a": [UnspecifiedEnum.A, FloatEnum.ONE]},
+                option=orjson.OPT_ENUM_BY_NAME,
+            )
+            == b'{"a":["A","ONE"]}'
+        )
+
+    def test_enum_by_name_int_enum(self):
+        assert orjson.dumps(IntEnum.ONE, option=orjson.OPT_ENUM_BY_NAME) == b'"ONE"'
+        assert (
+            orjson.dumps(IntEnumEnum.ONE, option=orjson.OPT_ENUM_BY_NAME) == b'"ONE"'
+        )
+        assert (
+            orjson.dumps([IntFlagEnum.ONE], option=orjson.OPT_ENUM_BY_NAME)
+            == b'["ONE"]'
+        )
+
+    def test_enum_by_name_str_enum(self):
+        assert orjson.dumps(StrEnum.AAA, option=orjson.OPT_ENUM_BY_NAME) == b'"AAA"'
+
+    def test_enum_by_name_flag(self):
+        assert orjson.dumps(ColorFlag.RED, option=orjson.OPT_ENUM_BY_NAME) == b'"RED"'
+
+    def test_enum_by_name_alias(self):
+        assert orjson.dumps(AliasEnum.B, option=orjson.OPT_ENUM_BY_NAME) == b'"A"'
+
+    def test_enum_by_name_composite_flag(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                ColorFlag.RED | ColorFlag.GREEN, option=orjson.OPT_ENUM_BY_NAME
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({"a": IntFlagEnum(3)}, option=orjson.OPT_ENUM_BY_NAME)
+
+    def test_enum_by_name_composite_flag_by_value(self):
+        assert orjson.dumps(ColorFlag.RED | ColorFlag.GREEN) == b"3"
+
+    def test_enum_by_name_non_str_keys(self):
+        assert (
+            orjson.dumps(
+                {IntEnum.ONE: 1, StrEnum.AAA: 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_ENUM_BY_NAME,
+            )
+            == b'{"ONE":1,"AAA":2}'
+        )
+
+    def test_enum_dataclass(self):
+        """
+        enum that is also a dataclass is serialized as an enum
+        """
+        assert "__dataclass_fields__" in DataclassEnum.__dict__
+        assert orjson.dumps(DataclassEnum.A) == b"1"
+        assert orjson.dumps([DataclassEnum.A]) == b"[1]"
+        assert orjson.dumps(DataclassEnum.A, option=orjson.OPT_ENUM_BY_NAME) == b'"A"'
+        assert (
+            orjson.dumps(DataclassEnum.A, option=orjson.OPT_PASSTHROUGH_DATACLASS)
+            == b"1"
+        )
+        assert (
+            orjson.dumps({DataclassEnum.A: 1}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"1":1}'
+        )
+
+    def test_enum_dataclass_mixin(self):
+        """
+        enum with a dataclass mixin is serialized as an enum
+        """
+        value = orjson.dumps(PointEnum.ORIGIN.value)
+        assert orjson.dumps(PointEnum.ORIGIN) == value
+        assert orjson.dumps({"a": PointEnum.ORIGIN}) == b'{"a":' + value + b"}"
+        assert (
+            orjson.dumps(PointEnum.ORIGIN, option=orjson.OPT_ENUM_BY_NAME)
+            == b'"ORIGIN"'
+        )
+        assert (
+            orjson.dumps(
+                {PointEnum.ORIGIN: 1},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_ENUM_BY_NAME,
+            )
+            == b'{"ORIGIN":1}'
+        )
+
+    def test_enum_dataclass_value(self):
+        """
+        enum with a dataclass value and the dataclass itself
+        """
+        assert orjson.dumps(Point(0, 0)) == b'{"x":0,"y":0}'
+        assert orjson.dumps(PointValueEnum.ORIGIN) == b'{"x":0,"y":0}'
+        assert (
+            orjson.dumps(PointValueEnum.ORIGIN, option=orjson.OPT_ENUM_BY_NAME)
+            == b'"ORIGIN"'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                PointValueEnum.ORIGIN, option=orjson.OPT_PASSTHROUGH_DATACLASS
+            )
+
+
+def enum_default(obj):
+    if isinstance(obj, enum.Enum):
+        return f"{type(obj).__name__}.{obj.name}"
+    raise TypeError
+
+
+class TestPassthroughEnum:
+    def test_passthrough_enum(self):
+        """
+        OPT_PASSTHROUGH_ENUM passes enums to default
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(FlagEnum.ONE, option=orjson.OPT_PASSTHROUGH_ENUM)
+        assert (
+            orjson.dumps(
+                [FlagEnum.ONE, {"a": UnspecifiedEnum.A}],
+                option=orjson.OPT_PASSTHROUGH_ENUM,
+                default=enum_default,
+            )
+            == b'["FlagEnum.ONE",{"a":"UnspecifiedEnum.A"}]'
+        )
+
+    def test_passthrough_enum_default_value(self):
+        """
+        OPT_PASSTHROUGH_ENUM default returning the value
+        """
+        assert (
+            orjson.dumps(
+                [UnspecifiedEnum.D, UnspecifiedEnum.F],
+                option=orjson.OPT_PASSTHROUGH_ENUM,
+                default=lambda obj: obj.value,
+            )
+            == b'[{"d":1},"1970-01-01T00:00:00"]'
+        )
+
+    @pytest.mark.parametrize(
+        "obj",
+        [
+            StrEnum.AAA,
+            IntEnum.ONE,
+            IntEnumEnum.ONE,
+            IntFlagEnum.ONE,
+            FloatEnum.ONE,
+            PointEnum.ORIGIN,
+        ],
+    )
+    def test_passthrough_enum_builtin_subclass(self, obj):
+        """
+        OPT_PASSTHROUGH_ENUM passes enums that subclass builtins or are
+        dataclasses to default
+        """
+        assert orjson.dumps(
+            obj, option=orjson.OPT_PASSTHROUGH_ENUM, default=enum_default
+        ) == f'"{type(obj).__name__}.{obj.name}"'.encode("utf-8")
+
+    def test_passthrough_enum_by_name(self):
+        """
+        OPT_PASSTHROUGH_ENUM takes precedence over OPT_ENUM_BY_NAME
+        """
+        assert (
+            orjson.dumps(
+                IntEnum.ONE,
+                option=orjson.OPT_PASSTHROUGH_ENUM | orjson.OPT_ENUM_BY_NAME,
+                default=lambda obj: obj.value * 2,
+            )
+            == b"2"
+        )
+
+    def test_passthrough_enum_without_option(self):
+        """
+        enums are serialized by value without OPT_PASSTHROUGH_ENUM
+        """
+        assert (
+            orjson.dumps([IntEnum.ONE, StrEnum.AAA], default=enum_default)
+            == b'[1,"aaa"]'
+        )
+
+    def test_passthrough_enum_non_str_keys(self):
+        """
+        OPT_PASSTHROUGH_ENUM does not affect dict keys
+        """
+        assert (
+            orjson.dumps(
+                {IntEnum.ONE: StrEnum.AAA},
+                option=orjson.OPT_PASSTHROUGH_ENUM | orjson.OPT_NON_STR_KEYS,
+                default=enum_default,
+            )
+            == b'{"1":"StrEnum.AAA"}'
+        )
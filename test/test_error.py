@@ -1,5 +1,6 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import dataclasses
 import json
 
 import pytest
@@ -101,6 +102,81 @@ class TestJsonDecodeError:
             "colno": 7,
         }
 
+    def test_attributes(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"a": 1,\n "b": x}')
+        assert exc_info.value.msg == "unexpected character"
+        assert exc_info.value.doc == '{"a": 1,\n "b": x}'
+        assert exc_info.value.pos == 15
+        assert exc_info.value.lineno == 2
+        assert exc_info.value.colno == 7
+
+    def test_snippet(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"a": 1, x}')
+        assert exc_info.value.snippet == '{"a": 1, x}'
+        assert str(exc_info.value) == (
+            "unexpected character: line 1 column 10 (char 9)\n"
+            '{"a": 1, x}\n'
+            "         ^"
+        )
+        assert exc_info.value.args == (str(exc_info.value),)
+
+    def test_snippet_line(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('[\n  1,\n  x,\n  3\n]')
+        assert exc_info.value.snippet == "  x,"
+        assert str(exc_info.value).endswith("\n  x,\n  ^")
+
+    def test_snippet_crlf(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[\r\n  1,\r\n  x\r\n]")
+        assert exc_info.value.snippet == "  x"
+
+    def test_snippet_window(self):
+        data = '["' + "a" * 1000 + '", x, "' + "b" * 1000 + '"]'
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(data)
+        snippet = exc_info.value.snippet
+        assert snippet == "a" * 37 + '", x, "' + "b" * 36
+        assert str(exc_info.value).endswith("\n" + " " * 40 + "^")
+        assert len(str(exc_info.value)) < 200
+
+    def test_snippet_end(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[1, 2")
+        assert exc_info.value.snippet == "[1, 2"
+        assert str(exc_info.value).endswith("\n[1, 2\n     ^")
+
+    def test_snippet_non_ascii(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('["東京😊", x]')
+        assert exc_info.value.pos == 8
+        assert exc_info.value.snippet == '["東京😊", x]'
+        assert str(exc_info.value).endswith("\n" + " " * 8 + "^")
+
+    def test_snippet_tab(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[\t\tx]")
+        assert str(exc_info.value).endswith("\n[\t\tx]\n \t\t^")
+
+    def test_snippet_option(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"a": 1, x}', option=orjson.OPT_PARSE_DECIMAL)
+        assert exc_info.value.snippet == '{"a": 1, x}'
+        assert exc_info.value.pos == 9
+
+    def test_snippet_none(self):
+        for data in ("", "   ", "\n\n"):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(data)
+            assert exc_info.value.snippet is None
+            assert "\n" not in str(exc_info.value)
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[]", option=-1)
+        assert exc_info.value.snippet is None
+        assert str(exc_info.value) == "Invalid opts: line 1 column 1 (char 0)"
+
 
 class Custom:
     pass
@@ -150,7 +226,9 @@ class TestJsonEncodeError:
         with pytest.raises(orjson.JSONEncodeError) as exc_info:
             orjson.dumps(Custom())
         assert exc_info.type == orjson.JSONEncodeError
-        assert str(exc_info.value) == "Type is not JSON serializable: Custom"
+        assert (
+            str(exc_info.value) == f"Type is not JSON serializable: {__name__}.Custom"
+        )
         assert exc_info.value.__cause__ is None
 
     def test_dumps_chain_u64(self):
@@ -184,6 +262,158 @@ class TestJsonEncodeError:
         assert isinstance(exc_info.value.__cause__, CustomException)
         assert str(exc_info.value.__cause__) == CUSTOM_ERROR_MESSAGE
 
+    def test_dumps_unsupported_type_path(self):
+        for obj, path in (
+            ({"a": [1, {"b": Custom()}]}, "$.a[1].b (output byte 13)"),
+            ([[], (1, Custom())], "$[1][1] (output byte 7)"),
+            ([{"a": (Custom(),)}], "$[0].a[0] (output byte 7)"),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj)
+            assert (
+                str(exc_info.value)
+                == f"Type is not JSON serializable: {__name__}.Custom at {path}"
+            )
+
+    def test_dumps_unsupported_type_path_dataclass(self):
+        @dataclasses.dataclass
+        class Dataclass:
+            a: object
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"b": Dataclass(Custom())})
+        assert str(exc_info.value).endswith(".Custom at $.b.a (output byte 10)")
+
+    def test_dumps_unsupported_type_path_option(self):
+        for option, obj, path in (
+            (orjson.OPT_SORT_KEYS, {"b": 1, "a": [Custom()]}, "$.a[0] (output byte 6)"),
+            (orjson.OPT_NON_STR_KEYS, {1: {"a": Custom()}}, '$["1"].a (output byte 10)'),
+            (orjson.OPT_INDENT_2, [1, [Custom()]], "$[1][0] (output byte 15)"),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, option=option)
+            assert str(exc_info.value).endswith(f".Custom at {path}")
+
+    def test_dumps_unsupported_type_path_quoted(self):
+        """
+        A key that is not an identifier is quoted in brackets in the path
+        """
+        for obj, path in (
+            ({"a.b": {"x y": [Custom()]}}, '$["a.b"]["x y"][0]'),
+            ({"a": {"b": {"x y": [Custom()]}}}, '$.a.b["x y"][0]'),
+            ({'say "hi"\\': [Custom()]}, '$["say \\"hi\\"\\\\"][0]'),
+            ({"": {"_a1": Custom()}}, '$[""]._a1'),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj)
+            assert f".Custom at {path} (output byte" in str(exc_info.value)
+
+    def test_dumps_unsupported_type_path_default(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": [Custom()]}, default=default_customerror)
+        assert str(exc_info.value).endswith(".Custom at $.a[0] (output byte 6)")
+        assert isinstance(exc_info.value.__cause__, CustomException)
+
+    def test_dumps_unsupported_type_path_reset(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({"a": Custom()})
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Custom())
+        assert (
+            str(exc_info.value) == f"Type is not JSON serializable: {__name__}.Custom"
+        )
+
+    def test_dumps_unsupported_type_builtin(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([object()])
+        assert (
+            str(exc_info.value)
+            == "Type is not JSON serializable: object at $[0] (output byte 1)"
+        )
+
+    def test_dumps_unsupported_type_nested_class(self):
+        class Nested:
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Nested())
+        assert str(exc_info.value) == (
+            f"Type is not JSON serializable: {__name__}.{Nested.__qualname__}"
+        )
+
+    def test_dumps_unsupported_type_encoder(self):
+        encoder = orjson.Encoder()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.write_chunk({"a": Custom()})
+        assert str(exc_info.value).endswith(".Custom at $.a (output byte 6)")
+
+    def test_dumps_key_must_be_str(self):
+        for obj, msg in (
+            ({1: 1}, "Dict key must be str, not int (output byte 1)"),
+            ({"a": 1, (1,): 2}, "Dict key must be str, not tuple (output byte 6)"),
+            (
+                {"a": [1, {"b": {Custom(): 1}}]},
+                f"Dict key must be str, not {__name__}.Custom at $.a[1].b "
+                "(output byte 14)",
+            ),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj)
+            assert str(exc_info.value) == msg
+
+    def test_dumps_key_must_be_str_sort_keys(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([{}, {"a": {1.5: 1}}], option=orjson.OPT_SORT_KEYS)
+        assert str(exc_info.value) == (
+            "Dict key must be str, not float at $[1].a (output byte 9)"
+        )
+
+    def test_dumps_key_must_be_str_dataclass(self):
+        @dataclasses.dataclass
+        class Dataclass:
+            a: int
+
+        obj = Dataclass(1)
+        obj.__dict__[1] = 2
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"b": [obj]})
+        assert str(exc_info.value) == (
+            "Dict key must be str, not int at $.b[0] (output byte 12)"
+        )
+
+    def test_dumps_output_byte(self):
+        obj = list(range(10000))
+        obj[9999] = Custom()  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, default=default_customerror)
+        written = len(orjson.dumps(obj[:9999]))
+        assert str(exc_info.value).endswith(f" at $[9999] (output byte {written})")
+        assert isinstance(exc_info.value.__cause__, CustomException)
+
+    def test_dumps_output_byte_top_level(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Custom())
+        assert "output byte" not in str(exc_info.value)
+
+    def test_dumps_output_byte_without_path(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([1, 2**64])
+        assert str(exc_info.value) == "Integer exceeds 64-bit range (output byte 3)"
+
+    def test_dumps_output_byte_dumps_into(self):
+        buffer = bytearray(b"abc")
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into([1, Custom()], buffer)
+        assert str(exc_info.value).endswith(" at $[1] (output byte 3)")
+
+    def test_dumps_output_byte_dumps_into_exported(self):
+        buffer = bytearray(b"abc")
+        view = memoryview(buffer)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into(["a" * 5000, Custom()], buffer)
+        assert str(exc_info.value).endswith(" at $[1] (output byte 5004)")
+        view.release()
+
     def test_dumps_normalize_exception(self):
         with pytest.raises(orjson.JSONEncodeError) as exc_info:
             orjson.dumps(10**60)
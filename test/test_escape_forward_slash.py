@@ -0,0 +1,133 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import json
+
+import pytest
+
+import orjson
+
+
+def stdlib(obj, ensure_ascii=False):
+    return (
+        json.dumps(obj, ensure_ascii=ensure_ascii, separators=(",", ":"))
+        .replace("/", "\\/")
+        .encode("utf-8")
+    )
+
+
+class TestEscapeForwardSlash:
+    def test_escape_forward_slash_default(self):
+        """
+        dumps() does not escape / by default
+        """
+        assert orjson.dumps("</script>") == b'"</script>"'
+
+    def test_escape_forward_slash_script(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH </script>
+        """
+        output = orjson.dumps("</script>", option=orjson.OPT_ESCAPE_FORWARD_SLASH)
+        assert output == b'"<\\/script>"'
+        assert b"</" not in output
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "",
+            "/",
+            "//",
+            "</script>",
+            "a/b/c",
+            "x" * 64 + "/",
+            "/" + "x" * 64,
+            '/"\\\n',
+            "é/",
+            "\\/",
+            "x" * 15 + "/" + "y" * 17 + "\n",
+        ],
+    )
+    def test_escape_forward_slash(self, val):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH
+        """
+        output = orjson.dumps(val, option=orjson.OPT_ESCAPE_FORWARD_SLASH)
+        assert output == stdlib(val)
+        assert orjson.loads(output) == val
+
+    def test_escape_forward_slash_no_slash(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH without / is unchanged
+        """
+        val = ["abc", "é", '"\\\n', "x" * 100]
+        output = orjson.dumps(val, option=orjson.OPT_ESCAPE_FORWARD_SLASH)
+        assert output == orjson.dumps(val)
+
+    def test_escape_forward_slash_key(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH dict key
+        """
+        assert (
+            orjson.dumps({"a/b": "</c>"}, option=orjson.OPT_ESCAPE_FORWARD_SLASH)
+            == b'{"a\\/b":"<\\/c>"}'
+        )
+
+    def test_escape_forward_slash_non_str_key(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH non-str dict key
+        """
+        assert (
+            orjson.dumps(
+                {1: "/"},
+                option=orjson.OPT_ESCAPE_FORWARD_SLASH | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":"\\/"}'
+        )
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "é/",
+            "/é",
+            "</😀>",
+            "x" * 64 + "/é",
+            "é" + "x" * 64 + "/",
+        ],
+    )
+    def test_escape_forward_slash_ascii(self, val):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH | OPT_ASCII
+        """
+        output = orjson.dumps(
+            val, option=orjson.OPT_ESCAPE_FORWARD_SLASH | orjson.OPT_ASCII
+        )
+        assert output == stdlib(val, ensure_ascii=True)
+        assert orjson.loads(output) == val
+
+    def test_escape_forward_slash_ascii_only(self):
+        """
+        dumps() OPT_ASCII does not escape /
+        """
+        assert orjson.dumps("é/", option=orjson.OPT_ASCII) == b'"\\u00e9/"'
+
+    def test_escape_forward_slash_indent(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH | OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a": ["/"]},
+                option=orjson.OPT_ESCAPE_FORWARD_SLASH | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "a": [\n    "\\/"\n  ]\n}'
+        )
+
+    def test_escape_forward_slash_fragment(self):
+        """
+        dumps() OPT_ESCAPE_FORWARD_SLASH does not modify orjson.Fragment
+        """
+        assert (
+            orjson.dumps(
+                [orjson.Fragment(b'"/"')], option=orjson.OPT_ESCAPE_FORWARD_SLASH
+            )
+            == b'["/"]'
+        )
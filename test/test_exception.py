@@ -0,0 +1,296 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import decimal
+import traceback
+
+import pytest
+
+import orjson
+
+OPTION = orjson.OPT_SERIALIZE_EXCEPTION
+
+
+class CustomError(Exception):
+    pass
+
+
+class StrRaises(Exception):
+    def __str__(self):
+        raise ZeroDivisionError
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+
+
+def raised(exc):
+    try:
+        raise exc
+    except BaseException as err:
+        return err
+
+
+def chained(exc, cause):
+    try:
+        try:
+            raise cause
+        except BaseException as err:
+            raise exc from err
+    except BaseException as err:
+        return err
+
+
+class TestSerializeException:
+    def test_exception(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION
+        """
+        assert (
+            orjson.dumps(ValueError("a"), option=OPTION)
+            == b'{"type":"ValueError","message":"a","args":["a"]}'
+        )
+        assert (
+            orjson.dumps(ValueError(), option=OPTION)
+            == b'{"type":"ValueError","message":"","args":[]}'
+        )
+
+    def test_exception_disabled(self):
+        """
+        dumps() exception is not serialized without OPT_SERIALIZE_EXCEPTION
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(ValueError("a"))
+        assert orjson.dumps(ValueError("a"), default=str) == b'"a"'
+
+    def test_exception_args(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION args are serialized as values
+        """
+        exc = CustomError("a", 1, [None], Dataclass(2))
+        assert orjson.dumps(exc, option=OPTION) == (
+            b'{"type":"CustomError","message":"(\'a\', 1, [None], Dataclass(a=2))",'
+            b'"args":["a",1,[null],{"a":2}]}'
+        )
+
+    def test_exception_args_default(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION args use default
+        """
+        exc = ValueError({1})
+        assert (
+            orjson.dumps(exc, default=list, option=OPTION)
+            == b'{"type":"ValueError","message":"{1}","args":[[1]]}'
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(exc, option=OPTION)
+        assert str(exc_info.value).startswith(
+            "Type is not JSON serializable: set at $.args[0]"
+        )
+
+    def test_exception_type_name(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION type is the __name__ of the type
+        """
+        for exc, name in (
+            (KeyError("a"), "KeyError"),
+            (KeyboardInterrupt(), "KeyboardInterrupt"),
+            (decimal.InvalidOperation(), "InvalidOperation"),
+            (CustomError(), "CustomError"),
+        ):
+            assert orjson.loads(orjson.dumps(exc, option=OPTION))["type"] == name
+
+    def test_exception_message(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION message is str()
+        """
+        assert orjson.loads(orjson.dumps(KeyError("a"), option=OPTION)) == {
+            "type": "KeyError",
+            "message": "'a'",
+            "args": ["a"],
+        }
+
+    def test_exception_cause(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION __cause__
+        """
+        exc = chained(KeyError("a"), ValueError("b"))
+        assert orjson.dumps(exc, option=OPTION) == (
+            b'{"type":"KeyError","message":"\'a\'","args":["a"],'
+            b'"cause":{"type":"ValueError","message":"b","args":["b"]}}'
+        )
+
+    def test_exception_context(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION __context__
+        """
+        try:
+            try:
+                raise ValueError("b")
+            except ValueError:
+                raise KeyError("a")
+        except KeyError as err:
+            exc = err
+        assert orjson.dumps(exc, option=OPTION) == (
+            b'{"type":"KeyError","message":"\'a\'","args":["a"],'
+            b'"context":{"type":"ValueError","message":"b","args":["b"]}}'
+        )
+
+    def test_exception_context_suppressed(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION raise from None suppresses __context__
+        """
+        try:
+            try:
+                raise ValueError("b")
+            except ValueError:
+                raise KeyError("a") from None
+        except KeyError as err:
+            exc = err
+        assert exc.__context__ is not None
+        assert (
+            orjson.dumps(exc, option=OPTION)
+            == b'{"type":"KeyError","message":"\'a\'","args":["a"]}'
+        )
+
+    def test_exception_chain_nested(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION chain of several exceptions
+        """
+        exc = chained(KeyError("a"), chained(ValueError("b"), TypeError("c")))
+        obj = orjson.loads(orjson.dumps(exc, option=OPTION))
+        assert obj["type"] == "KeyError"
+        assert obj["cause"]["type"] == "ValueError"
+        assert obj["cause"]["cause"] == {
+            "type": "TypeError",
+            "message": "c",
+            "args": ["c"],
+        }
+
+    def test_exception_chain_cycle(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION cycle of __cause__ is the recursion
+        limit
+        """
+        exc = ValueError("a")
+        exc.__cause__ = KeyError("b")
+        exc.__cause__.__cause__ = exc
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(exc, option=OPTION)
+        assert "Recursion limit reached" in str(exc_info.value)
+
+    def test_exception_sort_keys(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION OPT_SORT_KEYS
+        """
+        exc = chained(KeyError("a"), ValueError("b"))
+        assert orjson.dumps(exc, option=OPTION | orjson.OPT_SORT_KEYS) == (
+            b'{"args":["a"],"cause":{"args":["b"],"message":"b",'
+            b'"type":"ValueError"},"message":"\'a\'","type":"KeyError"}'
+        )
+
+    def test_exception_nested(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION in containers, with indentation
+        """
+        exc = ValueError("a")
+        expected = b'{"type":"ValueError","message":"a","args":["a"]}'
+        assert orjson.dumps([exc], option=OPTION) == b"[" + expected + b"]"
+        assert orjson.dumps({"e": exc}, option=OPTION) == b'{"e":' + expected + b"}"
+        assert orjson.dumps(exc, option=OPTION | orjson.OPT_INDENT_2) == (
+            b'{\n  "type": "ValueError",\n  "message": "a",\n  "args": [\n'
+            b'    "a"\n  ]\n}'
+        )
+
+    def test_exception_traceback_disabled(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION does not include the traceback without
+        exception_format="traceback"
+        """
+        exc = raised(ValueError("a"))
+        assert exc.__traceback__ is not None
+        assert "traceback" not in orjson.loads(orjson.dumps(exc, option=OPTION))
+
+    def test_exception_traceback(self):
+        """
+        dumps() exception_format="traceback"
+        """
+        exc = raised(ValueError("a"))
+        output = orjson.dumps(exc, option=OPTION, exception_format="traceback")
+        obj = orjson.loads(output)
+        assert obj["traceback"] == "".join(traceback.format_tb(exc.__traceback__))
+        assert "in raised" in obj["traceback"]
+        assert list(obj) == ["type", "message", "args", "traceback"]
+
+    def test_exception_traceback_not_raised(self):
+        """
+        dumps() exception_format="traceback" exception that was not raised
+        """
+        assert (
+            orjson.dumps(ValueError("a"), option=OPTION, exception_format="traceback")
+            == b'{"type":"ValueError","message":"a","args":["a"]}'
+        )
+
+    def test_exception_traceback_cause(self):
+        """
+        dumps() exception_format="traceback" includes the traceback of the cause
+        """
+        exc = chained(KeyError("a"), ValueError("b"))
+        output = orjson.dumps(exc, option=OPTION, exception_format="traceback")
+        obj = orjson.loads(output)
+        assert obj["traceback"] == "".join(traceback.format_tb(exc.__traceback__))
+        assert obj["cause"]["traceback"] == "".join(
+            traceback.format_tb(exc.__cause__.__traceback__)
+        )
+
+    def test_exception_traceback_without_serialize_exception(self):
+        """
+        dumps() exception_format="traceback" has no effect without
+        OPT_SERIALIZE_EXCEPTION
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(raised(ValueError("a")), exception_format="traceback")
+
+    def test_traceback_object(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION traceback object
+        """
+        tb = raised(ValueError("a")).__traceback__
+        assert orjson.loads(orjson.dumps(tb, option=OPTION)) == "".join(
+            traceback.format_tb(tb)
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(tb)
+
+    def test_exception_str_raises(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION __str__() raising is the cause
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([1, StrRaises()], option=OPTION)
+        assert str(exc_info.value) == (
+            "Exception could not be serialized because reading it raised at $[1] "
+            "(output byte 3)"
+        )
+        assert isinstance(exc_info.value.__cause__, ZeroDivisionError)
+
+    def test_exception_dict_key(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION exception is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {ValueError("a"): 1}, option=OPTION | orjson.OPT_NON_STR_KEYS
+            )
+
+    def test_exception_passthrough_dataclass(self):
+        """
+        dumps() OPT_SERIALIZE_EXCEPTION does not change a dataclass exception
+        """
+
+        @dataclasses.dataclass
+        class DataclassError(Exception):
+            code: int
+
+        assert orjson.dumps(DataclassError(1), option=OPTION) == b'{"code":1}'
@@ -0,0 +1,237 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import datetime
+import random
+
+import pytest
+
+import orjson
+
+try:
+    import numpy
+except ImportError:
+    numpy = None  # type: ignore
+
+
+def fixed(obj, precision):
+    return orjson.dumps(obj, option=orjson.OPT_FLOAT_FIXED, float_precision=precision)
+
+
+class TestFloatFixed:
+    def test_float_fixed_default(self):
+        """
+        dumps() float is the shortest representation by default
+        """
+        assert orjson.dumps([1.0, 0.1, 2.675, 1e-7]) == b"[1.0,0.1,2.675,1e-7]"
+
+    def test_float_fixed(self):
+        """
+        dumps() OPT_FLOAT_FIXED
+        """
+        assert fixed([1.0, 0.1, 2.5, 123.456], 2) == b"[1.00,0.10,2.50,123.46]"
+        assert fixed(1.25, 0) == b"1"
+        assert fixed(1.25, 4) == b"1.2500"
+        assert fixed(1e-7, 2) == b"0.00"
+        assert fixed(1e-7, 7) == b"0.0000001"
+        assert fixed(1e16, 1) == b"10000000000000000.0"
+
+    def test_float_fixed_half_to_even(self):
+        """
+        dumps() OPT_FLOAT_FIXED rounds exact halves to even
+        """
+        assert fixed([0.5, 1.5, 2.5, 3.5], 0) == b"[0,2,2,4]"
+        assert fixed([0.125, 0.375, 0.625, 0.875], 2) == b"[0.12,0.38,0.62,0.88]"
+
+    def test_float_fixed_binary(self):
+        """
+        dumps() OPT_FLOAT_FIXED rounds the exact value of the float
+        """
+        # 1.005 is 1.00499999999999989..., 2.675 is 2.67499999999999982...
+        assert fixed([1.005, 2.675, 1.015], 2) == b"[1.00,2.67,1.01]"
+
+    def test_float_fixed_negative(self):
+        """
+        dumps() OPT_FLOAT_FIXED negative
+        """
+        assert fixed([-1.5, -0.001, -0.0], 2) == b"[-1.50,-0.00,-0.00]"
+
+    @pytest.mark.parametrize("precision", [0, 1, 2, 6, 17, 20])
+    def test_float_fixed_python(self, precision):
+        """
+        dumps() OPT_FLOAT_FIXED is the same as Python's fixed-point format
+        """
+        rand = random.Random(precision)
+        values = [rand.uniform(-1e6, 1e6) for _ in range(1000)]
+        values += [rand.uniform(-1, 1) for _ in range(1000)]
+        values += [1e300, -1e-300, 5e-324, 1.7976931348623157e308]
+        expected = "[" + ",".join(f"{val:.{precision}f}" for val in values) + "]"
+        assert fixed(values, precision) == expected.encode("utf-8")
+
+    def test_float_fixed_nonfinite(self):
+        """
+        dumps() OPT_FLOAT_FIXED NaN and Infinity are as without it
+        """
+        obj = [float("nan"), float("inf"), float("-inf")]
+        assert fixed(obj, 2) == b"[null,null,null]"
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_FLOAT_FIXED | orjson.OPT_ALLOW_NONFINITE,
+                float_precision=2,
+            )
+            == b"[NaN,Infinity,-Infinity]"
+        )
+
+    def test_float_fixed_int(self):
+        """
+        dumps() OPT_FLOAT_FIXED does not change int
+        """
+        assert fixed([1, -1, 2**64 - 1, True], 2) == b"[1,-1,18446744073709551615,true]"
+
+    def test_float_fixed_nested(self):
+        """
+        dumps() OPT_FLOAT_FIXED in containers, with indentation
+        """
+        obj = {"a": [1.5, {"b": 0.1}]}
+        assert fixed(obj, 1) == b'{"a":[1.5,{"b":0.1}]}'
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_FLOAT_FIXED | orjson.OPT_INDENT_2,
+                float_precision=3,
+            )
+            == b'{\n  "a": [\n    1.500,\n    {\n      "b": 0.100\n    }\n  ]\n}'
+        )
+
+    def test_float_fixed_non_str_keys(self):
+        """
+        dumps() OPT_FLOAT_FIXED does not change float keys
+        """
+        assert (
+            orjson.dumps(
+                {1.5: 1.5},
+                option=orjson.OPT_FLOAT_FIXED | orjson.OPT_NON_STR_KEYS,
+                float_precision=2,
+            )
+            == b'{"1.5":1.50}'
+        )
+
+    def test_float_fixed_timedelta(self):
+        """
+        dumps() OPT_FLOAT_FIXED OPT_TIMEDELTA_SECONDS
+        """
+        assert (
+            orjson.dumps(
+                datetime.timedelta(seconds=1, microseconds=5),
+                option=orjson.OPT_FLOAT_FIXED | orjson.OPT_TIMEDELTA_SECONDS,
+                float_precision=3,
+            )
+            == b"1.000"
+        )
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_float_fixed_numpy(self):
+        """
+        dumps() OPT_FLOAT_FIXED numpy floats
+        """
+        option = orjson.OPT_FLOAT_FIXED | orjson.OPT_SERIALIZE_NUMPY
+        assert (
+            orjson.dumps(
+                numpy.array([1.5, 0.1], numpy.float64),
+                option=option,
+                float_precision=2,
+            )
+            == b"[1.50,0.10]"
+        )
+        assert (
+            orjson.dumps(numpy.float32(0.1), option=option, float_precision=3)
+            == b"0.100"
+        )
+
+    def test_float_fixed_dumps_str(self):
+        """
+        dumps_str() OPT_FLOAT_FIXED
+        """
+        assert (
+            orjson.dumps_str(
+                [0.125], option=orjson.OPT_FLOAT_FIXED, float_precision=2
+            )
+            == "[0.12]"
+        )
+
+    def test_float_fixed_dumps_into(self):
+        """
+        dumps_into() OPT_FLOAT_FIXED
+        """
+        buffer = bytearray()
+        assert (
+            orjson.dumps_into(
+                [0.125], buffer, option=orjson.OPT_FLOAT_FIXED, float_precision=2
+            )
+            == 6
+        )
+        assert buffer == b"[0.12]"
+
+    def test_float_fixed_reentrant(self):
+        """
+        dumps() float_precision applies only to the call it is given to
+        """
+
+        def default(obj):
+            return orjson.loads(orjson.dumps([0.5]))
+
+        assert (
+            orjson.dumps(
+                [0.5, object()],
+                default=default,
+                option=orjson.OPT_FLOAT_FIXED,
+                float_precision=2,
+            )
+            == b"[0.50,[0.50]]"
+        )
+        assert orjson.dumps([0.5]) == b"[0.5]"
+
+    @pytest.mark.parametrize("precision", [-1, 21, 2**64, 1.0, "2", True])
+    def test_float_fixed_invalid_precision(self, precision):
+        """
+        dumps() float_precision must be an int from 0 to 20
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            fixed(1.0, precision)
+        assert str(exc_info.value) == "Invalid float_precision"
+
+    def test_float_fixed_missing_precision(self):
+        """
+        dumps() OPT_FLOAT_FIXED requires float_precision
+        """
+        for kwargs in ({}, {"float_precision": None}):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(1.0, option=orjson.OPT_FLOAT_FIXED, **kwargs)
+            assert str(exc_info.value) == "Invalid float_precision"
+
+    def test_float_fixed_precision_without_option(self):
+        """
+        dumps() float_precision requires OPT_FLOAT_FIXED
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(1.0, float_precision=2)
+        assert str(exc_info.value) == "Invalid float_precision"
+        assert orjson.dumps(1.0, float_precision=None) == b"1.0"
+
+    def test_float_fixed_canonical(self):
+        """
+        dumps() OPT_FLOAT_FIXED cannot be combined with OPT_CANONICAL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                1.0,
+                option=orjson.OPT_FLOAT_FIXED | orjson.OPT_CANONICAL,
+                float_precision=2,
+            )
+
+    def test_float_fixed_encoder(self):
+        """
+        orjson.Encoder() does not support OPT_FLOAT_FIXED
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(option=orjson.OPT_FLOAT_FIXED)
@@ -0,0 +1,179 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import sys
+from fractions import Fraction
+
+import pytest
+
+import orjson
+
+
+class FractionSubclass(Fraction):
+    pass
+
+
+def dumps_fraction(obj, option=0, **kwargs):
+    return orjson.dumps(obj, option=orjson.OPT_SERIALIZE_FRACTION | option, **kwargs)
+
+
+class TestFraction:
+    def test_fraction_default(self):
+        """
+        Fraction is not serialized without OPT_SERIALIZE_FRACTION
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Fraction(1, 2))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Fraction(1, 2), fraction_format="float")
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Fraction(1, 2), fraction_format="string")
+
+    def test_fraction_array(self):
+        """
+        Fraction as [numerator, denominator] in lowest terms
+        """
+        assert dumps_fraction(Fraction(1, 3)) == b"[1,3]"
+        assert dumps_fraction(Fraction(6, -4)) == b"[-3,2]"
+        assert dumps_fraction(Fraction(0)) == b"[0,1]"
+        assert dumps_fraction(Fraction(5)) == b"[5,1]"
+        assert dumps_fraction(Fraction("1.25")) == b"[5,4]"
+
+    def test_fraction_array_big(self):
+        """
+        Fraction numerator and denominator are not limited to 64 bits
+        """
+        obj = Fraction(-(2**100) - 1, 3**50)
+        assert dumps_fraction(obj) == (
+            b"[-1267650600228229401496703205377,717897987691852588770249]"
+        )
+        assert Fraction(*orjson.loads(dumps_fraction(obj))) == obj
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(2**100)
+
+    def test_fraction_array_strict_integer(self):
+        """
+        Fraction numerator and denominator are limited to 53 bits with
+        OPT_STRICT_INTEGER
+        """
+        assert (
+            dumps_fraction(Fraction(2**53 - 1, 3), orjson.OPT_STRICT_INTEGER)
+            == b"[9007199254740991,3]"
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            dumps_fraction([Fraction(1, 2**53)], orjson.OPT_STRICT_INTEGER)
+        assert str(exc_info.value).startswith("Integer exceeds 53-bit range")
+
+    @pytest.mark.skipif(
+        not hasattr(sys, "set_int_max_str_digits"), reason="no int digit limit"
+    )
+    def test_fraction_array_int_max_str_digits(self):
+        """
+        Fraction numerator or denominator that cannot be converted to str
+        """
+        obj = Fraction(10**5000 + 1, 3)
+        limit = sys.get_int_max_str_digits()
+        sys.set_int_max_str_digits(4300)
+        try:
+            for fraction_format in (None, "string"):
+                with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                    dumps_fraction(obj, fraction_format=fraction_format)
+                assert str(exc_info.value).startswith(
+                    "fractions.Fraction numerator or denominator exceeds"
+                )
+        finally:
+            sys.set_int_max_str_digits(limit)
+
+    def test_fraction_float(self):
+        """
+        Fraction as float with fraction_format="float"
+        """
+        kwargs = {"fraction_format": "float"}
+        assert dumps_fraction(Fraction(1, 3), **kwargs) == b"0.3333333333333333"
+        assert dumps_fraction(Fraction(-3, 2), **kwargs) == b"-1.5"
+        assert dumps_fraction(Fraction(2), **kwargs) == b"2.0"
+        assert dumps_fraction(Fraction(1, 10**400), **kwargs) == b"0.0"
+
+    def test_fraction_float_overflow(self):
+        """
+        Fraction out of range of float with fraction_format="float" raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            dumps_fraction({"a": Fraction(10**400, 3)}, fraction_format="float")
+        assert str(exc_info.value).startswith(
+            'fractions.Fraction is out of range of float with fraction_format="float"'
+        )
+
+    def test_fraction_string(self):
+        """
+        Fraction as "n/d" with fraction_format="string", including a
+        denominator of 1
+        """
+        kwargs = {"fraction_format": "string"}
+        assert dumps_fraction(Fraction(1, 3), **kwargs) == b'"1/3"'
+        assert dumps_fraction(Fraction(6, -4), **kwargs) == b'"-3/2"'
+        assert dumps_fraction(Fraction(2), **kwargs) == b'"2/1"'
+        assert dumps_fraction(Fraction(2**70, 3), **kwargs) == (
+            b'"1180591620717411303424/3"'
+        )
+        assert (
+            dumps_fraction(Fraction(2**60, 3), orjson.OPT_STRICT_INTEGER, **kwargs)
+            == b'"1152921504606846976/3"'
+        )
+
+    def test_fraction_format_invalid(self):
+        """
+        fraction_format that is not one of its values raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            dumps_fraction(Fraction(1, 2), fraction_format="float|string")
+        assert str(exc_info.value) == "Invalid fraction_format"
+
+    def test_fraction_nested(self):
+        """
+        Fraction in list, tuple, and dict values
+        """
+        obj = {"a": [Fraction(1, 2), (Fraction(3),)], "b": Fraction(-1, 4)}
+        assert dumps_fraction(obj) == b'{"a":[[1,2],[[3,1]]],"b":[-1,4]}'
+        assert (
+            dumps_fraction(obj, fraction_format="float")
+            == b'{"a":[0.5,[3.0]],"b":-0.25}'
+        )
+        assert (
+            dumps_fraction(obj, fraction_format="string")
+            == b'{"a":["1/2",["3/1"]],"b":"-1/4"}'
+        )
+
+    def test_fraction_indent(self):
+        """
+        Fraction with OPT_INDENT_2
+        """
+        assert (
+            dumps_fraction([Fraction(1, 2)], orjson.OPT_INDENT_2)
+            == b"[\n  [\n    1,\n    2\n  ]\n]"
+        )
+
+    def test_fraction_subclass(self):
+        """
+        Fraction subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            dumps_fraction(FractionSubclass(1, 2))
+        assert dumps_fraction(FractionSubclass(1, 2), default=str) == b'"1/2"'
+
+    def test_fraction_dict_key(self):
+        """
+        Fraction is not a valid dict key with OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            dumps_fraction({Fraction(1, 2): 1}, orjson.OPT_NON_STR_KEYS)
+
+    def test_fraction_roundtrip(self):
+        """
+        Fraction from loads() of dumps() with each option
+        """
+        obj = [Fraction(n, d) for n in range(-5, 6) for d in range(1, 6)]
+        assert [Fraction(*val) for val in orjson.loads(dumps_fraction(obj))] == obj
+        assert [
+            Fraction(val)
+            for val in orjson.loads(dumps_fraction(obj, fraction_format="string"))
+        ] == obj
@@ -80,6 +80,80 @@ class TestFragment:
             orjson.dumps(orjson.Fragment(b"{}", contents=b"{}"))  # type: ignore
 
 
+class TestFragmentValidate:
+    def _dumps(self, obj):
+        return orjson.dumps(obj, option=orjson.OPT_VALIDATE_FRAGMENT)
+
+    def test_fragment_validate_valid(self):
+        """
+        OPT_VALIDATE_FRAGMENT valid contents are copied verbatim
+        """
+        for contents in (
+            b'{"cached":true}',
+            b' [1, {"a": [null]}] ',
+            b'"]}\\"[{"',
+            b"1",
+            b"truefalse",
+            "[\"🐈\"]",
+        ):
+            data = contents if isinstance(contents, bytes) else contents.encode()
+            assert self._dumps(orjson.Fragment(contents)) == data
+            assert self._dumps([orjson.Fragment(contents)]) == b"[" + data + b"]"
+            assert self._dumps({"a": orjson.Fragment(contents)}) == (
+                b'{"a":' + data + b"}"
+            )
+
+    def test_fragment_validate_invalid(self):
+        """
+        OPT_VALIDATE_FRAGMENT unbalanced or unterminated contents
+        """
+        for contents in (
+            b"",
+            b" \n",
+            b"[",
+            b"]",
+            b"{]",
+            b"[}",
+            b"[[]",
+            b"{}}",
+            b'"abc',
+            b'"abc\\"',
+            b'["]',
+            '{}"a\\',
+        ):
+            assert orjson.dumps(orjson.Fragment(contents)) == (
+                contents if isinstance(contents, bytes) else contents.encode()
+            )
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                self._dumps([orjson.Fragment(contents)])
+            assert "orjson.Fragment" in str(exc_info.value)
+
+    def test_fragment_validate_utf8(self):
+        """
+        OPT_VALIDATE_FRAGMENT bytes must be valid UTF-8
+        """
+        assert orjson.dumps(orjson.Fragment(b'"\xff"')) == b'"\xff"'
+        with pytest.raises(orjson.JSONEncodeError):
+            self._dumps(orjson.Fragment(b'"\xff"'))
+        with pytest.raises(orjson.JSONEncodeError):
+            self._dumps(orjson.Fragment(b'"\xed\xa0\x80"'))
+        assert self._dumps(orjson.Fragment('"é"'.encode())) == '"é"'.encode()
+
+    def test_fragment_validate_non_str_keys(self):
+        """
+        OPT_VALIDATE_FRAGMENT with other options
+        """
+        assert (
+            orjson.dumps(
+                {1: orjson.Fragment(b"[1]")},
+                option=orjson.OPT_VALIDATE_FRAGMENT
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "1": [1]\n}'
+        )
+
+
 @pytest.mark.skipif(pandas is None, reason="pandas is not installed")
 class TestFragmentPandas:
     def test_fragment_pandas(self):
@@ -103,6 +177,13 @@ class TestFragmentParsing:
     def _run_test(self, filename: str):
         data = read_fixture_bytes(filename, "parsing")
         orjson.dumps(orjson.Fragment(data))
+        if filename.startswith("y_"):
+            assert (
+                orjson.dumps(
+                    orjson.Fragment(data), option=orjson.OPT_VALIDATE_FRAGMENT
+                )
+                == data
+            )
 
     def test_fragment_y_array_arraysWithSpace(self):
         self._run_test("y_array_arraysWithSpaces.json")
@@ -3,6 +3,8 @@
 import datetime
 import json
 
+import pytest
+
 import orjson
 
 from .util import read_fixture_obj
@@ -89,3 +91,77 @@ class TestIndentedOutput:
         assert orjson.dumps(obj, option=orjson.OPT_INDENT_2) == json.dumps(
             obj, indent=2, ensure_ascii=False
         ).encode("utf-8")
+
+    def test_indent_4_equivalent(self):
+        """
+        OPT_INDENT_4 is equivalent to indent=4
+        """
+        obj = {"a": "b", "c": {"d": True}, "e": [1, 2]}
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_4) == json.dumps(
+            obj, indent=4
+        ).encode("utf-8")
+
+    def test_indent_4_empty(self):
+        obj = [{}, [[[]]], {"key": []}]
+        ref = (
+            b'[\n    {},\n    [\n        [\n            []\n        ]\n    ],'
+            b'\n    {\n        "key": []\n    }\n]'
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_4) == ref
+
+    def test_indent_4_twitter(self):
+        """
+        twitter.json OPT_INDENT_4
+        """
+        obj = read_fixture_obj("twitter.json.xz")
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_4) == json.dumps(
+            obj, indent=4, ensure_ascii=False
+        ).encode("utf-8")
+
+    def test_indent_tab_equivalent(self):
+        """
+        OPT_INDENT_TAB is equivalent to indent="\t"
+        """
+        obj = {"a": "b", "c": {"d": True}, "e": [1, 2]}
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_TAB) == json.dumps(
+            obj, indent="\t"
+        ).encode("utf-8")
+
+    def test_indent_tab_empty(self):
+        obj = [{}, [[[]]], {"key": []}]
+        ref = b'[\n\t{},\n\t[\n\t\t[\n\t\t\t[]\n\t\t]\n\t],\n\t{\n\t\t"key": []\n\t}\n]'
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_TAB) == ref
+
+    def test_indent_tab_github(self):
+        """
+        github.json OPT_INDENT_TAB
+        """
+        obj = read_fixture_obj("github.json.xz")
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_TAB) == json.dumps(
+            obj, indent="\t", ensure_ascii=False
+        ).encode("utf-8")
+
+    def test_indent_4_options(self):
+        obj = {"b": {1}, "a": None}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_INDENT_4
+                | orjson.OPT_SORT_KEYS
+                | orjson.OPT_SERIALIZE_SET
+                | orjson.OPT_APPEND_NEWLINE,
+            )
+            == b'{\n    "a": null,\n    "b": [\n        1\n    ]\n}\n'
+        )
+
+    def test_indent_multiple(self):
+        """
+        specifying more than one indent option raises
+        """
+        for option in (
+            orjson.OPT_INDENT_2 | orjson.OPT_INDENT_4,
+            orjson.OPT_INDENT_2 | orjson.OPT_INDENT_TAB,
+            orjson.OPT_INDENT_4 | orjson.OPT_INDENT_TAB,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps({}, option=option)
@@ -0,0 +1,141 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+def keys(obj):
+    return [key for key in obj]
+
+
+class TestInternKeys:
+    def test_intern_keys(self):
+        """
+        OPT_INTERN_KEYS equal keys are the same str
+        """
+        data = orjson.loads(
+            '[{"a": 1, "b": 2}, {"b": 3, "a": 4}]', option=orjson.OPT_INTERN_KEYS
+        )
+        assert data == [{"a": 1, "b": 2}, {"b": 3, "a": 4}]
+        first, second = keys(data[0]), keys(data[1])
+        assert first[0] is second[1]
+        assert first[1] is second[0]
+
+    def test_intern_keys_long(self):
+        """
+        OPT_INTERN_KEYS keys longer than the process-wide cache
+        """
+        key = "k" * 200
+        doc = f'[{{"{key}": 1}}, {{"{key}": 2}}]'
+        data = orjson.loads(doc, option=orjson.OPT_INTERN_KEYS)
+        assert data == [{key: 1}, {key: 2}]
+        assert keys(data[0])[0] is keys(data[1])[0]
+        data = orjson.loads(doc)
+        assert keys(data[0])[0] is not keys(data[1])[0]
+
+    def test_intern_keys_escaped(self):
+        """
+        OPT_INTERN_KEYS keys are compared after unescaping
+        """
+        data = orjson.loads(
+            '[{"\\u00e9": 1}, {"é": 2}, {"\\ud83d\\udc08": 3}, {"\U0001f408": 4}]',
+            option=orjson.OPT_INTERN_KEYS,
+        )
+        assert data == [{"é": 1}, {"é": 2}, {"\U0001f408": 3}, {"\U0001f408": 4}]
+        assert keys(data[0])[0] is keys(data[1])[0]
+        assert keys(data[2])[0] is keys(data[3])[0]
+
+    def test_intern_keys_nested(self):
+        """
+        OPT_INTERN_KEYS keys at different depths
+        """
+        data = orjson.loads('{"a": {"a": {"a": []}}}', option=orjson.OPT_INTERN_KEYS)
+        assert data == {"a": {"a": {"a": []}}}
+        assert keys(data)[0] is keys(data["a"])[0] is keys(data["a"]["a"])[0]
+
+    def test_intern_keys_values(self):
+        """
+        OPT_INTERN_KEYS does not affect str values
+        """
+        data = orjson.loads('{"a": "a", "b": ["a"]}', option=orjson.OPT_INTERN_KEYS)
+        assert data == {"a": "a", "b": ["a"]}
+
+    def test_intern_keys_limit(self):
+        """
+        OPT_INTERN_KEYS more distinct keys than are shared
+        """
+        obj = [{f"key{i}": i for i in range(10000)} for _ in range(2)]
+        data = orjson.loads(orjson.dumps(obj), option=orjson.OPT_INTERN_KEYS)
+        assert data == obj
+        assert keys(data[0])[0] is keys(data[1])[0]
+
+    @pytest.mark.parametrize(
+        "fixture", ["twitter.json.xz", "github.json.xz", "canada.json.xz"]
+    )
+    def test_intern_keys_fixture(self, fixture):
+        """
+        OPT_INTERN_KEYS output is the same as without
+        """
+        doc = orjson.dumps(read_fixture_obj(fixture))
+        assert orjson.loads(doc, option=orjson.OPT_INTERN_KEYS) == orjson.loads(doc)
+
+    def test_intern_keys_option(self):
+        """
+        OPT_INTERN_KEYS with other options
+        """
+        data = orjson.loads(
+            '[{"a": 1.5, /* c */ "b": NaN,}, {"a": 2,},]',
+            option=orjson.OPT_INTERN_KEYS
+            | orjson.OPT_PARSE_DECIMAL
+            | orjson.OPT_ALLOW_NONFINITE
+            | orjson.OPT_ALLOW_COMMENTS
+            | orjson.OPT_ALLOW_TRAILING_COMMA,
+        )
+        assert keys(data[0])[0] is keys(data[1])[0]
+        assert str(data[0]["a"]) == "1.5"
+        assert data[0]["b"] != data[0]["b"]
+
+    def test_intern_keys_duplicate(self):
+        """
+        OPT_INTERN_KEYS OPT_REJECT_DUPLICATE_KEYS
+        """
+        option = orjson.OPT_INTERN_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS
+        assert orjson.loads('[{"a": 1}, {"a": 2}]', option=option) == [
+            {"a": 1},
+            {"a": 2},
+        ]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads('{"a": 1, "a": 2}', option=option)
+
+    def test_intern_keys_object_hook(self):
+        """
+        OPT_INTERN_KEYS object_hook
+        """
+        seen = []
+
+        def hook(obj):
+            seen.extend(obj)
+            return obj
+
+        orjson.loads(
+            '[{"a": 1}, {"a": 2}]', option=orjson.OPT_INTERN_KEYS, object_hook=hook
+        )
+        assert seen[0] is seen[1]
+
+    def test_intern_keys_error(self):
+        """
+        OPT_INTERN_KEYS invalid document
+        """
+        for doc in ('[{"a": 1}, {"a": 2', '[{"a": 1}, {"a\\x": 2}]', '{"a": 1, "'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(doc, option=orjson.OPT_INTERN_KEYS)
+
+    def test_intern_keys_dumps(self):
+        """
+        OPT_INTERN_KEYS is not valid for dumps()
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({}, option=orjson.OPT_INTERN_KEYS)
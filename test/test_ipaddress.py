@@ -0,0 +1,130 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import ipaddress
+
+import pytest
+
+import orjson
+
+VALUES = [
+    ipaddress.IPv4Address("192.168.0.1"),
+    ipaddress.IPv6Address("2001:db8::1"),
+    ipaddress.IPv4Network("192.168.0.0/24"),
+    ipaddress.IPv6Network("2001:db8::/32"),
+    ipaddress.IPv4Interface("192.168.0.1/24"),
+    ipaddress.IPv6Interface("2001:db8::1/64"),
+]
+
+
+class IPv4AddressSubclass(ipaddress.IPv4Address):
+    pass
+
+
+class TestIpAddress:
+    @pytest.mark.parametrize("value", VALUES)
+    def test_ipaddress_default(self, value):
+        """
+        ipaddress is not serialized without OPT_SERIALIZE_IPADDRESS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(value)
+
+    @pytest.mark.parametrize("value", VALUES)
+    def test_ipaddress(self, value):
+        """
+        ipaddress types serialize as str()
+        """
+        assert orjson.dumps(
+            value, option=orjson.OPT_SERIALIZE_IPADDRESS
+        ) == orjson.dumps(str(value))
+
+    def test_ipaddress_values(self):
+        """
+        ipaddress output
+        """
+        assert (
+            orjson.dumps(VALUES, option=orjson.OPT_SERIALIZE_IPADDRESS)
+            == b'["192.168.0.1","2001:db8::1","192.168.0.0/24","2001:db8::/32",'
+            b'"192.168.0.1/24","2001:db8::1/64"]'
+        )
+
+    def test_ipaddress_ipv6_compressed(self):
+        """
+        IPv6Address is compressed and lowercase
+        """
+        assert (
+            orjson.dumps(
+                ipaddress.IPv6Address("2001:0DB8:0000:0000:0000:0000:0000:0001"),
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+            )
+            == b'"2001:db8::1"'
+        )
+        assert (
+            orjson.dumps(
+                ipaddress.IPv6Address("0:0:0:0:0:0:0:1"),
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+            )
+            == b'"::1"'
+        )
+
+    def test_ipaddress_ipv6_scope(self):
+        """
+        IPv6Address scope ID
+        """
+        if not hasattr(ipaddress.IPv6Address("::1"), "scope_id"):
+            pytest.skip("scope_id requires Python 3.9")
+        assert (
+            orjson.dumps(
+                ipaddress.IPv6Address("fe80::1%eth0"),
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+            )
+            == b'"fe80::1%eth0"'
+        )
+
+    def test_ipaddress_subclass(self):
+        """
+        ipaddress subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                IPv4AddressSubclass("127.0.0.1"),
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+            )
+        assert (
+            orjson.dumps(
+                IPv4AddressSubclass("127.0.0.1"),
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+                default=str,
+            )
+            == b'"127.0.0.1"'
+        )
+
+    def test_ipaddress_dict_value(self):
+        """
+        ipaddress as dict value
+        """
+        assert (
+            orjson.dumps(
+                {"a": ipaddress.IPv4Address("10.0.0.1")},
+                option=orjson.OPT_SERIALIZE_IPADDRESS,
+            )
+            == b'{"a":"10.0.0.1"}'
+        )
+
+    def test_ipaddress_dict_key(self):
+        """
+        ipaddress as dict key with OPT_NON_STR_KEYS
+        """
+        obj = {
+            ipaddress.IPv4Address("10.0.0.1"): 1,
+            ipaddress.IPv6Network("2001:db8::/32"): 2,
+        }
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_IPADDRESS | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"10.0.0.1":1,"2001:db8::/32":2}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
@@ -0,0 +1,314 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import itertools
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+class TestIterator:
+    def test_generator(self):
+        """
+        generator OPT_SERIALIZE_ITERATORS
+        """
+        assert (
+            orjson.dumps(
+                (val * val for val in range(4)), option=orjson.OPT_SERIALIZE_ITERATORS
+            )
+            == b"[0,1,4,9]"
+        )
+
+    def test_generator_function(self):
+        """
+        generator function OPT_SERIALIZE_ITERATORS
+        """
+
+        def gen():
+            yield 1
+            yield "a"
+            yield {"b": None}
+
+        assert (
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'[1,"a",{"b":null}]'
+        )
+
+    def test_iterator_builtins(self):
+        """
+        builtin iterators OPT_SERIALIZE_ITERATORS
+        """
+        assert (
+            orjson.dumps(
+                {
+                    "iter": iter([1, 2]),
+                    "map": map(str, [1, 2]),
+                    "zip": zip([1, 2], [3, 4]),
+                    "filter": filter(None, [0, 1]),
+                    "items": iter({"a": 1}.items()),
+                    "count": itertools.islice(itertools.count(), 3),
+                },
+                option=orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b'{"iter":[1,2],"map":["1","2"],"zip":[[1,3],[2,4]],'
+            b'"filter":[1],"items":[["a",1]],"count":[0,1,2]}'
+        )
+
+    def test_iterator_empty(self):
+        """
+        empty iterator OPT_SERIALIZE_ITERATORS
+        """
+        assert orjson.dumps(iter(()), option=orjson.OPT_SERIALIZE_ITERATORS) == b"[]"
+        assert (
+            orjson.dumps(
+                iter(()),
+                option=orjson.OPT_SERIALIZE_ITERATORS | orjson.OPT_INDENT_2,
+            )
+            == b"[]"
+        )
+
+    def test_iterator_nested(self):
+        """
+        iterator of iterators OPT_SERIALIZE_ITERATORS
+        """
+        obj = (iter([val, [val]]) for val in range(2))
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[[0,[0]],[1,[1]]]"
+        )
+
+    def test_iterator_indent(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS OPT_INDENT_2
+        """
+        assert orjson.dumps(
+            {"a": iter([1, {"b": 2}])},
+            option=orjson.OPT_SERIALIZE_ITERATORS | orjson.OPT_INDENT_2,
+        ) == orjson.dumps({"a": [1, {"b": 2}]}, option=orjson.OPT_INDENT_2)
+
+    def test_iterator_fixture(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS fixture
+        """
+        obj = read_fixture_obj("twitter.json.xz")["statuses"]
+        assert orjson.dumps(iter(obj), option=orjson.OPT_SERIALIZE_ITERATORS) == (
+            orjson.dumps(obj)
+        )
+
+    def test_iterator_consumed(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS is consumed
+        """
+        obj = iter([1, 2])
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS) == b"[1,2]"
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS) == b"[]"
+
+    def test_iterator_lazy(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS items are not collected first
+        """
+        alive = []
+
+        class Item:
+            def __init__(self):
+                alive.append(None)
+
+            def __del__(self):
+                alive.pop()
+
+        def gen():
+            for _ in range(100):
+                assert len(alive) == 0
+                yield Item()
+
+        assert (
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS, default=str)
+            .decode("utf-8")
+            .count("Item")
+            == 100
+        )
+
+    def test_iterator_not_enabled(self):
+        """
+        iterator is not serialized without OPT_SERIALIZE_ITERATORS
+        """
+        obj = iter([1, 2])
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj)
+        assert str(exc_info.value) == "Type is not JSON serializable: list_iterator"
+        assert orjson.dumps(obj, default=list) == b"[1,2]"
+
+    def test_iterable_not_iterator(self):
+        """
+        OPT_SERIALIZE_ITERATORS does not apply to iterables that are not
+        iterators
+        """
+
+        class Iterable:
+            def __iter__(self):
+                return iter([1])
+
+        for obj in (Iterable(), {1: 2}.keys(), frozenset()):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_class(self):
+        """
+        class defining __next__ OPT_SERIALIZE_ITERATORS
+        """
+
+        class Countdown:
+            def __init__(self, start):
+                self.current = start
+
+            def __iter__(self):
+                return self
+
+            def __next__(self):
+                if self.current == 0:
+                    raise StopIteration
+                self.current -= 1
+                return self.current
+
+        assert (
+            orjson.dumps(Countdown(3), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[2,1,0]"
+        )
+
+    def test_iterator_default(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS default applies to each item
+        """
+        obj = (complex(val, 1) for val in range(2))
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_ITERATORS,
+                default=lambda val: [val.real, val.imag],
+            )
+            == b"[[0.0,1.0],[1.0,1.0]]"
+        )
+
+    def test_iterator_default_returns_iterator(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS returned by default
+        """
+        assert (
+            orjson.dumps({1, 2}, option=orjson.OPT_SERIALIZE_ITERATORS, default=iter)
+            == b"[1,2]"
+        )
+
+    def test_iterator_unsupported_item(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS item of unsupported type
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": iter([1, object()])}, option=orjson.OPT_SERIALIZE_ITERATORS
+            )
+        assert (
+            str(exc_info.value)
+            == "Type is not JSON serializable: object at $.a[1] (output byte 8)"
+        )
+
+    def test_iterator_raises(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS exception is propagated as it is
+        """
+
+        def gen():
+            yield 1
+            raise ValueError("gen")
+
+        with pytest.raises(ValueError) as exc_info:
+            orjson.dumps([0, gen()], option=orjson.OPT_SERIALIZE_ITERATORS)
+        assert not isinstance(exc_info.value, orjson.JSONEncodeError)
+        assert str(exc_info.value) == "gen"
+        assert orjson.dumps([0]) == b"[0]"
+
+    def test_iterator_raises_first(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS exception before any item
+        """
+
+        def gen():
+            raise KeyError("gen")
+            yield
+
+        with pytest.raises(KeyError):
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_raises_nested(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS exception is propagated as it is from
+        options that buffer or sort output and from orjson.Encoder
+        """
+
+        def gen():
+            yield 1
+            raise ValueError("gen")
+
+        option = orjson.OPT_SERIALIZE_ITERATORS
+        for obj, extra in (
+            ({"b": gen(), "a": 1}, orjson.OPT_SORT_KEYS),
+            ({"b": gen()}, orjson.OPT_INDENT_2),
+            ({gen(), 1}, orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS),
+        ):
+            with pytest.raises(ValueError):
+                orjson.dumps(obj, option=option | extra)
+        with pytest.raises(ValueError):
+            orjson.dumps([object()], option=option, default=lambda _: gen())
+        with pytest.raises(ValueError):
+            orjson.Encoder(option=option).write_chunk(gen())
+
+    def test_iterator_recursion_limit(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS recursion limit
+        """
+
+        def nested(depth):
+            obj = iter([1])
+            for _ in range(depth):
+                obj = iter([obj])
+            return obj
+
+        assert (
+            orjson.dumps(nested(200), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[" * 201 + b"1" + b"]" * 201
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nested(1024), option=orjson.OPT_SERIALIZE_ITERATORS)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nested(5), option=orjson.OPT_SERIALIZE_ITERATORS, max_depth=3)
+
+    def test_iterator_dict_key(self):
+        """
+        iterator OPT_SERIALIZE_ITERATORS is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {iter([]): 1},
+                option=orjson.OPT_SERIALIZE_ITERATORS | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_iterator_dataclass(self):
+        """
+        dataclass defining __next__ is serialized as a dataclass
+        """
+        @dataclasses.dataclass
+        class Dataclass:
+            a: int
+
+            def __iter__(self):
+                return self
+
+            def __next__(self):
+                raise StopIteration
+
+        assert (
+            orjson.dumps(Dataclass(1), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'{"a":1}'
+        )
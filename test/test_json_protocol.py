@@ -0,0 +1,218 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import sys
+
+import pytest
+
+import orjson
+
+
+class Lazy:
+    def __init__(self, value):
+        self.value = value
+
+    def __json__(self):
+        return self.value
+
+
+class LazySubclass(Lazy):
+    pass
+
+
+class Raises:
+    def __json__(self):
+        raise ValueError("invalid")
+
+
+class ReturnsSelf:
+    def __json__(self):
+        return self
+
+
+class InstanceAttribute:
+    def __init__(self):
+        self.__json__ = lambda: 1
+
+
+class NotCallable:
+    __json__ = "json"
+
+
+class NoneAttribute:
+    __json__ = None
+
+
+@dataclasses.dataclass
+class DataclassWithJson:
+    a: int
+
+    def __json__(self):
+        return "json"
+
+
+class TestJsonProtocol:
+    def test_json_protocol(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL serializes the return value of __json__()
+        """
+        assert (
+            orjson.dumps(Lazy({"a": 1}), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+            == b'{"a":1}'
+        )
+
+    def test_json_protocol_nested(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL in containers and returned recursively
+        """
+        assert (
+            orjson.dumps(
+                {"a": [Lazy(1)], "b": Lazy([Lazy("c")])},
+                option=orjson.OPT_SERIALIZE_JSON_PROTOCOL,
+            )
+            == b'{"a":[1],"b":["c"]}'
+        )
+
+    def test_json_protocol_subclass(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL __json__ inherited from a base class
+        """
+        assert (
+            orjson.dumps(LazySubclass(1), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+            == b"1"
+        )
+
+    def test_json_protocol_options(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL return value is serialized with the options
+        """
+        assert (
+            orjson.dumps(
+                Lazy({"b": 1, "a": 2}),
+                option=orjson.OPT_SERIALIZE_JSON_PROTOCOL | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"b":1}'
+        )
+
+    def test_json_protocol_not_enabled(self):
+        """
+        __json__ is not called without OPT_SERIALIZE_JSON_PROTOCOL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Lazy(1))
+        assert orjson.dumps(Lazy(1), default=lambda obj: "default") == b'"default"'
+
+    def test_json_protocol_before_default(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL takes precedence over default
+        """
+        assert (
+            orjson.dumps(
+                [Lazy(1), object()],
+                default=lambda obj: "default",
+                option=orjson.OPT_SERIALIZE_JSON_PROTOCOL,
+            )
+            == b'[1,"default"]'
+        )
+
+    def test_json_protocol_return_default(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL return value may be passed to default
+        """
+        assert (
+            orjson.dumps(
+                Lazy({1, 2}),
+                default=sorted,
+                option=orjson.OPT_SERIALIZE_JSON_PROTOCOL,
+            )
+            == b"[1,2]"
+        )
+
+    def test_json_protocol_native_type(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL does not apply to natively serialized types
+        """
+        assert (
+            orjson.dumps(
+                DataclassWithJson(1), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL
+            )
+            == b'{"a":1}'
+        )
+
+    def test_json_protocol_instance_attribute(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL requires __json__ on the type
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                InstanceAttribute(), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL
+            )
+
+    def test_json_protocol_not_callable(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL __json__ that is not callable falls
+        through to default
+        """
+        option = orjson.OPT_SERIALIZE_JSON_PROTOCOL
+        for obj in (NotCallable(), NoneAttribute()):
+            assert orjson.dumps(obj, option=option, default=lambda _: 1) == b"1"
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, option=option)
+            assert str(exc_info.value).startswith("Type is not JSON serializable")
+            assert exc_info.value.__cause__ is None
+
+    def test_json_protocol_raises(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL exception from __json__ is propagated
+        """
+        with pytest.raises(ValueError) as exc_info:
+            orjson.dumps([Raises()], option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+        assert str(exc_info.value) == "invalid"
+        assert not isinstance(exc_info.value, orjson.JSONEncodeError)
+
+    @pytest.mark.skipif(sys.version_info < (3, 11), reason="add_note() is 3.11+")
+    def test_json_protocol_raises_note(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL exception from __json__ has a note with the
+        path of the value
+        """
+        with pytest.raises(ValueError) as exc_info:
+            orjson.dumps(
+                {"a": [1, Raises()]}, option=orjson.OPT_SERIALIZE_JSON_PROTOCOL
+            )
+        assert exc_info.value.__notes__ == [
+            "orjson: raised while serializing the value at $.a[1]"
+        ]
+
+    def test_json_protocol_raises_then_valid(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL later errors are JSONEncodeError
+        """
+        with pytest.raises(ValueError):
+            orjson.dumps(Raises(), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(object(), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+
+    def test_json_protocol_raises_dumps_variants(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL exception from dumps_str(), dumps_into(),
+        and Encoder
+        """
+        option = orjson.OPT_SERIALIZE_JSON_PROTOCOL
+        with pytest.raises(ValueError):
+            orjson.dumps_str(Raises(), option=option)
+        buffer = bytearray(b"a")
+        with pytest.raises(ValueError):
+            orjson.dumps_into(Raises(), buffer, option=option)
+        assert buffer == bytearray(b"a")
+        encoder = orjson.Encoder(option=option)
+        with pytest.raises(ValueError):
+            encoder.write_chunk(Raises())
+
+    def test_json_protocol_recursion(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL __json__ returning itself
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(ReturnsSelf(), option=orjson.OPT_SERIALIZE_JSON_PROTOCOL)
+        assert str(exc_info.value) == "default serializer exceeds recursion limit"
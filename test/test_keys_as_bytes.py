@@ -0,0 +1,144 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+def to_str_keys(obj):
+    if isinstance(obj, dict):
+        return {key.decode("utf-8"): to_str_keys(val) for key, val in obj.items()}
+    elif isinstance(obj, list):
+        return [to_str_keys(each) for each in obj]
+    return obj
+
+
+class TestKeysAsBytes:
+    def test_keys_as_bytes(self):
+        """
+        OPT_KEYS_AS_BYTES object keys are bytes
+        """
+        data = orjson.loads(
+            '{"a": 1, "b": {"c": [{"d": "e"}]}}', option=orjson.OPT_KEYS_AS_BYTES
+        )
+        assert data == {b"a": 1, b"b": {b"c": [{b"d": "e"}]}}
+        assert all(type(key) is bytes for key in data)
+
+    def test_keys_as_bytes_default(self):
+        """
+        object keys are str without OPT_KEYS_AS_BYTES
+        """
+        assert orjson.loads('{"a": 1}') == {"a": 1}
+
+    def test_keys_as_bytes_values(self):
+        """
+        OPT_KEYS_AS_BYTES does not affect str values
+        """
+        data = orjson.loads('{"a": "a", "b": ["b"]}', option=orjson.OPT_KEYS_AS_BYTES)
+        assert data == {b"a": "a", b"b": ["b"]}
+
+    def test_keys_as_bytes_lookup(self):
+        """
+        OPT_KEYS_AS_BYTES keys can be looked up by bytes
+        """
+        data = orjson.loads(
+            orjson.dumps({f"key{i}": i for i in range(1000)}),
+            option=orjson.OPT_KEYS_AS_BYTES,
+        )
+        assert len(data) == 1000
+        for i in range(1000):
+            assert data[f"key{i}".encode("utf-8")] == i
+        assert "key0" not in data
+
+    def test_keys_as_bytes_escaped(self):
+        """
+        OPT_KEYS_AS_BYTES keys are the UTF-8 of the unescaped key
+        """
+        data = orjson.loads(
+            '{"\\u00e9": 1, "\U0001f408": 2, "\\"\\n": 3, "": 4}',
+            option=orjson.OPT_KEYS_AS_BYTES,
+        )
+        assert data == {
+            "é".encode("utf-8"): 1,
+            "\U0001f408".encode("utf-8"): 2,
+            b'"\n': 3,
+            b"": 4,
+        }
+
+    def test_keys_as_bytes_long(self):
+        """
+        OPT_KEYS_AS_BYTES keys longer than the process-wide cache
+        """
+        key = "k" * 200
+        data = orjson.loads(f'{{"{key}": 1}}', option=orjson.OPT_KEYS_AS_BYTES)
+        assert data == {key.encode("utf-8"): 1}
+
+    def test_keys_as_bytes_cache(self):
+        """
+        OPT_KEYS_AS_BYTES does not affect the cache of str keys
+        """
+        assert orjson.loads('{"a": 1}', option=orjson.OPT_KEYS_AS_BYTES) == {b"a": 1}
+        assert orjson.loads('{"a": 1}') == {"a": 1}
+        assert orjson.loads('{"a": 1}', option=orjson.OPT_KEYS_AS_BYTES) == {b"a": 1}
+
+    def test_keys_as_bytes_duplicate(self):
+        """
+        OPT_KEYS_AS_BYTES duplicate keys
+        """
+        data = orjson.loads('{"a": 1, "a": 2}', option=orjson.OPT_KEYS_AS_BYTES)
+        assert data == {b"a": 2}
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(
+                '{"a": 1, "a": 2}',
+                option=orjson.OPT_KEYS_AS_BYTES | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+
+    def test_keys_as_bytes_intern_keys(self):
+        """
+        OPT_KEYS_AS_BYTES OPT_INTERN_KEYS equal keys are the same bytes
+        """
+        data = orjson.loads(
+            '[{"a": 1}, {"a": 2}]',
+            option=orjson.OPT_KEYS_AS_BYTES | orjson.OPT_INTERN_KEYS,
+        )
+        assert data == [{b"a": 1}, {b"a": 2}]
+        assert [*data[0]][0] is [*data[1]][0]
+
+    def test_keys_as_bytes_object_hook(self):
+        """
+        OPT_KEYS_AS_BYTES object_hook is called with bytes keys
+        """
+        data = orjson.loads(
+            '{"a": {"b": 1}}',
+            option=orjson.OPT_KEYS_AS_BYTES,
+            object_hook=lambda obj: sorted(obj),
+        )
+        assert data == [b"a"]
+
+    @pytest.mark.parametrize(
+        "fixture", ["twitter.json.xz", "github.json.xz", "canada.json.xz"]
+    )
+    def test_keys_as_bytes_fixture(self, fixture):
+        """
+        OPT_KEYS_AS_BYTES output is the same as without but for keys
+        """
+        doc = orjson.dumps(read_fixture_obj(fixture))
+        data = orjson.loads(doc, option=orjson.OPT_KEYS_AS_BYTES)
+        assert to_str_keys(data) == orjson.loads(doc)
+
+    def test_keys_as_bytes_error(self):
+        """
+        OPT_KEYS_AS_BYTES invalid document
+        """
+        for doc in ('{"a": 1, "b', '{"a\\x": 1}', '{"a" 1}'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(doc, option=orjson.OPT_KEYS_AS_BYTES)
+
+    def test_keys_as_bytes_dumps(self):
+        """
+        OPT_KEYS_AS_BYTES is not valid for dumps()
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({}, option=orjson.OPT_KEYS_AS_BYTES)
@@ -0,0 +1,188 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+
+import pytest
+
+import orjson
+
+try:
+    import numpy
+except ImportError:
+    numpy = None  # type: ignore
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: bool
+    b: None
+
+
+class TestCapitalizeBool:
+    def test_capitalize_bool(self):
+        """
+        dumps() OPT_CAPITALIZE_BOOL
+        """
+        assert orjson.dumps(True, option=orjson.OPT_CAPITALIZE_BOOL) == b"True"
+        assert orjson.dumps(False, option=orjson.OPT_CAPITALIZE_BOOL) == b"False"
+        assert (
+            orjson.dumps([True, False, None], option=orjson.OPT_CAPITALIZE_BOOL)
+            == b"[True,False,null]"
+        )
+
+    def test_capitalize_bool_default(self):
+        """
+        dumps() bool is lowercase without OPT_CAPITALIZE_BOOL
+        """
+        assert orjson.dumps([True, False]) == b"[true,false]"
+
+    def test_capitalize_bool_nested(self):
+        """
+        dumps() OPT_CAPITALIZE_BOOL in containers, with indentation
+        """
+        obj = {"a": [True, {"b": False}], "c": Dataclass(True, None)}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_CAPITALIZE_BOOL)
+            == b'{"a":[True,{"b":False}],"c":{"a":True,"b":null}}'
+        )
+        assert (
+            orjson.dumps(
+                [True], option=orjson.OPT_CAPITALIZE_BOOL | orjson.OPT_INDENT_2
+            )
+            == b"[\n  True\n]"
+        )
+
+    def test_capitalize_bool_non_str_keys(self):
+        """
+        dumps() OPT_CAPITALIZE_BOOL does not change bool keys
+        """
+        assert (
+            orjson.dumps(
+                {True: True},
+                option=orjson.OPT_CAPITALIZE_BOOL | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"true":True}'
+        )
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_capitalize_bool_numpy(self):
+        """
+        dumps() OPT_CAPITALIZE_BOOL numpy.bool_
+        """
+        assert (
+            orjson.dumps(
+                numpy.array([True, False]),
+                option=orjson.OPT_CAPITALIZE_BOOL | orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"[True,False]"
+        )
+
+    def test_capitalize_bool_canonical(self):
+        """
+        dumps() OPT_CAPITALIZE_BOOL cannot be combined with OPT_CANONICAL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                True, option=orjson.OPT_CAPITALIZE_BOOL | orjson.OPT_CANONICAL
+            )
+
+
+class TestNullAsNil:
+    def test_null_as_nil(self):
+        """
+        dumps() OPT_NULL_AS_NIL
+        """
+        assert orjson.dumps(None, option=orjson.OPT_NULL_AS_NIL) == b"nil"
+        assert (
+            orjson.dumps([None, True, 0], option=orjson.OPT_NULL_AS_NIL)
+            == b"[nil,true,0]"
+        )
+
+    def test_null_as_nil_default(self):
+        """
+        dumps() None is null without OPT_NULL_AS_NIL
+        """
+        assert orjson.dumps([None]) == b"[null]"
+
+    def test_null_as_nil_nested(self):
+        """
+        dumps() OPT_NULL_AS_NIL in containers, with indentation
+        """
+        obj = {"a": [None, {"b": None}], "c": Dataclass(False, None)}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NULL_AS_NIL)
+            == b'{"a":[nil,{"b":nil}],"c":{"a":false,"b":nil}}'
+        )
+        assert (
+            orjson.dumps(
+                {"a": None}, option=orjson.OPT_NULL_AS_NIL | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "a": nil\n}'
+        )
+
+    def test_null_as_nil_nonfinite(self):
+        """
+        dumps() OPT_NULL_AS_NIL NaN and Infinity, unless OPT_ALLOW_NONFINITE
+        """
+        obj = [float("nan"), float("inf"), float("-inf")]
+        assert orjson.dumps(obj, option=orjson.OPT_NULL_AS_NIL) == b"[nil,nil,nil]"
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_NULL_AS_NIL | orjson.OPT_ALLOW_NONFINITE
+            )
+            == b"[NaN,Infinity,-Infinity]"
+        )
+
+    def test_null_as_nil_non_str_keys(self):
+        """
+        dumps() OPT_NULL_AS_NIL does not change None keys
+        """
+        assert (
+            orjson.dumps(
+                {None: None},
+                option=orjson.OPT_NULL_AS_NIL | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"null":nil}'
+        )
+
+    def test_null_as_nil_skip_none_values(self):
+        """
+        dumps() OPT_NULL_AS_NIL OPT_SKIP_NONE_VALUES
+        """
+        assert (
+            orjson.dumps(
+                {"a": None, "b": [None]},
+                option=orjson.OPT_NULL_AS_NIL | orjson.OPT_SKIP_NONE_VALUES,
+            )
+            == b'{"b":[nil]}'
+        )
+
+    def test_null_as_nil_canonical(self):
+        """
+        dumps() OPT_NULL_AS_NIL cannot be combined with OPT_CANONICAL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(None, option=orjson.OPT_NULL_AS_NIL | orjson.OPT_CANONICAL)
+
+    def test_null_as_nil_capitalize_bool(self):
+        """
+        dumps() OPT_NULL_AS_NIL OPT_CAPITALIZE_BOOL
+        """
+        assert (
+            orjson.dumps(
+                [None, True, False],
+                option=orjson.OPT_NULL_AS_NIL | orjson.OPT_CAPITALIZE_BOOL,
+            )
+            == b"[nil,True,False]"
+        )
+
+    def test_null_as_nil_encoder(self):
+        """
+        orjson.Encoder() OPT_NULL_AS_NIL OPT_CAPITALIZE_BOOL
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_NULL_AS_NIL | orjson.OPT_CAPITALIZE_BOOL
+        )
+        assert encoder.write_chunk(None) == b"[nil"
+        assert encoder.write_chunk(True) == b",True"
+        assert encoder.close() == b"]"
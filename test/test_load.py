@@ -0,0 +1,238 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import array
+import inspect
+import io
+import mmap
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_bytes
+
+
+class TestLoadsBuffer:
+    def test_loads_mmap(self):
+        """
+        loads() mmap.mmap
+        """
+        buf = mmap.mmap(-1, 7)
+        buf.write(b'{"a":1}')
+        assert orjson.loads(buf) == {"a": 1}
+        buf.close()
+
+    def test_loads_mmap_released(self):
+        """
+        loads() releases the buffer so that mmap.mmap can be closed
+        """
+        buf = mmap.mmap(-1, 3)
+        buf.write(b"[1]")
+        assert orjson.loads(buf) == [1]
+        buf.seek(0)
+        buf.write(b"[1,")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(buf)
+        buf.close()
+        assert buf.closed
+
+    def test_loads_array(self):
+        """
+        loads() array.array of bytes
+        """
+        assert orjson.loads(array.array("b", b"[1,2]")) == [1, 2]
+
+    def test_loads_buffer_invalid_utf8(self):
+        """
+        loads() buffer protocol invalid UTF-8
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(array.array("B", b'["\xff"]'))
+        assert str(exc_info.value).startswith("str is not valid UTF-8")
+
+    def test_loads_buffer_empty(self):
+        """
+        loads() buffer protocol empty
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(array.array("b"))
+        assert str(exc_info.value).startswith("Input is a zero-length")
+
+    def test_loads_buffer_error(self):
+        """
+        loads() buffer protocol error refers to the document
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(array.array("b", b"[1,]"))
+        assert exc_info.value.doc == "[1,]"
+        assert exc_info.value.pos == 2
+
+    def test_loads_buffer_not_contiguous(self):
+        """
+        loads() memoryview that is not C contiguous
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(memoryview(b"[1, 2]")[::2])
+        assert str(exc_info.value).startswith(
+            "Input type memoryview must be a C contiguous buffer"
+        )
+
+    def test_loads_buffer_typed(self):
+        """
+        loads_typed() buffer protocol
+        """
+        from dataclasses import dataclass
+
+        @dataclass
+        class Point:
+            x: int
+
+        assert orjson.loads_typed(array.array("b", b'{"x":1}'), Point) == Point(1)
+
+    def test_loads_type_error(self):
+        """
+        loads() object that does not support the buffer protocol
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(io.BytesIO(b"[]"))
+        assert str(exc_info.value).startswith(
+            "Input must be bytes, bytearray, memoryview, str, or support the buffer "
+            "protocol"
+        )
+
+
+class TestLoad:
+    def test_load_binary_file(self, tmp_path):
+        """
+        load() file opened in binary mode
+        """
+        path = tmp_path / "a.json"
+        path.write_bytes(b'{"a":[1,2]}')
+        with open(path, "rb") as fp:
+            assert orjson.load(fp) == {"a": [1, 2]}
+
+    def test_load_text_file(self, tmp_path):
+        """
+        load() file opened in text mode
+        """
+        path = tmp_path / "a.json"
+        path.write_text('{"a":"é"}', encoding="utf-8")
+        with open(path, encoding="utf-8") as fp:
+            assert orjson.load(fp) == {"a": "é"}
+
+    def test_load_io(self):
+        """
+        load() io.BytesIO and io.StringIO
+        """
+        assert orjson.load(io.BytesIO(b"[1]")) == [1]
+        assert orjson.load(io.StringIO("[1]")) == [1]
+
+    def test_load_read_position(self):
+        """
+        load() reads from the current position
+        """
+        fp = io.BytesIO(b"xx[1]")
+        fp.seek(2)
+        assert orjson.load(fp) == [1]
+
+    def test_load_mmap(self, tmp_path):
+        """
+        load() mmap.mmap is read in place
+        """
+        path = tmp_path / "a.json"
+        path.write_bytes(read_fixture_bytes("twitter.json.xz"))
+        with open(path, "rb") as fp:
+            with mmap.mmap(fp.fileno(), 0, access=mmap.ACCESS_READ) as buf:
+                assert orjson.load(buf) == orjson.loads(path.read_bytes())
+
+    def test_load_buffer(self):
+        """
+        load() bytes, bytearray, and memoryview
+        """
+        for fp in (b"[1]", bytearray(b"[1]"), memoryview(b"[1]")):
+            assert orjson.load(fp) == [1]
+
+    def test_load_options(self):
+        """
+        load() option, max_depth, and object_hook
+        """
+        assert orjson.load(io.BytesIO(b"[1,]"), orjson.OPT_ALLOW_TRAILING_COMMA) == [1]
+        assert orjson.load(
+            io.BytesIO(b"[1,]"), option=orjson.OPT_ALLOW_TRAILING_COMMA
+        ) == [1]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(io.BytesIO(b"[[1]]"), max_depth=1)
+        assert orjson.load(io.BytesIO(b'{"a":1}'), object_hook=len) == 1
+
+    def test_load_invalid(self):
+        """
+        load() invalid document
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.load(io.BytesIO(b"[1,]"))
+        assert exc_info.value.pos == 2
+
+    def test_load_read_returns_invalid_type(self):
+        """
+        load() read() that does not return bytes or str
+        """
+
+        class Reader:
+            def read(self):
+                return 1
+
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(Reader())
+
+    def test_load_read_raises(self):
+        """
+        load() read() raising is propagated
+        """
+
+        class Reader:
+            def read(self):
+                raise OSError("closed")
+
+        with pytest.raises(OSError):
+            orjson.load(Reader())
+
+    def test_load_no_read(self):
+        """
+        load() fp without read() or the buffer protocol
+        """
+        for fp in ("[]", 1, None):
+            with pytest.raises(TypeError) as exc_info:
+                orjson.load(fp)
+            assert (
+                str(exc_info.value)
+                == "load() fp must have a read() method or support the buffer protocol"
+            )
+
+    def test_load_arguments(self):
+        """
+        load() invalid arguments
+        """
+        with pytest.raises(TypeError) as exc_info:
+            orjson.load()  # type: ignore
+        assert (
+            str(exc_info.value)
+            == "load() missing 1 required positional argument: 'fp'"
+        )
+        with pytest.raises(TypeError):
+            orjson.load(b"[]", None, None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.load(b"[]", None, option=None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.load(b"[]", zxc=None)  # type: ignore
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(b"[]", option=-1)
+
+    def test_load_signature(self):
+        """
+        load() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.load))
+            == "(fp, /, option=None, *, max_depth=None, object_hook=None, "
+            "array_hook=None)"
+        )
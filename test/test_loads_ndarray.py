@@ -0,0 +1,184 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import inspect
+
+import pytest
+
+import orjson
+
+try:
+    import numpy
+except ImportError:
+    numpy = None  # type: ignore
+
+
+@pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+class TestLoadsNdarray:
+    def test_loads_ndarray(self):
+        """
+        loads_ndarray() defaults to float64
+        """
+        arr = orjson.loads_ndarray(b"[1, 2.5, -3e2]")
+        assert isinstance(arr, numpy.ndarray)
+        assert arr.dtype == numpy.float64
+        assert arr.tolist() == [1.0, 2.5, -300.0]
+
+    def test_loads_ndarray_float32(self):
+        """
+        loads_ndarray() float32
+        """
+        arr = orjson.loads_ndarray("[0.5, -1.25, 3]", dtype=numpy.float32)
+        assert arr.dtype == numpy.float32
+        assert arr.tolist() == [0.5, -1.25, 3.0]
+        arr = orjson.loads_ndarray("[0.1]", numpy.float32)
+        assert arr.tolist() == [float(numpy.float32(0.1))]
+
+    def test_loads_ndarray_int(self):
+        """
+        loads_ndarray() integer dtypes
+        """
+        for dtype, low, high in (
+            (numpy.int8, -128, 127),
+            (numpy.int16, -32768, 32767),
+            (numpy.int32, -2147483648, 2147483647),
+            (numpy.int64, -9223372036854775808, 9223372036854775807),
+            (numpy.uint8, 0, 255),
+            (numpy.uint16, 0, 65535),
+            (numpy.uint32, 0, 4294967295),
+            (numpy.uint64, 0, 18446744073709551615),
+        ):
+            arr = orjson.loads_ndarray(f"[{low},{high}]", dtype=dtype)
+            assert arr.dtype == dtype
+            assert arr.tolist() == [low, high]
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads_ndarray(f"[{low - 1}]", dtype=dtype)
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads_ndarray(f"[{high + 1}]", dtype=dtype)
+
+    def test_loads_ndarray_empty(self):
+        """
+        loads_ndarray() empty array
+        """
+        arr = orjson.loads_ndarray(b" [ ] ", dtype=numpy.int32)
+        assert arr.dtype == numpy.int32
+        assert arr.tolist() == []
+
+    def test_loads_ndarray_input_types(self):
+        """
+        loads_ndarray() bytes, bytearray, memoryview, and str
+        """
+        for doc in (b"[1,2]", bytearray(b"[1,2]"), memoryview(b"[1,2]"), "[1,2]"):
+            assert orjson.loads_ndarray(doc).tolist() == [1.0, 2.0]
+
+    def test_loads_ndarray_large(self):
+        """
+        loads_ndarray() is the same as loads()
+        """
+        doc = orjson.dumps([i * 0.25 for i in range(-5000, 5000)])
+        assert orjson.loads_ndarray(doc).tolist() == orjson.loads(doc)
+
+    def test_loads_ndarray_fraction_int(self):
+        """
+        loads_ndarray() number with a fraction or exponent for an integer dtype
+        """
+        for doc in ("[1.0]", "[1e2]"):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads_ndarray(doc, dtype=numpy.int64)
+            assert exc_info.value.msg == "number is not an integer"
+            assert exc_info.value.pos == 1
+
+    def test_loads_ndarray_float32_range(self):
+        """
+        loads_ndarray() number out of the range of float32
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_ndarray("[1, 1e39]", dtype=numpy.float32)
+        assert exc_info.value.msg == "number is out of range for dtype"
+        assert exc_info.value.pos == 4
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_ndarray("[1e309]")
+
+    @pytest.mark.parametrize(
+        "doc",
+        [
+            "[1, [2]]",
+            "[1, null]",
+            '[1, "2"]',
+            "[true]",
+            "[1,]",
+            "[NaN]",
+            "{}",
+            "1",
+            "[1",
+            "[1] [2]",
+            "[01]",
+            "",
+            "   ",
+        ],
+    )
+    def test_loads_ndarray_invalid(self, doc):
+        """
+        loads_ndarray() not a flat array of numbers
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_ndarray(doc)
+
+    def test_loads_ndarray_invalid_message(self):
+        """
+        loads_ndarray() error message and position
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_ndarray("[1, 2, [3]]")
+        assert exc_info.value.msg == "array element is not a number"
+        assert exc_info.value.pos == 7
+
+    def test_loads_ndarray_dtype_unsupported(self):
+        """
+        loads_ndarray() dtype that is not an integer or floating type
+        """
+        for dtype in (numpy.bool_, numpy.float16, numpy.datetime64):
+            with pytest.raises(TypeError):
+                orjson.loads_ndarray("[1]", dtype=dtype)
+
+    def test_loads_ndarray_dtype_invalid(self):
+        """
+        loads_ndarray() dtype that numpy does not accept
+        """
+        with pytest.raises(TypeError):
+            orjson.loads_ndarray("[1]", dtype=object())
+
+    def test_loads_ndarray_dtype_none(self):
+        """
+        loads_ndarray() dtype None is float64
+        """
+        assert orjson.loads_ndarray("[1]", None).dtype == numpy.float64
+
+    def test_loads_ndarray_arguments(self):
+        """
+        loads_ndarray() invalid arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.loads_ndarray()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_ndarray("[1]", None, None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_ndarray("[1]", None, dtype=None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_ndarray("[1]", zxc=None)  # type: ignore
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_ndarray(1)  # type: ignore
+
+    def test_loads_ndarray_signature(self):
+        """
+        loads_ndarray() valid __text_signature__
+        """
+        assert str(inspect.signature(orjson.loads_ndarray)) == "(obj, /, dtype=None)"
+
+
+@pytest.mark.skipif(numpy is not None, reason="numpy is installed")
+def test_loads_ndarray_no_numpy():
+    """
+    loads_ndarray() without numpy
+    """
+    with pytest.raises(orjson.JSONDecodeError):
+        orjson.loads_ndarray("[1]")
@@ -0,0 +1,295 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import sys
+from typing import Any, Dict, List, Optional, Union
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Item:
+    name: str
+    price: float = 0.0
+
+
+@dataclasses.dataclass
+class Order:
+    id: int
+    items: List[Item]
+    note: Optional[str] = None
+    tags: List[str] = dataclasses.field(default_factory=list)
+
+
+@dataclasses.dataclass
+class Node:
+    value: int
+    children: List["Node"] = dataclasses.field(default_factory=list)
+
+
+@dataclasses.dataclass
+class Mapping:
+    items: Dict[str, Item]
+    extra: Any = None
+    raw: dict = dataclasses.field(default_factory=dict)
+
+
+@dataclasses.dataclass
+class Choice:
+    value: Union[int, str, Item, None]
+    number: Union[float, int] = 0
+
+
+@dataclasses.dataclass
+class NotInit:
+    a: int
+    b: int = dataclasses.field(init=False, default=2)
+
+
+@dataclasses.dataclass
+class PostInit:
+    a: int
+
+    def __post_init__(self):
+        if self.a < 0:
+            raise ValueError("a must not be negative")
+
+
+class TestLoadsTyped:
+    def test_loads_typed(self):
+        """
+        loads_typed() nested dataclasses
+        """
+        val = orjson.loads_typed(
+            '{"id": 1, "items": [{"name": "a", "price": 2.5}, {"name": "b"}]}', Order
+        )
+        assert val == Order(1, [Item("a", 2.5), Item("b", 0.0)])
+        assert isinstance(val.items[0], Item)
+
+    def test_loads_typed_input_types(self):
+        """
+        loads_typed() accepts the same input types as loads()
+        """
+        doc = b'{"name": "a"}'
+        for val in (doc.decode(), doc, bytearray(doc), memoryview(doc)):
+            assert orjson.loads_typed(val, Item) == Item("a")
+
+    def test_loads_typed_defaults(self):
+        """
+        loads_typed() populates absent fields from default and default_factory
+        """
+        val = orjson.loads_typed('{"id": 1, "items": []}', Order)
+        assert val == Order(1, [], None, [])
+        other = orjson.loads_typed('{"id": 2, "items": []}', Order)
+        assert other.tags is not val.tags
+
+    def test_loads_typed_optional(self):
+        """
+        loads_typed() Optional field
+        """
+        val = orjson.loads_typed('{"id": 1, "items": [], "note": null}', Order)
+        assert val.note is None
+        val = orjson.loads_typed('{"id": 1, "items": [], "note": "x"}', Order)
+        assert val.note == "x"
+
+    def test_loads_typed_float_from_int(self):
+        """
+        loads_typed() converts int to float for float fields
+        """
+        val = orjson.loads_typed('{"name": "a", "price": 3}', Item)
+        assert val.price == 3.0
+        assert type(val.price) is float
+
+    def test_loads_typed_recursive(self):
+        """
+        loads_typed() dataclass referring to itself
+        """
+        val = orjson.loads_typed(
+            '{"value": 1, "children": [{"value": 2, "children": [{"value": 3}]}]}', Node
+        )
+        assert val == Node(1, [Node(2, [Node(3)])])
+
+    def test_loads_typed_dict(self):
+        """
+        loads_typed() dict values and unannotated values
+        """
+        val = orjson.loads_typed(
+            '{"items": {"x": {"name": "a"}}, "extra": {"b": [1]}, "raw": {"c": {}}}',
+            Mapping,
+        )
+        assert val == Mapping({"x": Item("a")}, {"b": [1]}, {"c": {}})
+
+    def test_loads_typed_union(self):
+        """
+        loads_typed() Union chooses the member of the type of the value
+        """
+        assert orjson.loads_typed('{"value": 1}', Choice).value == 1
+        assert orjson.loads_typed('{"value": "a"}', Choice).value == "a"
+        assert orjson.loads_typed('{"value": {"name": "a"}}', Choice).value == Item(
+            "a"
+        )
+        assert orjson.loads_typed('{"value": null}', Choice).value is None
+        val = orjson.loads_typed('{"value": 1, "number": 1}', Choice)
+        assert type(val.number) is int
+        val = orjson.loads_typed('{"value": 1, "number": 1.5}', Choice)
+        assert val.number == 1.5
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_typed('{"value": []}', Choice)
+        assert str(exc_info.value).startswith(
+            "expected int | str | Item | None at $.value, got list"
+        )
+
+    @pytest.mark.skipif(sys.version_info < (3, 10), reason="X | Y")
+    def test_loads_typed_union_type(self):
+        """
+        loads_typed() X | None annotation
+        """
+
+        @dataclasses.dataclass
+        class Pep604:
+            a: "int | None"
+            b: "list[Item] | None" = None
+
+        assert orjson.loads_typed('{"a": null}', Pep604) == Pep604(None)
+        val = orjson.loads_typed('{"a": 1, "b": [{"name": "x"}]}', Pep604)
+        assert val == Pep604(1, [Item("x")])
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_typed('{"a": "1"}', Pep604)
+
+    def test_loads_typed_builtin_generics(self):
+        """
+        loads_typed() list[T] and dict[str, T] annotations
+        """
+
+        @dataclasses.dataclass
+        class Generics:
+            a: "list[Item]"
+            b: "dict[str, list[int]]"
+
+        val = orjson.loads_typed('{"a": [{"name": "x"}], "b": {"k": [1]}}', Generics)
+        assert val == Generics([Item("x")], {"k": [1]})
+
+    def test_loads_typed_missing(self):
+        """
+        loads_typed() missing required field
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_typed('{"id": 1, "items": [{"price": 1}]}', Order)
+        assert str(exc_info.value).startswith(
+            "missing required field 'name' of Item at $.items[0]"
+        )
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_typed('{"items": []}', Order)
+        assert str(exc_info.value).startswith(
+            "missing required field 'id' of Order at $"
+        )
+
+    def test_loads_typed_unknown(self):
+        """
+        loads_typed() unknown key is an error if strict
+        """
+        doc = '{"id": 1, "items": [{"name": "a", "colour": "red"}]}'
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_typed(doc, Order)
+        assert str(exc_info.value).startswith(
+            "unknown field 'colour' of Item at $.items[0]"
+        )
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_typed(doc, Order, strict=True)
+        assert orjson.loads_typed(doc, Order, strict=False) == Order(1, [Item("a")])
+
+    def test_loads_typed_not_init(self):
+        """
+        loads_typed() field with init=False is not populated from the document
+        """
+        assert orjson.loads_typed('{"a": 1}', NotInit).b == 2
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_typed('{"a": 1, "b": 3}', NotInit)
+        assert orjson.loads_typed('{"a": 1, "b": 3}', NotInit, strict=False).b == 2
+
+    def test_loads_typed_type_mismatch(self):
+        """
+        loads_typed() value not of the annotated type
+        """
+        for doc, msg in (
+            ('{"id": "1", "items": []}', "expected int at $.id, got str"),
+            ('{"id": true, "items": []}', "expected int at $.id, got bool"),
+            ('{"id": 1.5, "items": []}', "expected int at $.id, got float"),
+            ('{"id": 1, "items": {}}', "expected list[Item] at $.items, got dict"),
+            ('{"id": 1, "items": [1]}', "expected Item at $.items[0], got int"),
+            (
+                '{"id": 1, "items": [{"name": null}]}',
+                "expected str at $.items[0].name, got NoneType",
+            ),
+            (
+                '{"id": 1, "items": [], "tags": [1]}',
+                "expected str at $.tags[0], got int",
+            ),
+            ("[]", "expected Order at $, got list"),
+        ):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads_typed(doc, Order)
+            assert str(exc_info.value).startswith(msg)
+
+    def test_loads_typed_invalid_json(self):
+        """
+        loads_typed() invalid JSON
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_typed('{"id": 1,', Order)
+        assert exc_info.value.pos == 9
+
+    def test_loads_typed_exception(self):
+        """
+        loads_typed() exception creating a dataclass propagates unchanged
+        """
+        assert orjson.loads_typed('{"a": 1}', PostInit) == PostInit(1)
+        with pytest.raises(ValueError) as exc_info:
+            orjson.loads_typed('{"a": -1}', PostInit)
+        assert not isinstance(exc_info.value, orjson.JSONDecodeError)
+
+    def test_loads_typed_unresolved_annotation(self):
+        """
+        loads_typed() annotation that cannot be resolved propagates NameError
+        """
+
+        @dataclasses.dataclass
+        class Unresolved:
+            a: "DoesNotExist"  # type: ignore  # noqa: F821
+
+        with pytest.raises(NameError):
+            orjson.loads_typed('{"a": 1}', Unresolved)
+
+    def test_loads_typed_refcount(self):
+        """
+        loads_typed() does not leak references to the dataclass
+        """
+        ref = sys.getrefcount(Item)
+        for _ in range(100):
+            orjson.loads_typed('{"id": 1, "items": [{"name": "a"}]}', Order)
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads_typed('{"id": 1, "items": [{"price": 1}]}', Order)
+        assert sys.getrefcount(Item) == ref
+
+    def test_loads_typed_not_dataclass(self):
+        """
+        loads_typed() cls must be a dataclass type
+        """
+        for cls in (dict, Item("a"), None, "Item"):
+            with pytest.raises(TypeError):
+                orjson.loads_typed("{}", cls)  # type: ignore
+
+    def test_loads_typed_arguments(self):
+        """
+        loads_typed() invalid arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.loads_typed("{}")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_typed("{}", Item, True)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_typed("{}", Item, zxc=True)  # type: ignore
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_typed('{"name": "a"}', Item, strict=1)  # type: ignore
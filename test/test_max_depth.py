@@ -0,0 +1,259 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+from decimal import Decimal
+
+import pytest
+
+import orjson
+
+
+class TestMaxDepth:
+    def test_max_depth_none(self):
+        """
+        loads() max_depth=None is the default limit
+        """
+        assert orjson.loads("[[1]]", max_depth=None) == [[1]]
+        n = 1024
+        assert orjson.loads("[" * n + "]" * n, max_depth=None)
+        n = 1025
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[" * n + "]" * n, max_depth=None)
+
+    def test_max_depth_default_option(self):
+        """
+        loads() default limit is the same with an option
+        """
+        n = 1024
+        value = "[" * n + "]" * n
+        assert orjson.loads(value, option=orjson.OPT_PARSE_DECIMAL)
+        n = 1025
+        value = "[" * n + "]" * n
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(value, option=orjson.OPT_PARSE_DECIMAL)
+
+    def test_max_depth_at_limit(self):
+        """
+        loads() max_depth accepts nesting at the limit
+        """
+        assert orjson.loads("[[1]]", max_depth=2) == [[1]]
+        assert orjson.loads('{"a":{"b":1}}', max_depth=2) == {"a": {"b": 1}}
+        assert orjson.loads('[{"a":[]}]', max_depth=3) == [{"a": []}]
+
+    def test_max_depth_exceeded(self):
+        """
+        loads() max_depth exceeded reports depth and byte offset
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[[1]]", max_depth=1)
+        assert str(exc_info.value).startswith(
+            "array and object nesting depth 2 exceeds max_depth of 1 at byte 1:"
+        )
+        assert exc_info.value.pos == 1
+
+    def test_max_depth_exceeded_object(self):
+        """
+        loads() max_depth exceeded in object value
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('{"a": {"b": [1]}}', max_depth=2)
+        assert str(exc_info.value).startswith(
+            "array and object nesting depth 3 exceeds max_depth of 2 at byte 12:"
+        )
+        assert exc_info.value.pos == 12
+
+    def test_max_depth_exceeded_non_ascii(self):
+        """
+        loads() max_depth byte offset differs from char pos
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('["é", []]', max_depth=1)
+        assert "at byte 7:" in str(exc_info.value)
+        assert exc_info.value.pos == 6
+
+    def test_max_depth_sibling(self):
+        """
+        loads() max_depth applies to depth, not the number of containers
+        """
+        assert orjson.loads("[[],[],[[]]]", max_depth=3) == [[], [], [[]]]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[[],[],[[]]]", max_depth=2)
+
+    def test_max_depth_zero(self):
+        """
+        loads() max_depth=0 accepts only scalars
+        """
+        assert orjson.loads("1", max_depth=0) == 1
+        assert orjson.loads('"a"', max_depth=0) == "a"
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[]", max_depth=0)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("{}", max_depth=0)
+
+    def test_max_depth_above_default(self):
+        """
+        loads() max_depth above the default limit
+        """
+        n = 4096
+        value = "[" * n + "]" * n
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(value)
+        val = orjson.loads(value, max_depth=n)
+        for _ in range(n - 1):
+            assert len(val) == 1
+            val = val[0]
+        assert val == []
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(value, max_depth=n - 1)
+
+    def test_max_depth_partial(self):
+        """
+        loads() max_depth with unterminated input
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[" * (1024 * 1024), max_depth=1024 * 1024)
+
+    def test_max_depth_option(self):
+        """
+        loads() max_depth with option
+        """
+        val = orjson.loads("[[1.5]]", option=orjson.OPT_PARSE_DECIMAL, max_depth=2)
+        assert val == [[Decimal("1.5")]]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("[[1.5]]", option=orjson.OPT_PARSE_DECIMAL, max_depth=1)
+
+    def test_max_depth_invalid(self):
+        """
+        loads() max_depth invalid
+        """
+        for val in (-1, 1.5, "1", True, 1 << 64, -(1 << 64)):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads("[]", max_depth=val)  # type: ignore
+
+    def test_max_depth_positional(self):
+        """
+        loads() max_depth is keyword-only
+        """
+        with pytest.raises(TypeError):
+            orjson.loads("[]", None, 1)  # type: ignore
+
+
+def nested_list(n):
+    obj: object = 1
+    for _ in range(n):
+        obj = [obj]
+    return obj
+
+
+class TestDumpsMaxDepth:
+    def test_dumps_max_depth_default(self):
+        """
+        dumps() default limit is 254
+        """
+        assert orjson.dumps(nested_list(254))
+        assert orjson.dumps(nested_list(254), max_depth=None)
+        assert orjson.dumps(nested_list(254), max_depth=254)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nested_list(255))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nested_list(255), max_depth=None)
+
+    def test_dumps_max_depth_at_limit(self):
+        """
+        dumps() max_depth accepts nesting at the limit
+        """
+        assert orjson.dumps([[1]], max_depth=2) == b"[[1]]"
+        assert orjson.dumps({"a": {"b": 1}}, max_depth=2) == b'{"a":{"b":1}}'
+        assert orjson.dumps([{"a": ()}], max_depth=3) == b'[{"a":[]}]'
+        assert orjson.dumps(1, max_depth=1) == b"1"
+
+    def test_dumps_max_depth_exceeded(self):
+        """
+        dumps() max_depth exceeded
+        """
+        for obj, written in (
+            ([[1]], 1),
+            ({"a": {"b": 1}}, 5),
+            ([{"a": (1,)}], 1),
+            (([1],), 1),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, max_depth=1)
+            assert str(exc_info.value) == (
+                f"Recursion limit reached (output byte {written})"
+            )
+
+    def test_dumps_max_depth_option(self):
+        """
+        dumps() max_depth with OPT_SORT_KEYS and OPT_INDENT_2
+        """
+        option = orjson.OPT_SORT_KEYS | orjson.OPT_INDENT_2
+        obj = {"b": {"a": 1}}
+        assert orjson.dumps(obj, option=option, max_depth=2) == orjson.dumps(
+            obj, option=option
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=option, max_depth=1)
+
+    def test_dumps_max_depth_above_default(self):
+        """
+        dumps() max_depth greater than the default limit
+        """
+        obj = nested_list(1000)
+        assert orjson.dumps(obj, max_depth=1000) == b"[" * 1000 + b"1" + b"]" * 1000
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, max_depth=999)
+        assert orjson.dumps([], max_depth=2046) == b"[]"
+
+    def test_dumps_max_depth_circular(self):
+        """
+        dumps() max_depth with a circular reference
+        """
+        obj: list = []
+        obj.append(obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, max_depth=1000)
+
+    def test_dumps_max_depth_circular_dataclass(self):
+        """
+        dumps() max_depth with a circular reference through a dataclass field,
+        which is nested one level deeper than the dataclass
+        """
+
+        @dataclasses.dataclass
+        class Node:
+            children: list
+
+        obj = Node([])
+        obj.children.append(obj)
+        for max_depth in (None, 1, 2, 999, 1000, 2045, 2046):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, max_depth=max_depth)
+
+    def test_dumps_max_depth_default_callable(self):
+        """
+        dumps() max_depth applies to the output of default
+        """
+        assert orjson.dumps({1}, default=list, max_depth=1) == b"[1]"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([{1}], default=list, max_depth=1)
+
+    def test_dumps_max_depth_into(self):
+        """
+        dumps_into() max_depth
+        """
+        buffer = bytearray()
+        assert orjson.dumps_into([[1]], buffer, max_depth=2) == 5
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into([[1]], buffer, max_depth=1)
+        assert buffer == b"[[1]]"
+
+    def test_dumps_max_depth_invalid(self):
+        """
+        dumps() max_depth invalid
+        """
+        for val in (0, -1, 2047, 1.5, "1", True, 1 << 64, -(1 << 64)):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([], max_depth=val)  # type: ignore
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps_into([], bytearray(), max_depth=val)  # type: ignore
@@ -0,0 +1,203 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import collections
+import typing
+
+import pytest
+
+import orjson
+
+Point = collections.namedtuple("Point", ["x", "y"])
+
+
+class Line(typing.NamedTuple):
+    start: Point
+    end: Point
+    label: typing.Optional[str] = None
+
+
+class TestNamedTuple:
+    def test_namedtuple(self):
+        """
+        collections.namedtuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+        """
+        assert (
+            orjson.dumps(Point(1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_typing_namedtuple(self):
+        """
+        typing.NamedTuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT nested
+        """
+        assert (
+            orjson.dumps(
+                Line(Point(0, 0), Point(1, 2)),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'{"start":{"x":0,"y":0},"end":{"x":1,"y":2},"label":null}'
+        )
+
+    def test_namedtuple_in_containers(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT in dict, list, and tuple
+        """
+        assert (
+            orjson.dumps(
+                {"a": [Point(1, 2)], "b": (Point(3, 4),)},
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'{"a":[{"x":1,"y":2}],"b":[{"x":3,"y":4}]}'
+        )
+
+    def test_namedtuple_field_order(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT fields are in order
+        """
+        Reversed = collections.namedtuple("Reversed", ["z", "a"])
+        assert (
+            orjson.dumps(
+                Reversed(1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+            )
+            == b'{"z":1,"a":2}'
+        )
+
+    def test_namedtuple_sort_keys(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT OPT_SORT_KEYS
+        """
+        Reversed = collections.namedtuple("Reversed", ["z", "a"])
+        assert (
+            orjson.dumps(
+                Reversed(1, 2),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+                | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"z":1}'
+        )
+
+    def test_namedtuple_empty(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT without fields
+        """
+        Empty = collections.namedtuple("Empty", [])
+        assert (
+            orjson.dumps(Empty(), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+            == b"{}"
+        )
+
+    def test_namedtuple_subclass(self):
+        """
+        subclass of named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+        """
+
+        class SubPoint(Point):
+            def norm(self):
+                return abs(self.x) + abs(self.y)
+
+        assert (
+            orjson.dumps(
+                SubPoint(1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+            )
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_namedtuple_indent(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                [Point(1, 2)],
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT | orjson.OPT_INDENT_2,
+            )
+            == b'[\n  {\n    "x": 1,\n    "y": 2\n  }\n]'
+        )
+
+    def test_namedtuple_skip_none_values(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT OPT_SKIP_NONE_VALUES
+        """
+        assert (
+            orjson.dumps(
+                Line(Point(0, 0), Point(1, None)),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+                | orjson.OPT_SKIP_NONE_VALUES,
+            )
+            == b'{"start":{"x":0,"y":0},"end":{"x":1}}'
+        )
+
+    def test_namedtuple_not_enabled(self):
+        """
+        named tuple is not serialized without OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Point(1, 2))
+        assert orjson.dumps(Point(1, 2), default=list) == b"[1,2]"
+
+    def test_tuple_subclass(self):
+        """
+        tuple subclass that is not a named tuple
+        """
+
+        class SubTuple(tuple):
+            pass
+
+        class FieldsNotTuple(tuple):
+            _fields = ["a"]
+
+        for obj in (SubTuple((1,)), FieldsNotTuple((1,))):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+
+    def test_tuple(self):
+        """
+        tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+        """
+        assert (
+            orjson.dumps((1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+            == b"[1,2]"
+        )
+
+    def test_not_tuple_with_fields(self):
+        """
+        class with _fields that is not a tuple
+        """
+
+        class Fields:
+            _fields = ("a",)
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Fields(), option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_error_path(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT error path
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": Point(1, object())},
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT,
+            )
+        assert "object at $.a.y" in str(exc_info.value)
+
+    def test_namedtuple_recursion(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT recursion limit
+        """
+        obj: object = 1
+        for _ in range(1025):
+            obj = Point(obj, None)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_dict_key(self):
+        """
+        named tuple OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Point(1, 2): 1},
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+                | orjson.OPT_NON_STR_KEYS,
+            )
@@ -2,6 +2,7 @@
 
 import dataclasses
 import datetime
+import enum
 import uuid
 
 import pytest
@@ -23,6 +24,28 @@ class SubStr(str):
     pass
 
 
+class IntEnum(enum.IntEnum):
+    ONE = 1
+
+
+class KeyEnum(enum.Enum):
+    STR = "a"
+    INT = 2
+    FLOAT = 1.5
+    NONE = None
+    UUID = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+    DATE = datetime.date(1970, 1, 1)
+    NESTED = IntEnum.ONE
+
+
+class TupleEnum(enum.Enum):
+    A = (1, 2)
+
+
+class UUIDSubclass(uuid.UUID):
+    pass
+
+
 class TestNonStrKeyTests:
     def test_dict_keys_duplicate(self):
         """
@@ -33,6 +56,167 @@ class TestNonStrKeyTests:
             == b'{"1":true,"1":false}'
         )
 
+    def test_dict_keys_duplicate_reject(self):
+        """
+        OPT_NON_STR_KEYS OPT_REJECT_DUPLICATE_KEYS raises on keys that are the
+        same once serialized
+        """
+        option = orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS
+        for obj, key, keys in (
+            ({"1": True, 1: False}, "1", "'1' and 1"),
+            ({1: True, "1": False}, "1", "1 and '1'"),
+            ({True: 1, "true": 2}, "true", "True and 'true'"),
+            ({"false": 1, False: 2}, "false", "'false' and False"),
+            ({1.5: 1, "1.5": 2}, "1.5", "1.5 and '1.5'"),
+            (
+                {datetime.date(1970, 1, 1): 1, "1970-01-01": 2},
+                "1970-01-01",
+                "datetime.date(1970, 1, 1) and '1970-01-01'",
+            ),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, option=option)
+            assert (
+                str(exc_info.value)
+                == f'Dict key "{key}" is not unique when serialized as str: {keys}'
+            )
+
+    def test_dict_keys_duplicate_reject_path(self):
+        """
+        OPT_NON_STR_KEYS OPT_REJECT_DUPLICATE_KEYS error path is the dict
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": [{"1": 1, 1: 2}]},
+                option=orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_REJECT_DUPLICATE_KEYS
+                | orjson.OPT_SORT_KEYS,
+            )
+        assert str(exc_info.value) == (
+            'Dict key "1" is not unique when serialized as str: '
+            "'1' and 1 at $.a[0] (output byte 6)"
+        )
+
+    def test_dict_keys_duplicate_reject_unique(self):
+        """
+        OPT_NON_STR_KEYS OPT_REJECT_DUPLICATE_KEYS unique keys
+        """
+        obj = {"1": 1, 2: 2, None: 3, 1.5: 4, True: 5, "null ": 6}
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS
+            )
+            == orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"1":1,"2":2,"null":3,"1.5":4,"true":5,"null ":6}'
+        )
+
+    def test_dict_keys_duplicate_reject_str_keys(self):
+        """
+        OPT_REJECT_DUPLICATE_KEYS has no effect without OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps({"a": 1}, option=orjson.OPT_REJECT_DUPLICATE_KEYS)
+            == b'{"a":1}'
+        )
+
+    def test_dict_keys_null_bool_int_float(self):
+        """
+        OPT_NON_STR_KEYS None, bool, int, and float keys in the same dict
+        """
+        obj = {
+            None: 1,
+            True: 2,
+            False: 3,
+            2: 4,
+            -3: 5,
+            3.0: 6,
+            -0.5: 7,
+            2.5: 8,
+            float("nan"): 9,
+            float("inf"): 10,
+        }
+        assert orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == (
+            b'{"null":1,"true":2,"false":3,"2":4,"-3":5,"3.0":6,"-0.5":7,'
+            b'"2.5":8,"null":9,"null":10}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+        assert orjson.dumps(
+            obj, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_ALLOW_NONFINITE
+        ) == (
+            b'{"null":1,"true":2,"false":3,"2":4,"-3":5,"3.0":6,"-0.5":7,'
+            b'"2.5":8,"NaN":9,"Infinity":10}'
+        )
+
+    def test_dict_keys_null_collision(self):
+        """
+        OPT_NON_STR_KEYS None and other keys that are "null" once serialized
+        are duplicates as other coerced keys are, and raise with
+        OPT_REJECT_DUPLICATE_KEYS
+        """
+        for obj, expected in (
+            ({None: 1, "null": 2}, b'{"null":1,"null":2}'),
+            ({1: 1, "1": 2}, b'{"1":1,"1":2}'),
+            ({True: 1, "true": 2}, b'{"true":1,"true":2}'),
+        ):
+            assert orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == expected
+        for option in (
+            orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            orjson.OPT_NON_STR_KEYS
+            | orjson.OPT_REJECT_DUPLICATE_KEYS
+            | orjson.OPT_SORT_KEYS,
+        ):
+            for obj, keys in (
+                ({None: 1, "null": 2}, "None and 'null'"),
+                ({"null": 1, None: 2}, "'null' and None"),
+                ({None: 1, float("nan"): 2}, "None and nan"),
+                ({float("inf"): 1, 2: 2, None: 3}, "inf and None"),
+                ({"a": 1, float("nan"): 2, "null": 3}, "nan and 'null'"),
+            ):
+                with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                    orjson.dumps(obj, option=option)
+                assert str(exc_info.value) == (
+                    f'Dict key "null" is not unique when serialized as str: {keys}'
+                )
+            assert orjson.loads(
+                orjson.dumps(
+                    {None: 1, float("nan"): 2},
+                    option=option | orjson.OPT_ALLOW_NONFINITE,
+                )
+            ) == {"null": 1, "NaN": 2}
+
+    def test_dict_keys_null_collision_nested(self):
+        """
+        OPT_NON_STR_KEYS OPT_REJECT_DUPLICATE_KEYS None and "null" in a nested
+        dict reports its path
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": [{None: 1, "null": 2}]},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+        assert "$.a[0]" in str(exc_info.value)
+
+    def test_dict_keys_duplicate_reject_skip_none(self):
+        """
+        OPT_NON_STR_KEYS OPT_REJECT_DUPLICATE_KEYS keys of values skipped by
+        OPT_SKIP_NONE_VALUES are not compared
+        """
+        option = (
+            orjson.OPT_NON_STR_KEYS
+            | orjson.OPT_REJECT_DUPLICATE_KEYS
+            | orjson.OPT_SKIP_NONE_VALUES
+        )
+        assert orjson.dumps({None: None, "null": 1}, option=option) == b'{"null":1}'
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": None, 1: 1, 2: None, "1": 2}, option=option)
+        assert str(exc_info.value) == (
+            "Dict key \"1\" is not unique when serialized as str: 1 and '1'"
+        )
+
     def test_dict_keys_int(self):
         assert (
             orjson.dumps({1: True, 2: False}, option=orjson.OPT_NON_STR_KEYS)
@@ -155,6 +339,36 @@ class TestNonStrKeyTests:
             == b'{"true":true,"false":false}'
         )
 
+    def test_dict_keys_bool_not_int(self):
+        """
+        OPT_NON_STR_KEYS bool keys are "true" and "false", not "1" and "0"
+        """
+        assert (
+            orjson.dumps(
+                {True: 1, 2: 2, False: 0},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+            == b'{"true":1,"2":2,"false":0}'
+        )
+        assert (
+            orjson.dumps({1: 1, 0: 0}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"1":1,"0":0}'
+        )
+
+    def test_dict_keys_bool_int_equal(self):
+        """
+        OPT_NON_STR_KEYS 1 and True are the same dict key, which keeps the
+        first key inserted and the last value
+        """
+        assert (
+            orjson.dumps({1: "a", True: "b"}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"1":"b"}'
+        )
+        assert (
+            orjson.dumps({True: "a", 1: "b"}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"true":"b"}'
+        )
+
     def test_dict_keys_datetime(self):
         assert (
             orjson.dumps(
@@ -200,6 +414,97 @@ class TestNonStrKeyTests:
             == b'{"7202d115-7ff3-4c81-a7c1-2a1f067b1ece":true}'
         )
 
+    def test_dict_keys_uuid_duplicate(self):
+        """
+        OPT_NON_STR_KEYS UUID key colliding with str key is not deduplicated
+        """
+        val = "7202d115-7ff3-4c81-a7c1-2a1f067b1ece"
+        assert (
+            orjson.dumps(
+                {uuid.UUID(val): 1, val: 2},
+                option=orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"7202d115-7ff3-4c81-a7c1-2a1f067b1ece":1,'
+            b'"7202d115-7ff3-4c81-a7c1-2a1f067b1ece":2}'
+        )
+
+    def test_dict_keys_uuid_subclass(self):
+        """
+        OPT_NON_STR_KEYS does not serialize UUID subclass as key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {UUIDSubclass(int=1): True},
+                option=orjson.OPT_NON_STR_KEYS,
+            )
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            (KeyEnum.STR, b'{"a":true}'),
+            (KeyEnum.INT, b'{"2":true}'),
+            (KeyEnum.FLOAT, b'{"1.5":true}'),
+            (KeyEnum.NONE, b'{"null":true}'),
+            (KeyEnum.UUID, b'{"7202d115-7ff3-4c81-a7c1-2a1f067b1ece":true}'),
+            (KeyEnum.DATE, b'{"1970-01-01":true}'),
+            (KeyEnum.NESTED, b'{"1":true}'),
+            (IntEnum.ONE, b'{"1":true}'),
+        ],
+    )
+    def test_dict_keys_enum(self, value):
+        """
+        OPT_NON_STR_KEYS serializes Enum key as its value would be
+        """
+        key, expected = value
+        assert orjson.dumps({key: True}, option=orjson.OPT_NON_STR_KEYS) == expected
+
+    def test_dict_keys_enum_by_name(self):
+        """
+        OPT_NON_STR_KEYS serializes Enum key by name with OPT_ENUM_BY_NAME
+        """
+        assert (
+            orjson.dumps(
+                {KeyEnum.UUID: True, KeyEnum.INT: False},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_ENUM_BY_NAME,
+            )
+            == b'{"UUID":true,"INT":false}'
+        )
+
+    def test_dict_keys_enum_invalid_value(self):
+        """
+        OPT_NON_STR_KEYS Enum key with a value not serializable as a key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({TupleEnum.A: True}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_dict_keys_enum_duplicate(self):
+        """
+        OPT_NON_STR_KEYS Enum key colliding with another key is not deduplicated
+        """
+        assert (
+            orjson.dumps(
+                {KeyEnum.STR: 1, "a": 2, KeyEnum.INT: 3, 2: 4},
+                option=orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"a":1,"a":2,"2":3,"2":4}'
+        )
+
+    def test_dict_keys_enum_uuid_sort(self):
+        """
+        OPT_NON_STR_KEYS Enum and UUID keys with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {
+                    uuid.UUID(int=1): 1,
+                    KeyEnum.STR: 2,
+                    KeyEnum.DATE: 3,
+                },
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"00000000-0000-0000-0000-000000000001":1,"1970-01-01":3,"a":2}'
+        )
+
     def test_dict_keys_date(self):
         assert (
             orjson.dumps(
@@ -230,6 +535,57 @@ class TestNonStrKeyTests:
             == b'{"1970-01-03":3,"1970-01-05":2,"other":1}'
         )
 
+    def test_dict_non_str_sort_keys_mixed(self):
+        """
+        OPT_NON_STR_KEYS OPT_SORT_KEYS int, str, bool, and None keys sort by
+        their serialized str
+        """
+        assert (
+            orjson.dumps(
+                {"b": 1, 10: 2, True: 3, None: 4, 9: 5, "A": 6, -1: 7, 1.5: 8},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"-1":7,"1.5":8,"10":2,"9":5,"A":6,"b":1,"null":4,"true":3}'
+        )
+
+    def test_dict_non_str_sort_keys_duplicate(self):
+        """
+        OPT_NON_STR_KEYS OPT_SORT_KEYS keys that are the same once serialized
+        are in the order of the dict
+        """
+        assert (
+            orjson.dumps(
+                {"1": "a", "true": "b", 1: "c", "0": "e", False: "f"},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"0":"e","1":"a","1":"c","false":"f","true":"b"}'
+        )
+        assert (
+            orjson.dumps(
+                {1: "c", "0": "e", "1": "a", False: "f", "false": "g"},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"0":"e","1":"c","1":"a","false":"f","false":"g"}'
+        )
+
+    def test_dict_non_str_sort_keys_many_duplicates(self):
+        """
+        OPT_NON_STR_KEYS OPT_SORT_KEYS order of duplicate keys in a large dict
+        """
+        obj = {}  # type: ignore
+        for idx in range(1000):
+            if idx % 2:
+                obj[idx] = 1
+                obj[str(idx)] = 2
+            else:
+                obj[str(idx)] = 1
+                obj[idx] = 2
+        serialized = orjson.dumps(
+            obj, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS
+        )
+        for idx in range(1000):
+            assert f'"{idx}":1,"{idx}":2'.encode() in serialized
+
     @pytest.mark.skipif(pytz is None, reason="pytz optional")
     def test_dict_keys_time_err(self):
         """
@@ -292,7 +648,126 @@ class TestNonStrKeyTests:
 
     def test_dict_keys_unknown(self):
         with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps({frozenset(): True}, option=orjson.OPT_NON_STR_KEYS)
+            orjson.dumps({object(): True}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_dict_keys_frozenset(self):
+        """
+        frozenset key is a sorted array of its elements as keys
+        """
+        assert (
+            orjson.dumps({frozenset({2, "a", 10}): 1}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"[\\"10\\",\\"2\\",\\"a\\"]":1}'
+        )
+        assert (
+            orjson.dumps({frozenset(): 1}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"[]":1}'
+        )
+
+    def test_dict_keys_frozenset_order(self):
+        """
+        frozenset key does not depend on the order of iteration
+        """
+        elements = [str(idx) for idx in range(100)]
+        first = frozenset(elements)
+        second = frozenset(reversed(elements))
+        assert orjson.dumps(
+            {first: 1}, option=orjson.OPT_NON_STR_KEYS
+        ) == orjson.dumps({second: 1}, option=orjson.OPT_NON_STR_KEYS)
+        assert orjson.loads(
+            orjson.loads(orjson.dumps({first: 1}, option=orjson.OPT_NON_STR_KEYS))
+            .popitem()[0]
+        ) == sorted(elements)
+
+    def test_dict_keys_frozenset_mixed(self):
+        """
+        frozenset key elements are serialized as keys would be
+        """
+        obj = {
+            frozenset(
+                {None, True, 1.5, datetime.date(1970, 1, 1), frozenset({"b"})}
+            ): 1
+        }
+        key = orjson.loads(orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS))
+        assert orjson.loads(key.popitem()[0]) == sorted(
+            ["null", "true", "1.5", "1970-01-01", '["b"]']
+        )
+
+    def test_dict_keys_frozenset_escape(self):
+        """
+        frozenset key elements are escaped
+        """
+        obj = {frozenset({'a"', "b,c", "\n"}): 1}
+        key = orjson.loads(orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS))
+        assert orjson.loads(key.popitem()[0]) == sorted(['a"', "b,c", "\n"])
+
+    def test_dict_keys_frozenset_sort_keys(self):
+        """
+        frozenset key OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"b": 1, frozenset({1}): 2, "[": 3},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"[":3,"[\\"1\\"]":2,"b":1}'
+        )
+
+    def test_dict_keys_frozenset_duplicate(self):
+        """
+        frozenset key that is the same as another key once serialized
+        """
+        obj = {'["1"]': 1, frozenset({1}): 2}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"[\\"1\\"]":1,"[\\"1\\"]":2}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {frozenset({1}): 1, frozenset({"1"}): 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_REJECT_DUPLICATE_KEYS,
+            )
+
+    def test_dict_keys_frozenset_invalid(self):
+        """
+        frozenset key with an element that is not a valid key
+        """
+        for obj in ({frozenset({(1,)}): 1}, {frozenset({1, object()}): 1}):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_dict_keys_frozenset_recursion(self):
+        """
+        frozenset key nested too deeply
+        """
+        val: frozenset = frozenset()
+        for _ in range(100000):
+            val = frozenset([val])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({val: 1}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_dict_keys_frozenset_serialize_set(self):
+        """
+        frozenset key OPT_SERIALIZE_SET
+        """
+        assert (
+            orjson.dumps(
+                {frozenset({1}): frozenset({1})},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SERIALIZE_SET,
+            )
+            == b'{"[\\"1\\"]":[1]}'
+        )
+
+    def test_dict_keys_frozenset_without_option(self):
+        """
+        frozenset key without OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({frozenset({1}): 1})
 
     def test_dict_keys_no_str_call(self):
         class Obj:
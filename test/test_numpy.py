@@ -1,6 +1,6 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
-
+import datetime
 import sys
 
 import pytest
@@ -168,6 +168,37 @@ class TestNumpy:
             == b"[null,null,null,-0.0,0.0,3.140625]"
         )
 
+    def test_numpy_array_f16_as_f64(self):
+        obj = numpy.array(
+            [0.1, 0.33325195, -65504.0, 2**-24, -(2**-24), 2**-14 - 2**-24],
+            numpy.float16,
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NUMPY) == orjson.dumps(
+            obj.astype(numpy.float64), option=orjson.OPT_SERIALIZE_NUMPY
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NUMPY) == orjson.dumps(
+            obj.tolist()
+        )
+        assert orjson.dumps(
+            numpy.float16(0.1), option=orjson.OPT_SERIALIZE_NUMPY
+        ) == orjson.dumps(float(numpy.float16(0.1)))
+
+    def test_numpy_array_f16_allow_nonfinite(self):
+        assert (
+            orjson.dumps(
+                numpy.array([numpy.inf, -numpy.inf, numpy.nan, 1.0], numpy.float16),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_ALLOW_NONFINITE,
+            )
+            == b"[Infinity,-Infinity,NaN,1.0]"
+        )
+        assert (
+            orjson.dumps(
+                numpy.float16(numpy.inf),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_ALLOW_NONFINITE,
+            )
+            == b"Infinity"
+        )
+
     def test_numpy_array_f32_edge(self):
         assert (
             orjson.dumps(
@@ -206,6 +237,84 @@ class TestNumpy:
             == b"[null,null,null,-0.0,0.0,3.141592653589793]"
         )
 
+    def test_numpy_array_nonfinite_as_null(self):
+        """
+        OPT_NUMPY_NONFINITE_AS_NULL serializes non-finite elements as null
+        regardless of OPT_ALLOW_NONFINITE
+        """
+        for dtype in (numpy.float16, numpy.float32, numpy.float64):
+            obj = numpy.array(
+                [[numpy.nan, 1.5], [numpy.inf, -numpy.inf]], dtype  # type: ignore
+            )
+            for option in (0, orjson.OPT_ALLOW_NONFINITE):
+                assert (
+                    orjson.dumps(
+                        obj,
+                        option=orjson.OPT_SERIALIZE_NUMPY
+                        | orjson.OPT_NUMPY_NONFINITE_AS_NULL
+                        | option,
+                    )
+                    == b"[[null,1.5],[null,null]]"
+                )
+            assert (
+                orjson.dumps(
+                    obj,
+                    option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_ALLOW_NONFINITE,
+                )
+                == b"[[NaN,1.5],[Infinity,-Infinity]]"
+            )
+
+    def test_numpy_array_nonfinite_as_null_scalar(self):
+        """
+        OPT_NUMPY_NONFINITE_AS_NULL does not change scalars
+        """
+        option = (
+            orjson.OPT_SERIALIZE_NUMPY
+            | orjson.OPT_NUMPY_NONFINITE_AS_NULL
+            | orjson.OPT_ALLOW_NONFINITE
+        )
+        assert (
+            orjson.dumps([numpy.float64(numpy.nan), float("inf")], option=option)
+            == b"[NaN,Infinity]"
+        )
+
+    def test_numpy_array_nonfinite_canonical(self):
+        """
+        numpy non-finite elements raise with OPT_CANONICAL unless
+        OPT_NUMPY_NONFINITE_AS_NULL
+        """
+        obj = numpy.array([[1.0], [numpy.nan]], numpy.float64)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_CANONICAL
+            )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_NUMPY
+                | orjson.OPT_CANONICAL
+                | orjson.OPT_NUMPY_NONFINITE_AS_NULL,
+            )
+            == b"[[1],[null]]"
+        )
+
+    def test_numpy_array_nonfinite_as_null_record(self):
+        """
+        OPT_NUMPY_NONFINITE_AS_NULL applies to float fields of records
+        """
+        arr = numpy.array(
+            [(numpy.nan, 1, numpy.inf)], dtype=[("x", "f8"), ("y", "i4"), ("z", "f4")]
+        )
+        assert (
+            orjson.dumps(
+                arr,
+                option=orjson.OPT_SERIALIZE_NUMPY
+                | orjson.OPT_NUMPY_NONFINITE_AS_NULL
+                | orjson.OPT_ALLOW_NONFINITE,
+            )
+            == b'[{"x":null,"y":1,"z":null}]'
+        )
+
     def test_numpy_array_d1_f64(self):
         assert (
             orjson.dumps(
@@ -224,6 +333,56 @@ class TestNumpy:
             == b"[true,false,false,true]"
         )
 
+    def test_numpy_array_d1_bool_cast(self):
+        assert (
+            orjson.dumps(
+                numpy.array([0, 1, 2, -1], numpy.bool_),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"[false,true,true,true]"
+        )
+
+    def test_numpy_array_d1_bool_empty(self):
+        assert (
+            orjson.dumps(
+                numpy.array([], numpy.bool_),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"[]"
+        )
+
+    def test_numpy_array_d2_bool(self):
+        assert (
+            orjson.dumps(
+                numpy.array([[True, False, True], [False, True, False]]),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"[[true,false,true],[false,true,false]]"
+        )
+
+    def test_numpy_array_d3_bool(self):
+        array = numpy.array(
+            [[[True, False], [False, False]], [[True, True], [False, True]]]
+        )
+        assert array.dtype == numpy.bool_
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[[true,false],[false,false]],[[true,true],[false,true]]]"
+        )
+        assert orjson.loads(
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+        ) == array.tolist()
+
+    def test_numpy_array_d0_bool(self):
+        array = numpy.array(True)
+        assert array.ndim == 0
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"true"
+        assert orjson.dumps(array[()], option=orjson.OPT_SERIALIZE_NUMPY) == b"true"
+        assert (
+            orjson.dumps(numpy.array(False), option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"false"
+        )
+
     def test_numpy_array_d1_datetime64_years(self):
         assert (
             orjson.dumps(
@@ -364,6 +523,31 @@ class TestNumpy:
             == b'["2021-01-01T00:00:00","2021-01-01T00:00:00.172000","2021-01-01T00:00:00.567891"]'
         )
 
+    def test_numpy_array_d1_datetime64_nanoseconds_option(self):
+        assert (
+            orjson.dumps(
+                numpy.array(
+                    [
+                        numpy.datetime64("2021-01-01T00:00:00"),
+                        numpy.datetime64("2021-01-01T00:00:00.172"),
+                        numpy.datetime64("2021-01-01T00:00:00.567891234"),
+                    ]
+                ),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NANOSECOND,
+            )
+            == b'["2021-01-01T00:00:00","2021-01-01T00:00:00.172000000",'
+            b'"2021-01-01T00:00:00.567891234"]'
+        )
+        assert (
+            orjson.dumps(
+                numpy.datetime64("2021-01-01T00:00:00.000000001"),
+                option=orjson.OPT_SERIALIZE_NUMPY
+                | orjson.OPT_NANOSECOND
+                | orjson.OPT_NAIVE_UTC,
+            )
+            == b'"2021-01-01T00:00:00.000000001+00:00"'
+        )
+
     def test_numpy_array_d1_datetime64_picoseconds(self):
         try:
             orjson.dumps(
@@ -535,8 +719,7 @@ class TestNumpy:
     def test_numpy_array_dimension_zero(self):
         array = numpy.array(0)
         assert array.ndim == 0
-        with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"0"
 
         array = numpy.empty((0, 4, 2))
         assert (
@@ -571,6 +754,99 @@ class TestNumpy:
             == array.tolist()
         )
 
+    def test_numpy_array_d0_int(self):
+        for dtype in (
+            numpy.int8,
+            numpy.int16,
+            numpy.int32,
+            numpy.int64,
+            numpy.uint8,
+            numpy.uint16,
+            numpy.uint32,
+            numpy.uint64,
+        ):
+            array = numpy.array(numpy.iinfo(dtype).max, dtype)
+            assert array.ndim == 0
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == (
+                str(numpy.iinfo(dtype).max).encode()
+            )
+        array = numpy.array(-5, numpy.int64)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"-5"
+
+    def test_numpy_array_d0_float(self):
+        for dtype, expected in (
+            (numpy.float64, b"1.5"),
+            (numpy.float32, b"1.5"),
+            (numpy.float16, b"1.5"),
+        ):
+            array = numpy.array(1.5, dtype)
+            assert array.ndim == 0
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == expected
+
+    def test_numpy_array_d0_scalar(self):
+        """
+        0-d array is serialized as its scalar and not as a 1-element array
+        """
+        for array in (
+            numpy.array(5),
+            numpy.array(0.1, numpy.float32),
+            numpy.array(True),
+            numpy.array(numpy.datetime64("2021-01-01T00:00:00")),
+            numpy.asarray(numpy.arange(10).sum()),
+            numpy.asarray(numpy.array([1.5, 2.5]).mean()),
+        ):
+            assert array.ndim == 0
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == (
+                orjson.dumps(array[()], option=orjson.OPT_SERIALIZE_NUMPY)
+            )
+            assert orjson.dumps(
+                array.reshape(1), option=orjson.OPT_SERIALIZE_NUMPY
+            ) == b"[" + orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) + b"]"
+        assert (
+            orjson.dumps(
+                {"a": [numpy.array(1), numpy.array(2.0)]},
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b'{"a":[1,2.0]}'
+        )
+
+    def test_numpy_array_d0_nonfinite(self):
+        """
+        0-d array is not affected by OPT_NUMPY_NONFINITE_AS_NULL, as its scalar
+        """
+        option = (
+            orjson.OPT_SERIALIZE_NUMPY
+            | orjson.OPT_NUMPY_NONFINITE_AS_NULL
+            | orjson.OPT_ALLOW_NONFINITE
+        )
+        assert orjson.dumps(numpy.array(numpy.nan), option=option) == b"NaN"
+        assert orjson.dumps(numpy.float64(numpy.nan), option=option) == b"NaN"
+        assert orjson.dumps(numpy.array([numpy.nan]), option=option) == b"[null]"
+
+    def test_numpy_array_d0_datetime64(self):
+        assert (
+            orjson.dumps(
+                numpy.array(numpy.datetime64("2021-01-01T00:00:00.172")),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b'"2021-01-01T00:00:00.172000"'
+        )
+        assert (
+            orjson.dumps(
+                numpy.array(numpy.datetime64("NaT")),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"null"
+        )
+
+    def test_numpy_array_d0_record(self):
+        array = numpy.zeros((), dtype=[("x", "i4"), ("y", "f8")])
+        assert array.ndim == 0
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'{"x":0,"y":0.0}'
+        )
+
     def test_numpy_array_dimension_max(self):
         array = numpy.random.rand(
             1,
@@ -718,6 +994,29 @@ class TestNumpy:
             == b'{"a":true,"b":false}'
         )
 
+    def test_numpy_bool_top_level(self):
+        assert orjson.dumps(numpy.bool_(True), option=orjson.OPT_SERIALIZE_NUMPY) == (
+            b"true"
+        )
+        assert orjson.dumps(
+            [numpy.bool_(False), numpy.array([True])[0]],
+            option=orjson.OPT_SERIALIZE_NUMPY,
+        ) == b"[false,true]"
+
+    def test_numpy_datetime_rfc2822(self):
+        obj = numpy.datetime64("1994-11-06T08:49:37.500")
+        option = orjson.OPT_SERIALIZE_NUMPY
+        val = orjson.dumps(
+            [obj, numpy.array([obj])],
+            option=option | orjson.OPT_NAIVE_UTC,
+            datetime_format="rfc2822",
+        )
+        assert val == (
+            b'["Sun, 06 Nov 1994 08:49:37 GMT",["Sun, 06 Nov 1994 08:49:37 GMT"]]'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=option, datetime_format="rfc2822")
+
     def test_numpy_datetime(self):
         assert (
             orjson.dumps(
@@ -797,10 +1096,232 @@ class TestNumpy:
         )
 
     def test_numpy_datetime_nat(self):
-        with pytest.raises(orjson.JSONEncodeError):
+        assert (
             orjson.dumps(numpy.datetime64("NaT"), option=orjson.OPT_SERIALIZE_NUMPY)
-        with pytest.raises(orjson.JSONEncodeError):
+            == b"null"
+        )
+        assert (
             orjson.dumps([numpy.datetime64("NaT")], option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[null]"
+        )
+        assert (
+            orjson.dumps(
+                numpy.datetime64("NaT", "ns"), option=orjson.OPT_SERIALIZE_NUMPY
+            )
+            == b"null"
+        )
+
+    def test_numpy_array_datetime64_nat(self):
+        assert (
+            orjson.dumps(
+                numpy.array(["2021-01-01", "NaT"], numpy.dtype("datetime64[D]")),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b'["2021-01-01T00:00:00",null]'
+        )
+        assert (
+            orjson.dumps(
+                numpy.array(["NaT", "2021-01-01T00:00:00.5"], "datetime64[ns]"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NAIVE_UTC,
+            )
+            == b'[null,"2021-01-01T00:00:00.500000+00:00"]'
+        )
+
+    def test_numpy_datetime_before_epoch(self):
+        for unit in ("ms", "us", "ns"):
+            assert (
+                orjson.dumps(
+                    numpy.array(["1969-12-31T23:59:59.5"], f"datetime64[{unit}]"),
+                    option=orjson.OPT_SERIALIZE_NUMPY,
+                )
+                == b'["1969-12-31T23:59:59.500000"]'
+            )
+        assert (
+            orjson.dumps(
+                numpy.datetime64("1969-11", "M"), option=orjson.OPT_SERIALIZE_NUMPY
+            )
+            == b'"1969-11-01T00:00:00"'
+        )
+
+    def test_numpy_datetime_nanoseconds_range(self):
+        assert (
+            orjson.dumps(
+                numpy.array(
+                    ["1677-09-21T00:12:43.145224193", "2262-04-11T23:47:16.854775807"],
+                    "datetime64[ns]",
+                ),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_UTC_Z,
+            )
+            == b'["1677-09-21T00:12:43.145224","2262-04-11T23:47:16.854775"]'
+        )
+
+    def test_numpy_datetime_unrepresentable(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                numpy.datetime64(10**15, "D"), option=orjson.OPT_SERIALIZE_NUMPY
+            )
+
+    def test_numpy_timedelta_seconds(self):
+        assert (
+            orjson.dumps(
+                numpy.timedelta64(90, "m"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_SECONDS,
+            )
+            == b"5400.0"
+        )
+        assert orjson.dumps(
+            numpy.array([1, -1500000000, "NaT"], "timedelta64[ns]"),
+            option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_SECONDS,
+        ) == orjson.dumps([1e-9, -1.5, None])
+
+    def test_numpy_timedelta_iso8601(self):
+        assert (
+            orjson.dumps(
+                numpy.timedelta64(90, "m"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'"PT1H30M"'
+        )
+        assert (
+            orjson.dumps(
+                numpy.array([1, -1500000000, 0, "NaT"], "timedelta64[ns]"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'["PT0.000000001S","-PT1.5S","PT0S",null]'
+        )
+        assert (
+            orjson.dumps(
+                numpy.array([2, 9], "timedelta64[W]"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'["P14D","P63D"]'
+        )
+
+    def test_numpy_timedelta_matches_timedelta(self):
+        value = datetime.timedelta(days=-3, seconds=5, microseconds=7)
+        for option in (orjson.OPT_TIMEDELTA_SECONDS, orjson.OPT_TIMEDELTA_ISO8601):
+            assert orjson.dumps(
+                numpy.array([value], "timedelta64[us]"),
+                option=orjson.OPT_SERIALIZE_NUMPY | option,
+            ) == orjson.dumps([value], option=option)
+
+    def test_numpy_timedelta_requires_option(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(numpy.timedelta64(1, "s"), option=orjson.OPT_SERIALIZE_NUMPY)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                numpy.array([1], "timedelta64[s]"), option=orjson.OPT_SERIALIZE_NUMPY
+            )
+        assert (
+            orjson.dumps(
+                numpy.array([1], "timedelta64[s]"),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+                default=lambda obj: obj.astype(numpy.int64).tolist(),
+            )
+            == b"[1]"
+        )
+
+    def test_numpy_timedelta_unsupported_unit(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                numpy.timedelta64(1, "Y"),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_TIMEDELTA_SECONDS,
+            )
+        assert str(exc_info.value) == "unsupported numpy.timedelta64 unit: years"
+
+    def test_numpy_array_structured(self):
+        arr = numpy.array([(1.5, 2), (-0.0, -7)], dtype=[("x", "f8"), ("y", "i4")])
+        assert (
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"x":1.5,"y":2},{"x":-0.0,"y":-7}]'
+        )
+        assert orjson.dumps(
+            arr, option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_INDENT_2
+        ) == orjson.dumps(
+            [{"x": 1.5, "y": 2}, {"x": -0.0, "y": -7}], option=orjson.OPT_INDENT_2
+        )
+
+    def test_numpy_array_structured_unaligned(self):
+        arr = numpy.array(
+            [(True, 0.1, "ab"), (False, 2.0, "é€x")],
+            dtype=[("flag", "?"), ("v", "f8"), ("name", "U3")],
+        )
+        assert (
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+            == '[{"flag":true,"v":0.1,"name":"ab"},{"flag":false,"v":2.0,"name":"é€x"}]'.encode()
+        )
+
+    def test_numpy_array_structured_aligned(self):
+        dtype = numpy.dtype([("a", "u1"), ("b", "f8"), ("c", "i2")], align=True)
+        arr = numpy.array([(1, 2.5, -3)], dtype=dtype)
+        assert (
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":2.5,"c":-3}]'
+        )
+
+    def test_numpy_array_structured_sort_keys(self):
+        dtype = numpy.dtype([("b", "i4"), ("inner", [("z", "u1"), ("a", "u1")])])
+        arr = numpy.array([(1, (2, 3))], dtype=dtype)
+        assert (
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_SORT_KEYS)
+            == b'[{"b":1,"inner":{"a":3,"z":2}}]'
+        )
+
+    def test_numpy_array_structured_nested(self):
+        dtype = numpy.dtype(
+            [
+                ("id", "i4"),
+                ("inner", [("a", "u1"), ("b", "i4", (2, 2))]),
+                ("pts", [("x", "f8"), ("y", "i4")], (2,)),
+                ("dt", "M8[s]"),
+            ]
+        )
+        arr = numpy.array(
+            [(9, (3, [[1, 2], [3, 4]]), [(1.0, 1), (2.0, 2)], "NaT")] * 4,
+            dtype=dtype,
+        ).reshape(2, 2)
+        row = b'{"id":9,"inner":{"a":3,"b":[[1,2],[3,4]]},"pts":[{"x":1.0,"y":1},{"x":2.0,"y":2}],"dt":null}'
+        assert (
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[" + row + b"," + row + b"],[" + row + b"," + row + b"]]"
+        )
+
+    def test_numpy_array_structured_datetime(self):
+        arr = numpy.array(
+            [("2021-01-01T00:00:00.5", 61)],
+            dtype=[("at", "M8[ms]"), ("took", "m8[s]")],
+        )
+        assert (
+            orjson.dumps(
+                arr,
+                option=orjson.OPT_SERIALIZE_NUMPY
+                | orjson.OPT_NAIVE_UTC
+                | orjson.OPT_UTC_Z
+                | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'[{"at":"2021-01-01T00:00:00.500000Z","took":"PT1M1S"}]'
+        )
+
+    def test_numpy_array_structured_unsupported(self):
+        arr = numpy.array([(b"ab", 1)], dtype=[("a", "S2"), ("b", "i4")])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+        assert (
+            orjson.dumps(
+                arr,
+                option=orjson.OPT_SERIALIZE_NUMPY,
+                default=lambda obj: [[each.decode() for each in obj["a"]]],
+            )
+            == b'[["ab"]]'
+        )
+        arr = numpy.array([(1,)], dtype=[("a", "m8[s]")])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_array_structured_big_endian(self):
+        arr = numpy.array([(1.0,)], dtype=[("a", ">f8")])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(arr, option=orjson.OPT_SERIALIZE_NUMPY)
 
     def test_numpy_repeated(self):
         data = numpy.array([[[1, 2], [3, 4], [5, 6], [7, 8]]], numpy.int64)  # type: ignore
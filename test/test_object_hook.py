@@ -0,0 +1,154 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import json
+import sys
+import types
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+class TestObjectHook:
+    def test_object_hook(self):
+        """
+        loads() object_hook replaces each object
+        """
+        def hook(obj):
+            return types.SimpleNamespace(**obj)
+
+        val = orjson.loads('{"a": {"b": 1}}', object_hook=hook)
+        assert isinstance(val, types.SimpleNamespace)
+        assert isinstance(val.a, types.SimpleNamespace)
+        assert val.a.b == 1
+
+    def test_object_hook_bottom_up(self):
+        """
+        loads() object_hook is called with complete objects, innermost first
+        """
+        calls = []
+
+        def hook(obj):
+            calls.append(dict(obj))
+            return len(calls)
+
+        val = orjson.loads('[{"a": {"b": {}}}, {"c": [{}]}]', object_hook=hook)
+        assert val == [3, 5]
+        assert calls == [{}, {"b": 1}, {"a": 2}, {}, {"c": [4]}]
+
+    def test_object_hook_empty(self):
+        """
+        loads() object_hook is called for empty objects
+        """
+        assert orjson.loads("{}", object_hook=lambda obj: "x") == "x"
+        assert orjson.loads(b"{}", object_hook=lambda obj: "x") == "x"
+        assert orjson.loads("[]", object_hook=lambda obj: "x") == []
+        assert orjson.loads('""', object_hook=lambda obj: "x") == ""
+
+    def test_object_hook_scalar(self):
+        """
+        loads() object_hook is not called without objects
+        """
+
+        def hook(obj):
+            raise AssertionError
+
+        assert orjson.loads("[1, [2.5], null]", object_hook=hook) == [1, [2.5], None]
+
+    def test_object_hook_same_as_json(self):
+        """
+        loads() object_hook output is the same as json.loads()
+        """
+
+        def hook(obj):
+            return sorted(obj.items())
+
+        for filename in ("twitter.json.xz", "github.json.xz"):
+            doc = orjson.dumps(read_fixture_obj(filename))
+            assert orjson.loads(doc, object_hook=hook) == json.loads(
+                doc, object_hook=hook
+            )
+
+    def test_object_hook_none(self):
+        """
+        loads() object_hook=None is the same as not specifying it
+        """
+        assert orjson.loads('{"a": {}}', object_hook=None) == {"a": {}}
+        assert orjson.loads("{}", object_hook=None) == {}
+
+    def test_object_hook_option(self):
+        """
+        loads() object_hook with option and max_depth
+        """
+        val = orjson.loads(
+            '{"a": 1.5}',
+            option=orjson.OPT_PARSE_DECIMAL,
+            max_depth=1,
+            object_hook=lambda obj: list(obj.values()),
+        )
+        assert val == [orjson.loads("1.5", option=orjson.OPT_PARSE_DECIMAL)]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads('{"a": {}}', max_depth=1, object_hook=dict)
+
+    def test_object_hook_exception(self):
+        """
+        loads() object_hook exception propagates unchanged
+        """
+
+        class CustomError(Exception):
+            pass
+
+        def hook(obj):
+            if "b" in obj:
+                raise CustomError("zxc")
+            return obj
+
+        with pytest.raises(CustomError) as exc_info:
+            orjson.loads('[{"a": 1}, {"b": [{"c": 2}]}, {"d": 3}]', object_hook=hook)
+        assert not isinstance(exc_info.value, orjson.JSONDecodeError)
+        assert str(exc_info.value) == "zxc"
+
+    def test_object_hook_exception_refcount(self):
+        """
+        loads() object_hook exception releases objects
+        """
+        sentinel = object()
+        ref = sys.getrefcount(sentinel)
+        calls = []
+
+        def hook(obj):
+            calls.append(obj)
+            if len(calls) == 3:
+                raise ValueError
+            return sentinel
+
+        with pytest.raises(ValueError):
+            orjson.loads('[{}, {"a": {}, "b": {"c": 1}}]', object_hook=hook)
+        calls.clear()
+        assert sys.getrefcount(sentinel) == ref
+
+    def test_object_hook_invalid_document(self):
+        """
+        loads() object_hook with invalid JSON raises JSONDecodeError
+        """
+        calls = []
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads('[{"a": 1}, {"b": ]', object_hook=calls.append)
+        assert calls == [{"a": 1}]
+
+    def test_object_hook_not_callable(self):
+        """
+        loads() object_hook that is not callable raises TypeError
+        """
+        with pytest.raises(TypeError):
+            orjson.loads("{}", object_hook=1)  # type: ignore
+        assert orjson.loads("[]", object_hook=1) == []  # type: ignore
+
+    def test_object_hook_positional(self):
+        """
+        loads() object_hook is keyword-only
+        """
+        with pytest.raises(TypeError):
+            orjson.loads("{}", None, dict)  # type: ignore
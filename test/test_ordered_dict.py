@@ -0,0 +1,149 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import collections
+
+import pytest
+
+import orjson
+
+PRESERVE_SORT = orjson.OPT_PRESERVE_ORDERED_DICT | orjson.OPT_SORT_KEYS
+
+
+class OrderedDictSubclass(collections.OrderedDict):
+    pass
+
+
+class TestOrderedDict:
+    def test_ordered_dict_default(self):
+        """
+        OrderedDict is serialized as a dict
+        """
+        obj = collections.OrderedDict([("b", 1), ("a", 2)])
+        assert orjson.dumps(obj) == b'{"b":1,"a":2}'
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == b'{"a":2,"b":1}'
+
+    def test_ordered_dict_preserve(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT ignores OPT_SORT_KEYS for OrderedDict
+        """
+        obj = collections.OrderedDict([("b", 1), ("c", 2), ("a", 3)])
+        assert orjson.dumps(obj, option=PRESERVE_SORT) == b'{"b":1,"c":2,"a":3}'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_PRESERVE_ORDERED_DICT)
+            == b'{"b":1,"c":2,"a":3}'
+        )
+
+    def test_ordered_dict_preserve_nested_dict(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT sorts a dict nested in an OrderedDict
+        """
+        obj = collections.OrderedDict(
+            [("b", {"d": 1, "c": 2}), ("a", [{"f": 3, "e": 4}])]
+        )
+        assert (
+            orjson.dumps(obj, option=PRESERVE_SORT)
+            == b'{"b":{"c":2,"d":1},"a":[{"e":4,"f":3}]}'
+        )
+
+    def test_ordered_dict_preserve_nested_ordered_dict(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT OrderedDict nested in a dict
+        """
+        obj = {"b": collections.OrderedDict([("d", 1), ("c", 2)]), "a": 3}
+        assert orjson.dumps(obj, option=PRESERVE_SORT) == b'{"a":3,"b":{"d":1,"c":2}}'
+
+    def test_ordered_dict_preserve_move_to_end(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT uses the order after move_to_end()
+        """
+        obj = collections.OrderedDict([("a", 1), ("b", 2), ("c", 3)])
+        obj.move_to_end("a")
+        obj.move_to_end("c", last=False)
+        assert list(obj) == ["c", "b", "a"]
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_PRESERVE_ORDERED_DICT)
+            == b'{"c":3,"b":2,"a":1}'
+        )
+
+    def test_ordered_dict_preserve_subclass(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT OrderedDict subclass
+        """
+        obj = OrderedDictSubclass([("b", 1), ("a", 2)])
+        assert orjson.dumps(obj, option=PRESERVE_SORT) == b'{"b":1,"a":2}'
+
+    def test_ordered_dict_preserve_empty(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT empty OrderedDict
+        """
+        assert orjson.dumps(collections.OrderedDict(), option=PRESERVE_SORT) == b"{}"
+
+    def test_ordered_dict_preserve_indent(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT with OPT_INDENT_2
+        """
+        obj = collections.OrderedDict([("b", 1), ("a", 2)])
+        assert (
+            orjson.dumps(obj, option=PRESERVE_SORT | orjson.OPT_INDENT_2)
+            == b'{\n  "b": 1,\n  "a": 2\n}'
+        )
+
+    def test_ordered_dict_preserve_skip_none(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT with OPT_SKIP_NONE_VALUES
+        """
+        obj = collections.OrderedDict([("b", None), ("a", 2)])
+        assert (
+            orjson.dumps(obj, option=PRESERVE_SORT | orjson.OPT_SKIP_NONE_VALUES)
+            == b'{"a":2}'
+        )
+
+    def test_ordered_dict_preserve_non_str_keys(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT with OPT_NON_STR_KEYS
+        """
+        obj = collections.OrderedDict([(2, 1), ("b", 2), (None, 3)])
+        assert (
+            orjson.dumps(obj, option=PRESERVE_SORT | orjson.OPT_NON_STR_KEYS)
+            == b'{"2":1,"b":2,"null":3}'
+        )
+
+    def test_ordered_dict_preserve_non_str_key_invalid(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT non-str key without OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(collections.OrderedDict([(1, 1)]), option=PRESERVE_SORT)
+
+    def test_ordered_dict_preserve_iter_error(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT chains an exception raised by __iter__
+        """
+
+        class Subclass(collections.OrderedDict):
+            def __iter__(self):
+                raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Subclass(a=1), option=PRESERVE_SORT)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_ordered_dict_preserve_iter_missing_key(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT __iter__ yielding a missing key
+        """
+
+        class Subclass(collections.OrderedDict):
+            def __iter__(self):
+                return iter(["b"])
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Subclass(a=1), option=PRESERVE_SORT)
+
+    def test_ordered_dict_preserve_passthrough_subclass(self):
+        """
+        OPT_PRESERVE_ORDERED_DICT with OPT_PASSTHROUGH_SUBCLASS
+        """
+        obj = collections.OrderedDict([("b", 1), ("a", 2)])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=PRESERVE_SORT | orjson.OPT_PASSTHROUGH_SUBCLASS)
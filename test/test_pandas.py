@@ -0,0 +1,154 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import datetime
+
+import pytest
+
+import orjson
+
+try:
+    import pandas
+except ImportError:
+    pandas = None  # type: ignore
+
+
+@pytest.mark.skipif(pandas is None, reason="pandas is not installed")
+class TestPandas:
+    def test_pandas_timestamp(self):
+        """
+        pandas.Timestamp
+        """
+        assert (
+            orjson.dumps(
+                pandas.Timestamp("2020-01-02T03:04:05.123456"),
+                option=orjson.OPT_SERIALIZE_PANDAS,
+            )
+            == b'"2020-01-02T03:04:05.123456"'
+        )
+
+    def test_pandas_timestamp_nanosecond(self):
+        """
+        pandas.Timestamp nanoseconds are truncated
+        """
+        assert (
+            orjson.dumps(
+                pandas.Timestamp("2020-01-02T03:04:05.123456789"),
+                option=orjson.OPT_SERIALIZE_PANDAS,
+            )
+            == b'"2020-01-02T03:04:05.123456"'
+        )
+
+    def test_pandas_timestamp_nanosecond_option(self):
+        """
+        pandas.Timestamp OPT_NANOSECOND keeps nanoseconds
+        """
+        option = orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_NANOSECOND
+        for val, ref in (
+            ("2020-01-02T03:04:05.123456789", b'"2020-01-02T03:04:05.123456789"'),
+            ("2020-01-02T03:04:05.000000001", b'"2020-01-02T03:04:05.000000001"'),
+            ("2020-01-02T03:04:05.5", b'"2020-01-02T03:04:05.500000000"'),
+            ("2020-01-02T03:04:05", b'"2020-01-02T03:04:05"'),
+        ):
+            assert orjson.dumps(pandas.Timestamp(val), option=option) == ref
+        assert (
+            orjson.dumps(
+                {pandas.Timestamp("2020-01-02T03:04:05.123456789", tz="UTC"): 1},
+                option=option | orjson.OPT_NON_STR_KEYS | orjson.OPT_UTC_Z,
+            )
+            == b'{"2020-01-02T03:04:05.123456789Z":1}'
+        )
+
+    def test_pandas_timestamp_utc(self):
+        """
+        pandas.Timestamp with a UTC timezone
+        """
+        val = pandas.Timestamp("2020-01-02T03:04:05", tz="UTC")
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_PANDAS)
+            == b'"2020-01-02T03:04:05+00:00"'
+        )
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_UTC_Z)
+            == b'"2020-01-02T03:04:05Z"'
+        )
+
+    def test_pandas_timestamp_naive_utc(self):
+        """
+        pandas.Timestamp OPT_NAIVE_UTC
+        """
+        assert (
+            orjson.dumps(
+                pandas.Timestamp("2020-01-02T03:04:05"),
+                option=orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_NAIVE_UTC,
+            )
+            == b'"2020-01-02T03:04:05+00:00"'
+        )
+
+    def test_pandas_timestamp_omit_microseconds(self):
+        """
+        pandas.Timestamp OPT_OMIT_MICROSECONDS
+        """
+        assert (
+            orjson.dumps(
+                pandas.Timestamp("2020-01-02T03:04:05.123456789"),
+                option=orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_OMIT_MICROSECONDS,
+            )
+            == b'"2020-01-02T03:04:05"'
+        )
+
+    def test_pandas_nat(self):
+        """
+        pandas.NaT is null
+        """
+        assert (
+            orjson.dumps(
+                {"a": pandas.NaT, "b": [pandas.NaT]},
+                option=orjson.OPT_SERIALIZE_PANDAS,
+            )
+            == b'{"a":null,"b":[null]}'
+        )
+
+    def test_pandas_non_str_keys(self):
+        """
+        pandas.Timestamp dict key
+        """
+        assert (
+            orjson.dumps(
+                {pandas.Timestamp("2020-01-02T03:04:05"): 1},
+                option=orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2020-01-02T03:04:05":1}'
+        )
+
+    def test_pandas_not_enabled(self):
+        """
+        pandas.Timestamp and pandas.NaT are not serialized without the option
+        """
+        for val in (pandas.Timestamp("2020-01-02T03:04:05"), pandas.NaT):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(val)
+            assert orjson.dumps(val, default=lambda _: "x") == b'"x"'
+
+    def test_pandas_passthrough_datetime(self):
+        """
+        pandas.Timestamp and pandas.NaT OPT_PASSTHROUGH_DATETIME
+        """
+        option = orjson.OPT_SERIALIZE_PANDAS | orjson.OPT_PASSTHROUGH_DATETIME
+        for val in (pandas.Timestamp("2020-01-02T03:04:05"), pandas.NaT):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(val, option=option)
+
+    def test_pandas_datetime_subclass(self):
+        """
+        datetime.datetime subclass that is not from pandas is a datetime
+        """
+
+        class Subclass(datetime.datetime):
+            pass
+
+        assert (
+            orjson.dumps(
+                Subclass(2020, 1, 2, 3, 4, 5), option=orjson.OPT_SERIALIZE_PANDAS
+            )
+            == b'"2020-01-02T03:04:05"'
+        )
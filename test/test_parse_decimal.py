@@ -0,0 +1,143 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import decimal
+import json
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_str
+
+
+class TestParseDecimal:
+    def test_parse_decimal_default(self):
+        """
+        loads() deserializes numbers with a fraction to float by default
+        """
+        assert orjson.loads("0.1") == 0.1
+        assert type(orjson.loads("0.1")) is float
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "0.1",
+            "-0.1",
+            "1.0",
+            "1.00",
+            "-0.0",
+            "1e2",
+            "1E+2",
+            "1e-2",
+            "1.5E-10",
+            "0.0842389659712649442845",
+            "123456789012345678901234567890.123456789",
+            "1e400",
+            "-1e-400",
+        ],
+    )
+    def test_parse_decimal(self, val):
+        """
+        loads() OPT_PARSE_DECIMAL preserves the exact text
+        """
+        obj = orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL)
+        assert type(obj) is decimal.Decimal
+        assert str(obj) == str(decimal.Decimal(val))
+        assert obj.as_tuple() == decimal.Decimal(val).as_tuple()
+
+    def test_parse_decimal_trailing_zero(self):
+        """
+        loads() OPT_PARSE_DECIMAL does not normalize
+        """
+        obj = orjson.loads("[1.10, 2.000]", option=orjson.OPT_PARSE_DECIMAL)
+        assert [str(each) for each in obj] == ["1.10", "2.000"]
+
+    def test_parse_decimal_int(self):
+        """
+        loads() OPT_PARSE_DECIMAL integers are int
+        """
+        val = "[0, -1, 18446744073709551615]"
+        obj = orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL)
+        assert obj == [0, -1, 18446744073709551615]
+        assert all(type(each) is int for each in obj)
+
+    def test_parse_decimal_big_int(self):
+        """
+        loads() OPT_PARSE_DECIMAL integers overflowing 64 bits are int
+        """
+        val = "123456789012345678901234567890"
+        assert orjson.loads(val) == int(val)
+        obj = orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL)
+        assert type(obj) is int
+        assert obj == 123456789012345678901234567890
+        assert orjson.loads("-" + val, option=orjson.OPT_PARSE_DECIMAL) == -int(val)
+
+    def test_parse_decimal_nested(self):
+        """
+        loads() OPT_PARSE_DECIMAL in containers
+        """
+        assert orjson.loads(
+            '{"a": [1.5, {"b": 2.25}], "c": "3.5", "d": 4}',
+            option=orjson.OPT_PARSE_DECIMAL,
+        ) == {
+            "a": [decimal.Decimal("1.5"), {"b": decimal.Decimal("2.25")}],
+            "c": "3.5",
+            "d": 4,
+        }
+
+    def test_parse_decimal_roundtrip(self):
+        """
+        loads(), dumps() OPT_PARSE_DECIMAL, OPT_SERIALIZE_DECIMAL
+        """
+        val = b'{"amount":0.0842389659712649442845,"count":3}'
+        obj = orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL)
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_DECIMAL) == val
+
+    @pytest.mark.parametrize(
+        "filename",
+        [
+            "canada.json.xz",
+            "citm_catalog.json.xz",
+            "github.json.xz",
+            "twitter.json.xz",
+        ],
+    )
+    def test_parse_decimal_fixture(self, filename):
+        """
+        loads() OPT_PARSE_DECIMAL fixtures
+        """
+        val = read_fixture_str(filename)
+        assert orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL) == json.loads(
+            val, parse_float=decimal.Decimal
+        )
+
+    def test_parse_decimal_error_pos(self):
+        """
+        loads() OPT_PARSE_DECIMAL error position
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[1.5, 2.]", option=orjson.OPT_PARSE_DECIMAL)
+        assert exc_info.value.pos == 8
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "1.",
+            ".1",
+            "-",
+            "-.1",
+            "01.5",
+            "1e",
+            "1e+",
+            "1.5x",
+            "[1.5,]",
+            "NaN",
+            "Infinity",
+        ],
+    )
+    def test_parse_decimal_invalid(self, val):
+        """
+        loads() OPT_PARSE_DECIMAL invalid number
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(val, option=orjson.OPT_PARSE_DECIMAL)
@@ -0,0 +1,142 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import uuid
+
+import pytest
+
+import orjson
+
+
+def loads_uuid(data, option=0, **kwargs):
+    return orjson.loads(data, option=orjson.OPT_PARSE_UUID | option, **kwargs)
+
+
+class TestParseUUID:
+    def test_parse_uuid_default(self):
+        """
+        loads() deserializes UUID strings to str by default
+        """
+        obj = orjson.loads('"7202d115-7ff3-4c81-a7c1-2a1f067b1ece"')
+        assert type(obj) is str
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1ece",
+            "00000000-0000-0000-0000-000000000000",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d",
+            "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "12345678-1234-1234-1234-123456789abc",
+        ],
+    )
+    def test_parse_uuid(self, val):
+        """
+        loads() OPT_PARSE_UUID
+        """
+        obj = loads_uuid(f'"{val}"')
+        assert type(obj) is uuid.UUID
+        assert obj == uuid.UUID(val)
+        assert str(obj) == val
+
+    def test_parse_uuid_uppercase(self):
+        """
+        loads() OPT_PARSE_UUID hexadecimal digits of either case
+        """
+        val = "7202D115-7ff3-4C81-A7C1-2a1f067b1ECE"
+        assert loads_uuid(f'"{val}"') == uuid.UUID(val)
+
+    def test_parse_uuid_version_variant(self):
+        """
+        loads() OPT_PARSE_UUID does not check the version or variant
+        """
+        for val in (
+            "7202d115-7ff3-0c81-07c1-2a1f067b1ece",
+            "7202d115-7ff3-fc81-e7c1-2a1f067b1ece",
+        ):
+            obj = loads_uuid(f'"{val}"')
+            assert type(obj) is uuid.UUID
+            assert str(obj) == val
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            "",
+            "7202d115",
+            "7202d1157ff34c81a7c12a1f067b1ece",
+            "{7202d115-7ff3-4c81-a7c1-2a1f067b1ece}",
+            "urn:uuid:7202d115-7ff3-4c81-a7c1-2a1f067b1ece",
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1ec",
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1ecee",
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1ecg",
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1ec-",
+            "7202d1157-ff3-4c81-a7c1-2a1f067b1ece",
+            "7202d115_7ff3_4c81_a7c1_2a1f067b1ece",
+            " 202d115-7ff3-4c81-a7c1-2a1f067b1ece",
+            "7202d115-7ff3-4c81-a7c1-2a1f067b1é",
+        ],
+    )
+    def test_parse_uuid_not_uuid(self, val):
+        """
+        loads() OPT_PARSE_UUID strings not in the canonical form are str
+        """
+        obj = loads_uuid(orjson.dumps(val))
+        assert type(obj) is str
+        assert obj == val
+
+    def test_parse_uuid_escaped(self):
+        """
+        loads() OPT_PARSE_UUID string with escapes is compared unescaped
+        """
+        obj = loads_uuid('"\\u0037202d115-7ff3-4c81-a7c1-2a1f067b1ece"')
+        assert obj == uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+
+    def test_parse_uuid_nested(self):
+        """
+        loads() OPT_PARSE_UUID in arrays and object values, not keys
+        """
+        val = "7202d115-7ff3-4c81-a7c1-2a1f067b1ece"
+        obj = loads_uuid(f'{{"{val}": ["{val}", {{"a": "{val}"}}], "b": "c"}}')
+        assert obj == {
+            val: [uuid.UUID(val), {"a": uuid.UUID(val)}],
+            "b": "c",
+        }
+        assert type([*obj][0]) is str
+
+    def test_parse_uuid_roundtrip(self):
+        """
+        loads() OPT_PARSE_UUID of dumps() of uuid.UUID
+        """
+        obj = [uuid.uuid4() for _ in range(100)]
+        assert loads_uuid(orjson.dumps(obj)) == obj
+
+    def test_parse_uuid_object_hook(self):
+        """
+        loads() OPT_PARSE_UUID values are uuid.UUID in object_hook
+        """
+        val = "7202d115-7ff3-4c81-a7c1-2a1f067b1ece"
+        obj = loads_uuid(f'{{"a": "{val}"}}', object_hook=lambda obj: type(obj["a"]))
+        assert obj is uuid.UUID
+
+    def test_parse_uuid_dict_as_pairs(self):
+        """
+        loads() OPT_PARSE_UUID OPT_DICT_AS_PAIRS uuid.UUID key
+        """
+        val = "7202d115-7ff3-4c81-a7c1-2a1f067b1ece"
+        assert loads_uuid(f'[["{val}", 1]]', orjson.OPT_DICT_AS_PAIRS) == {
+            uuid.UUID(val): 1
+        }
+
+    def test_parse_uuid_error(self):
+        """
+        loads() OPT_PARSE_UUID invalid document
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            loads_uuid('["7202d115-7ff3-4c81-a7c1-2a1f067b1ece"')
+
+    def test_parse_uuid_dumps(self):
+        """
+        dumps() does not accept OPT_PARSE_UUID
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([], option=orjson.OPT_PARSE_UUID)
@@ -1,10 +1,28 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import decimal
+import json
+import os
+
 import pytest
 
 import orjson
 
-from .util import read_fixture_bytes
+from .util import dirname, read_fixture_bytes
+
+PARSING_FIXTURES = sorted(
+    filename
+    for filename in os.listdir(os.path.join(dirname, "parsing"))
+    if not filename.startswith("i_")
+)
+
+# Arguments to loads() that do not change the result but cause the document to
+# be deserialized by the parser rather than by yyjson or serde_json.
+PARSER_KWARGS = (
+    {"object_hook": lambda obj: obj},
+    {"array_hook": lambda arr: arr},
+    {"option": orjson.OPT_INTERN_KEYS},
+)
 
 
 class TestJSONTestSuiteParsing:
@@ -1943,4 +1961,42 @@ class TestJSONTestSuiteParsing:
         """
         i_structure_UTF-8_BOM_empty_object.json
         """
-        self._run_fail_json("i_structure_UTF-8_BOM_empty_object.json")
+        self._run_pass_json("i_structure_UTF-8_BOM_empty_object.json")
+
+
+class TestJSONTestSuiteParsingOption:
+    @pytest.mark.parametrize("filename", PARSING_FIXTURES)
+    def test_option(self, filename):
+        """
+        loads() with an option accepts and rejects the same documents
+        """
+        data = read_fixture_bytes(filename, "parsing")
+        try:
+            orjson.loads(data)
+        except orjson.JSONDecodeError:
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(data, option=orjson.OPT_PARSE_DECIMAL)
+        else:
+            assert orjson.loads(data, option=orjson.OPT_PARSE_DECIMAL) == json.loads(
+                data, parse_float=decimal.Decimal
+            )
+
+    @pytest.mark.parametrize("kwargs", PARSER_KWARGS)
+    @pytest.mark.parametrize(
+        "filename", sorted(os.listdir(os.path.join(dirname, "parsing")))
+    )
+    def test_parser(self, filename, kwargs):
+        """
+        loads() with a hook or option deserializes every document of
+        JSONTestSuite with the same result or error as without
+        """
+        data = read_fixture_bytes(filename, "parsing")
+        try:
+            expected = orjson.loads(data)
+        except orjson.JSONDecodeError as exc:
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(data, **kwargs)
+            assert str(exc_info.value) == str(exc)
+            assert exc_info.value.pos == exc.pos
+        else:
+            assert orjson.loads(data, **kwargs) == expected
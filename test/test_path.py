@@ -0,0 +1,141 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pathlib
+
+import pytest
+
+import orjson
+
+
+class PathSubclass(type(pathlib.Path())):  # type: ignore
+    pass
+
+
+class TestPath:
+    def test_path_default(self):
+        """
+        pathlib.Path is not serialized without OPT_SERIALIZE_PATH
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(pathlib.Path("a"))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(pathlib.PurePosixPath("a"))
+
+    def test_path(self):
+        """
+        pathlib.Path
+        """
+        assert (
+            orjson.dumps(pathlib.Path("/tmp/a.txt"), option=orjson.OPT_SERIALIZE_PATH)
+            == orjson.dumps(str(pathlib.Path("/tmp/a.txt")))
+        )
+
+    def test_path_pure_posix(self):
+        """
+        pathlib.PurePosixPath
+        """
+        assert (
+            orjson.dumps(
+                pathlib.PurePosixPath("/tmp/a b/é.txt"),
+                option=orjson.OPT_SERIALIZE_PATH,
+            )
+            == '"/tmp/a b/é.txt"'.encode("utf-8")
+        )
+
+    def test_path_pure_windows(self):
+        """
+        pathlib.PureWindowsPath backslash is escaped
+        """
+        val = orjson.dumps(
+            pathlib.PureWindowsPath("C:/Users/a.txt"),
+            option=orjson.OPT_SERIALIZE_PATH,
+        )
+        assert val == b'"C:\\\\Users\\\\a.txt"'
+        assert orjson.loads(val) == "C:\\Users\\a.txt"
+
+    def test_path_relative(self):
+        """
+        pathlib.PurePath relative and empty
+        """
+        assert (
+            orjson.dumps(pathlib.PurePosixPath("a/b"), option=orjson.OPT_SERIALIZE_PATH)
+            == b'"a/b"'
+        )
+        assert (
+            orjson.dumps(pathlib.PurePosixPath(), option=orjson.OPT_SERIALIZE_PATH)
+            == b'"."'
+        )
+
+    def test_path_subclass(self):
+        """
+        pathlib.Path subclass
+        """
+        assert (
+            orjson.dumps(PathSubclass("/a"), option=orjson.OPT_SERIALIZE_PATH)
+            == b'"/a"'
+        )
+
+    def test_path_list(self):
+        """
+        pathlib.Path in list
+        """
+        assert (
+            orjson.dumps(
+                [pathlib.PurePosixPath("/a"), pathlib.PurePosixPath("/b")],
+                option=orjson.OPT_SERIALIZE_PATH,
+            )
+            == b'["/a","/b"]'
+        )
+
+    def test_path_dict_value(self):
+        """
+        pathlib.Path as dict value
+        """
+        assert (
+            orjson.dumps(
+                {"a": pathlib.PurePosixPath("/a")}, option=orjson.OPT_SERIALIZE_PATH
+            )
+            == b'{"a":"/a"}'
+        )
+
+    def test_path_dict_key(self):
+        """
+        pathlib.Path as dict key with OPT_NON_STR_KEYS
+        """
+        obj = {pathlib.PurePosixPath("/b"): 1, pathlib.PureWindowsPath("C:/a"): 2}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_PATH
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"/b":1,"C:\\\\a":2}'
+        )
+
+    def test_path_dict_key_invalid(self):
+        """
+        pathlib.Path as dict key requires OPT_SERIALIZE_PATH
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {pathlib.PurePosixPath("/a"): 1}, option=orjson.OPT_NON_STR_KEYS
+            )
+
+    def test_path_default_function(self):
+        """
+        pathlib.Path default is not called with OPT_SERIALIZE_PATH
+        """
+
+        def default(obj):
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                pathlib.PurePosixPath("/a"),
+                default=default,
+                option=orjson.OPT_SERIALIZE_PATH,
+            )
+            == b'"/a"'
+        )
+        assert orjson.dumps(pathlib.PurePosixPath("/a"), default=str) == b'"/a"'
@@ -0,0 +1,177 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import gc
+import weakref
+
+import pytest
+
+import orjson
+
+
+class Record(dict):
+    pass
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+
+
+class Wrapper:
+    def __init__(self, wrapped):
+        self.__wrapped__ = wrapped
+
+
+class WrapsSelf:
+    @property
+    def __wrapped__(self):
+        return self
+
+
+class Unserializable:
+    pass
+
+
+class TestProxy:
+    def test_proxy_weakref(self):
+        """
+        OPT_SERIALIZE_PROXY serializes the referent of weakref.proxy
+        """
+        obj = Record(a=[1, 2])
+        assert (
+            orjson.dumps(weakref.proxy(obj), option=orjson.OPT_SERIALIZE_PROXY)
+            == b'{"a":[1,2]}'
+        )
+        obj = Dataclass(1)
+        assert (
+            orjson.dumps([weakref.proxy(obj)], option=orjson.OPT_SERIALIZE_PROXY)
+            == b'[{"a":1}]'
+        )
+
+    def test_proxy_weakref_callable(self):
+        """
+        OPT_SERIALIZE_PROXY serializes the referent of a callable weakref.proxy
+        """
+
+        class Callable(Record):
+            def __call__(self):
+                pass
+
+        obj = Callable(a=1)
+        proxy = weakref.proxy(obj)
+        assert isinstance(proxy, weakref.CallableProxyType)
+        assert orjson.dumps(proxy, option=orjson.OPT_SERIALIZE_PROXY) == b'{"a":1}'
+
+    def test_proxy_weakref_dead(self):
+        """
+        OPT_SERIALIZE_PROXY weakref.proxy whose referent no longer exists
+        """
+        obj = Record()
+        proxy = weakref.proxy(obj)
+        del obj
+        gc.collect()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": proxy}, option=orjson.OPT_SERIALIZE_PROXY)
+        assert str(exc_info.value) == (
+            "weakref.proxy referent no longer exists at $.a (output byte 5)"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                proxy, default=lambda _: None, option=orjson.OPT_SERIALIZE_PROXY
+            )
+
+    def test_proxy_wrapped(self):
+        """
+        OPT_SERIALIZE_PROXY serializes __wrapped__
+        """
+        assert (
+            orjson.dumps(
+                {"a": Wrapper(Wrapper(Dataclass(1)))},
+                option=orjson.OPT_SERIALIZE_PROXY,
+            )
+            == b'{"a":{"a":1}}'
+        )
+        obj = Record(a=1)
+        assert (
+            orjson.dumps(Wrapper(weakref.proxy(obj)), option=orjson.OPT_SERIALIZE_PROXY)
+            == b'{"a":1}'
+        )
+
+    def test_proxy_options(self):
+        """
+        OPT_SERIALIZE_PROXY referent is serialized with the options
+        """
+        obj = Record(b=1, a=2)
+        assert (
+            orjson.dumps(
+                Wrapper(obj),
+                option=orjson.OPT_SERIALIZE_PROXY | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"b":1}'
+        )
+
+    def test_proxy_without_option(self):
+        """
+        proxies are not dereferenced without OPT_SERIALIZE_PROXY
+        """
+        obj = Record(a=1)
+        for proxy in (weakref.proxy(obj), Wrapper(obj)):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(proxy)
+
+    def test_proxy_default(self):
+        """
+        OPT_SERIALIZE_PROXY referent may be passed to default
+        """
+        obj = Unserializable()
+        assert (
+            orjson.dumps(
+                weakref.proxy(obj),
+                default=lambda val: type(val).__name__,
+                option=orjson.OPT_SERIALIZE_PROXY,
+            )
+            == b'"Unserializable"'
+        )
+        assert (
+            orjson.dumps(
+                Unserializable(),
+                default=lambda _: 1,
+                option=orjson.OPT_SERIALIZE_PROXY,
+            )
+            == b"1"
+        )
+
+    def test_proxy_not_proxy(self):
+        """
+        OPT_SERIALIZE_PROXY does not apply to natively serialized types
+        """
+        obj = Record(a=1)
+        obj.__wrapped__ = 2  # type: ignore
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_PROXY) == b'{"a":1}'
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Unserializable(), option=orjson.OPT_SERIALIZE_PROXY)
+
+    def test_proxy_wraps_self(self):
+        """
+        OPT_SERIALIZE_PROXY __wrapped__ returning itself
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(WrapsSelf(), option=orjson.OPT_SERIALIZE_PROXY)
+
+    def test_proxy_json_protocol(self):
+        """
+        OPT_SERIALIZE_JSON_PROTOCOL takes precedence over OPT_SERIALIZE_PROXY
+        """
+
+        class Both(Wrapper):
+            def __json__(self):
+                return "json"
+
+        assert (
+            orjson.dumps(
+                Both(1),
+                option=orjson.OPT_SERIALIZE_PROXY | orjson.OPT_SERIALIZE_JSON_PROTOCOL,
+            )
+            == b'"json"'
+        )
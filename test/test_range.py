@@ -0,0 +1,197 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+
+class TestRange:
+    def test_range(self):
+        """
+        range is an object of start, stop, and step
+        """
+        assert (
+            orjson.dumps(range(1, 10, 2), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":1,"stop":10,"step":2}'
+        )
+
+    def test_range_sort_keys(self):
+        """
+        range OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                range(1, 10, 2),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"start":1,"step":2,"stop":10}'
+        )
+
+    def test_range_defaults(self):
+        """
+        range start and step default to 0 and 1
+        """
+        assert (
+            orjson.dumps(range(3), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":0,"stop":3,"step":1}'
+        )
+
+    def test_range_negative_step(self):
+        """
+        range with a negative step
+        """
+        assert (
+            orjson.dumps(range(5, -5, -3), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":5,"stop":-5,"step":-3}'
+        )
+
+    def test_range_empty(self):
+        """
+        empty range is not normalized
+        """
+        assert (
+            orjson.dumps(range(5, 1), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":5,"stop":1,"step":1}'
+        )
+
+    def test_range_huge(self):
+        """
+        range object form does not depend on its length
+        """
+        assert (
+            orjson.dumps(range(2**62), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":0,"stop":4611686018427387904,"step":1}'
+        )
+
+    def test_range_big_int(self):
+        """
+        range attributes that do not fit in 64 bits raise
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(2**64), option=orjson.OPT_SERIALIZE_RANGE)
+
+    def test_range_strict_integer(self):
+        """
+        range attributes OPT_STRICT_INTEGER
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                range(2**53),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_STRICT_INTEGER,
+            )
+        assert (
+            str(exc_info.value) == "Integer exceeds 53-bit range (output byte 18)"
+        )
+
+    def test_range_array(self):
+        """
+        range range_format="array"
+        """
+        assert (
+            orjson.dumps(
+                range(1, 10, 2),
+                option=orjson.OPT_SERIALIZE_RANGE,
+                range_format="array",
+            )
+            == b"[1,3,5,7,9]"
+        )
+
+    def test_range_array_negative_step(self):
+        """
+        range range_format="array" with a negative step
+        """
+        assert (
+            orjson.dumps(
+                range(5, -5, -3),
+                option=orjson.OPT_SERIALIZE_RANGE,
+                range_format="array",
+            )
+            == b"[5,2,-1,-4]"
+        )
+
+    def test_range_array_empty(self):
+        """
+        empty range range_format="array"
+        """
+        for obj in (range(0), range(5, 1), range(1, 5, -1)):
+            assert (
+                orjson.dumps(
+                    obj, option=orjson.OPT_SERIALIZE_RANGE, range_format="array"
+                )
+                == b"[]"
+            )
+
+    def test_range_array_matches_list(self):
+        """
+        range range_format="array" is the same as a list of it
+        """
+        for obj in (range(-1000, 1000, 7), range(2**63 - 3, 2**63 - 1)):
+            assert orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_RANGE, range_format="array"
+            ) == orjson.dumps(list(obj))
+
+    def test_range_array_big_int(self):
+        """
+        range range_format="array" element that does not fit in 64 bits raises
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                range(2**64 - 1, 2**64 + 1),
+                option=orjson.OPT_SERIALIZE_RANGE,
+                range_format="array",
+            )
+        assert (
+            str(exc_info.value) == "Integer exceeds 64-bit range (output byte 22)"
+        )
+
+    def test_range_nested(self):
+        """
+        range in list and dict
+        """
+        assert (
+            orjson.dumps(
+                {"a": [range(2)], "b": range(1, 2)},
+                option=orjson.OPT_SERIALIZE_RANGE,
+            )
+            == b'{"a":[{"start":0,"stop":2,"step":1}],'
+            b'"b":{"start":1,"stop":2,"step":1}}'
+        )
+        assert (
+            orjson.dumps(
+                {"a": [range(2)], "b": range(1, 2)},
+                option=orjson.OPT_SERIALIZE_RANGE,
+                range_format="array",
+            )
+            == b'{"a":[[0,1]],"b":[1]}'
+        )
+
+    def test_range_max_depth(self):
+        """
+        range is a level of max_depth
+        """
+        assert (
+            orjson.dumps([range(1)], option=orjson.OPT_SERIALIZE_RANGE, max_depth=2)
+            == b'[{"start":0,"stop":1,"step":1}]'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([range(1)], option=orjson.OPT_SERIALIZE_RANGE, max_depth=1)
+
+    def test_range_not_enabled(self):
+        """
+        range is not serialized without OPT_SERIALIZE_RANGE
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(3))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(3), range_format="array")
+        assert orjson.dumps(range(3), default=list) == b"[0,1,2]"
+
+    def test_range_dict_key(self):
+        """
+        range dict key is invalid with OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {range(1): 1},
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_NON_STR_KEYS,
+            )
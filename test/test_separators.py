@@ -0,0 +1,118 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import json
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+OBJ = {"a": [1, 2, {"b": None}], "c": {}, "d": [], "e": "x: y, z"}
+
+
+class TestSeparators:
+    def test_space_after_colon(self):
+        """
+        OPT_SPACE_AFTER_COLON
+        """
+        assert (
+            orjson.dumps(OBJ, option=orjson.OPT_SPACE_AFTER_COLON)
+            == b'{"a": [1,2,{"b": null}],"c": {},"d": [],"e": "x: y, z"}'
+        )
+
+    def test_space_after_comma(self):
+        """
+        OPT_SPACE_AFTER_COMMA
+        """
+        assert (
+            orjson.dumps(OBJ, option=orjson.OPT_SPACE_AFTER_COMMA)
+            == b'{"a":[1, 2, {"b":null}], "c":{}, "d":[], "e":"x: y, z"}'
+        )
+
+    def test_space_after_colon_and_comma(self):
+        """
+        OPT_SPACE_AFTER_COLON and OPT_SPACE_AFTER_COMMA are equivalent to the
+        default separators of json.dumps()
+        """
+        option = orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA
+        assert orjson.dumps(OBJ, option=option) == json.dumps(OBJ).encode("utf-8")
+
+    @pytest.mark.parametrize(
+        "fixture", ["twitter.json.xz", "github.json.xz", "canada.json.xz"]
+    )
+    def test_space_fixture(self, fixture):
+        """
+        OPT_SPACE_AFTER_COLON and OPT_SPACE_AFTER_COMMA fixture
+        """
+        obj = read_fixture_obj(fixture)
+        option = orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA
+        assert orjson.loads(orjson.dumps(obj, option=option)) == obj
+        assert orjson.dumps(obj, option=option) == json.dumps(
+            obj, ensure_ascii=False
+        ).encode("utf-8")
+
+    def test_space_empty(self):
+        """
+        OPT_SPACE_AFTER_COLON and OPT_SPACE_AFTER_COMMA empty and single item
+        """
+        option = orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA
+        assert orjson.dumps([], option=option) == b"[]"
+        assert orjson.dumps({}, option=option) == b"{}"
+        assert orjson.dumps([1], option=option) == b"[1]"
+        assert orjson.dumps(1, option=option) == b"1"
+
+    def test_space_indent(self):
+        """
+        OPT_SPACE_AFTER_COLON and OPT_SPACE_AFTER_COMMA do not affect OPT_INDENT_2
+        """
+        option = orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA
+        for indent in (orjson.OPT_INDENT_2, orjson.OPT_INDENT_4, orjson.OPT_INDENT_TAB):
+            assert orjson.dumps(OBJ, option=option | indent) == orjson.dumps(
+                OBJ, option=indent
+            )
+
+    def test_space_options(self):
+        """
+        OPT_SPACE_AFTER_COMMA with other options
+        """
+        assert (
+            orjson.dumps(
+                {"b": {3, 1}, "a": (1, 2), 1: 2},
+                option=orjson.OPT_SPACE_AFTER_COLON
+                | orjson.OPT_SPACE_AFTER_COMMA
+                | orjson.OPT_SORT_KEYS
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_SERIALIZE_SET
+                | orjson.OPT_APPEND_NEWLINE,
+            )
+            == b'{"1": 2, "a": [1, 2], "b": [1, 3]}\n'
+        )
+
+    def test_space_dumps_str(self):
+        """
+        OPT_SPACE_AFTER_COMMA dumps_str() and dumps_into()
+        """
+        option = orjson.OPT_SPACE_AFTER_COLON | orjson.OPT_SPACE_AFTER_COMMA
+        assert orjson.dumps_str(OBJ, option=option) == json.dumps(OBJ)
+        buffer = bytearray()
+        orjson.dumps_into(OBJ, buffer, option=option)
+        assert buffer == json.dumps(OBJ).encode("utf-8")
+
+    def test_space_fragment(self):
+        """
+        OPT_SPACE_AFTER_COMMA does not modify orjson.Fragment
+        """
+        assert (
+            orjson.dumps(
+                [orjson.Fragment(b"[1,2]"), 3], option=orjson.OPT_SPACE_AFTER_COMMA
+            )
+            == b"[[1,2], 3]"
+        )
+
+    def test_space_loads(self):
+        """
+        OPT_SPACE_AFTER_COLON is not valid for loads()
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads("{}", option=orjson.OPT_SPACE_AFTER_COLON)
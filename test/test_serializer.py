@@ -0,0 +1,284 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import datetime
+import inspect
+import sys
+import threading
+import uuid
+
+import pytest
+
+import orjson
+
+from .util import read_fixture_obj
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+    b: str
+
+
+OBJ = {
+    "b": [1, {"c": 2.5}, None],
+    "a": "é/",
+    "d": Dataclass(1, "x"),
+    "e": datetime.datetime(2020, 1, 2, 3, 4, 5),
+}
+
+
+class TestSerializer:
+    def test_serializer(self):
+        """
+        Serializer.dumps()
+        """
+        serializer = orjson.Serializer()
+        assert serializer.dumps(OBJ) == orjson.dumps(OBJ)
+        assert serializer.dumps([]) == b"[]"
+        assert serializer.dumps(1) == b"1"
+
+    @pytest.mark.parametrize(
+        "option",
+        [
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_NAIVE_UTC | orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_ESCAPE_FORWARD_SLASH | orjson.OPT_UTF8_BOM,
+            orjson.OPT_CANONICAL,
+        ],
+    )
+    def test_serializer_option(self, option):
+        """
+        Serializer.dumps() is the same as dumps() with option
+        """
+        serializer = orjson.Serializer(option=option)
+        assert serializer.dumps(OBJ) == orjson.dumps(OBJ, option=option)
+
+    def test_serializer_fixture(self):
+        """
+        Serializer.dumps() fixture
+        """
+        obj = read_fixture_obj("twitter.json.xz")
+        assert orjson.Serializer().dumps(obj) == orjson.dumps(obj)
+
+    def test_serializer_default(self):
+        """
+        Serializer default
+        """
+
+        def default(obj):
+            if isinstance(obj, set):
+                return sorted(obj)
+            raise TypeError
+
+        serializer = orjson.Serializer(default=default)
+        assert serializer.dumps({"a": {2, 1}}) == b'{"a":[1,2]}'
+        with pytest.raises(orjson.JSONEncodeError):
+            serializer.dumps(object())
+        assert serializer.dumps({3}) == b"[3]"
+
+    def test_serializer_format(self):
+        """
+        Serializer.dumps() is the same as dumps() with *_format arguments
+        """
+        obj = [uuid.UUID(int=1), datetime.date(1970, 1, 2), b"a"]
+        kwargs = {"uuid_format": "int", "date_format": "epoch_day"}
+        serializer = orjson.Serializer(bytes_format="base64", **kwargs)
+        assert serializer.dumps(obj) == b'[1,1,"YQ=="]'
+        assert serializer.dumps(obj) == orjson.dumps(
+            obj, bytes_format="base64", **kwargs
+        )
+        assert orjson.Serializer(uuid_format=None).dumps(obj[:2]) == (
+            b'["00000000-0000-0000-0000-000000000001","1970-01-02"]'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(uuid_format="hex")
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(option=orjson.OPT_CANONICAL, uuid_format="int")
+
+    def test_serializer_reused(self):
+        """
+        Serializer is unaffected by previous calls, including ones that raised
+        """
+        serializer = orjson.Serializer(option=orjson.OPT_INDENT_2)
+        with pytest.raises(orjson.JSONEncodeError):
+            serializer.dumps([object()])
+        for _ in range(3):
+            assert serializer.dumps([1]) == b"[\n  1\n]"
+
+    def test_serializer_independent(self):
+        """
+        Serializer instances and dumps() are independent
+        """
+        first = orjson.Serializer(default=str)
+        second = orjson.Serializer(option=orjson.OPT_INDENT_2)
+        assert first.dumps([{1}]) == b'["{1}"]'
+        assert second.dumps([1]) == b"[\n  1\n]"
+        assert orjson.dumps([1]) == b"[1]"
+        with pytest.raises(orjson.JSONEncodeError):
+            second.dumps([{1}])
+
+    def test_serializer_dumps_keywords(self):
+        """
+        Serializer.dumps() is the same as dumps() with max_depth, include, and
+        float_precision
+        """
+        obj = [Dataclass(1, "x"), [[1.25]], 0.5]
+        for kwargs in (
+            {"max_depth": 3},
+            {"include": {"a"}},
+            {"include": frozenset()},
+            {"float_precision": 1, "option": orjson.OPT_FLOAT_FIXED},
+            {"max_depth": None, "include": None, "float_precision": None},
+        ):
+            assert orjson.Serializer(**kwargs).dumps(obj) == orjson.dumps(
+                obj, **kwargs
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(max_depth=2).dumps(obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, max_depth=2)
+
+    def test_serializer_include_copied(self):
+        """
+        Serializer copies include so that it is not affected by changes to it
+        """
+        include = {"a"}
+        serializer = orjson.Serializer(include=include)
+        include.add("b")
+        assert serializer.dumps(Dataclass(1, "x")) == b'{"a":1}'
+
+    def test_serializer_not_dumps_arguments(self):
+        """
+        Serializer.dumps() uses its own max_depth, include, and
+        float_precision, regardless of previous calls to dumps()
+        """
+        obj = [Dataclass(1, "x"), [[[1]]], 0.25]
+        serializer = orjson.Serializer()
+        orjson.dumps(
+            obj,
+            max_depth=4,
+            include={"a"},
+            float_precision=1,
+            option=orjson.OPT_FLOAT_FIXED,
+        )
+        assert serializer.dumps(obj) == b'[{"a":1,"b":"x"},[[[1]]],0.25]'
+
+    def test_serializer_threads(self):
+        """
+        Serializer shared between threads
+        """
+        serializer = orjson.Serializer(default=list, option=orjson.OPT_SORT_KEYS)
+        objs = [{"b": {idx}, "a": [idx] * idx} for idx in range(8)]
+        results = [None] * len(objs)
+
+        def target(idx):
+            for _ in range(100):
+                results[idx] = serializer.dumps(objs[idx])
+
+        threads = [
+            threading.Thread(target=target, args=(idx,)) for idx in range(len(objs))
+        ]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+        assert results == [
+            orjson.dumps(obj, default=list, option=orjson.OPT_SORT_KEYS)
+            for obj in objs
+        ]
+
+    def test_serializer_default_refcount(self):
+        """
+        Serializer holds a reference to default until deallocated
+        """
+
+        def default(obj):
+            return str(obj)
+
+        ref = sys.getrefcount(default)
+        serializer = orjson.Serializer(default=default)
+        assert sys.getrefcount(default) == ref + 1
+        serializer.dumps(object())
+        del serializer
+        assert sys.getrefcount(default) == ref
+
+    def test_serializer_arguments(self):
+        """
+        Serializer positional and keyword arguments
+        """
+        serializer = orjson.Serializer(list, orjson.OPT_INDENT_2)
+        assert serializer.dumps({1}) == b"[\n  1\n]"
+        serializer = orjson.Serializer(None, None)
+        assert serializer.dumps(1) == b"1"
+        serializer = orjson.Serializer(default=None, option=None)
+        assert serializer.dumps(1) == b"1"
+
+    def test_serializer_invalid_arguments(self):
+        """
+        Serializer invalid arguments raise when it is created
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(None, None, None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(None, default=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(None, None, option=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(zxc=1)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(max_depth=0)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(include=["a"])  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(float_precision=1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(option="1")  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(option=orjson.OPT_INDENT_2 | orjson.OPT_INDENT_4)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(option=orjson.OPT_FLOAT_FIXED)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer(max_depth=1, option=orjson.OPT_FLOAT_FIXED)
+
+    def test_serializer_default_not_callable(self):
+        """
+        Serializer default that is not callable raises when it is created
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.Serializer(default=1)  # type: ignore
+        assert str(exc_info.value) == "Invalid default"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Serializer("str")  # type: ignore
+
+    def test_serializer_dumps_arguments(self):
+        """
+        Serializer.dumps() takes exactly one argument
+        """
+        serializer = orjson.Serializer()
+        with pytest.raises(TypeError):
+            serializer.dumps()  # type: ignore
+        with pytest.raises(TypeError):
+            serializer.dumps(1, 2)  # type: ignore
+        with pytest.raises(TypeError):
+            serializer.dumps(1, option=orjson.OPT_INDENT_2)  # type: ignore
+
+    def test_serializer_signature(self):
+        """
+        Serializer.dumps() valid __text_signature__
+        """
+        assert str(inspect.signature(orjson.Serializer().dumps)) == "(obj, /)"
+
+    def test_serializer_doc(self):
+        """
+        Serializer has a docstring and valid __text_signature__
+        """
+        assert orjson.Serializer.__doc__ == (
+            "Serialize Python objects to JSON with arguments validated once."
+        )
+        signature = inspect.signature(orjson.Serializer)
+        assert list(signature.parameters)[:2] == ["default", "option"]
+        signature.bind(str, 1, max_depth=1, uuid_format="int")
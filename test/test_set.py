@@ -0,0 +1,247 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import dataclasses
+import datetime
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass(frozen=True)
+class Point:
+    x: int
+    y: int
+
+
+class SetSubclass(set):
+    pass
+
+
+class TestSet:
+    def test_set_default(self):
+        """
+        dumps() set without OPT_SERIALIZE_SET is unsupported
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({1})
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(frozenset([1]))
+
+    def test_set_default_callable(self):
+        """
+        dumps() set without OPT_SERIALIZE_SET passes to default
+        """
+        assert orjson.dumps({1}, default=list) == b"[1]"
+
+    def test_set(self):
+        """
+        dumps() set
+        """
+        assert orjson.dumps({1}, option=orjson.OPT_SERIALIZE_SET) == b"[1]"
+        assert sorted(
+            orjson.loads(orjson.dumps({1, 2, 3}, option=orjson.OPT_SERIALIZE_SET))
+        ) == [1, 2, 3]
+
+    def test_frozenset(self):
+        """
+        dumps() frozenset
+        """
+        assert orjson.dumps(frozenset(["a"]), option=orjson.OPT_SERIALIZE_SET) == b'["a"]'
+
+    def test_set_empty(self):
+        """
+        dumps() empty set
+        """
+        assert orjson.dumps(set(), option=orjson.OPT_SERIALIZE_SET) == b"[]"
+        assert orjson.dumps(frozenset(), option=orjson.OPT_SERIALIZE_SET) == b"[]"
+        assert (
+            orjson.dumps(
+                {"a": set()}, option=orjson.OPT_SERIALIZE_SET | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "a": []\n}'
+        )
+
+    def test_set_subclass(self):
+        """
+        dumps() set subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SetSubclass([1]), option=orjson.OPT_SERIALIZE_SET)
+
+    def test_set_nested(self):
+        """
+        dumps() set elements recurse
+        """
+        assert (
+            orjson.dumps(
+                {"a": [{Point(1, 2)}, frozenset([datetime.date(1970, 1, 1)])]},
+                option=orjson.OPT_SERIALIZE_SET,
+            )
+            == b'{"a":[[{"x":1,"y":2}],["1970-01-01"]]}'
+        )
+
+    def test_set_nested_frozenset(self):
+        """
+        dumps() frozenset in set
+        """
+        assert (
+            orjson.dumps({frozenset([1])}, option=orjson.OPT_SERIALIZE_SET)
+            == b"[[1]]"
+        )
+
+    def test_set_sort_keys(self):
+        """
+        dumps() set with OPT_SORT_KEYS is sorted by serialized form
+        """
+        val = {"c", "a", "b", "aa", "ab"}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS)
+            == b'["a","aa","ab","b","c"]'
+        )
+
+    def test_set_sort_keys_serialized_form(self):
+        """
+        dumps() set with OPT_SORT_KEYS compares serialized bytes, not values
+        """
+        val = {10, 9, 100, 1}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS)
+            == b"[1,10,100,9]"
+        )
+
+    def test_set_sort_keys_mixed(self):
+        """
+        dumps() set with OPT_SORT_KEYS and mixed types is deterministic
+        """
+        val = frozenset([1, "a", None, True, 1.5, (2, "b")])
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS)
+            == b'["a",1,1.5,[2,"b"],null]'
+        )
+
+    def test_set_sort_keys_indent(self):
+        """
+        dumps() set with OPT_SORT_KEYS and OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"b", "a"},
+                option=orjson.OPT_SERIALIZE_SET
+                | orjson.OPT_SORT_KEYS
+                | orjson.OPT_INDENT_2,
+            )
+            == b'[\n  "a",\n  "b"\n]'
+        )
+
+    def test_set_sort_keys_nested(self):
+        """
+        dumps() set with OPT_SORT_KEYS sorts nested sets and dicts
+        """
+        assert (
+            orjson.dumps(
+                {"x": {frozenset([3, 2]), frozenset([1])}, "a": 1},
+                option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":1,"x":[[1],[2,3]]}'
+        )
+
+    def test_set_sort_keys_default(self):
+        """
+        dumps() set with OPT_SORT_KEYS calls default
+        """
+
+        class Custom:
+            def __init__(self, val):
+                self.val = val
+
+            def __hash__(self):
+                return hash(self.val)
+
+        def default(obj):
+            if isinstance(obj, Custom):
+                return obj.val
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                {Custom("b"), Custom("a")},
+                option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS,
+                default=default,
+            )
+            == b'["a","b"]'
+        )
+
+    def test_set_sort_keys_default_once(self):
+        """
+        dumps() set with OPT_SORT_KEYS calls default once for each element
+        """
+
+        class Custom:
+            def __init__(self, val):
+                self.val = val
+
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            return obj.val
+
+        val = {Custom("b"), Custom("a")}
+        option = orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS
+        assert orjson.dumps(val, option=option, default=default) == b'["a","b"]'
+        assert len(calls) == 2
+        _, stats = orjson.dumps_with_stats(val, option=option, default=default)
+        assert stats["default_calls"] == 2
+
+    def test_set_sort_keys_iterator(self):
+        """
+        dumps() set with OPT_SORT_KEYS consumes a generator element once
+        """
+        assert (
+            orjson.dumps(
+                {(each for each in [2, 1]), "a"},
+                option=orjson.OPT_SERIALIZE_SET
+                | orjson.OPT_SORT_KEYS
+                | orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b'["a",[2,1]]'
+        )
+
+    def test_set_sort_keys_unsupported(self):
+        """
+        dumps() set with OPT_SORT_KEYS raises on unsupported element
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {object()}, option=orjson.OPT_SERIALIZE_SET | orjson.OPT_SORT_KEYS
+            )
+
+    def test_set_unsupported(self):
+        """
+        dumps() set raises on unsupported element
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({object()}, option=orjson.OPT_SERIALIZE_SET)
+
+    def test_set_recursion(self):
+        """
+        dumps() deeply nested frozenset reaches the recursion limit
+        """
+        val: frozenset = frozenset()
+        for _ in range(300):
+            val = frozenset([val])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_SET)
+
+    def test_set_dict_key(self):
+        """
+        dumps() frozenset OPT_NON_STR_KEYS key is not serialized as a set
+        """
+        assert (
+            orjson.dumps(
+                {frozenset([1]): 1},
+                option=orjson.OPT_SERIALIZE_SET | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"[\\"1\\"]":1}'
+        )
@@ -0,0 +1,133 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+from dataclasses import dataclass
+from typing import Optional
+
+import orjson
+
+
+@dataclass
+class Sparse:
+    a: Optional[int]
+    b: Optional[str]
+    c: Optional[list]
+
+
+@dataclass
+class SparseSlots:
+    __slots__ = ("a", "b")
+    a: Optional[int]
+    b: Optional[int]
+
+
+class TestSkipNoneValues:
+    def test_skip_none_default(self):
+        """
+        dumps() None values are serialized without OPT_SKIP_NONE_VALUES
+        """
+        assert orjson.dumps({"a": None, "b": 1}) == b'{"a":null,"b":1}'
+
+    def test_skip_none_dict(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dict
+        """
+        assert (
+            orjson.dumps(
+                {"a": None, "b": 1, "c": None, "d": "x"},
+                option=orjson.OPT_SKIP_NONE_VALUES,
+            )
+            == b'{"b":1,"d":"x"}'
+        )
+
+    def test_skip_none_dict_all(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dict with only None values
+        """
+        assert (
+            orjson.dumps({"a": None, "b": None}, option=orjson.OPT_SKIP_NONE_VALUES)
+            == b"{}"
+        )
+        assert (
+            orjson.dumps(
+                {"a": None},
+                option=orjson.OPT_SKIP_NONE_VALUES | orjson.OPT_INDENT_2,
+            )
+            == b"{}"
+        )
+
+    def test_skip_none_dict_indent(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dict with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a": None, "b": 1},
+                option=orjson.OPT_SKIP_NONE_VALUES | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "b": 1\n}'
+        )
+
+    def test_skip_none_dict_sorted(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dict with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"c": 1, "b": None, "a": 2},
+                option=orjson.OPT_SKIP_NONE_VALUES | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"c":1}'
+        )
+
+    def test_skip_none_dict_non_str_keys(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dict with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {1: None, 2: "a", None: 3},
+                option=orjson.OPT_SKIP_NONE_VALUES | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2":"a","null":3}'
+        )
+
+    def test_skip_none_nested(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES applies to nested dicts but not list elements
+        """
+        assert (
+            orjson.dumps(
+                {"a": {"b": None, "c": [None, 1]}, "d": [{"e": None}]},
+                option=orjson.OPT_SKIP_NONE_VALUES,
+            )
+            == b'{"a":{"c":[null,1]},"d":[{}]}'
+        )
+
+    def test_skip_none_top_level(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES does not affect a top-level None
+        """
+        assert orjson.dumps(None, option=orjson.OPT_SKIP_NONE_VALUES) == b"null"
+        assert orjson.dumps([None], option=orjson.OPT_SKIP_NONE_VALUES) == b"[null]"
+
+    def test_skip_none_dataclass(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dataclass
+        """
+        assert (
+            orjson.dumps(Sparse(1, None, None), option=orjson.OPT_SKIP_NONE_VALUES)
+            == b'{"a":1}'
+        )
+        assert (
+            orjson.dumps(Sparse(None, None, None), option=orjson.OPT_SKIP_NONE_VALUES)
+            == b"{}"
+        )
+
+    def test_skip_none_dataclass_slots(self):
+        """
+        dumps() OPT_SKIP_NONE_VALUES dataclass with __slots__
+        """
+        assert (
+            orjson.dumps(SparseSlots(None, 2), option=orjson.OPT_SKIP_NONE_VALUES)
+            == b'{"b":2}'
+        )
@@ -48,3 +48,89 @@ class TestDictSortKeys:
         serialized = orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
         val = orjson.loads(serialized)
         assert list(val.keys()) == sorted(list(val.keys()))
+
+
+class TestDictSortKeysCaseInsensitive:
+    def test_case_insensitive(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE
+        """
+        obj = {"b": 1, "C": 2, "a": 3, "A": 4}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE)
+            == b'{"A":4,"a":3,"b":1,"C":2}'
+        )
+
+    def test_case_insensitive_with_sort_keys(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE combined with OPT_SORT_KEYS
+        """
+        obj = {"b": 1, "C": 2, "a": 3, "A": 4}
+        assert orjson.dumps(
+            obj,
+            option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE | orjson.OPT_SORT_KEYS,
+        ) == orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE)
+
+    def test_case_insensitive_tiebreak(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE orders keys equal ignoring case by bytes
+        """
+        keys = ["aB", "Ab", "ab", "AB", "a", "A", "b", "B", "_", "[", "z", "Z"]
+        for obj in ({key: 0 for key in keys}, {key: 0 for key in reversed(keys)}):
+            val = orjson.loads(
+                orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE)
+            )
+            assert list(val.keys()) == [
+                "[",
+                "_",
+                "A",
+                "a",
+                "AB",
+                "Ab",
+                "aB",
+                "ab",
+                "B",
+                "b",
+                "Z",
+                "z",
+            ]
+
+    def test_case_insensitive_non_ascii(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE compares non-ASCII by bytes
+        """
+        obj = {"ä": 1, "Ä": 2, "b": 3, "A": 4}
+        val = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE)
+        )
+        assert list(val.keys()) == ["A", "b", "Ä", "ä"]
+
+    def test_case_insensitive_nested(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE nested and indented
+        """
+        obj = {"b": [{"Y": 1, "x": 2}], "A": {"d": 3, "C": 4}}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE | orjson.OPT_INDENT_2,
+            )
+            == orjson.dumps(
+                {"A": {"C": 4, "d": 3}, "b": [{"x": 2, "Y": 1}]},
+                option=orjson.OPT_INDENT_2,
+            )
+        )
+
+    def test_case_insensitive_non_str_keys(self):
+        """
+        OPT_SORT_KEYS_CASE_INSENSITIVE with OPT_NON_STR_KEYS
+        """
+        obj = {"b": 1, "B": 2, 1: 3, False: 4, None: 5}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SORT_KEYS_CASE_INSENSITIVE
+                | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":3,"B":2,"b":1,"false":4,"null":5}'
+        )
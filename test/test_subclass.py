@@ -32,6 +32,23 @@ class SubTuple(tuple):
     pass
 
 
+class SubDictOverride(dict):
+    def __getitem__(self, key):
+        raise AssertionError
+
+    def __iter__(self):
+        raise AssertionError
+
+    def items(self):
+        raise AssertionError
+
+    def keys(self):
+        raise AssertionError
+
+    def values(self):
+        raise AssertionError
+
+
 class TestSubclass:
     def test_subclass_str(self):
         assert orjson.dumps(SubStr("zxc")) == b'"zxc"'
@@ -55,6 +72,94 @@ class TestSubclass:
     def test_subclass_dict(self):
         assert orjson.dumps(SubDict({"a": "b"})) == b'{"a":"b"}'
 
+    def test_subclass_dict_methods(self):
+        """
+        dumps() dict subclass does not call overridden methods
+        """
+        obj = SubDictOverride({"a": 1, "b": 2})
+        assert orjson.dumps(obj) == b'{"a":1,"b":2}'
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == b'{"a":1,"b":2}'
+
+    def test_defaultdict(self):
+        """
+        dumps() collections.defaultdict
+        """
+        obj = collections.defaultdict(list, {"a": [1], "b": []})
+        assert orjson.dumps(obj) == b'{"a":[1],"b":[]}'
+        assert orjson.dumps([{"x": obj}]) == b'[{"x":{"a":[1],"b":[]}}]'
+        assert orjson.dumps(collections.defaultdict(list)) == b"{}"
+
+    def test_defaultdict_not_mutated(self):
+        """
+        dumps() collections.defaultdict does not create missing keys
+        """
+        calls = []
+
+        def factory():
+            calls.append(None)
+            return collections.defaultdict(factory)
+
+        obj = collections.defaultdict(factory)
+        obj["a"]["b"]["c"] = 1
+        calls.clear()
+        for option in (
+            0,
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_NON_STR_KEYS,
+            orjson.OPT_INDENT_2,
+        ):
+            orjson.dumps(obj, option=option)
+        assert orjson.dumps(obj) == b'{"a":{"b":{"c":1}}}'
+        assert not calls
+        assert list(obj) == ["a"]
+        assert list(obj["a"]) == ["b"]
+
+    def test_defaultdict_options(self):
+        """
+        dumps() collections.defaultdict OPT_SORT_KEYS and OPT_NON_STR_KEYS
+        """
+        obj = collections.defaultdict(int, {"b": 1, "a": 2})
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == b'{"a":2,"b":1}'
+        obj = collections.defaultdict(int, {1: 2, None: 3})
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == b'{"1":2,"null":3}'
+        )
+
+    def test_counter(self):
+        """
+        dumps() collections.Counter counts are int
+        """
+        obj = collections.Counter("abracadabra")
+        assert orjson.dumps(obj) == b'{"a":5,"b":2,"r":2,"c":1,"d":1}'
+        assert orjson.loads(orjson.dumps(obj)) == dict(obj)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
+            == b'{"a":5,"b":2,"c":1,"d":1,"r":2}'
+        )
+
+    def test_counter_arithmetic(self):
+        """
+        dumps() collections.Counter with zero and negative counts, and
+        the result of arithmetic
+        """
+        obj = collections.Counter(a=1, b=0)
+        obj.subtract({"a": 3, "c": 1})
+        assert orjson.dumps(obj) == b'{"a":-2,"b":0,"c":-1}'
+        assert orjson.dumps(+obj) == b"{}"
+        assert (
+            orjson.dumps(collections.Counter(a=2) + collections.Counter(a=1, b=1))
+            == b'{"a":3,"b":1}'
+        )
+
+    def test_counter_non_str_keys(self):
+        """
+        dumps() collections.Counter OPT_NON_STR_KEYS
+        """
+        obj = collections.Counter([1, 2, 2, True])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj)
+        assert orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == b'{"1":2,"2":2}'
+
     def test_subclass_list(self):
         assert orjson.dumps(SubList(["a", "b"])) == b'["a","b"]'
         ref = [True] * 512
@@ -110,3 +215,39 @@ class TestSubclassPassthrough:
     def test_subclass_list(self):
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(SubList(["a", "b"]), option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+
+    def test_defaultdict(self):
+        """
+        OPT_PASSTHROUGH_SUBCLASS passes collections.defaultdict to default
+        """
+        obj = collections.defaultdict(list, {"a": [1]})
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_PASSTHROUGH_SUBCLASS,
+                default=lambda val: {"factory": val.default_factory.__name__},
+            )
+            == b'{"factory":"list"}'
+        )
+        assert list(obj) == ["a"]
+
+    def test_counter(self):
+        """
+        OPT_PASSTHROUGH_SUBCLASS passes collections.Counter to default
+        """
+        obj = collections.Counter("aab")
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+        assert (
+            str(exc_info.value) == "Type is not JSON serializable: collections.Counter"
+        )
+        assert (
+            orjson.dumps(
+                {"x": obj},
+                option=orjson.OPT_PASSTHROUGH_SUBCLASS,
+                default=lambda val: val.most_common(1),
+            )
+            == b'{"x":[["a",2]]}'
+        )
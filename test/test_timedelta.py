@@ -0,0 +1,197 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import datetime
+
+import pytest
+
+import orjson
+
+
+class TimedeltaSubclass(datetime.timedelta):
+    pass
+
+
+class TestTimedelta:
+    def test_timedelta_default(self):
+        """
+        datetime.timedelta is not serialized without an option
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(datetime.timedelta(seconds=1))
+
+    def test_timedelta_both_options(self):
+        """
+        OPT_TIMEDELTA_ISO8601 and OPT_TIMEDELTA_SECONDS are mutually exclusive
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.timedelta(seconds=1),
+                option=orjson.OPT_TIMEDELTA_ISO8601 | orjson.OPT_TIMEDELTA_SECONDS,
+            )
+
+    def test_timedelta_subclass(self):
+        """
+        datetime.timedelta subclass is not serialized natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                TimedeltaSubclass(seconds=1), option=orjson.OPT_TIMEDELTA_SECONDS
+            )
+
+
+class TestTimedeltaSeconds:
+    def test_timedelta_seconds(self):
+        """
+        OPT_TIMEDELTA_SECONDS
+        """
+        assert (
+            orjson.dumps(
+                datetime.timedelta(hours=1, minutes=30),
+                option=orjson.OPT_TIMEDELTA_SECONDS,
+            )
+            == b"5400.0"
+        )
+
+    def test_timedelta_seconds_zero(self):
+        """
+        OPT_TIMEDELTA_SECONDS zero
+        """
+        assert (
+            orjson.dumps(datetime.timedelta(), option=orjson.OPT_TIMEDELTA_SECONDS)
+            == b"0.0"
+        )
+
+    def test_timedelta_seconds_microseconds(self):
+        """
+        OPT_TIMEDELTA_SECONDS microseconds
+        """
+        assert (
+            orjson.dumps(
+                datetime.timedelta(seconds=59, microseconds=120),
+                option=orjson.OPT_TIMEDELTA_SECONDS,
+            )
+            == b"59.00012"
+        )
+
+    def test_timedelta_seconds_negative(self):
+        """
+        OPT_TIMEDELTA_SECONDS negative
+        """
+        assert (
+            orjson.dumps(
+                -datetime.timedelta(microseconds=1),
+                option=orjson.OPT_TIMEDELTA_SECONDS,
+            )
+            == b"-1e-6"
+        )
+        assert (
+            orjson.dumps(
+                datetime.timedelta(hours=-1, microseconds=500000),
+                option=orjson.OPT_TIMEDELTA_SECONDS,
+            )
+            == b"-3599.5"
+        )
+
+    def test_timedelta_seconds_total_seconds(self):
+        """
+        OPT_TIMEDELTA_SECONDS is the same as timedelta.total_seconds()
+        """
+        for val in (
+            datetime.timedelta.max,
+            datetime.timedelta.min,
+            datetime.timedelta(days=-1, seconds=86399, microseconds=999999),
+            datetime.timedelta(days=123456789, seconds=1, microseconds=7),
+            datetime.timedelta(days=-123456789, seconds=1, microseconds=7),
+            datetime.timedelta(seconds=0.1),
+            datetime.timedelta(seconds=1.3),
+        ):
+            assert orjson.loads(
+                orjson.dumps(val, option=orjson.OPT_TIMEDELTA_SECONDS)
+            ) == val.total_seconds()
+
+
+class TestTimedeltaIso8601:
+    @pytest.mark.parametrize(
+        "value",
+        [
+            (datetime.timedelta(), b'"PT0S"'),
+            (datetime.timedelta(hours=1, minutes=30), b'"PT1H30M"'),
+            (datetime.timedelta(hours=2), b'"PT2H"'),
+            (datetime.timedelta(minutes=5), b'"PT5M"'),
+            (datetime.timedelta(seconds=5), b'"PT5S"'),
+            (datetime.timedelta(hours=1, seconds=5), b'"PT1H5S"'),
+            (datetime.timedelta(days=1), b'"P1D"'),
+            (datetime.timedelta(days=1, minutes=1), b'"P1DT1M"'),
+            (datetime.timedelta(days=2, microseconds=500000), b'"P2DT0.5S"'),
+            (datetime.timedelta(seconds=59, microseconds=120), b'"PT59.00012S"'),
+            (datetime.timedelta(microseconds=1), b'"PT0.000001S"'),
+            (datetime.timedelta.max, b'"P999999999DT23H59M59.999999S"'),
+        ],
+    )
+    def test_timedelta_iso8601(self, value):
+        """
+        OPT_TIMEDELTA_ISO8601
+        """
+        val, expected = value
+        assert orjson.dumps(val, option=orjson.OPT_TIMEDELTA_ISO8601) == expected
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            (-datetime.timedelta(seconds=1), b'"-PT1S"'),
+            (-datetime.timedelta(microseconds=1), b'"-PT0.000001S"'),
+            (datetime.timedelta(hours=-1, microseconds=500000), b'"-PT59M59.5S"'),
+            (-datetime.timedelta(days=1, hours=1), b'"-P1DT1H"'),
+            (datetime.timedelta.min, b'"-P999999999D"'),
+        ],
+    )
+    def test_timedelta_iso8601_negative(self, value):
+        """
+        OPT_TIMEDELTA_ISO8601 negative
+        """
+        val, expected = value
+        assert orjson.dumps(val, option=orjson.OPT_TIMEDELTA_ISO8601) == expected
+
+    def test_timedelta_list(self):
+        """
+        datetime.timedelta in list
+        """
+        assert (
+            orjson.dumps(
+                [datetime.timedelta(seconds=1), datetime.timedelta(minutes=1)],
+                option=orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'["PT1S","PT1M"]'
+        )
+
+    def test_timedelta_dict_value(self):
+        """
+        datetime.timedelta as dict value
+        """
+        assert (
+            orjson.dumps(
+                {"a": datetime.timedelta(seconds=1)},
+                option=orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'{"a":"PT1S"}'
+        )
+
+    def test_timedelta_dict_key(self):
+        """
+        datetime.timedelta as dict key with OPT_NON_STR_KEYS
+        """
+        obj = {datetime.timedelta(seconds=1, microseconds=500000): 1}
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_TIMEDELTA_ISO8601 | orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"PT1.5S":1}'
+        )
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_TIMEDELTA_SECONDS | orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"1.5":1}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
@@ -0,0 +1,165 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import array
+import collections
+import dataclasses
+import datetime
+import decimal
+import enum
+import ipaddress
+import pathlib
+import uuid
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+    b: str
+    c: "Dataclass | None" = None
+
+
+class AnEnum(enum.Enum):
+    ONE = 1
+
+
+class StrSubclass(str):
+    pass
+
+
+class IntSubclass(int):
+    pass
+
+
+Point = collections.namedtuple("Point", ["x", "y"])
+
+OPTION = (
+    orjson.OPT_SERIALIZE_ARRAY
+    | orjson.OPT_SERIALIZE_COMPLEX
+    | orjson.OPT_SERIALIZE_DECIMAL
+    | orjson.OPT_SERIALIZE_IPADDRESS
+    | orjson.OPT_SERIALIZE_NAMEDTUPLE_AS_OBJECT
+    | orjson.OPT_SERIALIZE_PATH
+    | orjson.OPT_SERIALIZE_RANGE
+    | orjson.OPT_SERIALIZE_SET
+)
+
+FORMATS = {"bytes_format": "base64"}
+
+VALUES = (
+    uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece"),
+    datetime.datetime(2020, 1, 2, 3, 4, 5, 6),
+    datetime.datetime(2020, 1, 2, 3, 4, 5, tzinfo=datetime.timezone.utc),
+    datetime.date(2020, 1, 2),
+    datetime.time(3, 4, 5),
+    Dataclass(1, "a", Dataclass(2, "b")),
+    AnEnum.ONE,
+    StrSubclass("a"),
+    IntSubclass(1),
+    "a",
+    1,
+    2**64 - 1,
+    -(2**63),
+    1.5,
+    True,
+    None,
+    {"a": 1, "b": [2]},
+    [1, "a"],
+    (1, "a"),
+    orjson.Fragment(b'{"a":1}'),
+    decimal.Decimal("1.5"),
+    pathlib.PurePosixPath("/a/b"),
+    ipaddress.ip_address("192.168.0.1"),
+    array.array("i", [1, 2]),
+    {1},
+    frozenset({1}),
+    Point(1, 2),
+    1 + 2j,
+    range(1, 3),
+    b"ab",
+)
+
+
+class TestTopLevel:
+    @pytest.mark.parametrize("obj", VALUES)
+    def test_toplevel_same_as_nested(self, obj):
+        """
+        dumps() of an object is the same at the root as in a list or dict
+        """
+        for option in (OPTION, OPTION | orjson.OPT_SORT_KEYS):
+            root = orjson.dumps(obj, option=option, **FORMATS)
+            assert orjson.dumps([obj], option=option, **FORMATS) == (
+                b"[" + root + b"]"
+            )
+            assert orjson.dumps({"k": obj}, option=option, **FORMATS) == (
+                b'{"k":' + root + b"}"
+            )
+            assert orjson.dumps({"k": [obj]}, option=option, **FORMATS) == (
+                b'{"k":[' + root + b"]}"
+            )
+
+    @pytest.mark.parametrize("obj", VALUES)
+    def test_toplevel_indent_same_as_nested(self, obj):
+        """
+        dumps() of an object is the same at the root as in a list with
+        OPT_INDENT_2
+        """
+        option = OPTION | orjson.OPT_INDENT_2
+        root = orjson.dumps(obj, option=option, **FORMATS)
+        nested = orjson.dumps([obj], option=option, **FORMATS)
+        assert nested == b"[\n  " + root.replace(b"\n", b"\n  ") + b"\n]"
+
+    def test_toplevel_uuid(self):
+        """
+        dumps() uuid.UUID at the root
+        """
+        obj = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert orjson.dumps(obj) == b'"7202d115-7ff3-4c81-a7c1-2a1f067b1ece"'
+        assert (
+            orjson.dumps(obj, uuid_format="int")
+            == b"151546616840194781678008611711208857294"
+        )
+
+    def test_toplevel_datetime(self):
+        """
+        dumps() datetime.datetime at the root
+        """
+        obj = datetime.datetime(2020, 1, 2, 3, 4, 5, 6)
+        assert orjson.dumps(obj) == b'"2020-01-02T03:04:05.000006"'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NAIVE_UTC | orjson.OPT_UTC_Z)
+            == b'"2020-01-02T03:04:05.000006Z"'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_MICROSECONDS)
+            == b'"2020-01-02T03:04:05"'
+        )
+
+    def test_toplevel_dataclass(self):
+        """
+        dumps() dataclass at the root
+        """
+        obj = Dataclass(1, "a", Dataclass(2, "b"))
+        assert (
+            orjson.dumps(obj) == b'{"a":1,"b":"a","c":{"a":2,"b":"b","c":null}}'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SKIP_NONE_VALUES)
+            == b'{"a":1,"b":"a","c":{"a":2,"b":"b"}}'
+        )
+
+    def test_toplevel_passthrough(self):
+        """
+        dumps() passthrough options apply at the root
+        """
+        for obj, option in (
+            (datetime.datetime(2020, 1, 2), orjson.OPT_PASSTHROUGH_DATETIME),
+            (Dataclass(1, "a"), orjson.OPT_PASSTHROUGH_DATACLASS),
+            (StrSubclass("a"), orjson.OPT_PASSTHROUGH_SUBCLASS),
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=option)
+            assert orjson.dumps(obj, default=lambda _: "x", option=option) == b'"x"'
@@ -0,0 +1,84 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+
+def loads(val):
+    return orjson.loads(val, option=orjson.OPT_ALLOW_TRAILING_COMMA)
+
+
+class TestAllowTrailingComma:
+    def test_trailing_comma_default(self):
+        """
+        loads() trailing comma is an error by default
+        """
+        for val in ("[1,]", '{"a": 1,}', "[[1,]]"):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(val)
+            assert exc_info.value.msg == "trailing comma is not allowed"
+
+    def test_trailing_comma_array(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA array
+        """
+        assert loads("[1,]") == [1]
+        assert loads("[1, 2 , ]") == [1, 2]
+        assert loads("[[1,],[2,],]") == [[1], [2]]
+        assert loads('["a",\n]') == ["a"]
+
+    def test_trailing_comma_object(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA object
+        """
+        assert loads('{"a": 1,}') == {"a": 1}
+        assert loads('{"a": {"b": [1,],}, }') == {"a": {"b": [1]}}
+
+    def test_trailing_comma_invalid(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA still rejects other commas
+        """
+        for val in (
+            "[,]",
+            "[,1]",
+            "[1,,]",
+            "[1,,2]",
+            "{,}",
+            '{,"a": 1}',
+            '{"a": 1,,}',
+            '{"a",}',
+            '{"a": ,}',
+            "1,",
+            "[1],",
+            "[1,",
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                loads(val)
+
+    def test_trailing_comma_output(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA output is the same as without
+        """
+        val = '{"a": [1, {"b": null}], "c": "d"}'
+        assert loads(val) == orjson.loads(val)
+        assert loads(val.replace("]", ",]").replace("}", ",}")) == orjson.loads(val)
+
+    def test_trailing_comma_comments(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA with OPT_ALLOW_COMMENTS
+        """
+        val = '{\n  "a": [1, 2,], // a\n  "b": 3, /* b */\n}'
+        option = orjson.OPT_ALLOW_TRAILING_COMMA | orjson.OPT_ALLOW_COMMENTS
+        assert orjson.loads(val, option=option) == {"a": [1, 2], "b": 3}
+
+    def test_trailing_comma_object_hook(self):
+        """
+        loads() OPT_ALLOW_TRAILING_COMMA with object_hook
+        """
+        val = orjson.loads(
+            '[{"a": 1,}, {},]',
+            option=orjson.OPT_ALLOW_TRAILING_COMMA,
+            object_hook=len,
+        )
+        assert val == [1, 0]
@@ -1,6 +1,8 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import dataclasses
 import io
+import json
 import sys
 
 import pytest
@@ -187,6 +189,62 @@ class TestType:
     def test_str_escape_x32_buffer(self):
         orjson.dumps(["\t" * 4096] * 1024)
 
+    @pytest.mark.parametrize("char", ['"', "\\", "\x00", "\x1f", "\n"])
+    def test_str_escape_offset(self, char):
+        """
+        str escape after every length of escape-free prefix
+        """
+        text = "a !#[]~\x7f\xe9\u597d\U0001f408" * 4
+        for idx in range(len(text) + 1):
+            obj = text[:idx] + char + text[idx:]
+            assert orjson.dumps(obj) == json.dumps(obj, ensure_ascii=False).encode(
+                "utf-8"
+            )
+
+    def test_str_escape_free(self):
+        """
+        str without escapes, of each width and long
+        """
+        for obj in ("a !#[]~\x7f" * 4096, "\xe9\u597d\uffff\U0001f408" * 4096):
+            assert orjson.dumps(obj) == b'"' + obj.encode("utf-8") + b'"'
+
+    @pytest.mark.parametrize("char", ['"', "\\", "\x00", "\x1f", "/", "\xe9", "\u597d"])
+    def test_dict_key_escape_offset(self, char):
+        """
+        dict key escape or non-ASCII character at every offset of keys of
+        every length around a multiple of 8 bytes and the longest written as is
+        """
+        for length in (0, 1, 7, 8, 9, 15, 16, 17, 63, 64, 65):
+            for idx in range(length + 1):
+                key = "a" * idx + char + "b" * (length - idx)
+                obj = {key: 1}
+                assert orjson.dumps(obj) == json.dumps(
+                    obj, ensure_ascii=False, separators=(",", ":")
+                ).encode("utf-8")
+                assert orjson.dumps(obj, option=orjson.OPT_ASCII) == json.dumps(
+                    obj, separators=(",", ":")
+                ).encode("utf-8")
+                option = orjson.OPT_ESCAPE_FORWARD_SLASH
+                assert orjson.dumps(obj, option=option) == (
+                    b"{" + orjson.dumps(key, option=option) + b":1}"
+                )
+
+    def test_dict_key_escape_free(self):
+        """
+        dict and dataclass keys that are ASCII without escapes
+        """
+        for length in (0, 1, 7, 8, 9, 64, 65, 1024):
+            key = "".join(chr(32 + idx % 95) for idx in range(length))
+            key = key.replace('"', "a").replace("\\", "b")
+            obj = {key: 1}
+            assert orjson.dumps(obj) == b'{"' + key.encode("utf-8") + b'":1}'
+            assert orjson.dumps(obj, option=orjson.OPT_ASCII) == orjson.dumps(obj)
+        fields = ["a" * length for length in (1, 7, 8, 9, 64, 65)]
+        obj = dataclasses.make_dataclass("Keys", fields)(*range(len(fields)))
+        assert orjson.dumps(obj) == json.dumps(
+            dataclasses.asdict(obj), separators=(",", ":")
+        ).encode("utf-8")
+
     def test_str_emoji(self):
         ref = "®️"
         assert orjson.loads(orjson.dumps(ref)) == ref
@@ -443,6 +501,106 @@ class TestType:
         for val in (18446744073709551616, -9223372036854775809):
             pytest.raises(orjson.JSONEncodeError, orjson.dumps, val)
 
+    @pytest.mark.parametrize(
+        "val",
+        [
+            2**64 - 1,
+            2**64,
+            2**64 + 1,
+            10**20 - 1,
+            10**20,
+            -(2**63),
+            -(2**63) - 1,
+            -(2**64) + 1,
+            -(2**64),
+            -(2**64) - 1,
+            2**128,
+            -(2**128),
+            10**39 + 1234567890123456789,
+            int("9" * 100),
+            -int("9" * 400),
+        ],
+    )
+    def test_int_big_loads(self, val):
+        """
+        loads() int beyond 64 bits is an exact int
+        """
+        text = str(val)
+        for doc, expected in (
+            (text, val),
+            (f"[{text}]", [val]),
+            (f'{{"a":[1,{text},2.5]}}', {"a": [1, val, 2.5]}),
+            (f"[{text} ]", [val]),
+        ):
+            obj = orjson.loads(doc)
+            assert obj == expected
+            assert orjson.loads(doc, option=orjson.OPT_ALLOW_COMMENTS) == expected
+        assert type(orjson.loads(text)) is int
+
+    def test_int_big_loads_fraction(self):
+        """
+        loads() number beyond 64 bits with a fraction or exponent is a float
+        """
+        for text in (
+            "18446744073709551616.0",
+            "18446744073709551616e0",
+            "1234567890123456789012345678901234567890.5",
+            "-18446744073709551616E+1",
+        ):
+            for option in (None, orjson.OPT_ALLOW_COMMENTS):
+                val = orjson.loads(text, option=option)
+                assert type(val) is float
+                assert val == float(text)
+
+    def test_int_big_loads_large_float(self):
+        """
+        loads() floats of at least 2**63 in magnitude alongside ints beyond 64
+        bits, and errors in documents having them
+        """
+        doc = '{"a":[1e300,-9.3e18,18446744073709551616,1.5],"b":9223372036854775808.0}'
+        obj = orjson.loads(doc)
+        assert obj == {
+            "a": [1e300, -9.3e18, 18446744073709551616, 1.5],
+            "b": 9223372036854775808.0,
+        }
+        assert [type(val) for val in obj["a"]] == [float, float, int, float]
+        assert type(obj["b"]) is float
+        for doc, pos in (("[1e20, ]", 5), ('{"a":1e20,', 10), ("[1e400, 1]", 1)):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(doc)
+            assert exc_info.value.pos == pos
+        assert orjson.loads("[1]") == [1]
+
+    def test_int_big_roundtrip(self):
+        """
+        loads() 40-digit int round-trips through a str
+        """
+        val = 1234567890123456789012345678901234567890
+        assert str(orjson.loads(str(val))) == str(val)
+        assert orjson.loads(f"[{val},{val}]") == [val, val]
+
+    @pytest.mark.skipif(
+        not hasattr(sys, "set_int_max_str_digits"),
+        reason="sys.set_int_max_str_digits() is not available",
+    )
+    def test_int_big_loads_max_str_digits(self):
+        """
+        loads() int with more digits than Python converts raises
+        """
+        previous = sys.get_int_max_str_digits()
+        sys.set_int_max_str_digits(640)
+        try:
+            text = "1" * 641
+            for doc, pos in ((text, 0), (f"[1, {text}]", 4), (f'{{"a":{text}}}', 5)):
+                for option in (None, orjson.OPT_ALLOW_COMMENTS):
+                    with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                        orjson.loads(doc, option=option)
+                    assert exc_info.value.msg == "number is too large to convert to int"
+                    assert exc_info.value.pos == pos
+            assert orjson.loads("1" * 640) == int("1" * 640)
+        finally:
+            sys.set_int_max_str_digits(previous)
+
     def test_float(self):
         """
         float
@@ -534,6 +692,61 @@ class TestType:
         assert orjson.dumps(obj) == ref.encode("utf-8")
         assert orjson.loads(ref) == list(obj)
 
+    def test_list_int(self):
+        """
+        list of int
+        """
+        obj = [0, 1, -1, 2**63 - 1, -(2**63), 2**64 - 1] * 100
+        assert orjson.dumps(obj) == (
+            "[" + ",".join(str(val) for val in obj) + "]"
+        ).encode("utf-8")
+        assert orjson.dumps(tuple(obj)) == orjson.dumps(obj)
+        assert orjson.dumps([[1, 2], (3,)]) == b"[[1,2],[3]]"
+
+    def test_list_int_then_other(self):
+        """
+        list of int followed by elements of other types
+        """
+        assert orjson.dumps([1, 2, True, 3, "a", 4, None]) == (
+            b'[1,2,true,3,"a",4,null]'
+        )
+        assert orjson.dumps(["a", 1, 2]) == b'["a",1,2]'
+        assert orjson.dumps([1, {"a": [2, 3.5]}]) == b'[1,{"a":[2,3.5]}]'
+
+    def test_list_int_subclass(self):
+        """
+        list of int with an int subclass
+        """
+
+        class IntSubclass(int):
+            pass
+
+        assert orjson.dumps([1, IntSubclass(2), 3]) == b"[1,2,3]"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([1, IntSubclass(2)], option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+
+    def test_list_int_options(self):
+        """
+        list of int with options
+        """
+        obj = [1, 2, 3]
+        assert orjson.dumps(obj, option=orjson.OPT_INDENT_2) == b"[\n  1,\n  2,\n  3\n]"
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SPACE_AFTER_COMMA) == b"[1, 2, 3]"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([1, 2**53], option=orjson.OPT_STRICT_INTEGER)
+
+    def test_list_int_error(self):
+        """
+        list of int with an int exceeding 64 bits
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([1, 2, 2**64])
+        assert str(exc_info.value) == "Integer exceeds 64-bit range (output byte 5)"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([1, "a", -(2**63) - 1])
+
     def test_object(self):
         """
         object() dumps()
@@ -20,3 +20,23 @@ class TestTypedDict:
 
         obj = TypedDict1(a="a", b=1)
         assert orjson.dumps(obj) == b'{"a":"a","b":1}'
+
+    def test_typeddict_order(self):
+        """
+        dumps() TypedDict is a dict at runtime and so is serialized in
+        insertion order, not annotation order, with keys not annotated
+        """
+
+        class TypedDict2(TypedDict):
+            b: int
+            a: str
+
+        obj = TypedDict2(a="a", b=1)
+        assert type(obj) is dict
+        assert orjson.dumps(obj) == b'{"a":"a","b":1}'
+        obj["c"] = None  # type: ignore
+        assert orjson.dumps(obj) == b'{"a":"a","b":1,"c":null}'
+        assert (
+            orjson.dumps({key: obj[key] for key in TypedDict2.__annotations__})
+            == b'{"b":1,"a":"a"}'
+        )
@@ -0,0 +1,132 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import pytest
+
+import orjson
+
+BOM = b"\xef\xbb\xbf"
+
+
+class TestUtf8Bom:
+    def test_utf8_bom(self):
+        """
+        dumps() OPT_UTF8_BOM
+        """
+        assert orjson.dumps({"a": 1}, option=orjson.OPT_UTF8_BOM) == BOM + b'{"a":1}'
+        assert orjson.dumps("", option=orjson.OPT_UTF8_BOM) == BOM + b'""'
+        assert orjson.dumps({"a": 1}) == b'{"a":1}'
+
+    def test_utf8_bom_newline_indent(self):
+        """
+        dumps() OPT_UTF8_BOM with OPT_APPEND_NEWLINE and OPT_INDENT_2
+        """
+        obj = {"a": [1]}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_UTF8_BOM | orjson.OPT_APPEND_NEWLINE)
+            == BOM + b'{"a":[1]}\n'
+        )
+        option = orjson.OPT_UTF8_BOM | orjson.OPT_APPEND_NEWLINE | orjson.OPT_INDENT_2
+        assert (
+            orjson.dumps(obj, option=option)
+            == BOM + orjson.dumps(obj, option=option & ~orjson.OPT_UTF8_BOM)
+        )
+
+    def test_utf8_bom_dumps_str(self):
+        """
+        dumps_str() OPT_UTF8_BOM
+        """
+        assert orjson.dumps_str([1], option=orjson.OPT_UTF8_BOM) == "\ufeff[1]"
+
+    def test_utf8_bom_dumps_into(self):
+        """
+        dumps_into() OPT_UTF8_BOM is written after the existing content
+        """
+        buffer = bytearray(b"abc")
+        assert orjson.dumps_into([1], buffer, option=orjson.OPT_UTF8_BOM) == 6
+        assert buffer == b"abc" + BOM + b"[1]"
+
+    def test_utf8_bom_error(self):
+        """
+        dumps() OPT_UTF8_BOM error output byte includes the byte order mark
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([object()], option=orjson.OPT_UTF8_BOM)
+        assert str(exc_info.value) == (
+            "Type is not JSON serializable: object at $[0] (output byte 4)"
+        )
+
+    def test_utf8_bom_canonical(self):
+        """
+        dumps() OPT_UTF8_BOM with OPT_CANONICAL
+        """
+        assert (
+            orjson.dumps(
+                {"b": 1, "a": 2}, option=orjson.OPT_UTF8_BOM | orjson.OPT_CANONICAL
+            )
+            == BOM + b'{"a":2,"b":1}'
+        )
+
+    def test_utf8_bom_encoder(self):
+        """
+        orjson.Encoder() OPT_UTF8_BOM is written once at the beginning
+        """
+        encoder = orjson.Encoder(option=orjson.OPT_UTF8_BOM)
+        assert encoder.write_chunk(1) == BOM + b"[1"
+        assert encoder.write_chunk(2) == b",2"
+        assert encoder.close() == b"]"
+        encoder = orjson.Encoder(option=orjson.OPT_UTF8_BOM)
+        assert encoder.close() == BOM + b"[]"
+
+    def test_loads_utf8_bom(self):
+        """
+        loads() skips a leading byte order mark
+        """
+        for data in (
+            BOM + b'{"a":[1]}',
+            bytearray(BOM + b"[]"),
+            memoryview(BOM + b"1"),
+        ):
+            assert orjson.loads(data) == orjson.loads(bytes(data)[3:])
+        assert orjson.loads('\ufeff{"a":"\ufeff"}') == {"a": "\ufeff"}
+
+    def test_loads_utf8_bom_roundtrip(self):
+        """
+        loads() output of OPT_UTF8_BOM
+        """
+        obj = {"a": [1, 2.5, None, "\u00e9"]}
+        for option in (orjson.OPT_UTF8_BOM, orjson.OPT_UTF8_BOM | orjson.OPT_INDENT_2):
+            assert orjson.loads(orjson.dumps(obj, option=option)) == obj
+        assert orjson.loads(orjson.dumps_str(obj, option=orjson.OPT_UTF8_BOM)) == obj
+
+    def test_loads_utf8_bom_option(self):
+        """
+        loads() skips a leading byte order mark with options
+        """
+        option = orjson.OPT_ALLOW_TRAILING_COMMA
+        assert orjson.loads(BOM + b"[1,]", option=option) == [1]
+        assert orjson.loads(BOM + b"[[1]]", max_depth=2) == [[1]]
+        assert orjson.loads(BOM + b'{"a":1}', object_hook=len) == 1
+
+    def test_loads_utf8_bom_invalid(self):
+        """
+        loads() byte order mark only, repeated, or not at the beginning
+        """
+        for data in (BOM, BOM + BOM + b"[]", b"[" + BOM + b"]", b" " + BOM + b"[]"):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(data)
+
+    def test_loads_utf8_bom_error_pos(self):
+        """
+        loads() error position is in the input including the byte order mark
+        """
+        for data in (b'[1,"a",x]', b'[\n1,\n]', b'{"a":'):
+            with pytest.raises(orjson.JSONDecodeError) as without_bom:
+                orjson.loads(data)
+            with pytest.raises(orjson.JSONDecodeError) as with_bom:
+                orjson.loads(BOM + data)
+            assert with_bom.value.pos == without_bom.value.pos + 1
+            assert with_bom.value.doc == "\ufeff" + data.decode("utf-8")
+            if without_bom.value.lineno == 1:
+                assert with_bom.value.colno == without_bom.value.colno + 1
+            else:
+                assert with_bom.value.colno == without_bom.value.colno
@@ -1,5 +1,6 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 
+import base64
 import uuid
 
 import pytest
@@ -100,3 +101,95 @@ class TestUUID:
         )
         for val in uuids:
             assert orjson.dumps(val) == f'"{val}"'.encode("utf-8")
+
+
+class TestUUIDOption:
+    def test_uuid_as_int(self):
+        """
+        uuid_format="int"
+        """
+        for val in (
+            uuid.UUID(int=0),
+            uuid.UUID(int=2**64),
+            uuid.UUID(int=2**128 - 1),
+            uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+        ):
+            output = orjson.dumps([val], uuid_format="int")
+            assert output == f"[{val.int}]".encode("ascii")
+
+    def test_uuid_as_int_indent(self):
+        """
+        uuid_format="int" in a dict with OPT_INDENT_2
+        """
+        val = uuid.UUID(int=2**128 - 1)
+        assert orjson.dumps(
+            {"a": val}, option=orjson.OPT_INDENT_2, uuid_format="int"
+        ) == f'{{\n  "a": {val.int}\n}}'.encode("ascii")
+
+    def test_uuid_as_int_strict(self):
+        """
+        uuid_format="int" with OPT_STRICT_INTEGER raises beyond 53 bits
+        """
+        val = uuid.UUID(int=2**53 - 1)
+        assert (
+            orjson.dumps(val, option=orjson.OPT_STRICT_INTEGER, uuid_format="int")
+            == b"9007199254740991"
+        )
+        for val in (uuid.UUID(int=2**53), uuid.UUID(int=2**100)):
+            for obj in (val, [val], {"a": val}):
+                with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                    orjson.dumps(
+                        obj, option=orjson.OPT_STRICT_INTEGER, uuid_format="int"
+                    )
+                assert str(exc_info.value).startswith("Integer exceeds 53-bit range")
+
+    def test_uuid_as_base64(self):
+        """
+        uuid_format="base64"
+        """
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        assert (
+            orjson.dumps(val, uuid_format="base64")
+            == b'"+B1Prn3sEdCnZQCgyR5r9g=="'
+        )
+        assert (
+            orjson.dumps({"a": [val]}, uuid_format="base64url")
+            == b'{"a":["-B1Prn3sEdCnZQCgyR5r9g=="]}'
+        )
+
+    def test_uuid_as_base64_bytes(self):
+        """
+        uuid_format="base64" is of UUID.bytes
+        """
+        for val in (uuid.UUID(int=0), uuid.UUID(int=2**128 - 1), uuid.uuid4()):
+            output = orjson.dumps(val, uuid_format="base64")
+            assert base64.b64decode(orjson.loads(output)) == val.bytes
+
+    def test_uuid_option_non_str_keys(self):
+        """
+        uuid_format does not affect dict keys
+        """
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        for uuid_format in ("int", "base64", "base64url"):
+            assert (
+                orjson.dumps(
+                    {val: 1}, option=orjson.OPT_NON_STR_KEYS, uuid_format=uuid_format
+                )
+                == b'{"f81d4fae-7dec-11d0-a765-00a0c91e6bf6":1}'
+            )
+
+    def test_uuid_format_invalid(self):
+        """
+        uuid_format that is not one of its values raises
+        """
+        for uuid_format in ("hex", "INT", b"int", 1):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(uuid.uuid4(), uuid_format=uuid_format)
+            assert str(exc_info.value) == "Invalid uuid_format"
+
+    def test_uuid_format_none(self):
+        """
+        uuid_format=None is the default
+        """
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        assert orjson.dumps(val, uuid_format=None) == orjson.dumps(val)
@@ -0,0 +1,131 @@
+# SPDX-License-Identifier: (Apache-2.0 OR MIT)
+
+import decimal
+
+import pytest
+
+import orjson
+
+OPTION = orjson.OPT_WHOLE_FLOAT_AS_INT
+
+
+def loads(doc):
+    return orjson.loads(doc, option=OPTION)
+
+
+class TestWholeFloatAsInt:
+    def test_whole_float_as_int_default(self):
+        """
+        loads() whole floats are float without OPT_WHOLE_FLOAT_AS_INT
+        """
+        assert type(orjson.loads("1.0")) is float
+        assert type(orjson.loads("1e2")) is float
+
+    @pytest.mark.parametrize(
+        "val",
+        [
+            ("1.0", 1),
+            ("-1.0", -1),
+            ("0.0", 0),
+            ("-0.0", 0),
+            ("1.000", 1),
+            ("1e2", 100),
+            ("1E+2", 100),
+            ("-2.5e1", -25),
+            ("1000e-3", 1),
+            ("0.5e1", 5),
+            ("123456789.0", 123456789),
+            ("9007199254740992.0", 2**53),
+        ],
+    )
+    def test_whole_float_as_int(self, val):
+        """
+        OPT_WHOLE_FLOAT_AS_INT floats with no fraction are int
+        """
+        doc, expected = val
+        assert type(loads(doc)) is int
+        assert loads(doc) == expected
+
+    @pytest.mark.parametrize("doc", ["1.5", "-0.1", "1e-2", "1.0000001", "5e-324"])
+    def test_whole_float_as_int_fraction(self, doc):
+        """
+        OPT_WHOLE_FLOAT_AS_INT floats with a fraction are float
+        """
+        val = loads(doc)
+        assert type(val) is float
+        assert val == float(doc)
+
+    @pytest.mark.parametrize(
+        "doc", ["1e40", "-1e40", "9223372036854775808.0", "1.7976931348623157e308"]
+    )
+    def test_whole_float_as_int_large(self, doc):
+        """
+        OPT_WHOLE_FLOAT_AS_INT floats out of the range of a 64-bit integer are
+        float
+        """
+        val = loads(doc)
+        assert type(val) is float
+        assert val == float(doc)
+
+    def test_whole_float_as_int_range(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT at the limits of a 64-bit integer
+        """
+        assert loads("-9223372036854775808.0") == -(2**63)
+        assert type(loads("-9223372036854775808.0")) is int
+        assert loads("9223372036854774784.0") == 2**63 - 1024
+        assert type(loads("-9223372036854777856.0")) is float
+
+    def test_whole_float_as_int_integer(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT does not change integers
+        """
+        assert loads("[1, -1, 18446744073709551615, 100000000000000000000000]") == [
+            1,
+            -1,
+            18446744073709551615,
+            100000000000000000000000,
+        ]
+
+    def test_whole_float_as_int_nested(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT in arrays and objects
+        """
+        val = loads('{"a": [1.0, 1.5, {"b": 2e0}], "c": "1.0"}')
+        assert val == {"a": [1, 1.5, {"b": 2}], "c": "1.0"}
+        assert type(val["a"][0]) is int
+        assert type(val["a"][1]) is float
+
+    def test_whole_float_as_int_infinity(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT numbers that are infinity as a double raise
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            loads("1e400")
+
+    def test_whole_float_as_int_nonfinite(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT OPT_ALLOW_NONFINITE
+        """
+        val = orjson.loads(
+            "[NaN, Infinity, -Infinity, 1.0]",
+            option=OPTION | orjson.OPT_ALLOW_NONFINITE,
+        )
+        assert val[0] != val[0]
+        assert val[1:] == [float("inf"), float("-inf"), 1]
+        assert type(val[3]) is int
+
+    def test_whole_float_as_int_parse_decimal(self):
+        """
+        OPT_PARSE_DECIMAL takes precedence over OPT_WHOLE_FLOAT_AS_INT
+        """
+        val = orjson.loads("1.0", option=OPTION | orjson.OPT_PARSE_DECIMAL)
+        assert type(val) is decimal.Decimal
+        assert str(val) == "1.0"
+
+    def test_whole_float_as_int_dumps(self):
+        """
+        OPT_WHOLE_FLOAT_AS_INT is not valid for dumps()
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(1.0, option=OPTION)